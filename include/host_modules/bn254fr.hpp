@@ -1090,6 +1090,11 @@ struct bn254fr_module : public host_module {
         if (!call_cache_.contains(addr)) {
             call_cache_[addr] = call_lookup_table_[name];
         }
+        // Only the assertions and checked decompositions emit constraints
+        if (host_call_trace::instance().enabled &&
+            (name.starts_with("bn254fr_assert_") || name.find("_checked") != std::string_view::npos)) {
+            host_call_trace::instance().record(module_name, name);
+        }
         (this->*call_cache_[addr])();
         return exec_ok();
     }
@@ -458,6 +458,7 @@ struct ecc_module : public host_module {
     }
 
     exec_result call_host(address_t global_id, std::string_view name) override {
+        // Hints only; the SDK constrains the results with bn254fr calls
         (this->*call_lookup_table_[name])();
         return exec_ok();
     }
@@ -16,6 +16,13 @@
 
 #pragma once
 
+#include <algorithm>
+#include <cstdlib>
+#include <filesystem>
+#include <fstream>
+#include <string>
+#include <vector>
+
 #include <boost/algorithm/hex.hpp>
 #include <host_modules/host_interface.hpp>
 #include <util/log.hpp>
@@ -157,6 +164,133 @@ struct env_module : public host_module {
         ctx_->stack_push(len);
     }
 
+    void file_read_chunk() {
+        u32 len      = ctx_->stack_pop().as_u32();
+        u64 offset   = ctx_->stack_pop().as_u64();
+        u64 name_ptr = ctx_->stack_pop().as_u64();
+        u64 buf_ptr  = ctx_->stack_pop().as_u64();
+
+        address_t addr        = ctx_->module()->memaddrs[0];
+        memory_instance& memi = ctx_->store()->memorys[addr];
+        auto *mem             = reinterpret_cast<char *>(memi.data.data());
+
+        if (buf_ptr + len > memi.data.size()) {
+            throw wasm_trap("file_read_chunk: buffer out of bounds");
+        }
+
+        std::filesystem::path p{mem + name_ptr};
+        std::ifstream ifs(p, std::ios::binary);
+        if (!ifs) {
+            throw wasm_trap("Cannot read file "s + p.string());
+        }
+
+        // Reads past the end return 0 bytes
+        u64 size = std::filesystem::file_size(p);
+        u32 n    = 0;
+        if (offset < size) {
+            n = static_cast<u32>(std::min<u64>(len, size - offset));
+            ifs.seekg(offset);
+            if (!ifs.read(mem + buf_ptr, n)) {
+                throw wasm_trap("Cannot read file "s + p.string());
+            }
+        }
+
+        ctx_->stack_push(n);
+    }
+
+    // Writes are only permitted when LIGETRON_OUTPUT_DIR names a directory.
+    // The file name must be relative and stay inside that directory.
+    void file_put() {
+        u32 len      = ctx_->stack_pop().as_u32();
+        u64 buf_ptr  = ctx_->stack_pop().as_u64();
+        u64 name_ptr = ctx_->stack_pop().as_u64();
+
+        address_t addr        = ctx_->module()->memaddrs[0];
+        memory_instance& memi = ctx_->store()->memorys[addr];
+        auto *mem             = reinterpret_cast<const char *>(memi.data.data());
+
+        if (buf_ptr + len > memi.data.size()) {
+            throw wasm_trap("file_put: buffer out of bounds");
+        }
+
+        const char *out_dir = std::getenv("LIGETRON_OUTPUT_DIR");
+        std::filesystem::path name{mem + name_ptr};
+        bool escapes = name.is_absolute() ||
+            std::any_of(name.begin(), name.end(),
+                        [](const auto& part) { return part == ".."; });
+        if (out_dir == nullptr || escapes) {
+            ctx_->stack_push(static_cast<u32>(-1));
+            return;
+        }
+
+        std::filesystem::path p = std::filesystem::path{out_dir} / name;
+        std::ofstream ofs(p, std::ios::binary | std::ios::trunc);
+        if (!ofs.write(mem + buf_ptr, len)) {
+            ctx_->stack_push(static_cast<u32>(-1));
+            return;
+        }
+
+        ctx_->stack_push(len);
+    }
+
+    // Match `name` against a glob supporting `*` and `?`
+    static bool glob_match(std::string_view pat, std::string_view name) {
+        if (pat.empty()) return name.empty();
+        if (pat[0] == '*') {
+            for (size_t i = 0; i <= name.size(); i++) {
+                if (glob_match(pat.substr(1), name.substr(i))) return true;
+            }
+            return false;
+        }
+        if (name.empty()) return false;
+        if (pat[0] != '?' && pat[0] != name[0]) return false;
+        return glob_match(pat.substr(1), name.substr(1));
+    }
+
+    // List files whose name matches the pattern's last component, in the
+    // directory given by the rest of the pattern. Names are written to the
+    // buffer as NUL-terminated paths in sorted order; returns the total
+    // size needed, so a call with a zero-length buffer queries the size.
+    void file_list() {
+        u32 len         = ctx_->stack_pop().as_u32();
+        u64 buf_ptr     = ctx_->stack_pop().as_u64();
+        u64 pattern_ptr = ctx_->stack_pop().as_u64();
+
+        address_t addr        = ctx_->module()->memaddrs[0];
+        memory_instance& memi = ctx_->store()->memorys[addr];
+        auto *mem             = reinterpret_cast<char *>(memi.data.data());
+
+        if (buf_ptr + len > memi.data.size()) {
+            throw wasm_trap("file_list: buffer out of bounds");
+        }
+
+        std::filesystem::path pattern{mem + pattern_ptr};
+        std::filesystem::path dir = pattern.parent_path();
+        std::string glob          = pattern.filename().string();
+
+        std::vector<std::string> names;
+        std::error_code ec;
+        for (const auto& entry : std::filesystem::directory_iterator(
+                 dir.empty() ? std::filesystem::path{"."} : dir, ec)) {
+            if (entry.is_regular_file() &&
+                glob_match(glob, entry.path().filename().string())) {
+                names.push_back((dir / entry.path().filename()).string());
+            }
+        }
+        std::sort(names.begin(), names.end());
+
+        std::string out;
+        for (const auto& n : names) {
+            out += n;
+            out.push_back('\0');
+        }
+
+        if (out.size() <= len) {
+            std::copy(out.begin(), out.end(), mem + buf_ptr);
+        }
+        ctx_->stack_push(static_cast<u32>(out.size()));
+    }
+
     void assert_is_concrete() {
         auto s = ctx_->stack_pop();
         if (!s.is_val())
@@ -187,6 +321,14 @@ struct env_module : public host_module {
         ctx_->stack_push(std::move(range_checked_x));
     }
 
+    void constraint_trace_reset() {
+        host_call_trace::instance().reset();
+    }
+
+    void constraint_trace_digest() {
+        ctx_->stack_push(host_call_trace::instance().digest);
+    }
+
     void initialize() override {
         call_lookup_table_ = {
             { "assert_zero",       &Self::assert_zero        },
@@ -203,10 +345,21 @@ struct env_module : public host_module {
             {"dump_memory", &Self::dump_memory},
             {"file_size_get", &Self::file_size_get},
             {"file_get", &Self::file_get},
+            {"file_read_chunk", &Self::file_read_chunk},
+            {"file_put", &Self::file_put},
+            {"file_list", &Self::file_list},
+            {"constraint_trace_reset", &Self::constraint_trace_reset},
+            {"constraint_trace_digest", &Self::constraint_trace_digest},
         };
     }
 
     exec_result call_host(address_t global_id, std::string_view name) override {
+        // Only the assertions and private constants emit constraints
+        if (host_call_trace::instance().enabled &&
+            (name.starts_with("assert_") || name.ends_with("_private_const") ||
+             name.starts_with("witness_cast"))) {
+            host_call_trace::instance().record(module_name, name);
+        }
         (this->*call_lookup_table_[name])();
         return exec_ok();
     }
@@ -16,11 +16,65 @@
 
 #pragma once
 
+#include <cstdint>
 #include <string>
+#include <vector>
 #include <types.hpp>
 
 namespace ligero::vm {
 
+/// Running FNV-1a digest over the sequence of constraint-emitting host
+/// calls and their numeric arguments, so guests can snapshot-test that
+/// refactors keep the emitted circuit unchanged. Only records while
+/// enabled by the guest.
+struct host_call_trace {
+    static constexpr uint64_t offset_basis = 0xcbf29ce484222325ULL;
+    static constexpr uint64_t prime        = 0x100000001b3ULL;
+
+    bool enabled    = false;
+    uint64_t digest = offset_basis;
+
+    /// Numeric arguments (handle addresses and immediates) of the host call
+    /// being made, staged by the context before dispatching it
+    std::vector<uint64_t> operands;
+
+    static host_call_trace& instance() {
+        static host_call_trace trace;
+        return trace;
+    }
+
+    void reset() {
+        enabled = true;
+        digest  = offset_basis;
+    }
+
+    void record(std::string_view module, std::string_view name) {
+        if (!enabled) {
+            return;
+        }
+        absorb(module);
+        absorb(".");
+        absorb(name);
+        for (uint64_t op : operands) {
+            for (int i = 0; i < 8; i++) {
+                absorb_byte(static_cast<unsigned char>(op >> (8 * i)));
+            }
+        }
+        absorb(";");
+    }
+
+private:
+    void absorb_byte(unsigned char c) {
+        digest = (digest ^ c) * prime;
+    }
+
+    void absorb(std::string_view bytes) {
+        for (unsigned char c : bytes) {
+            absorb_byte(c);
+        }
+    }
+};
+
 struct host_module {
     virtual exec_result call_host(address_t addr, std::string_view name) = 0;
     virtual void initialize() = 0;
@@ -247,6 +247,7 @@ struct uint256_module : public host_module {
         if (!call_cache_.contains(addr)) {
             call_cache_[addr] = call_lookup_table_[name];
         }
+        // Hints only; the SDK constrains the results with bn254fr calls
         (this->*call_cache_[addr])();
         return exec_ok();
     }
@@ -242,9 +242,49 @@ struct vbn254fr_module : public host_module {
 
         mpz_class tmp;
         for (size_t k = 0; k < count; k++) {
-            mpz_import(tmp.get_mpz_t(), len, 1, sizeof(u8), 0, 0, bytes + len * count);
+            mpz_import(tmp.get_mpz_t(), len, 1, sizeof(u8), 0, 0, bytes + len * k);
+            mpz.push_back(tmp);
+        }
+
+        executor_.write_limbs(x, mpz);
+        ctx_->on_batch_init(x);
+    }
+
+    void vbn254fr_set_bytes_from_offsets() {
+        u64 len         = ctx_->stack_pop().as_u64();
+        u64 count       = ctx_->stack_pop().as_u64();
+        u32 offsets_ptr = ctx_->stack_pop().as_u32();
+        u32 bytes_ptr   = ctx_->stack_pop().as_u32();
+        u32 fp_addr     = ctx_->stack_pop().as_u32();
+
+        if (count > executor_.message_size()) {
+            throw wasm_trap("vbn254fr_set_bytes_from_offsets: too many offsets");
+        }
 
-            std::cout << "imported: " << tmp << std::endl;
+        const u64 mem_size = ctx_->memory_data().size();
+        if (offsets_ptr > mem_size || count * sizeof(u64) > mem_size - offsets_ptr) {
+            throw wasm_trap("vbn254fr_set_bytes_from_offsets: offsets out of bounds");
+        }
+
+        const auto *mem = ctx_->memory_data().data();
+        u32 handle = load_vbn254(fp_addr);
+        const u8 *bytes = reinterpret_cast<const u8*>(mem + bytes_ptr);
+        const u64 *offsets = reinterpret_cast<const u64*>(mem + offsets_ptr);
+
+        for (size_t k = 0; k < count; k++) {
+            if (bytes_ptr > mem_size
+                || offsets[k] > mem_size - bytes_ptr
+                || len > mem_size - bytes_ptr - offsets[k]) {
+                throw wasm_trap("vbn254fr_set_bytes_from_offsets: range out of bounds");
+            }
+        }
+
+        buffer_t x = get_buffer_from_offset(handle);
+        std::vector<mpz_class> mpz;
+
+        mpz_class tmp;
+        for (size_t k = 0; k < count; k++) {
+            mpz_import(tmp.get_mpz_t(), len, 1, sizeof(u8), 0, 0, bytes + offsets[k]);
             mpz.push_back(tmp);
         }
 
@@ -295,6 +335,40 @@ struct vbn254fr_module : public host_module {
         ctx_->on_batch_equal(out, in);
     }
 
+    /// Copy the first `count` lanes to memory as 32-byte big-endian values,
+    /// for debugging. The copy is not constrained.
+    void vbn254fr_get_bytes() {
+        u64 count    = ctx_->stack_pop().as_u64();
+        u32 addr     = ctx_->stack_pop().as_u32();
+        u32 out_addr = ctx_->stack_pop().as_u32();
+
+        constexpr size_t lane_bytes = 32;
+
+        if (count > executor_.message_size()) {
+            throw wasm_trap("vbn254fr_get_bytes: too many lanes");
+        }
+
+        const u64 mem_size = ctx_->memory_data().size();
+        if (out_addr > mem_size || count * lane_bytes > mem_size - out_addr) {
+            throw wasm_trap("vbn254fr_get_bytes: buffer out of bounds");
+        }
+
+        auto *mem = ctx_->memory_data().data();
+        u32 handle = load_vbn254(addr);
+        buffer_t x = get_buffer_from_offset(handle);
+
+        auto host = executor_.template copy_to_host<typename executor_t::device_bignum_type>(x);
+
+        memset(mem + out_addr, 0, count * lane_bytes);
+        for (size_t i = 0; i < count; i++) {
+            mpz_class v = host[i].to_mpz();
+            size_t size = (mpz_sizeinbase(v.get_mpz_t(), 2) + 7) / 8;
+            size_t written = 0;
+            mpz_export(mem + out_addr + i * lane_bytes + (lane_bytes - size),
+                       &written, 1, sizeof(u8), 0, 0, v.get_mpz_t());
+        }
+    }
+
     void vbn254fr_print() {
         u32 base = ctx_->stack_pop().as_u32();
         u32 addr = ctx_->stack_pop().as_u32();
@@ -568,6 +642,13 @@ struct vbn254fr_module : public host_module {
     }
 
     exec_result call_host(address_t addr, std::string_view name) override {
+        // Only copies, multiplications, divisions, assertions and bit
+        // decompositions emit constraints
+        if (host_call_trace::instance().enabled &&
+            (name == "vbn254fr_copy" || name == "vbn254fr_mulmod" || name == "vbn254fr_divmod" ||
+             name == "vbn254fr_assert_equal" || name == "vbn254fr_bit_decompose")) {
+            host_call_trace::instance().record(module_name, name);
+        }
         (this->*call_map_[name])();
         return exec_ok();
     }
@@ -583,8 +664,10 @@ struct vbn254fr_module : public host_module {
             { "vbn254fr_set_str_scalar",        &Self::vbn254fr_set_str_scalar         },
             { "vbn254fr_set_bytes",             &Self::vbn254fr_set_bytes              },
             { "vbn254fr_set_bytes_scalar",      &Self::vbn254fr_set_bytes_scalar       },
+            { "vbn254fr_set_bytes_from_offsets", &Self::vbn254fr_set_bytes_from_offsets },
             { "vbn254fr_copy",                  &Self::vbn254fr_copy                   },
             { "vbn254fr_print",                 &Self::vbn254fr_print                  },
+            { "vbn254fr_get_bytes",             &Self::vbn254fr_get_bytes              },
             { "vbn254fr_constant_set_str",      &Self::vbn254fr_constant_set_str       },
             { "vbn254fr_addmod",                &Self::vbn254fr_addmod                 },
             { "vbn254fr_addmod_constant",       &Self::vbn254fr_addmod_constant        },
@@ -17,6 +17,8 @@
 #pragma once
 
 #include <array>
+#include <cstdlib>
+#include <map>
 #include <random>
 #include <span>
 #include <unordered_map>
@@ -198,12 +200,24 @@ struct nonbatch_context_base {
         host_module_.push_back(std::move(ptr));
     }
 
-    exec_result call_host(address_t addr, std::string module_name, std::string func) {
+    exec_result call_host(address_t addr, std::string module_name, std::string func, size_t params) {
         // std::cout << "call: " << addr << std::endl;
         if (!module_map_.contains(module_name)) {
             std::cerr << std::format("ERROR: Could not load module {}, aborting!", module_name) << std::endl;
             return exec_exit{ .exit_code = -1 };
         }
+        if (host_stats_enabled_) {
+            ++host_call_counts_[module_name];
+        }
+        if (auto& trace = host_call_trace::instance(); trace.enabled) {
+            // Stage the numeric arguments, in call order, for the trace
+            trace.operands.clear();
+            for (auto it = stack_.end() - params; it != stack_.end(); ++it) {
+                if (const auto *n = it->get_if_numeric()) {
+                    trace.operands.push_back(n->type() == native_numeric::i32 ? n->as_u32() : n->as_u64());
+                }
+            }
+        }
         return module_map_[module_name]->call_host(addr, func);
     }
 
@@ -353,6 +367,31 @@ struct nonbatch_context_base {
         }
 
         backend_.finalize();
+
+        if (host_stats_enabled_) {
+            print_host_stats();
+        }
+    }
+
+    // Host call counts per import module, collected when the
+    // LIGETRON_HOST_STATS environment variable is set
+    const std::map<std::string, u64>& host_call_counts() const {
+        return host_call_counts_;
+    }
+
+    void print_host_stats() const {
+        u64 total = 0;
+        for (const auto& [name, count] : host_call_counts_) {
+            total += count;
+        }
+
+        std::cout << "Host calls by module:" << std::endl;
+        for (const auto& [name, count] : host_call_counts_) {
+            std::cout << std::format("  {:<28}{:>12}  ({:.1f}%)",
+                                     name, count, 100.0 * count / total)
+                      << std::endl;
+        }
+        std::cout << std::format("  {:<28}{:>12}", "total", total) << std::endl;
     }
 
     // template <typename Expr>
@@ -381,6 +420,9 @@ protected:
     std::unordered_map<std::string, host_module*> module_map_;
     std::vector<std::unique_ptr<host_module>> host_module_;
 
+    bool host_stats_enabled_ = std::getenv("LIGETRON_HOST_STATS") != nullptr;
+    std::map<std::string, u64> host_call_counts_;
+
     // Zero-knowledge related
 protected:
     Executor& executor_;
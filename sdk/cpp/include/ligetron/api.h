@@ -40,8 +40,16 @@ LIGETRON_API(env, witness_cast_u64) uint64_t witness_cast_u64(uint64_t x);
 LIGETRON_API(env, print_str)       void print_str(const void*, int);
 LIGETRON_API(env, dump_memory)     void dump_memory(const void*, int);
 
+/* Digest of constraint-emitting host calls, for snapshot tests */
+LIGETRON_API(env, constraint_trace_reset)  void constraint_trace_reset(void);
+LIGETRON_API(env, constraint_trace_digest) uint64_t constraint_trace_digest(void);
+
 LIGETRON_API(env, file_size_get)   int file_size_get(const char *);
 LIGETRON_API(env, file_get)        int file_get(char *, const char *);
+LIGETRON_API(env, file_read_chunk)
+int file_read_chunk(char *, const char *, uint64_t offset, uint32_t len);
+LIGETRON_API(env, file_put)        int file_put(const char *, const void *, uint32_t len);
+LIGETRON_API(env, file_list)       int file_list(const char *, char *, uint32_t len);
 
 /* Elliptic Curve Orales */
 typedef enum ECCCurveType {
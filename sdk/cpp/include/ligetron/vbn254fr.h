@@ -84,6 +84,10 @@ void vbn254fr_set_bytes(vbn254fr_t v, const unsigned char *bytes, uint64_t num_b
 LIGETRON_API(vbn254fr, vbn254fr_set_bytes_scalar)
 void vbn254fr_set_bytes_scalar(vbn254fr_t v, const unsigned char *bytes, uint64_t num_bytes);
 
+LIGETRON_API(vbn254fr, vbn254fr_set_bytes_from_offsets)
+void vbn254fr_set_bytes_from_offsets(vbn254fr_t v, const unsigned char *bytes,
+                                     const uint64_t *offsets, uint64_t count, uint64_t num_bytes);
+
 /* --------------- Vector Arithmetic --------------- */
 
 LIGETRON_API(vbn254fr, vbn254fr_addmod)
@@ -121,6 +125,10 @@ void vbn254fr_copy(vbn254fr_t out, const vbn254fr_t in);
 LIGETRON_API(vbn254fr, vbn254fr_print)
 void vbn254fr_print(const vbn254fr_t v, uint32_t base);
 
+/* Copy the first `count` lanes out as 32-byte big-endian values (debug only, unconstrained) */
+LIGETRON_API(vbn254fr, vbn254fr_get_bytes)
+void vbn254fr_get_bytes(unsigned char *out, const vbn254fr_t v, uint64_t count);
+
 LIGETRON_API(vbn254fr, vbn254fr_bit_decompose)
 void vbn254fr_bit_decompose(vbn254fr_t arr[], const vbn254fr_t x);
 
@@ -0,0 +1,77 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Generates the Poseidon constant tables.
+//!
+//! Widths 3 and 5 are always generated. Additional state widths can be
+//! requested with a comma separated list, e.g.
+//! `LIGETRON_POSEIDON_WIDTHS=8,12 cargo build`, which defines
+//! `poseidon::PoseidonPermx5Bit254T8` and `poseidon::PoseidonPermx5Bit254T12`.
+
+#[path = "src/poseidon_gen.rs"]
+#[allow(dead_code)]
+mod poseidon_gen;
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const WIDTHS_VAR: &str = "LIGETRON_POSEIDON_WIDTHS";
+const SECURITY_BITS: usize = 128;
+
+fn write_table(out: &mut String, name: &str, values: &[String]) {
+    writeln!(out, "pub const {}: &[&str] = &[", name).unwrap();
+    for v in values {
+        writeln!(out, "    \"{}\",", v).unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/poseidon_gen.rs");
+    println!("cargo:rerun-if-env-changed={}", WIDTHS_VAR);
+
+    let mut widths = vec![3, 5];
+    if let Ok(extra) = env::var(WIDTHS_VAR) {
+        for w in extra.split(',').map(str::trim).filter(|w| !w.is_empty()) {
+            let t: usize = w.parse()
+                .unwrap_or_else(|_| panic!("{}: invalid width '{}'", WIDTHS_VAR, w));
+            if !widths.contains(&t) {
+                widths.push(t);
+            }
+        }
+    }
+
+    let mut constants = String::new();
+    let mut params = String::new();
+    for &t in &widths {
+        let p = poseidon_gen::generate_params(t, 5, SECURITY_BITS);
+        write_table(&mut constants, &format!("POSEIDON_{}_ARC_STR", t), &p.round_constants);
+        write_table(&mut constants, &format!("POSEIDON_{}_MDS_STR", t), &p.mds);
+        write_table(&mut constants, &format!("POSEIDON_{}_MDS_MONTGOMERY_STR", t), &p.mds_montgomery);
+        write_table(&mut constants, &format!("POSEIDON_{}_ARC_OPT_STR", t), &p.round_constants_optimized);
+        write_table(&mut constants, &format!("POSEIDON_{}_PRE_SPARSE_STR", t), &p.pre_sparse_mds);
+        write_table(&mut constants, &format!("POSEIDON_{}_PRE_SPARSE_MONTGOMERY_STR", t), &p.pre_sparse_mds_montgomery);
+        write_table(&mut constants, &format!("POSEIDON_{}_SPARSE_STR", t), &p.sparse_mds);
+        write_table(&mut constants, &format!("POSEIDON_{}_SPARSE_MONTGOMERY_STR", t), &p.sparse_mds_montgomery);
+        writeln!(params, "poseidon_param!({}, {}, {});", t, p.r_f, p.r_p).unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("poseidon_constant.rs"), constants).unwrap();
+    fs::write(Path::new(&out_dir).join("poseidon_params.rs"), params).unwrap();
+}
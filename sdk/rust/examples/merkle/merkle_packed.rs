@@ -0,0 +1,67 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+
+//! Packed Poseidon2 Merkle Tree Benchmark
+//!
+//! Builds the bottom of a Merkle tree over `lane_count · 2^depth` leaves,
+//! either on the vector unit with `merkle::build_packed` or one node at a
+//! time with the scalar functions, so the two can be timed against each
+//! other with the prover.
+//!
+//! Arguments:
+//!     [1]: <i64> 1 for the packed builder, 0 for the scalar one
+//!     [2]: <i64> Depth of each lane's subtree
+
+
+use ligetron::get_args;
+use ligetron::bn254fr::Bn254Fr;
+use ligetron::merkle;
+use ligetron::vbn254fr::lane_count;
+
+fn main() {
+    let args = get_args();
+    let packed = args.get_as_int(1) != 0;
+    let depth = args.get_as_int(2) as usize;
+
+    let leaves: Vec<[u8; 32]> = (0..lane_count() << depth)
+        .map(|i| {
+            let mut leaf = [0u8; 32];
+            leaf[24..].copy_from_slice(&(i as u64).to_be_bytes());
+            leaf
+        })
+        .collect();
+
+    if packed {
+        let forest = merkle::build_packed(&leaves);
+        forest.roots.print_hex();
+    } else {
+        let mut level: Vec<Bn254Fr> = leaves
+            .iter()
+            .map(|bytes| {
+                let mut v = Bn254Fr::new();
+                v.set_bytes_big(bytes);
+                merkle::leaf(&v)
+            })
+            .collect();
+        while level.len() > lane_count() {
+            level = level.chunks(2).map(|pair| merkle::node(&pair[0], &pair[1])).collect();
+        }
+        for root in &level {
+            root.print_hex();
+        }
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Merkle Accumulators for Ligetron
+//!
+//! A set of field elements accumulated into a single Poseidon2 root. The
+//! accumulator is a Merkle tree of fixed depth `d` over [`merkle`] hashing:
+//! an empty slot holds `0` and an occupied slot holds `merkle::leaf(value)`.
+//! Because the depth is fixed up front, a membership witness is always `d`
+//! siblings and a slot index, however many elements the set holds.
+//!
+//! [`Accumulator`] keeps the tree off-circuit, proves every update against
+//! the previous root with constraints, and hands out witnesses that
+//! [`verify_membership`] checks against a root. Elements are placed in the
+//! first free slot; removed slots are reused.
+
+use std::collections::HashMap;
+
+use crate::bn254fr::Bn254Fr;
+use crate::merkle::{self, MerklePath};
+
+/// Default accumulator depth (about 10^6 elements)
+pub const DEFAULT_DEPTH: usize = 20;
+
+/// Membership witness of one element
+#[derive(Clone)]
+pub struct MembershipWitness {
+    /// Slot holding the element
+    pub index: u64,
+    /// Sibling hashes from the leaf level up
+    pub siblings: Vec<Bn254Fr>,
+}
+
+impl MembershipWitness {
+    fn path(&self) -> MerklePath {
+        MerklePath::new(self.siblings.clone(), self.index)
+    }
+}
+
+/// Assert that `value` is a member of the accumulator with the given `root`
+pub fn verify_membership(root: &Bn254Fr, value: &Bn254Fr, witness: &MembershipWitness) {
+    merkle::verify_path(root, &merkle::leaf(value), &witness.path());
+}
+
+/// Fixed-depth Poseidon2 Merkle accumulator
+pub struct Accumulator {
+    depth: usize,
+    /// `nodes[h]` maps node index to hash; missing nodes are empty subtrees
+    nodes: Vec<HashMap<u64, Bn254Fr>>,
+    /// Roots of empty subtrees by height
+    empty: Vec<Bn254Fr>,
+    /// Slots freed by [`Accumulator::remove`]
+    free: Vec<u64>,
+    next: u64,
+    root: Bn254Fr,
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEPTH)
+    }
+}
+
+impl Accumulator {
+    /// Empty accumulator of the given depth
+    pub fn new(depth: usize) -> Self {
+        assert!((1..=63).contains(&depth), "Accumulator: depth must be between 1 and 63");
+        let mut empty = vec![Bn254Fr::from_u32(0)];
+        for h in 0..depth {
+            empty.push(merkle::node(&empty[h], &empty[h]));
+        }
+        Accumulator {
+            depth,
+            nodes: vec![HashMap::new(); depth + 1],
+            root: empty[depth].clone(),
+            empty,
+            free: Vec::new(),
+            next: 0,
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> u64 {
+        self.next - self.free.len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Current root
+    pub fn root(&self) -> Bn254Fr {
+        self.root.clone()
+    }
+
+    fn node(&self, h: usize, i: u64) -> Bn254Fr {
+        self.nodes[h].get(&i).cloned().unwrap_or_else(|| self.empty[h].clone())
+    }
+
+    /// Membership witness of the slot `index`
+    pub fn prove(&self, index: u64) -> MembershipWitness {
+        assert!(index < (1u64 << self.depth), "Accumulator::prove: index out of range");
+        let siblings = (0..self.depth)
+            .map(|h| self.node(h, (index >> h) ^ 1))
+            .collect();
+        MembershipWitness { index, siblings }
+    }
+
+    /// Write `leaf_hash` into slot `index` after checking that the slot
+    /// currently holds `old_leaf` under the current root
+    fn update(&mut self, index: u64, old_leaf: &Bn254Fr, leaf_hash: Bn254Fr) {
+        let path = self.prove(index).path();
+        merkle::verify_path(&self.root, old_leaf, &path);
+
+        let mut cur = leaf_hash;
+        let mut i = index;
+        for h in 0..self.depth {
+            self.nodes[h].insert(i, cur.clone());
+            let sib = self.node(h, i ^ 1);
+            cur = if i & 1 == 0 { merkle::node(&cur, &sib) } else { merkle::node(&sib, &cur) };
+            i >>= 1;
+        }
+        self.nodes[self.depth].insert(0, cur.clone());
+        self.root = cur;
+    }
+
+    /// Add `value` and return its slot
+    pub fn add(&mut self, value: &Bn254Fr) -> u64 {
+        let index = match self.free.pop() {
+            Some(i) => i,
+            None => {
+                assert!(self.next < (1u64 << self.depth), "Accumulator::add: accumulator is full");
+                self.next += 1;
+                self.next - 1
+            }
+        };
+        self.update(index, &Bn254Fr::from_u32(0), merkle::leaf(value));
+        index
+    }
+
+    /// Remove `value` from slot `index`
+    pub fn remove(&mut self, index: u64, value: &Bn254Fr) {
+        assert!(index < self.next && !self.free.contains(&index),
+                "Accumulator::remove: slot is empty");
+        self.update(index, &merkle::leaf(value), Bn254Fr::from_u32(0));
+        self.free.push(index);
+    }
+}
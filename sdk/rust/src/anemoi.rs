@@ -0,0 +1,321 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Anemoi Hash Function for Ligetron
+//!
+//! ## Algorithm Details
+//!
+//! Anemoi (<https://eprint.iacr.org/2022/840>) over BN254 Fr with a single
+//! column (`l = 1`, state `(x, y)`):
+//! - **Round Constants**: `x += C_r`, `y += D_r`
+//! - **Linear Layer**: pseudo-Hadamard transform `y += x; x += y`
+//! - **S-box**: open Flystel with `Q(y) = g·y^2` and `E^-1(x) = x^(1/5)`:
+//!   `x -= g·y^2 + g^-1; y -= x^(1/5); x += g·y^2`
+//! - **Rounds**: 21, followed by a final linear layer
+//!
+//! The S-box costs five multiplications per round (the fifth root is
+//! supplied as a witness and checked with `w^5 == x`), about 105 in total
+//! against roughly 250 for Poseidon with t=3.
+//!
+//! ## Modes
+//!
+//! - [`anemoi_compress`]: Jive 2-to-1 compression `a + b + u + v` where
+//!   `(u, v) = P(a, b)`, intended for Merkle trees
+//! - [`GenericAnemoiContext`]: sponge with rate 1 and capacity 1
+//!
+//! ## Vectorized Backend
+//!
+//! The vectorized backend has no unconstrained exponentiation, so the fifth
+//! root of every lane is computed by the scalar host, loaded back with
+//! [`VBn254Fr::set_bytes`] and checked with `w^5 == x`. [`VAnemoiContext`]
+//! therefore costs the same five vector multiplications per round as the
+//! scalar backend, plus one unconstrained scalar exponentiation per lane.
+//!
+//! ## Test Vectors
+//!
+//! The tests re-derive the round constants from `pi_0` and `g = 5` as given
+//! in the paper, and check the permutation, sponge and Jive outputs against
+//! vectors from an independent implementation of the specification.
+
+use crate::anemoi_constant::*;
+use crate::bn254fr::{self, Bn254Fr};
+use crate::field::LigetronField;
+use crate::vbn254fr::VBn254Fr;
+
+/// Field backends that can evaluate the Anemoi inverse S-box
+pub trait AnemoiField: LigetronField {
+    /// Constrained `x^(1/5)`
+    fn inv_alpha_root(&self) -> Self;
+}
+
+/// Compute x^5 with three constrained multiplications
+fn pow5<F: LigetronField>(x: &F) -> F {
+    let mut x2 = F::new();
+    let mut x4 = F::new();
+    let mut result = F::new();
+    F::mul_checked(&mut x2, x, x);
+    F::mul_checked(&mut x4, &x2, &x2);
+    F::mul_checked(&mut result, &x4, x);
+    result
+}
+
+impl AnemoiField for Bn254Fr {
+    fn inv_alpha_root(&self) -> Self {
+        let mut w = Bn254Fr::new();
+        bn254fr::powmod(&mut w, self, &Bn254Fr::from_str(ANEMOI_INV_ALPHA_STR));
+        Bn254Fr::assert_equal(&pow5(&w), self);
+        w
+    }
+}
+
+impl AnemoiField for VBn254Fr {
+    fn inv_alpha_root(&self) -> Self {
+        // Fifth root of every lane through the scalar host, loaded back as
+        // a witness and checked with w^5 == x
+        let lanes = VBn254Fr::get_size() as usize;
+        let e = Bn254Fr::from_str(ANEMOI_INV_ALPHA_STR);
+        let mut root = Bn254Fr::new();
+        let mut bytes = Vec::with_capacity(lanes * 32);
+        for lane in self.get_bytes_unchecked(lanes) {
+            bn254fr::powmod(&mut root, &Bn254Fr::from_bytes_big(&lane), &e);
+            bytes.extend_from_slice(&root.get_bytes_big());
+        }
+
+        let mut w = VBn254Fr::new();
+        w.set_bytes(&bytes, lanes as u64);
+        VBn254Fr::assert_equal(&pow5(&w), self);
+        w
+    }
+}
+
+/// Anemoi sponge context (rate 1, capacity 1), generic over the field backend
+pub struct GenericAnemoiContext<F: AnemoiField> {
+    x: F,
+    y: F,
+    c: Vec<F::Constant>,
+    d: Vec<F::Constant>,
+    g: F::Constant,
+    g_inv: F::Constant,
+}
+
+/// Anemoi context over BN254 field elements
+pub type AnemoiContext = GenericAnemoiContext<Bn254Fr>;
+
+/// Anemoi context over vectorized BN254 field elements
+pub type VAnemoiContext = GenericAnemoiContext<VBn254Fr>;
+
+impl<F: AnemoiField> Default for GenericAnemoiContext<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: AnemoiField> GenericAnemoiContext<F> {
+    pub fn new() -> Self {
+        GenericAnemoiContext {
+            x: F::from_u32(0),
+            y: F::from_u32(0),
+            c: ANEMOI_C_STR.iter().map(|s| F::constant(s)).collect(),
+            d: ANEMOI_D_STR.iter().map(|s| F::constant(s)).collect(),
+            g: F::constant(ANEMOI_G_STR),
+            g_inv: F::constant(ANEMOI_G_INV_STR),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.x.set_u32(0);
+        self.y.set_u32(0);
+    }
+
+    /// Absorb one element into the rate and permute
+    pub fn update(&mut self, data: &F) {
+        self.x.add_assign_checked(data);
+        self.permute();
+    }
+
+    pub fn finalize(&mut self) -> F {
+        self.x.clone()
+    }
+
+    /// Apply the Anemoi permutation to `(x, y)`
+    pub fn permute_pair(&self, x: &mut F, y: &mut F) {
+        for r in 0..ANEMOI_BN254_ROUNDS {
+            x.add_constant_assign(&self.c[r]);
+            y.add_constant_assign(&self.d[r]);
+            Self::linear_layer(x, y);
+            self.flystel(x, y);
+        }
+        Self::linear_layer(x, y);
+    }
+
+    /// Apply the Anemoi permutation to the sponge state
+    pub fn permute(&mut self) {
+        let mut x = self.x.clone();
+        let mut y = self.y.clone();
+        self.permute_pair(&mut x, &mut y);
+        self.x = x;
+        self.y = y;
+    }
+
+    /// Jive compression of two elements: `a + b + u + v` with `(u, v) = P(a, b)`
+    pub fn compress(&self, a: &F, b: &F) -> F {
+        let mut u = a.clone();
+        let mut v = b.clone();
+        self.permute_pair(&mut u, &mut v);
+
+        let mut out = F::new();
+        F::add_checked(&mut out, a, b);
+        out.add_assign_checked(&u);
+        out.add_assign_checked(&v);
+        out
+    }
+
+    fn linear_layer(x: &mut F, y: &mut F) {
+        y.add_assign_checked(x);
+        x.add_assign_checked(y);
+    }
+
+    fn flystel(&self, x: &mut F, y: &mut F) {
+        // x -= g·y^2 + g^-1
+        let mut t = F::new();
+        F::mul_checked(&mut t, y, y);
+        t.mul_constant_assign(&self.g);
+        x.sub_assign_checked(&t);
+        x.sub_constant_assign(&self.g_inv);
+
+        // y -= x^(1/5)
+        y.sub_assign_checked(&x.inv_alpha_root());
+
+        // x += g·y^2
+        F::mul_checked(&mut t, y, y);
+        t.mul_constant_assign(&self.g);
+        x.add_assign_checked(&t);
+    }
+}
+
+/// Convenience function to compute the Anemoi sponge hash
+pub fn anemoi_hash(inputs: &[Bn254Fr]) -> Bn254Fr {
+    let mut ctx = AnemoiContext::new();
+
+    for input in inputs {
+        ctx.update(input);
+    }
+
+    ctx.finalize()
+}
+
+/// Convenience function to compute the Anemoi Jive compression of two elements
+pub fn anemoi_compress(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    AnemoiContext::new().compress(a, b)
+}
+
+/// Convenience function to compute the vectorized Anemoi sponge hash
+pub fn vanemoi_hash(inputs: &[VBn254Fr]) -> VBn254Fr {
+    let mut ctx = VAnemoiContext::new();
+
+    for input in inputs {
+        ctx.update(input);
+    }
+
+    ctx.finalize()
+}
+
+/// Convenience function to compute the vectorized Anemoi Jive compression
+pub fn vanemoi_compress(a: &VBn254Fr, b: &VBn254Fr) -> VBn254Fr {
+    VAnemoiContext::new().compress(a, b)
+}
+
+#[cfg(all(test, feature = "native-sim"))]
+mod tests {
+    use super::*;
+
+    /// `pi_0` of the paper reduced mod p
+    const PI_0: &str = "0x01d26d46c25331caa4a73249ac66cee8a2c809bddd85047e3fccd4b8c212b4f8";
+
+    /// `P(0, 0)`, `P(1, 2)`, `Jive(0, 0)`, `Jive(1, 2)` and `H(1, 2, 3)`
+    const PERM_0_0: [&str; 2] = [
+        "0x2712dd0747a5942584df0352c38abb4fe9aa78e557c3e0b7d26ecbc912c1182b",
+        "0x12b837bf304483b65f7d65e0b50e0c1511ccf728d94187e8f3ac62eb135f9f0d",
+    ];
+    const PERM_1_2: [&str; 2] = [
+        "0x1235c9852fb3428f648eaaad48ffa0ac7445e472d7dd17212dc52b4593bfc9d4",
+        "0x138fb6528b7cac918c86b8e48c7ffb2d7e1058aaf7ac752d89cb21296d959061",
+    ];
+    const JIVE_0_0: &str = "0x0966c65396b877b22c0c237cf7176f07d34387c5b74bf80f823939203620b737";
+    const JIVE_1_2: &str = "0x25c57fd7bb2fef20f1156391d57f9bd9f2563d1dcf898c4eb7904c6f01555a38";
+    const HASH_1_2_3: &str = "0x1f2f54244748d649129f26fb032ecfc79edf5f566d6b586cf85974a50a95239d";
+
+    fn hex_bytes(s: &str) -> [u8; 32] {
+        Bn254Fr::from_str(s).get_bytes_big()
+    }
+
+    #[test]
+    fn round_constants() {
+        // C_r = g·pi_0^(2r) + (pi_0^r + 1)^5, D_r = g + (pi_0^r + 1)^5 + g^-1
+        let g = Bn254Fr::from_u32(5);
+        let pi = Bn254Fr::from_str(PI_0);
+        let mut pi_r = Bn254Fr::from_u32(1);
+        let mut t = Bn254Fr::new();
+        let mut c = Bn254Fr::new();
+        let mut d = Bn254Fr::new();
+        for r in 0..ANEMOI_BN254_ROUNDS {
+            bn254fr::addmod(&mut t, &pi_r, &Bn254Fr::from_u32(1));
+            let t5 = pow5(&t);
+
+            bn254fr::mulmod(&mut c, &pi_r, &pi_r);
+            c.mulmod(&g);
+            c.addmod(&t5);
+            c.assert_equal_const_str(ANEMOI_C_STR[r]);
+
+            bn254fr::addmod(&mut d, &g, &t5);
+            d.addmod(&Bn254Fr::from_str(ANEMOI_G_INV_STR));
+            d.assert_equal_const_str(ANEMOI_D_STR[r]);
+
+            pi_r.mulmod(&pi);
+        }
+    }
+
+    #[test]
+    fn permutation_vectors() {
+        let ctx = AnemoiContext::new();
+        for ((a, b), [u, v]) in [((0, 0), PERM_0_0), ((1, 2), PERM_1_2)] {
+            let mut x = Bn254Fr::from_u32(a);
+            let mut y = Bn254Fr::from_u32(b);
+            ctx.permute_pair(&mut x, &mut y);
+            x.assert_equal_const_str(u);
+            y.assert_equal_const_str(v);
+        }
+    }
+
+    #[test]
+    fn compress_and_hash_vectors() {
+        let zero = Bn254Fr::from_u32(0);
+        anemoi_compress(&zero, &zero).assert_equal_const_str(JIVE_0_0);
+        anemoi_compress(&Bn254Fr::from_u32(1), &Bn254Fr::from_u32(2))
+            .assert_equal_const_str(JIVE_1_2);
+
+        let inputs: Vec<Bn254Fr> = (1..=3).map(Bn254Fr::from_u32).collect();
+        anemoi_hash(&inputs).assert_equal_const_str(HASH_1_2_3);
+    }
+
+    #[test]
+    fn vectorized_matches_scalar() {
+        let a = VBn254Fr::from_ui(&[0, 1]);
+        let b = VBn254Fr::from_ui(&[0, 2]);
+        let out = vanemoi_compress(&a, &b).get_bytes_unchecked(2);
+        assert_eq!(out, [hex_bytes(JIVE_0_0), hex_bytes(JIVE_1_2)]);
+    }
+}
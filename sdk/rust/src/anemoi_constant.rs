@@ -0,0 +1,76 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/// Anemoi BN254 parameters (l = 1, alpha = 5, 128-bit security)
+pub const ANEMOI_BN254_ROUNDS: usize = 21;
+
+/// Multiplicative generator g of BN254 Fr and its inverse
+pub const ANEMOI_G_STR: &str = "5";
+pub const ANEMOI_G_INV_STR: &str = "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667";
+
+/// 1/alpha = 5^-1 mod (p - 1)
+pub const ANEMOI_INV_ALPHA_STR: &str = "0x26b6a528b427b35493736af8679aad17535cb9d394945a0dcfe7f7a98ccccccd";
+
+/// Anemoi round constants for the x and y columns:
+/// - C_r = g * pi_0^(2r) + (pi_0^r + 1)^alpha
+/// - D_r = g + (pi_0^r + 1)^alpha + g^-1
+pub const ANEMOI_C_STR: &[&str; ANEMOI_BN254_ROUNDS] = &[
+    "0x0000000000000000000000000000000000000000000000000000000000000025",
+    "0x1d851aa3a1ad5cfa398a47d33b9c614f0879e1c79891ea5a21ff8ece689f96a9",
+    "0x13cf1ad003eda20a5223e28f2b7aaa5d12d6128ac67cd2dd53ecd2b2695ebd9b",
+    "0x07200ee6f1261ae24b5d9887e7b2955e0e511df646085706304134927f554dd5",
+    "0x1949a1753252aa42804dc3212af703bba8f85b2fb9ed269c87537a8729a9c055",
+    "0x208e8894681bab57e9469e78383500b5b80787320bf51735e884e0e57760d21d",
+    "0x08042f50d6fde723aa9b666fbaaeb8167846c47f5ae31f113fee0d0d8c59a6be",
+    "0x010636a632dfd37321d8db96bf4edb26218ffdbed735817b1251aafbb326e8bf",
+    "0x2690def299310b1e5ffd20b0f76b16c4d3a93900dcaccaffcbbba0ab1f5c2981",
+    "0x17caa1a332d82688b206471cbd9c861a0a0c71c421ec35cb382d33f055ec3730",
+    "0x037021e76044464089e5f4964d1d172947c91f5adc2f36ad36dadd6dd134be6d",
+    "0x11a77f05482b4d24e84fcc06d71704c2e5f9288acca092848bcd318fb80c9f69",
+    "0x1528fb21bda777a250b3d6d0c053f1202aca44fc52ec14b519b056cbba1fcecb",
+    "0x27b656d33f3dc3b879ac63999d0bed50518bece8ead6a6b1b7b5486962e06889",
+    "0x1b624e0ad6dcf65d0887d5fe0ff8a493e37e80fcfb8e667a2c64da2741b90b39",
+    "0x2ed6144422f782b0c04afff934c27256cfd3ac13541db0b61bcf022b6538d432",
+    "0x06ae20043bc1c0491bf2d8968b9ef5611b1b261eb5db7038949c3a548da8ec2c",
+    "0x0f7ce6c8772669ffe3124a4b13156a3af7be5c1c094c48141beca658ae794798",
+    "0x088ed0990cd88d6f919086db6f56e4a991d8d44b3a5aebac604f59a07777cead",
+    "0x1bfd291265bde38f1a6df77b2135d9078c294c7712a210d7963ba41776b5ecd2",
+    "0x1caad067047dab936832407ed1e9100039af6bfcb8fd203542971ff514143549",
+];
+
+pub const ANEMOI_D_STR: &[&str; ANEMOI_BN254_ROUNDS] = &[
+    "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c666668c",
+    "0x0b9600e2b2bdd62adf15f55c26da154bd4ca3e19ff98c7fc971568d5215b9e2a",
+    "0x13ecfefe27ca58493b421659e9cf5c056f879e613101b95cd0d80c9460ad8bae",
+    "0x3051f6b4575b550151f0a4d11b0bde4c49579bbfa93cfd88cd1c08fa0ad8285f",
+    "0x18d262fe1684723d7a37ad0a2b8cce9b28b3a38c63d8d73d868745950bf4fa47",
+    "0x124d150dd5ca235c52ff9be5db1309e00c4476d05105e0909423822db70ea185",
+    "0x2e3796b9718260f8a9d4787313e2a4d9619f1550f7b6368c17d40a7ee75792be",
+    "0x0434ead34c59b1208b4536cfa7b378a6d85d6f7dc613ec33cd23f0cdf2fa97de",
+    "0x1ff3dd7d9e9a28afb5fe76bd90d446e6cc7c62e437054d826bd2bdf308736b04",
+    "0x1752085ba9964afe3cbde284d496afaee92dab06f8b99a0287aa3b6c0608cc63",
+    "0x25bc8ef554367127acb079907da14304f1a0d4ebd8b4b70c2cf08c56f4f878d9",
+    "0x23d0b729d2976dc00d2a7275bfaaeaafdac884006d133c4e94896de7014893b9",
+    "0x2a55b40fb8b6c0c267b18521e5024a1a6b9f430f852735e2197bb88a09b7e62e",
+    "0x0c2745a06592c06ae9b1b32c591f9760fb9303ce4298f0529c9d0e8f8bfda7f6",
+    "0x28a22beaca06bdb5d74634cfaeedbfe8f43b2f876b25fdf562b8a9b6f93d9e82",
+    "0x1103c859880bfb49b748d6d50647be3825042365676efbd3b964cdecd0599816",
+    "0x02218448bf89f18f4a64f94b904879ca580e3d088e1ebc0655b15f107c3f95ef",
+    "0x1b713066fd2aa6c06be1c510616e13a75b1053f8e703c607ce30e1b55187323b",
+    "0x0f69a06f7adc84762053d61221652e79bc0a88c6434b560d10bc6a09164c4762",
+    "0x17cfb3a1d64d47ac3f859c215023b86e078c782834431b624788cac6c9ed057d",
+    "0x09ae70089ef0f96bb1c2a01831efd655bdf9f7d9c81ca98062918cbf14f699dc",
+];
@@ -16,7 +16,11 @@
 
 //! Basic API functions for Ligetron
 
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::bn254fr::Bn254Fr;
 
 pub fn assert_zero<T: Into<i32>>(val: T) {
     unsafe {
@@ -49,6 +53,32 @@ pub fn dump_memory(data: &[u8]) {
     unsafe { _dump_memory(data.as_ptr(), data.len() as i32) }
 }
 
+/// Reset the constraint trace and start recording.
+///
+/// The host folds the module, name and numeric arguments (handle addresses
+/// and immediates) of every constraint-emitting host call (field assertions
+/// and checked bit decompositions, vector copies, multiplications,
+/// divisions, assertions and bit decompositions, and `env` assertions and
+/// private constants) into a running digest. Hint-only
+/// calls such as arithmetic, `uint256` division, `get_u64` and prints, and
+/// plain wasm instructions, are not recorded.
+pub fn constraint_trace_reset() {
+    unsafe { _constraint_trace_reset() }
+}
+
+/// Digest of the host calls recorded since [`constraint_trace_reset`]
+pub fn constraint_trace_digest() -> u64 {
+    unsafe { _constraint_trace_digest() }
+}
+
+/// Digest of the host calls made by `f`, for snapshot tests asserting that a
+/// gadget still emits the same sequence of operations after a refactor
+pub fn constraint_digest_of<R>(f: impl FnOnce() -> R) -> (R, u64) {
+    constraint_trace_reset();
+    let out = f();
+    (out, constraint_trace_digest())
+}
+
 pub fn get_file_size(filename: &str) -> i32 {
     let c_str = format!("{}\0", filename);
     unsafe { _file_size_get(c_str.as_ptr()) }
@@ -59,6 +89,105 @@ pub fn read_file(filename: &str, buffer: &mut [u8]) -> i32 {
     unsafe { _file_get(buffer.as_mut_ptr(), c_str.as_ptr()) }
 }
 
+/// List host files matching `pattern`, in sorted order.
+///
+/// The last path component may contain `*` and `?` wildcards; the rest is
+/// taken literally as the directory to search, e.g. `"docs/*.json"`.
+pub fn list_files(pattern: &str) -> Vec<String> {
+    let c_str = format!("{}\0", pattern);
+    let size = unsafe { _file_list(c_str.as_ptr(), ptr::null_mut(), 0) } as u32 as usize;
+
+    let mut buffer = vec![0u8; size];
+    unsafe {
+        _file_list(c_str.as_ptr(), buffer.as_mut_ptr(), size as u32);
+    }
+
+    buffer.split(|&b| b == 0)
+        .filter(|name| !name.is_empty())
+        .map(|name| String::from_utf8_lossy(name).into_owned())
+        .collect()
+}
+
+/// Iterate over `(name, contents)` of the host files matching `pattern`,
+/// reading one file at a time
+pub fn read_files(pattern: &str) -> impl Iterator<Item = (String, Vec<u8>)> {
+    list_files(pattern).into_iter().map(|name| {
+        let mut buffer = vec![0u8; get_file_size(&name) as u32 as usize];
+        read_file(&name, &mut buffer);
+        (name, buffer)
+    })
+}
+
+/// Write `data` to a host file, replacing it if it exists.
+///
+/// The host only permits writes when `LIGETRON_OUTPUT_DIR` is set; the file
+/// is created relative to that directory. Returns the number of bytes
+/// written, or -1 if the write was refused or failed.
+pub fn write_file(filename: &str, data: &[u8]) -> i32 {
+    let c_str = format!("{}\0", filename);
+    unsafe { _file_put(c_str.as_ptr(), data.as_ptr(), data.len() as u32) }
+}
+
+/// Streaming reader over a host file.
+///
+/// Reads the file in chunks instead of loading it into one buffer, so large
+/// witness files do not need to fit in wasm memory at once.
+pub struct FileReader {
+    name: String,
+    size: u64,
+}
+
+impl FileReader {
+    /// Open a host file for chunked reading
+    pub fn open(filename: &str) -> Self {
+        FileReader {
+            name: format!("{}\0", filename),
+            size: get_file_size(filename) as u32 as u64,
+        }
+    }
+
+    /// Size of the file in bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Read up to `buffer.len()` bytes starting at `offset`.
+    /// Returns the number of bytes read, which is 0 past the end of the file.
+    pub fn read_chunk_into(&self, offset: u64, buffer: &mut [u8]) -> usize {
+        unsafe {
+            _file_read_chunk(buffer.as_mut_ptr(), self.name.as_ptr(), offset, buffer.len() as u32)
+                as usize
+        }
+    }
+
+    /// Read up to `len` bytes starting at `offset`
+    pub fn read_chunk(&self, offset: u64, len: usize) -> Vec<u8> {
+        let mut buffer = vec![0u8; len];
+        let n = self.read_chunk_into(offset, &mut buffer);
+        buffer.truncate(n);
+        buffer
+    }
+
+    /// Iterate over the file in chunks of at most `chunk_size` bytes
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+        assert!(chunk_size > 0, "FileReader: chunk size must be nonzero");
+        (0..self.size)
+            .step_by(chunk_size)
+            .map(move |offset| self.read_chunk(offset, chunk_size))
+    }
+}
+
+/// Fill `buffer` from the host random source (WASI `random_get`).
+///
+/// The host generator is deterministically seeded so that every prover pass
+/// observes the same values; the output is not secret. Use private inputs
+/// for values that must stay hidden.
+pub fn random_bytes(buffer: &mut [u8]) {
+    unsafe {
+        _random_get(buffer.as_mut_ptr(), buffer.len() as i32);
+    }
+}
+
 pub fn strlen(string_start: *const i8) -> usize {
     let ptr = string_start;
     let mut len: usize = 0;
@@ -71,6 +200,113 @@ pub fn strlen(string_start: *const i8) -> usize {
     len
 }
 
+// ============= Memory Introspection =============
+
+static CURRENT_ALLOCATION: AtomicUsize = AtomicUsize::new(0);
+static PEAK_ALLOCATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Size of wasm linear memory in bytes (0 on non-wasm targets)
+pub fn heap_size() -> usize {
+    #[cfg(target_arch = "wasm32")]
+    {
+        core::arch::wasm32::memory_size(0) * 65536
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        0
+    }
+}
+
+/// Bytes currently allocated. Only tracked when [`TrackingAllocator`] is
+/// the global allocator; 0 otherwise.
+pub fn current_allocation() -> usize {
+    CURRENT_ALLOCATION.load(Ordering::Relaxed)
+}
+
+/// Highest number of bytes allocated at once. Only tracked when
+/// [`TrackingAllocator`] is the global allocator; 0 otherwise.
+pub fn peak_allocation() -> usize {
+    PEAK_ALLOCATION.load(Ordering::Relaxed)
+}
+
+/// Reset the peak to the current allocation
+pub fn reset_peak_allocation() {
+    PEAK_ALLOCATION.store(current_allocation(), Ordering::Relaxed);
+}
+
+/// Global allocator wrapper that records current and peak allocation.
+///
+/// Install it in the guest program to enable [`current_allocation`] and
+/// [`peak_allocation`]:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: ligetron::api::TrackingAllocator = ligetron::api::TrackingAllocator::new();
+/// ```
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+impl TrackingAllocator<System> {
+    pub const fn new() -> Self {
+        TrackingAllocator { inner: System }
+    }
+}
+
+impl Default for TrackingAllocator<System> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Track allocations made through `inner`
+    pub const fn with_allocator(inner: A) -> Self {
+        TrackingAllocator { inner }
+    }
+
+    fn record_alloc(size: usize) {
+        let current = CURRENT_ALLOCATION.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_ALLOCATION.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        CURRENT_ALLOCATION.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let p = self.inner.alloc(layout);
+        if !p.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        p
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let p = self.inner.alloc_zeroed(layout);
+        if !p.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        p
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let p = self.inner.realloc(ptr, layout, new_size);
+        if !p.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        p
+    }
+}
+
 #[macro_export]
 macro_rules! fail_with_message {
     ($msg:expr) => {{
@@ -96,13 +332,27 @@ impl ArgHolder {
     /// Get the argument at the given index as an i64 integer
     pub fn get_as_int(&self, index: usize) -> i64 {
         let arg_bytes = self.get_as_bytes(index);
-        if arg_bytes.len() != 8 {
-            fail_with_message!(b"Invalid argument length for i64!");
-        } else {
-            unsafe { *(arg_bytes.as_ptr() as *const i64) }
+        match arg_bytes.try_into() {
+            Ok(bytes) => i64::from_le_bytes(bytes),
+            Err(_) => fail_with_message!(b"Invalid argument length for i64!"),
         }
     }
 
+    /// Get the i64 argument at the given index as a field element,
+    /// range constrained to 64 bits. Negative values are taken as their
+    /// two's complement bit pattern.
+    pub fn get_as_fr_from_int(&self, index: usize) -> Bn254Fr {
+        let out = Bn254Fr::from_u64(self.get_as_int(index) as u64);
+        let _ = out.to_bits(64);
+        out
+    }
+
+    /// Get several i64 arguments as range-constrained field elements.
+    /// See [`ArgHolder::get_as_fr_from_int`].
+    pub fn get_as_fr_from_ints(&self, indices: &[usize]) -> Vec<Bn254Fr> {
+        indices.iter().map(|&i| self.get_as_fr_from_int(i)).collect()
+    }
+
     /// Get the argument at the given index as a pointer to C-Style string
     pub fn get_as_c_str(&self, index: usize) -> *const i8 {
         let start = self.arg_ranges.get(index)
@@ -158,6 +408,11 @@ pub fn get_args() -> ArgHolder {
     arg_struct
 }
 
+/// Branch-free `c ? t : f` on plain integers.
+///
+/// Adds no constraints and is only oblivious while the compiled code stays
+/// branch-free; see [`oblivious`](crate::oblivious) for constrained
+/// selection on secret conditions.
 pub fn oblivious_if<T>(c: bool, t: T, f: T) -> T
 where
     T: From<bool> + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
@@ -165,6 +420,7 @@ where
     (t * T::from(c)) + (f * T::from(!c))
 }
 
+/// Branch-free minimum; see [`oblivious_if`] for when this is sound
 pub fn oblivious_min<T>(a: T, b: T) -> T
 where
     T: From<bool> + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + std::cmp::PartialOrd,
@@ -172,6 +428,7 @@ where
     oblivious_if(a < b, a, b)
 }
 
+/// Branch-free maximum; see [`oblivious_if`] for when this is sound
 pub fn oblivious_max<T>(a: T, b: T) -> T
 where
     T: From<bool> + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + std::cmp::PartialOrd,
@@ -180,7 +437,7 @@ where
 }
 
 #[link(wasm_import_module = "env")]
-extern "C" {
+extern "C-unwind" {
     /// Assert that a value is zero
     #[link_name = "assert_zero"]
     fn _assert_zero(value: i32);
@@ -201,6 +458,14 @@ extern "C" {
     #[link_name = "dump_memory"]
     fn _dump_memory(ptr: *const u8, len: i32);
 
+    /// Start recording constraint-emitting host calls
+    #[link_name = "constraint_trace_reset"]
+    fn _constraint_trace_reset();
+
+    /// Digest of the host calls recorded since the last reset
+    #[link_name = "constraint_trace_digest"]
+    fn _constraint_trace_digest() -> u64;
+
     /// Get file size
     #[link_name = "file_size_get"]
     fn _file_size_get(name_ptr: *const u8) -> i32;
@@ -208,14 +473,29 @@ extern "C" {
     /// Read file contents
     #[link_name = "file_get"]
     fn _file_get(buf_ptr: *mut u8, name_ptr: *const u8) -> i32;
+
+    /// Read part of a file, returning the number of bytes read
+    #[link_name = "file_read_chunk"]
+    fn _file_read_chunk(buf_ptr: *mut u8, name_ptr: *const u8, offset: u64, len: u32) -> i32;
+
+    /// Write file contents, returning the number of bytes written or -1
+    #[link_name = "file_put"]
+    fn _file_put(name_ptr: *const u8, buf_ptr: *const u8, len: u32) -> i32;
+
+    /// List matching files into a buffer, returning the size needed
+    #[link_name = "file_list"]
+    fn _file_list(pattern_ptr: *const u8, buf_ptr: *mut u8, len: u32) -> i32;
 }
 
 // WASI imports
 #[link(wasm_import_module = "wasi_snapshot_preview1")]
-extern "C" {
+extern "C-unwind" {
     #[link_name = "args_sizes_get"]
     fn _args_sizes_get(argc: *mut i32, buf_size: *mut i32) -> i32;
 
     #[link_name = "args_get"]
     fn _args_get(argv: *mut *mut u8, buf: *mut u8) -> i32;
+
+    #[link_name = "random_get"]
+    fn _random_get(buf: *mut u8, buf_len: i32) -> i32;
 }
\ No newline at end of file
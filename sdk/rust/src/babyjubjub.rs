@@ -29,440 +29,599 @@
 //! - Parameters: A = 168698, B = 1
 //! - Generator: (7, 4258727773875940690362607550498304598101071202821725296872974770776423442226)
 //! - Base Point: (7117928050407583618111176421555214756675765419608405867398403713213306743542, 14577268218881899420966779687690205425227431577728659819975198491127179315626)
+//!
+//! ## Point Compression
+//! Compressed points use the 32-byte circomlib encoding: y in little-endian
+//! order, with the most significant bit of the last byte set when x is
+//! "negative" (x > (p - 1) / 2).
+//!
+//! The RFC 8032 encoding ([`JubjubPoint::to_rfc8032`]) uses the same layout
+//! but flags the parity of x instead, as Ed25519 does.
+//!
+//! ## Scalar Multiplication
+//! [`GenericJubjubPoint::scalar_mul`] uses [`DEFAULT_SCALAR_MUL_STRATEGY`];
+//! [`GenericJubjubPoint::scalar_mul_with`] selects a [`ScalarMulStrategy`]
+//! explicitly. Both strategies run the same sequence of operations for
+//! every scalar, the ladder additionally keeps the same sequence for
+//! every bit.
+
+use crate::bn254fr::{self, Bn254Fr, BN254FR_MODULUS, BN254FR_HALF_MODULUS};
+use crate::field::{self, LigetronField};
+use crate::vbn254fr::VBn254Fr;
+
+/// Baby Jubjub curve parameters. Values are decimal or 0x-prefixed hex
+/// strings accepted by `from_str`; the group orders are hex so they can be
+/// used with the constant comparison gadgets.
+#[derive(Clone, Copy, Debug)]
+pub struct JubjubParams {
+    /// Base field modulus (the BN254 scalar field)
+    pub field_modulus: &'static str,
+    /// Twisted Edwards coefficient a
+    pub a: &'static str,
+    /// Twisted Edwards coefficient d
+    pub d: &'static str,
+    /// Montgomery coefficient A
+    pub montgomery_a: &'static str,
+    /// Order l of the prime-order subgroup
+    pub subgroup_order: &'static str,
+    /// Order of the full group (cofactor · l)
+    pub group_order: &'static str,
+    pub cofactor: u32,
+    /// Generator of the full group, in Twisted Edwards form
+    pub generator: (&'static str, &'static str),
+    /// Generator of the prime-order subgroup (cofactor · generator)
+    pub base_point: (&'static str, &'static str),
+}
+
+impl JubjubParams {
+    /// Generator of the full group
+    pub fn generator<F: LigetronField>(&self) -> GenericJubjubPoint<F> {
+        GenericJubjubPoint::new(F::from_str(self.generator.0), F::from_str(self.generator.1))
+    }
+
+    /// Generator of the prime-order subgroup
+    pub fn base_point<F: LigetronField>(&self) -> GenericJubjubPoint<F> {
+        GenericJubjubPoint::new(F::from_str(self.base_point.0), F::from_str(self.base_point.1))
+    }
+}
 
-use crate::bn254fr::{Bn254Fr, addmod_checked, submod_checked, mulmod_checked, divmod_checked, mux};
-use crate::vbn254fr::{VBn254Fr, VBn254FrConstant, submod_vec, mulmod_vec, divmod_vec,
-                     addmod_constant, submod_constant, mulmod_constant, constant_submod};
-use lazy_static::lazy_static;
+/// Baby Jubjub parameters
+pub const PARAMS: JubjubParams = JubjubParams {
+    field_modulus: BN254FR_MODULUS,
+    a: "168700",
+    d: "168696",
+    montgomery_a: "168698",
+    subgroup_order: "0x060c89ce5c263405370a08b6d0302b0bab3eedb83920ee0a677297dc392126f1",
+    group_order: "0x30644e72e131a029b85045b68181585d59f76dc1c90770533b94bee1c9093788",
+    cofactor: 8,
+    generator: (
+        "995203441582195749578291179787384436505546430278305826713579947235728471134",
+        "5472060717959818805561601436314318772137091100104008585924551046643952123905",
+    ),
+    base_point: (
+        "5299619240641551281634865583518297030282874472190772894086521144482721001553",
+        "16950150798460657717958625567821834550301663161624707787222815936182638968203",
+    ),
+};
+
+/// Baby Jubjub parameters
+pub fn params() -> &'static JubjubParams {
+    &PARAMS
+}
 
-const COEF_A: &str = "168700";
-const COEF_D: &str = "168696";
-const COEF_MONT_A: &str = "168698";
+/// 2·A for Montgomery doubling
 const COEF_TWO_A: &str = "337396";
 
-// Curve constants for vectorized implementation
-lazy_static! {
-    static ref VEC_ONE: VBn254FrConstant = VBn254FrConstant::from_str("1");
-    static ref VEC_TWO: VBn254FrConstant = VBn254FrConstant::from_str("2");
-    static ref VEC_THREE: VBn254FrConstant = VBn254FrConstant::from_str("3");
-    static ref VEC_A: VBn254FrConstant = VBn254FrConstant::from_str(COEF_A);
-    static ref VEC_D: VBn254FrConstant = VBn254FrConstant::from_str(COEF_D);
-    static ref VEC_MONT_A: VBn254FrConstant = VBn254FrConstant::from_str(COEF_MONT_A);
-    static ref VEC_TWO_A: VBn254FrConstant = VBn254FrConstant::from_str(COEF_TWO_A);
+/// Scalar multiplication algorithm for [`GenericJubjubPoint::scalar_mul_with`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScalarMulStrategy {
+    /// Fixed 2-bit windows: two doublings and one table addition per window,
+    /// about 381 additions
+    Windowed,
+    /// Montgomery ladder: one addition and one doubling of a selected
+    /// register per bit, about 508 additions
+    Ladder,
 }
 
-/// Baby Jubjub elliptic curve point
+/// Strategy used by [`GenericJubjubPoint::scalar_mul`]
+pub const DEFAULT_SCALAR_MUL_STRATEGY: ScalarMulStrategy = ScalarMulStrategy::Windowed;
+
+/// Baby Jubjub elliptic curve point over a field backend
 #[derive(Clone)]
-pub struct JubjubPoint {
-    pub x: Bn254Fr,
-    pub y: Bn254Fr,
+pub struct GenericJubjubPoint<F: LigetronField> {
+    pub x: F,
+    pub y: F,
 }
 
-impl JubjubPoint {
-    pub fn new(x: Bn254Fr, y: Bn254Fr) -> Self {
-        JubjubPoint { x, y }
+/// Baby Jubjub elliptic curve point
+pub type JubjubPoint = GenericJubjubPoint<Bn254Fr>;
+
+/// Baby Jubjub elliptic curve point using vectorized field arithmetic
+pub type JubjubPointVec = GenericJubjubPoint<VBn254Fr>;
+
+impl<F: LigetronField> GenericJubjubPoint<F> {
+    pub fn new(x: F, y: F) -> Self {
+        GenericJubjubPoint { x, y }
     }
 
     /// Create the identity point (0, 1) in Twisted Edwards form
     pub fn identity() -> Self {
-        JubjubPoint {
-            x: Bn254Fr::from_u32(0),
-            y: Bn254Fr::from_u32(1),
+        GenericJubjubPoint {
+            x: F::from_u32(0),
+            y: F::from_u32(1),
         }
     }
 
-    pub fn assert_equal(p: &mut JubjubPoint, q: &mut JubjubPoint) {
-        Bn254Fr::assert_equal(&mut p.x, &mut q.x);
-        Bn254Fr::assert_equal(&mut p.y, &mut q.y);
+    /// Base point of the prime-order subgroup (8 times the generator)
+    pub fn base_point() -> Self {
+        PARAMS.base_point()
+    }
+
+    /// Both sides of a·x² + y² = 1 + d·x²·y²
+    fn curve_equation(&self) -> (F, F) {
+        let mut x2 = F::new();
+        let mut y2 = F::new();
+        F::mul_checked(&mut x2, &self.x, &self.x);
+        F::mul_checked(&mut y2, &self.y, &self.y);
+
+        let mut lhs = F::new();
+        F::mul_constant(&mut lhs, &x2, &F::constant(PARAMS.a));
+        lhs.add_assign_checked(&y2);
+
+        let mut rhs = F::new();
+        F::mul_checked(&mut rhs, &x2, &y2);
+        rhs.mul_constant_assign(&F::constant(PARAMS.d));
+        rhs.add_constant_assign(&F::constant("1"));
+
+        (lhs, rhs)
+    }
+
+    /// Constrain the point to satisfy a·x² + y² = 1 + d·x²·y²
+    pub fn assert_on_curve(&self) {
+        let (lhs, rhs) = self.curve_equation();
+        F::assert_equal(&lhs, &rhs);
+    }
+
+    /// Return 1 if the point satisfies the curve equation, 0 otherwise
+    /// (with constraints)
+    pub fn is_on_curve(&self) -> F {
+        let (lhs, rhs) = self.curve_equation();
+        field::is_equal_checked(&lhs, &rhs)
+    }
+
+    /// Constrain an on-curve point to lie in the prime-order subgroup
+    /// (`order·P = O`)
+    pub fn assert_in_subgroup(&self) {
+        let mut q = self.scalar_mul(&F::from_str(PARAMS.subgroup_order));
+        Self::assert_equal(&mut q, &mut Self::identity());
+    }
+
+    pub fn assert_equal(p: &mut Self, q: &mut Self) {
+        F::assert_equal(&p.x, &q.x);
+        F::assert_equal(&p.y, &q.y);
+    }
+
+    /// Compressed form of the point as `(y, sign)`, where `sign` is 1 if
+    /// x > (p - 1) / 2 and 0 otherwise (with constraints)
+    pub fn compress(&self) -> (F, F) {
+        (self.y.clone(), field::sign_checked(&self.x))
+    }
+
+    /// Constrain the point to match the compressed form `(y, sign)`
+    pub fn assert_compressed(&self, y: &F, sign: &F) {
+        F::assert_equal(&self.y, y);
+        F::assert_equal(&field::sign_checked(&self.x), sign);
     }
 
     /// Conditional selection between two points
-    pub fn mux(cond: &Bn254Fr, b0: &JubjubPoint, b1: &JubjubPoint) -> JubjubPoint {
-        let mut result = JubjubPoint::new(Bn254Fr::new(), Bn254Fr::new());
-        mux(&mut result.x, cond, &b0.x, &b1.x);
-        mux(&mut result.y, cond, &b0.y, &b1.y);
+    pub fn mux(cond: &F, b0: &Self, b1: &Self) -> Self {
+        let mut result = Self::new(F::new(), F::new());
+        F::mux(&mut result.x, cond, &b0.x, &b1.x);
+        F::mux(&mut result.y, cond, &b0.y, &b1.y);
         result
     }
 
     /// 4-way conditional selection
     /// Selects one of four points based on two selector bits
-    pub fn mux2(s0: &Bn254Fr, s1: &Bn254Fr,
-                b0: &JubjubPoint, b1: &JubjubPoint,
-                b2: &JubjubPoint, b3: &JubjubPoint) -> JubjubPoint {
-        let mut result = JubjubPoint::new(Bn254Fr::new(), Bn254Fr::new());
-        crate::bn254fr::mux2(&mut result.x, s0, s1, &b0.x, &b1.x, &b2.x, &b3.x);
-        crate::bn254fr::mux2(&mut result.y, s0, s1, &b0.y, &b1.y, &b2.y, &b3.y);
+    pub fn mux2(s0: &F, s1: &F,
+                b0: &Self, b1: &Self,
+                b2: &Self, b3: &Self) -> Self {
+        let mut result = Self::new(F::new(), F::new());
+        F::mux2(&mut result.x, s0, s1, &b0.x, &b1.x, &b2.x, &b3.x);
+        F::mux2(&mut result.y, s0, s1, &b0.y, &b1.y, &b2.y, &b3.y);
         result
     }
 
     /// Convert from Twisted Edwards to Montgomery form
     /// TE -> Montgomery: u = (1+y)/(1-y), v = (1-y)/(x*(1+y))
-    pub fn to_montgomery(&self) -> JubjubPoint {
-        let mut one_plus_y = Bn254Fr::new();
-        let mut one_minus_y = Bn254Fr::new();
-        let one = Bn254Fr::from_u32(1);
+    pub fn to_montgomery(&self) -> Self {
+        let one = F::constant("1");
+        let mut one_plus_y = F::new();
+        let mut one_minus_y = F::new();
 
-        addmod_checked(&mut one_plus_y, &one, &self.y);
-        submod_checked(&mut one_minus_y, &one, &self.y);
+        F::add_constant(&mut one_plus_y, &self.y, &one);
+        F::constant_sub(&mut one_minus_y, &one, &self.y);
 
-        let mut mnt = JubjubPoint::new(Bn254Fr::new(), Bn254Fr::new());
-        divmod_checked(&mut mnt.x, &one_plus_y, &one_minus_y);
+        let mut mnt = Self::new(F::new(), F::new());
+        F::div_checked(&mut mnt.x, &one_plus_y, &one_minus_y);
 
-        let mut temp = Bn254Fr::new();
-        mulmod_checked(&mut temp, &one_minus_y, &self.x);
-        divmod_checked(&mut mnt.y, &one_plus_y, &temp);
+        let mut temp = F::new();
+        F::mul_checked(&mut temp, &one_minus_y, &self.x);
+        F::div_checked(&mut mnt.y, &one_plus_y, &temp);
 
         mnt
     }
 
     /// Convert from Montgomery to Twisted Edwards form
     /// Montgomery -> TE: x = u/v, y = (u-1)/(u+1)
-    pub fn to_twisted_edward(&self) -> JubjubPoint {
-        let one = Bn254Fr::from_u32(1);
-        let mut ted = JubjubPoint::new(Bn254Fr::new(), Bn254Fr::new());
+    pub fn to_twisted_edward(&self) -> Self {
+        let one = F::constant("1");
+        let mut ted = Self::new(F::new(), F::new());
 
-        divmod_checked(&mut ted.x, &self.x, &self.y);
+        F::div_checked(&mut ted.x, &self.x, &self.y);
 
-        let mut temp = Bn254Fr::new();
-        submod_checked(&mut ted.y, &self.x, &one);
-        addmod_checked(&mut temp, &self.x, &one);
-        ted.y.divmod_checked(&temp);
+        let mut temp = F::new();
+        F::sub_constant(&mut ted.y, &self.x, &one);
+        F::add_constant(&mut temp, &self.x, &one);
+        ted.y.div_assign_checked(&temp);
 
         ted
     }
 
     /// Twisted Edwards point addition
     /// Formula: ((x1*y2 + y1*x2)/(1 + d*x1*x2*y1*y2), (y1*y2 - a*x1*x2)/(1 - d*x1*x2*y1*y2))
-    pub fn twisted_edward_add(a: &JubjubPoint, b: &JubjubPoint) -> JubjubPoint {
-        let one = Bn254Fr::from_u32(1);
-        let coeff_te_a = Bn254Fr::from_str(COEF_A);  // Twisted Edwards parameter a
-        let coeff_te_d = Bn254Fr::from_str(COEF_D);  // Twisted Edwards parameter d
-        let mut lambda = Bn254Fr::new();
-
-        mulmod_checked(&mut lambda, &coeff_te_d, &a.x);
-        lambda.mulmod_checked(&a.y);
-        lambda.mulmod_checked(&b.x);
-        lambda.mulmod_checked(&b.y);
-
-        let mut t1 = Bn254Fr::new();
-        let mut t2 = Bn254Fr::new();
-        let mut t3 = Bn254Fr::new();
-
-        mulmod_checked(&mut t1, &a.x, &b.y);
-        mulmod_checked(&mut t2, &a.y, &b.x);
-        addmod_checked(&mut t3, &one, &lambda);
-
-        t1.addmod_checked(&t2);
-        let mut result_x = Bn254Fr::new();
-        divmod_checked(&mut result_x, &t1, &t3);
-
-        mulmod_checked(&mut t1, &a.y, &b.y);
-        mulmod_checked(&mut t2, &a.x, &b.x);
-        let t2_temp = t2.clone();
-        mulmod_checked(&mut t2, &coeff_te_a, &t2_temp);
-        submod_checked(&mut t3, &one, &lambda);
-
-        t1.submod_checked(&t2);
-        let mut result_y = Bn254Fr::new();
-        divmod_checked(&mut result_y, &t1, &t3);
-
-        JubjubPoint::new(result_x, result_y)
+    pub fn twisted_edward_add(a: &Self, b: &Self) -> Self {
+        let one = F::constant("1");
+        let coeff_te_a = F::constant(PARAMS.a);  // Twisted Edwards parameter a
+        let coeff_te_d = F::constant(PARAMS.d);  // Twisted Edwards parameter d
+        let mut lambda = F::new();
+
+        F::mul_constant(&mut lambda, &a.x, &coeff_te_d);
+        lambda.mul_assign_checked(&a.y);
+        lambda.mul_assign_checked(&b.x);
+        lambda.mul_assign_checked(&b.y);
+
+        let mut t1 = F::new();
+        let mut t2 = F::new();
+        let mut t3 = F::new();
+
+        F::mul_checked(&mut t1, &a.x, &b.y);
+        F::mul_checked(&mut t2, &a.y, &b.x);
+        F::add_constant(&mut t3, &lambda, &one);
+
+        t1.add_assign_checked(&t2);
+        let mut result_x = F::new();
+        F::div_checked(&mut result_x, &t1, &t3);
+
+        F::mul_checked(&mut t1, &a.y, &b.y);
+        F::mul_checked(&mut t2, &a.x, &b.x);
+        t2.mul_constant_assign(&coeff_te_a);
+        F::constant_sub(&mut t3, &one, &lambda);
+
+        t1.sub_assign_checked(&t2);
+        let mut result_y = F::new();
+        F::div_checked(&mut result_y, &t1, &t3);
+
+        Self::new(result_x, result_y)
     }
 
     /// Montgomery point doubling
     /// Uses Montgomery form for efficient doubling operation
-    pub fn montgomery_double(p: &JubjubPoint) -> JubjubPoint {
-        let one = Bn254Fr::from_u32(1);
-        let coeff_mont_a = Bn254Fr::from_str(COEF_MONT_A);   // Montgomery parameter A
-        let mut result = JubjubPoint::new(Bn254Fr::new(), Bn254Fr::new());
+    pub fn montgomery_double(p: &Self) -> Self {
+        let one = F::constant("1");
+        let two = F::constant("2");
+        let coeff_mont_a = F::constant(PARAMS.montgomery_a);   // Montgomery parameter A
+        let mut result = Self::new(F::new(), F::new());
 
         // Calculate lambda = (3x² + 2Ax + 1) / (2y)
-        let mut lam = Bn254Fr::from_u32(3);
-        lam.mulmod_checked(&p.x);
-        lam.mulmod_checked(&p.x);
+        let mut lam = F::new();
+        F::mul_constant(&mut lam, &p.x, &F::constant("3"));
+        lam.mul_assign_checked(&p.x);
 
-        let mut t1 = Bn254Fr::from_str(COEF_TWO_A);
-        t1.mulmod_checked(&p.x);
+        let mut t1 = F::new();
+        F::mul_constant(&mut t1, &p.x, &F::constant(COEF_TWO_A));
 
-        lam.addmod_checked(&t1);
-        lam.addmod_checked(&one);
+        lam.add_assign_checked(&t1);
+        lam.add_constant_assign(&one);
 
-        let mut t2 = Bn254Fr::from_u32(2);
-        t2.mulmod_checked(&p.y);
+        let mut t2 = F::new();
+        F::mul_constant(&mut t2, &p.y, &two);
 
-        lam.divmod_checked(&t2);
+        lam.div_assign_checked(&t2);
 
         // x₂ = λ² - 2x - A
-        let mut t4 = Bn254Fr::from_u32(2);
-        mulmod_checked(&mut result.x, &lam, &lam);
-        t4.mulmod_checked(&p.x);
-        result.x.submod_checked(&t4);
-        result.x.submod_checked(&coeff_mont_a);
+        let mut t4 = F::new();
+        F::mul_checked(&mut result.x, &lam, &lam);
+        F::mul_constant(&mut t4, &p.x, &two);
+        result.x.sub_assign_checked(&t4);
+        result.x.sub_constant_assign(&coeff_mont_a);
 
         // y₂ = λ(x - x₂) - y
-        let mut t5 = Bn254Fr::new();
-        submod_checked(&mut t5, &p.x, &result.x);
-        mulmod_checked(&mut result.y, &lam, &t5);
-        result.y.submod_checked(&p.y);
+        let mut t5 = F::new();
+        F::sub_checked(&mut t5, &p.x, &result.x);
+        F::mul_checked(&mut result.y, &lam, &t5);
+        result.y.sub_assign_checked(&p.y);
 
         result
     }
 
+    /// Scalar multiplication with [`DEFAULT_SCALAR_MUL_STRATEGY`]
+    pub fn scalar_mul(&self, x: &F) -> Self {
+        self.scalar_mul_with(x, DEFAULT_SCALAR_MUL_STRATEGY)
+    }
+
+    /// Scalar multiplication with the given strategy
+    pub fn scalar_mul_with(&self, x: &F, strategy: ScalarMulStrategy) -> Self {
+        match strategy {
+            ScalarMulStrategy::Windowed => self.scalar_mul_windowed(x),
+            ScalarMulStrategy::Ladder => self.scalar_mul_ladder(x),
+        }
+    }
+
     /// Scalar multiplication using windowing method
     /// Multiplies this point by scalar x using 2-bit windows
-    pub fn scalar_mul(&self, x: &Bn254Fr) -> JubjubPoint {
+    fn scalar_mul_windowed(&self, x: &F) -> Self {
+        self.scalar_mul_bits(&x.bits())
+    }
 
-        let w0 = JubjubPoint::identity();
+    /// Windowed scalar multiplication over a constrained 254-bit
+    /// little-endian decomposition, e.g. a canonical one
+    pub fn scalar_mul_bits(&self, bits: &[F]) -> Self {
+        assert!(bits.len() == 254, "scalar_mul_bits: expected 254 bits");
+        let w0 = Self::identity();
         let w1 = self.clone();
-        let w2 = JubjubPoint::twisted_edward_add(self, self);
-        let w3 = JubjubPoint::twisted_edward_add(&w1, &w2);
-
-        let bits = x.to_bits(254);
+        let w2 = Self::twisted_edward_add(self, self);
+        let w3 = Self::twisted_edward_add(&w1, &w2);
 
-        let mut acc = JubjubPoint::mux2(&bits[252], &bits[253], &w0, &w1, &w2, &w3);
+        let mut acc = Self::mux2(&bits[252], &bits[253], &w0, &w1, &w2, &w3);
 
         for i in (0..251).step_by(2).rev() {
-            acc = JubjubPoint::twisted_edward_add(&acc, &acc);
-            acc = JubjubPoint::twisted_edward_add(&acc, &acc);
+            acc = Self::twisted_edward_add(&acc, &acc);
+            acc = Self::twisted_edward_add(&acc, &acc);
 
-            let temp = JubjubPoint::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3);
-            acc = JubjubPoint::twisted_edward_add(&acc, &temp);
+            let temp = Self::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3);
+            acc = Self::twisted_edward_add(&acc, &temp);
         }
 
         acc
     }
 
+    /// Scalar multiplication using the Montgomery ladder.
+    /// Keeps R1 - R0 = P; each bit adds the registers and doubles the one
+    /// selected by the bit, so every step has the same shape.
+    fn scalar_mul_ladder(&self, x: &F) -> Self {
+        let mut r0 = Self::identity();
+        let mut r1 = self.clone();
+
+        for bit in x.bits().iter().rev() {
+            let sum = Self::twisted_edward_add(&r0, &r1);
+            let sel = Self::mux(bit, &r0, &r1);
+            let dbl = Self::twisted_edward_add(&sel, &sel);
+
+            // bit = 0: (2·R0, R0 + R1); bit = 1: (R0 + R1, 2·R1)
+            r0 = Self::mux(bit, &dbl, &sum);
+            r1 = Self::mux(bit, &sum, &dbl);
+        }
+
+        r0
+    }
+
+    /// `a·p + b·q` with the Straus-Shamir trick: one pass over the bits of
+    /// both scalars with shared doublings and the table `{O, p, q, p + q}`,
+    /// about 509 additions instead of 763 for two separate multiplications
+    pub fn double_scalar_mul(a: &F, p: &Self, b: &F, q: &Self) -> Self {
+        Self::double_scalar_mul_bits(&a.bits(), p, &b.bits(), q)
+    }
+
+    /// [`Self::double_scalar_mul`] over constrained 254-bit little-endian
+    /// decompositions, e.g. canonical ones already used for a range check
+    pub fn double_scalar_mul_bits(a_bits: &[F], p: &Self, b_bits: &[F], q: &Self) -> Self {
+        assert!(a_bits.len() == 254 && b_bits.len() == 254, "double_scalar_mul_bits: expected 254 bits");
+        let w0 = Self::identity();
+        let w3 = Self::twisted_edward_add(p, q);
+
+        let mut acc = Self::mux2(&a_bits[253], &b_bits[253], &w0, p, q, &w3);
+        for i in (0..253).rev() {
+            acc = Self::twisted_edward_add(&acc, &acc);
+            let temp = Self::mux2(&a_bits[i], &b_bits[i], &w0, p, q, &w3);
+            acc = Self::twisted_edward_add(&acc, &temp);
+        }
+
+        acc
+    }
+
+    /// Negation `(-x, y)`
+    pub fn neg(&self) -> Self {
+        let mut x = F::new();
+        F::constant_sub(&mut x, &F::constant("0"), &self.x);
+        Self::new(x, self.y.clone())
+    }
+
     /// Extended scalar multiplication with two scalars
     /// Computes x1*G + x2*H where G is this point and H is computed from x2
-    pub fn scalar_mul_extend(&self, x1: &Bn254Fr, x2: &Bn254Fr) -> JubjubPoint {
-        let w0 = JubjubPoint::identity();
+    pub fn scalar_mul_extend(&self, x1: &F, x2: &F) -> Self {
+        let w0 = Self::identity();
         let w1 = self.clone();
-        let w2 = JubjubPoint::twisted_edward_add(self, self);
-        let w3 = JubjubPoint::twisted_edward_add(&w1, &w2);
+        let w2 = Self::twisted_edward_add(self, self);
+        let w3 = Self::twisted_edward_add(&w1, &w2);
 
         // Process x2 first
-        let mut bits = x2.to_bits(254);
-        let mut acc = JubjubPoint::mux2(&bits[252], &bits[253], &w0, &w1, &w2, &w3);
+        let mut bits = x2.bits();
+        let mut acc = Self::mux2(&bits[252], &bits[253], &w0, &w1, &w2, &w3);
 
         for i in (0..251).step_by(2).rev() {
-            acc = JubjubPoint::twisted_edward_add(&acc, &acc);
-            acc = JubjubPoint::twisted_edward_add(&acc, &acc);
+            acc = Self::twisted_edward_add(&acc, &acc);
+            acc = Self::twisted_edward_add(&acc, &acc);
 
-            let temp = JubjubPoint::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3);
-            acc = JubjubPoint::twisted_edward_add(&acc, &temp);
+            let temp = Self::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3);
+            acc = Self::twisted_edward_add(&acc, &temp);
         }
 
         // Process x1
-        bits = x1.to_bits(254);
+        bits = x1.bits();
         for i in (0..253).step_by(2).rev() {
-            acc = JubjubPoint::twisted_edward_add(&acc, &acc);
-            acc = JubjubPoint::twisted_edward_add(&acc, &acc);
+            acc = Self::twisted_edward_add(&acc, &acc);
+            acc = Self::twisted_edward_add(&acc, &acc);
 
-            let temp = JubjubPoint::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3);
-            acc = JubjubPoint::twisted_edward_add(&acc, &temp);
+            let temp = Self::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3);
+            acc = Self::twisted_edward_add(&acc, &temp);
         }
 
         acc
     }
 }
 
-/// Baby Jubjub elliptic curve point using vectorized field arithmetic
-#[derive(Clone)]
-pub struct JubjubPointVec {
-    pub x: VBn254Fr,
-    pub y: VBn254Fr,
-}
-
-impl JubjubPointVec {
-    pub fn new(x: VBn254Fr, y: VBn254Fr) -> Self {
-        JubjubPointVec { x, y }
+/// Split a compressed point into the hex string of y and the sign bit.
+/// Returns `None` if y is not below the field modulus.
+fn split_compressed(bytes: &[u8; 32]) -> Option<(String, bool)> {
+    let sign = bytes[31] & 0x80 != 0;
+    let mut y = *bytes;
+    y[31] &= 0x7f;
+
+    // Compare big-endian bytes against the modulus
+    let modulus = bn254fr::hex_to_bits(BN254FR_MODULUS, 256);
+    let mut m = [0u8; 32];
+    for (i, bit) in modulus.iter().enumerate() {
+        m[31 - i / 8] |= (*bit as u8) << (i % 8);
     }
-
-    /// Create the identity point (0, 1) in Twisted Edwards form
-    pub fn identity() -> Self {
-        let mut x = VBn254Fr::new();
-        let mut y = VBn254Fr::new();
-        x.set_ui_scalar(0);
-        y.set_ui_scalar(1);
-        JubjubPointVec { x, y }
+    y.reverse();
+    if y >= m {
+        return None;
     }
 
-    pub fn assert_equal(p: &mut JubjubPointVec, q: &mut JubjubPointVec) {
-        VBn254Fr::assert_equal(&mut p.x, &mut q.x);
-        VBn254Fr::assert_equal(&mut p.y, &mut q.y);
-    }
+    let hex: String = y.iter().map(|b| format!("{:02x}", b)).collect();
+    Some((format!("0x{}", hex), sign))
+}
 
-    /// Conditional selection between two vectorized points
-    pub fn mux(cond: &VBn254Fr, b0: &JubjubPointVec, b1: &JubjubPointVec) -> JubjubPointVec {
-        let mut result = JubjubPointVec::new(VBn254Fr::new(), VBn254Fr::new());
-        crate::vbn254fr::mux_vec(&mut result.x, cond, &b0.x, &b1.x);
-        crate::vbn254fr::mux_vec(&mut result.y, cond, &b0.y, &b1.y);
-        result
+/// Hex string of a field element (unconstrained)
+fn to_hex_unchecked(x: &Bn254Fr) -> String {
+    let bits = x.to_bits_unchecked(254);
+    let mut bytes = [0u8; 32];
+    for (i, bit) in bits.iter().enumerate() {
+        bytes[31 - i / 8] |= (bit.get_u64() as u8) << (i % 8);
     }
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("0x{}", hex)
+}
 
-    /// 4-way conditional selection for vectorized points
-    pub fn mux2(s0: &VBn254Fr, s1: &VBn254Fr,
-                b0: &JubjubPointVec, b1: &JubjubPointVec,
-                b2: &JubjubPointVec, b3: &JubjubPointVec) -> JubjubPointVec {
-        let mut result = JubjubPointVec::new(VBn254Fr::new(), VBn254Fr::new());
-        crate::vbn254fr::mux2_vec(&mut result.x, s0, s1, &b0.x, &b1.x, &b2.x, &b3.x);
-        crate::vbn254fr::mux2_vec(&mut result.y, s0, s1, &b0.y, &b1.y, &b2.y, &b3.y);
-        result
+/// Recover the x coordinate for `y` and `sign` (unconstrained).
+/// Solves x² = (1 - y²) / (a - d·y²), returning `None` if there is no
+/// such point on the curve.
+fn recover_x(y: &Bn254Fr, sign: bool) -> Option<Bn254Fr> {
+    let mut y2 = Bn254Fr::new();
+    bn254fr::mulmod(&mut y2, y, y);
+
+    let mut num = Bn254Fr::from_u32(1);
+    num.submod(&y2);
+
+    let mut den = Bn254Fr::from_str(PARAMS.d);
+    den.mulmod(&y2);
+    let mut a = Bn254Fr::from_str(PARAMS.a);
+    a.submod(&den);
+    if bn254fr::eq(&a, &Bn254Fr::from_u32(0)) {
+        return None;
     }
 
-    /// Convert from Twisted Edwards to Montgomery form (vectorized)
-    pub fn to_montgomery(&self) -> JubjubPointVec {
-        let mut one_plus_y = VBn254Fr::new();
-        let mut one_minus_y = VBn254Fr::new();
-
-        addmod_constant(&mut one_plus_y, &self.y, &VEC_ONE);
-        constant_submod(&mut one_minus_y, &VEC_ONE, &self.y);
-
-        let mut mnt = JubjubPointVec::new(VBn254Fr::new(), VBn254Fr::new());
-        divmod_vec(&mut mnt.x, &one_plus_y, &one_minus_y);
+    let mut x2 = Bn254Fr::new();
+    bn254fr::divmod(&mut x2, &num, &a);
 
-        let mut temp = VBn254Fr::new();
-        mulmod_vec(&mut temp, &one_minus_y, &self.x);
-        divmod_vec(&mut mnt.y, &one_plus_y, &temp);
-
-        mnt
+    let mut x = Bn254Fr::new();
+    if !bn254fr::sqrtmod(&mut x, &x2) {
+        return None;
     }
 
-    /// Convert from Montgomery to Twisted Edwards form (vectorized)
-    pub fn to_twisted_edward(&self) -> JubjubPointVec {
-        let mut ted = JubjubPointVec::new(VBn254Fr::new(), VBn254Fr::new());
-        divmod_vec(&mut ted.x, &self.x, &self.y);
-
-        let mut temp = VBn254Fr::new();
-        submod_constant(&mut ted.y, &self.x, &VEC_ONE);
-        addmod_constant(&mut temp, &self.x, &VEC_ONE);
-        ted.y.divmod_vec(&temp);
-
-        ted
+    let zero = Bn254Fr::from_u32(0);
+    if bn254fr::eq(&x, &zero) {
+        // x = 0 only has the positive encoding
+        return if sign { None } else { Some(x) };
     }
 
-    /// Vectorized Twisted Edwards point addition
-    pub fn twisted_edward_add(a: &JubjubPointVec, b: &JubjubPointVec) -> JubjubPointVec {
-        let mut lambda = VBn254Fr::new();
-        mulmod_constant(&mut lambda, &a.x, &VEC_D);
-        lambda.mulmod_vec(&a.y);
-        lambda.mulmod_vec(&b.x);
-        lambda.mulmod_vec(&b.y);
-
-        let mut t1 = VBn254Fr::new();
-        let mut t2 = VBn254Fr::new();
-        let mut t3 = VBn254Fr::new();
-
-        mulmod_vec(&mut t1, &a.x, &b.y);
-        mulmod_vec(&mut t2, &a.y, &b.x);
-        addmod_constant(&mut t3, &lambda, &VEC_ONE);
-
-        t1.addmod_vec(&t2);
-        let mut result_x = VBn254Fr::new();
-        divmod_vec(&mut result_x, &t1, &t3);
-
-        mulmod_vec(&mut t1, &a.y, &b.y);
-        mulmod_vec(&mut t2, &a.x, &b.x);
-        t2.mulmod_constant(&VEC_A);
-        constant_submod(&mut t3, &VEC_ONE, &lambda);
-
-        t1.submod_vec(&t2);
-        let mut result_y = VBn254Fr::new();
-        divmod_vec(&mut result_y, &t1, &t3);
-
-        JubjubPointVec::new(result_x, result_y)
+    let negative = bn254fr::gt(&x, &Bn254Fr::from_str(BN254FR_HALF_MODULUS));
+    if negative != sign {
+        x.negmod();
     }
+    Some(x)
+}
 
-    /// Vectorized Montgomery point doubling
-    pub fn montgomery_double(p: &JubjubPointVec) -> JubjubPointVec {
-        let mut result = JubjubPointVec::new(VBn254Fr::new(), VBn254Fr::new());
-
-        let mut lam = VBn254Fr::new();
-        mulmod_constant(&mut lam, &p.x, &VEC_THREE);
-        lam.mulmod_vec(&p.x);
-
-        let mut t1 = VBn254Fr::new();
-        mulmod_constant(&mut t1, &p.x, &VEC_TWO_A);
-
-        lam.addmod_vec(&t1);
-        lam.addmod_constant(&VEC_ONE);
-
-        let mut t2 = VBn254Fr::new();
-        mulmod_constant(&mut t2, &p.y, &VEC_TWO);
-
-        lam.divmod_vec(&t2);
-
-        let mut t4 = VBn254Fr::new();
-        mulmod_vec(&mut result.x, &lam, &lam);
-        mulmod_constant(&mut t4, &p.x, &VEC_TWO);
-        result.x.submod_vec(&t4);
-        result.x.submod_constant(&VEC_MONT_A);
-
-        let mut t5 = VBn254Fr::new();
-        submod_vec(&mut t5, &p.x, &result.x);
-        mulmod_vec(&mut result.y, &lam, &t5);
-        result.y.submod_vec(&p.y);
-
-        result
+impl JubjubPoint {
+    /// Decode a point from its 32-byte compressed form, constraining the
+    /// result to be on the curve and to match the encoded sign.
+    /// Returns `None` if the bytes do not encode a curve point.
+    pub fn decompress(bytes: &[u8; 32]) -> Option<Self> {
+        let (y_hex, sign) = split_compressed(bytes)?;
+        let y = Bn254Fr::from_str(&y_hex);
+        let x = recover_x(&y, sign)?;
+
+        let p = JubjubPoint::new(x, y);
+        p.assert_on_curve();
+        p.assert_compressed(&p.y, &Bn254Fr::from_u32(sign as u32));
+        Some(p)
     }
 
-    /// Vectorized scalar multiplication
-    pub fn scalar_mul(&self, x: &VBn254Fr) -> JubjubPointVec {
-        let bits = x.bit_decompose();
-
-        let w0 = JubjubPointVec::identity();
-        let w1 = self.clone();
-        let w2 = JubjubPointVec::twisted_edward_add(self, self);
-        let w3 = JubjubPointVec::twisted_edward_add(&w1, &w2);
-
-        let mut acc = JubjubPointVec::mux2(&bits[252], &bits[253], &w0, &w1, &w2, &w3);
-
-        for i in (0..251).step_by(2).rev() {
-            acc = JubjubPointVec::twisted_edward_add(&acc, &acc);
-            acc = JubjubPointVec::twisted_edward_add(&acc, &acc);
-
-            acc = JubjubPointVec::twisted_edward_add(
-                &acc,
-                &JubjubPointVec::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3),
-            );
+    /// RFC 8032 encoding: y in little-endian order with the parity of x in
+    /// the most significant bit of the last byte
+    pub fn to_rfc8032(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, bit) in self.y.to_bits_unchecked(254).iter().enumerate() {
+            bytes[i / 8] |= (bit.get_u64() as u8) << (i % 8);
         }
-
-        acc
+        bytes[31] |= ((self.x.get_u64() & 1) as u8) << 7;
+        bytes
     }
 
-    /// Vectorized extended scalar multiplication
-    pub fn scalar_mul_extend(&self, x1: &VBn254Fr, x2: &VBn254Fr) -> JubjubPointVec {
-        let w0 = JubjubPointVec::identity();
-        let w1 = self.clone();
-        let w2 = JubjubPointVec::twisted_edward_add(self, self);
-        let w3 = JubjubPointVec::twisted_edward_add(&w1, &w2);
-
-        // Process x2 first
-        let mut bits = x2.bit_decompose();
-        let mut acc = JubjubPointVec::mux2(&bits[252], &bits[253], &w0, &w1, &w2, &w3);
-
-        for i in (0..251).step_by(2).rev() {
-            acc = JubjubPointVec::twisted_edward_add(&acc, &acc);
-            acc = JubjubPointVec::twisted_edward_add(&acc, &acc);
-
-            acc = JubjubPointVec::twisted_edward_add(
-                &acc,
-                &JubjubPointVec::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3),
-            );
+    /// Decode an RFC 8032 encoding, constraining the result to be on the
+    /// curve and x to have the encoded parity.
+    /// Returns `None` if the bytes do not encode a curve point.
+    pub fn from_rfc8032(bytes: &[u8; 32]) -> Option<Self> {
+        let (y_hex, odd) = split_compressed(bytes)?;
+        let y = Bn254Fr::from_str(&y_hex);
+        let mut x = recover_x(&y, false)?;
+        if bn254fr::eq(&x, &Bn254Fr::from_u32(0)) && odd {
+            return None;
+        }
+        if (x.get_u64() & 1 == 1) != odd {
+            x.negmod();
         }
 
-        // Process x1
-        bits = x1.bit_decompose();
-        for i in (0..253).step_by(2).rev() {
-            acc = JubjubPointVec::twisted_edward_add(&acc, &acc);
-            acc = JubjubPointVec::twisted_edward_add(&acc, &acc);
+        let p = JubjubPoint::new(x, y);
+        p.assert_on_curve();
+        Bn254Fr::assert_equal(&p.x.is_odd_checked(), &Bn254Fr::from_u32(odd as u32));
+        Some(p)
+    }
+}
 
-            acc = JubjubPointVec::twisted_edward_add(
-                &acc,
-                &JubjubPointVec::mux2(&bits[i], &bits[i + 1], &w0, &w1, &w2, &w3),
-            );
+impl JubjubPointVec {
+    /// Decode one point per lane from 32-byte compressed forms, constraining
+    /// every lane to be on the curve and to match its encoded sign.
+    ///
+    /// Lanes beyond `points.len()` are filled with the identity. Returns
+    /// `None` if any entry does not encode a curve point or if there are
+    /// more points than lanes.
+    pub fn decompress(points: &[[u8; 32]]) -> Option<Self> {
+        let lanes = VBn254Fr::get_size() as usize;
+        if points.len() > lanes {
+            return None;
         }
 
-        acc
+        let mut xs = Vec::with_capacity(lanes);
+        let mut ys = Vec::with_capacity(lanes);
+        let mut signs = Vec::with_capacity(lanes);
+        for bytes in points {
+            let (y_hex, sign) = split_compressed(bytes)?;
+            let x = recover_x(&Bn254Fr::from_str(&y_hex), sign)?;
+            xs.push(to_hex_unchecked(&x));
+            ys.push(y_hex);
+            signs.push(if sign { "1" } else { "0" });
+        }
+        xs.resize(lanes, String::from("0"));
+        ys.resize(lanes, String::from("1"));
+        signs.resize(lanes, "0");
+
+        let xs: Vec<&str> = xs.iter().map(String::as_str).collect();
+        let ys: Vec<&str> = ys.iter().map(String::as_str).collect();
+        let p = JubjubPointVec::new(VBn254Fr::from_str(&xs), VBn254Fr::from_str(&ys));
+        p.assert_on_curve();
+        p.assert_compressed(&p.y, &VBn254Fr::from_str(&signs));
+        Some(p)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,263 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Field Backends for Ligetron
+//!
+//! Scalar field elements live in the host and are referenced from the guest
+//! through opaque [`FieldHandle`]s. [`FieldBackend`] describes the host ABI
+//! for one prime field: handle management, setters, unconstrained
+//! arithmetic and the constraint primitives everything else is built from.
+//!
+//! The SDK ships a single backend, [`Bn254Backend`](crate::bn254fr::Bn254Backend),
+//! bound to the `bn254fr` host module. A host exposing another field (for
+//! example BLS12-381 Fr or Goldilocks) is supported by implementing this
+//! trait over its import module; the element wrapper and gadgets only go
+//! through the trait.
+//!
+//! ## Safety
+//!
+//! All methods are thin wrappers over host imports. Callers must pass
+//! handles obtained from [`FieldBackend::alloc`] that have not been freed,
+//! and buffers valid for `count` handles or `len` bytes.
+
+#[doc(hidden)]
+pub use paste;
+
+/// Opaque handle to a field element managed by the host
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct FieldHandle {
+    pub handle: u64,
+}
+
+/// Host ABI of a prime field
+#[allow(clippy::missing_safety_doc)]
+pub trait FieldBackend {
+    /// Host import module name
+    const MODULE: &'static str;
+
+    /// Field modulus (0x-prefixed hex)
+    const MODULUS: &'static str;
+
+    /// Bit length of the modulus
+    const BITS: usize;
+
+    // Memory management
+    unsafe fn alloc(fr: *mut FieldHandle);
+    unsafe fn free(fr: *mut FieldHandle);
+
+    // Setters
+    unsafe fn set_u32(out: *mut FieldHandle, x: u32);
+    unsafe fn set_u64(out: *mut FieldHandle, x: u64);
+    unsafe fn set_bytes(out: *mut FieldHandle, bytes: *const u8, len: u32, order: i32);
+    unsafe fn set_str(out: *mut FieldHandle, s: *const i8, base: u32);
+
+    // Getters
+    unsafe fn get_u64(x: *const FieldHandle) -> u64;
+    unsafe fn to_bytes(out: *mut u8, x: *const FieldHandle, len: u32, order: i32);
+
+    // Copy / Print
+    unsafe fn copy(dest: *mut FieldHandle, src: *const FieldHandle);
+    unsafe fn print(a: *const FieldHandle, base: u32);
+
+    // Constraint assertions
+    unsafe fn assert_equal(a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn assert_equal_u64(a: *const FieldHandle, b: u64);
+    unsafe fn assert_equal_bytes(a: *const FieldHandle, bytes: *const u8, len: u32, order: i32);
+    unsafe fn assert_add(out: *const FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn assert_mul(out: *const FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn assert_mulc(out: *const FieldHandle, a: *const FieldHandle, k: *const FieldHandle);
+
+    // Checked bit operations
+    unsafe fn to_bits_checked(outs: *mut FieldHandle, a: *const FieldHandle, count: u32);
+    unsafe fn from_bits_checked(outs: *mut FieldHandle, bits: *const FieldHandle, count: u32);
+
+    // Arithmetic
+    unsafe fn addmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn submod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn mulmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn divmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn invmod(out: *mut FieldHandle, a: *const FieldHandle);
+    unsafe fn negmod(out: *mut FieldHandle, a: *const FieldHandle);
+    unsafe fn powmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn idiv(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn irem(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+
+    // Comparisons
+    unsafe fn eq(a: *const FieldHandle, b: *const FieldHandle) -> bool;
+    unsafe fn lt(a: *const FieldHandle, b: *const FieldHandle) -> bool;
+    unsafe fn lte(a: *const FieldHandle, b: *const FieldHandle) -> bool;
+    unsafe fn gt(a: *const FieldHandle, b: *const FieldHandle) -> bool;
+    unsafe fn gte(a: *const FieldHandle, b: *const FieldHandle) -> bool;
+    unsafe fn eqz(a: *const FieldHandle) -> bool;
+
+    // Logical operations
+    unsafe fn land(a: *const FieldHandle, b: *const FieldHandle) -> bool;
+    unsafe fn lor(a: *const FieldHandle, b: *const FieldHandle) -> bool;
+
+    // Bitwise operations
+    unsafe fn band(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn bor(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn bxor(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn bnot(out: *mut FieldHandle, a: *const FieldHandle);
+
+    // Unchecked bit operations
+    unsafe fn to_bits(outs: *mut FieldHandle, a: *const FieldHandle, count: u32);
+    unsafe fn from_bits(outs: *mut FieldHandle, bits: *const FieldHandle, count: u32);
+
+    // Shift operations
+    unsafe fn shrmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+    unsafe fn shlmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle);
+
+    // Bigint operations over arrays of raw handles, one limb of `bits` bits each
+    unsafe fn bigint_idiv(
+        q: *mut u64,
+        r: *mut u64,
+        a: *const u64,
+        b: *const u64,
+        a_count: u32,
+        b_count: u32,
+        bits: u32,
+    );
+    unsafe fn bigint_invmod(
+        out: *mut u64,
+        a: *const u64,
+        m: *const u64,
+        a_count: u32,
+        m_count: u32,
+        bits: u32,
+    );
+}
+
+/// Implement [`FieldBackend`] by forwarding to `extern` host imports named
+/// `<prefix>_<op>` (for example `_bn254fr_addmod`)
+#[macro_export]
+macro_rules! impl_field_backend {
+    ($backend:ty, $prefix:ident, $module:expr, $modulus:expr, $bits:expr) => {
+        impl $crate::backend::FieldBackend for $backend {
+            const MODULE: &'static str = $module;
+            const MODULUS: &'static str = $modulus;
+            const BITS: usize = $bits;
+
+            $crate::impl_field_backend!(@ops $prefix;
+                alloc(fr: *mut); free(fr: *mut);
+                copy(dest: *mut, src: *const);
+                assert_equal(a: *const, b: *const);
+                assert_add(out: *const, a: *const, b: *const);
+                assert_mul(out: *const, a: *const, b: *const);
+                assert_mulc(out: *const, a: *const, k: *const);
+                to_bits_checked(outs: *mut, a: *const; count);
+                from_bits_checked(outs: *mut, bits: *const; count);
+                to_bits(outs: *mut, a: *const; count);
+                from_bits(outs: *mut, bits: *const; count);
+                invmod(out: *mut, a: *const); negmod(out: *mut, a: *const);
+                bnot(out: *mut, a: *const);
+                addmod(out: *mut, a: *const, b: *const); submod(out: *mut, a: *const, b: *const);
+                mulmod(out: *mut, a: *const, b: *const); divmod(out: *mut, a: *const, b: *const);
+                powmod(out: *mut, a: *const, b: *const);
+                idiv(out: *mut, a: *const, b: *const); irem(out: *mut, a: *const, b: *const);
+                band(out: *mut, a: *const, b: *const); bor(out: *mut, a: *const, b: *const);
+                bxor(out: *mut, a: *const, b: *const);
+                shrmod(out: *mut, a: *const, b: *const); shlmod(out: *mut, a: *const, b: *const)
+            );
+
+            unsafe fn set_u32(out: *mut $crate::backend::FieldHandle, x: u32) {
+                $crate::backend::paste::paste! { [<_ $prefix _set_u32>](out, x) }
+            }
+
+            unsafe fn set_u64(out: *mut $crate::backend::FieldHandle, x: u64) {
+                $crate::backend::paste::paste! { [<_ $prefix _set_u64>](out, x) }
+            }
+
+            unsafe fn set_bytes(out: *mut $crate::backend::FieldHandle, bytes: *const u8, len: u32, order: i32) {
+                $crate::backend::paste::paste! { [<_ $prefix _set_bytes>](out, bytes, len, order) }
+            }
+
+            unsafe fn set_str(out: *mut $crate::backend::FieldHandle, s: *const i8, base: u32) {
+                $crate::backend::paste::paste! { [<_ $prefix _set_str>](out, s, base) }
+            }
+
+            unsafe fn get_u64(x: *const $crate::backend::FieldHandle) -> u64 {
+                $crate::backend::paste::paste! { [<_ $prefix _get_u64>](x) }
+            }
+
+            unsafe fn to_bytes(out: *mut u8, x: *const $crate::backend::FieldHandle, len: u32, order: i32) {
+                $crate::backend::paste::paste! { [<_ $prefix _to_bytes>](out, x, len, order) }
+            }
+
+            unsafe fn print(a: *const $crate::backend::FieldHandle, base: u32) {
+                $crate::backend::paste::paste! { [<_ $prefix _print>](a, base) }
+            }
+
+            unsafe fn assert_equal_u64(a: *const $crate::backend::FieldHandle, b: u64) {
+                $crate::backend::paste::paste! { [<_ $prefix _assert_equal_u64>](a, b) }
+            }
+
+            unsafe fn assert_equal_bytes(a: *const $crate::backend::FieldHandle, bytes: *const u8, len: u32, order: i32) {
+                $crate::backend::paste::paste! { [<_ $prefix _assert_equal_bytes>](a, bytes, len, order) }
+            }
+
+            $crate::impl_field_backend!(@preds $prefix; eq, lt, lte, gt, gte, land, lor);
+
+            unsafe fn eqz(a: *const $crate::backend::FieldHandle) -> bool {
+                $crate::backend::paste::paste! { [<_ $prefix _eqz>](a) }
+            }
+
+            unsafe fn bigint_idiv(
+                q: *mut u64,
+                r: *mut u64,
+                a: *const u64,
+                b: *const u64,
+                a_count: u32,
+                b_count: u32,
+                bits: u32,
+            ) {
+                $crate::backend::paste::paste! {
+                    [<_ $prefix _bigint_idiv>](q, r, a, b, a_count, b_count, bits)
+                }
+            }
+
+            unsafe fn bigint_invmod(
+                out: *mut u64,
+                a: *const u64,
+                m: *const u64,
+                a_count: u32,
+                m_count: u32,
+                bits: u32,
+            ) {
+                $crate::backend::paste::paste! {
+                    [<_ $prefix _bigint_invmod>](out, a, m, a_count, m_count, bits)
+                }
+            }
+        }
+    };
+
+    (@ops $prefix:ident; $($op:ident($($arg:ident: *$m:ident),+ $(; $count:ident)?));+ $(;)?) => {
+        $(
+            unsafe fn $op($($arg: *$m $crate::backend::FieldHandle),+ $(, $count: u32)?) {
+                $crate::backend::paste::paste! { [<_ $prefix _ $op>]($($arg),+ $(, $count)?) }
+            }
+        )+
+    };
+
+    (@preds $prefix:ident; $($op:ident),+) => {
+        $(
+            unsafe fn $op(a: *const $crate::backend::FieldHandle, b: *const $crate::backend::FieldHandle) -> bool {
+                $crate::backend::paste::paste! { [<_ $prefix _ $op>](a, b) }
+            }
+        )+
+    };
+}
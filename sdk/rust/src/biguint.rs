@@ -0,0 +1,511 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Arbitrary-Width Unsigned Integers for Ligetron
+//!
+//! [`BigUintN<L>`] is an unsigned integer of `L` 64-bit limbs stored as BN254
+//! field elements (limb 0 is the least significant). Aliases are provided for
+//! common widths: [`Uint256`] (with extra operations on the `uint256` host
+//! module), [`Uint384`] for P-384 and [`Uint2048`] for RSA moduli.
+//!
+//! The operations here are built on the generic `bn254fr_bigint_*` host
+//! calls. Division and inversion take unconstrained host hints and constrain
+//! the result: `a = q·b + r` with `r < b`, and `a·a⁻¹ ≡ 1 (mod m)`.
+//!
+//! [`LimbAccumulator`] exposes the unreduced limb arithmetic underneath:
+//! products and values are added limb-wise without carries and normalized
+//! once, so a multi-operand sum costs one carry chain instead of one per
+//! operand.
+
+use crate::bn254fr::{self, Bn254Fr};
+use crate::uint256::{add_limb_with_carry, sub_limb_with_borrow, Uint256, UINT256_NLIMBS};
+
+/// Number of bits per limb
+pub const LIMB_BITS: usize = 64;
+
+/// 384-bit unsigned integer
+pub type Uint384 = BigUintN<6>;
+
+/// 512-bit unsigned integer
+pub type Uint512 = BigUintN<8>;
+
+/// 2048-bit unsigned integer
+pub type Uint2048 = BigUintN<32>;
+
+/// Unsigned integer of `L` 64-bit limbs
+#[derive(Clone)]
+pub struct BigUintN<const L: usize> {
+    pub(crate) limbs: [Bn254Fr; L],
+}
+
+/// Result of addition/subtraction with carry/borrow
+pub struct BigUintNCc<const L: usize> {
+    pub val: BigUintN<L>,
+    pub carry: Bn254Fr,
+}
+
+/// Double-width result of multiplication
+pub struct BigUintNWide<const L: usize> {
+    pub lo: BigUintN<L>,
+    pub hi: BigUintN<L>,
+}
+
+impl<const L: usize> Default for BigUintN<L> {
+    fn default() -> Self {
+        BigUintN {
+            limbs: std::array::from_fn(|_| Bn254Fr::new()),
+        }
+    }
+}
+
+impl<const L: usize> BigUintN<L> {
+    /// Number of bits
+    pub const BITS: usize = L * LIMB_BITS;
+
+    /// Create a new value with all limbs set to zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get a reference to a limb
+    #[inline(always)]
+    pub fn limb(&self, i: usize) -> &Bn254Fr {
+        &self.limbs[i]
+    }
+
+    /// Get a mutable reference to a limb
+    #[inline(always)]
+    pub fn limb_mut(&mut self, i: usize) -> &mut Bn254Fr {
+        &mut self.limbs[i]
+    }
+
+    /// All limbs, least significant first
+    pub fn limbs(&self) -> &[Bn254Fr; L] {
+        &self.limbs
+    }
+
+    /// Create from limbs, range checking each to 64 bits
+    pub fn from_limbs(limbs: [Bn254Fr; L]) -> Self {
+        for l in &limbs {
+            let _ = l.to_bits(LIMB_BITS);
+        }
+        BigUintN { limbs }
+    }
+
+    /// Create from a u64 value
+    pub fn from_u64(val: u64) -> Self {
+        let mut out = Self::new();
+        out.limbs[0] = Bn254Fr::from_u64(val);
+        out
+    }
+
+    /// Parse a hex string (with optional 0x prefix), range checked.
+    /// Panics if the string is not hex or does not fit in `L` limbs.
+    pub fn from_hex(s: &str) -> Self {
+        let digits = s.trim_start_matches("0x");
+        assert!(digits.len() <= L * 16, "BigUintN::from_hex: value too large");
+
+        let limbs = std::array::from_fn(|i| {
+            let end = digits.len().saturating_sub(i * 16);
+            let start = end.saturating_sub(16);
+            let v = if start < end {
+                u64::from_str_radix(&digits[start..end], 16)
+                    .expect("BigUintN::from_hex: invalid hex digit")
+            } else {
+                0
+            };
+            Bn254Fr::from_u64(v)
+        });
+        Self::from_limbs(limbs)
+    }
+
+    /// Set value from little-endian bytes with constraints.
+    /// Panics if `bytes` is longer than `8·L`.
+    pub fn set_bytes_little(&mut self, bytes: &[u8]) {
+        assert!(bytes.len() <= L * 8, "BigUintN::set_bytes_little: value too large");
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let start = (i * 8).min(bytes.len());
+            let end = (start + 8).min(bytes.len());
+            limb.set_bytes_little(&bytes[start..end]);
+            let _ = limb.to_bits(LIMB_BITS);
+        }
+    }
+
+    /// Set value from big-endian bytes with constraints.
+    /// Panics if `bytes` is longer than `8·L`.
+    pub fn set_bytes_big(&mut self, bytes: &[u8]) {
+        let mut little = bytes.to_vec();
+        little.reverse();
+        self.set_bytes_little(&little);
+    }
+
+    /// Convert to little-endian bytes (`8·L` bytes) with constraints
+    pub fn to_bytes_little(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(L * 8);
+        for limb in &self.limbs {
+            let _ = limb.to_bits(LIMB_BITS);
+            bytes.extend_from_slice(&limb.get_u64().to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Convert to big-endian bytes (`8·L` bytes) with constraints
+    pub fn to_bytes_big(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_little();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Decompose into `64·L` bits with constraints
+    pub fn to_bits(&self) -> Vec<Bn254Fr> {
+        self.limbs.iter().flat_map(|l| l.to_bits(LIMB_BITS)).collect()
+    }
+
+    /// Compose from `64·L` bits with constraints
+    pub fn from_bits(bits: &[Bn254Fr]) -> Self {
+        assert_eq!(bits.len(), Self::BITS, "BigUintN::from_bits: wrong number of bits");
+        BigUintN {
+            limbs: std::array::from_fn(|i| {
+                Bn254Fr::from_bits_checked(&bits[i * LIMB_BITS..(i + 1) * LIMB_BITS])
+            }),
+        }
+    }
+
+    /// Widen a [`Uint256`], zero-extending the upper limbs. Requires `L >= 4`.
+    pub fn from_uint256(x: &Uint256) -> Self {
+        assert!(L >= UINT256_NLIMBS, "BigUintN::from_uint256: too few limbs");
+        let mut out = Self::new();
+        for i in 0..UINT256_NLIMBS {
+            out.limbs[i] = x.limb(i).clone();
+        }
+        out
+    }
+
+    /// Narrow to a [`Uint256`], constraining the upper limbs to be zero.
+    /// Requires `L >= 4`.
+    pub fn to_uint256(&self) -> Uint256 {
+        assert!(L >= UINT256_NLIMBS, "BigUintN::to_uint256: too few limbs");
+        let zero = Bn254Fr::from_u32(0);
+        for limb in &self.limbs[UINT256_NLIMBS..] {
+            Bn254Fr::assert_equal(limb, &zero);
+        }
+        let mut out = Uint256::new();
+        for i in 0..UINT256_NLIMBS {
+            *out.limb_mut(i) = self.limbs[i].clone();
+        }
+        out
+    }
+}
+
+// ============= Comparison Operations =============
+
+/// Return 1 if `x == y`, 0 otherwise. Adds constraints.
+pub fn eq<const L: usize>(x: &BigUintN<L>, y: &BigUintN<L>) -> Bn254Fr {
+    let mut out = Bn254Fr::from_u32(1);
+    let mut e = Bn254Fr::new();
+    for (a, b) in x.limbs.iter().zip(y.limbs.iter()) {
+        bn254fr::eq_checked(&mut e, a, b);
+        out.mulmod_checked(&e);
+    }
+    out
+}
+
+/// Return 1 if `x == 0`, 0 otherwise. Adds constraints.
+pub fn eqz<const L: usize>(x: &BigUintN<L>) -> Bn254Fr {
+    eq(x, &BigUintN::new())
+}
+
+/// Return 1 if `x < y`, 0 otherwise. Adds constraints.
+pub fn lt<const L: usize>(x: &BigUintN<L>, y: &BigUintN<L>) -> Bn254Fr {
+    sub_cc(x, y).carry
+}
+
+/// Conditional select: returns b if cond == 1, a if cond == 0.
+/// cond must be either 0 or 1. Adds constraints.
+pub fn mux<const L: usize>(cond: &Bn254Fr, a: &BigUintN<L>, b: &BigUintN<L>) -> BigUintN<L> {
+    let mut result = BigUintN::new();
+    for i in 0..L {
+        bn254fr::mux(result.limb_mut(i), cond, a.limb(i), b.limb(i));
+    }
+    result
+}
+
+/// Add equality constraints for two values
+pub fn assert_equal<const L: usize>(x: &BigUintN<L>, y: &BigUintN<L>) {
+    for (a, b) in x.limbs.iter().zip(y.limbs.iter()) {
+        Bn254Fr::assert_equal(a, b);
+    }
+}
+
+// ============= Arithmetic Operations =============
+
+/// Addition with carry, adds constraints
+pub fn add_cc<const L: usize>(a: &BigUintN<L>, b: &BigUintN<L>) -> BigUintNCc<L> {
+    let mut result = BigUintN::new();
+    let mut carry = Bn254Fr::from_u32(0);
+
+    for i in 0..L {
+        let (limb, c) = add_limb_with_carry(a.limb(i), b.limb(i), &carry);
+        result.limbs[i] = limb;
+        carry = c;
+    }
+
+    BigUintNCc { val: result, carry }
+}
+
+/// Subtraction with borrow, adds constraints. The borrow is returned as
+/// `carry` and is 1 when `a < b`.
+pub fn sub_cc<const L: usize>(a: &BigUintN<L>, b: &BigUintN<L>) -> BigUintNCc<L> {
+    let mut result = BigUintN::new();
+    let mut borrow = Bn254Fr::from_u32(0);
+
+    for i in 0..L {
+        let (limb, c) = sub_limb_with_borrow(a.limb(i), b.limb(i), &borrow);
+        result.limbs[i] = limb;
+        borrow = c;
+    }
+
+    BigUintNCc { val: result, carry: borrow }
+}
+
+/// Bit bound on the coefficients of the unreduced product of two arrays of
+/// 64-bit limbs
+fn product_bits(a_len: usize, b_len: usize) -> u32 {
+    2 * LIMB_BITS as u32 + a_len.min(b_len).next_power_of_two().trailing_zeros()
+}
+
+/// Product of two limb arrays in proper representation
+/// (`a.len() + b.len()` limbs), adds constraints
+fn mul_limbs(a: &[Bn254Fr], b: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    let mut acc = LimbAccumulator::new(a.len() + b.len());
+    acc.add_product(a, b, 0);
+    acc.finish()
+}
+
+/// Multiplication returning the double-width result, adds constraints
+pub fn mul_wide<const L: usize>(a: &BigUintN<L>, b: &BigUintN<L>) -> BigUintNWide<L> {
+    let out = mul_limbs(&a.limbs, &b.limbs);
+    BigUintNWide {
+        lo: BigUintN { limbs: std::array::from_fn(|i| out[i].clone()) },
+        hi: BigUintN { limbs: std::array::from_fn(|i| out[L + i].clone()) },
+    }
+}
+
+/// Multiplication returning the low `64·L` bits, adds constraints
+pub fn mul_lo<const L: usize>(a: &BigUintN<L>, b: &BigUintN<L>) -> BigUintN<L> {
+    mul_wide(a, b).lo
+}
+
+/// Multiplication returning the high `64·L` bits, adds constraints
+pub fn mul_hi<const L: usize>(a: &BigUintN<L>, b: &BigUintN<L>) -> BigUintN<L> {
+    mul_wide(a, b).hi
+}
+
+/// Divide the range-checked limbs `a` by `b`, constraining
+/// `a = q·b + r` and `r < b`. Returns `(q, r)` with `q` of `a.len()` limbs.
+fn div_rem_limbs<const L: usize>(a: &[Bn254Fr], b: &BigUintN<L>) -> (Vec<Bn254Fr>, BigUintN<L>) {
+    let mut q: Vec<Bn254Fr> = (0..a.len()).map(|_| Bn254Fr::new()).collect();
+    let mut r = BigUintN::<L>::new();
+    bn254fr::bigint_idiv(&mut q, &mut r.limbs, a, &b.limbs, LIMB_BITS as u32);
+
+    for limb in q.iter().chain(r.limbs.iter()) {
+        let _ = limb.to_bits(LIMB_BITS);
+    }
+
+    // q·b + r, with r added before carry propagation
+    let mut acc = LimbAccumulator::new(a.len() + L);
+    acc.add_product(&q, &b.limbs, 0);
+    acc.add(&r.limbs, 0);
+    let qbr = acc.finish();
+
+    let zero = Bn254Fr::from_u32(0);
+    for (i, limb) in qbr.iter().enumerate() {
+        Bn254Fr::assert_equal(limb, a.get(i).unwrap_or(&zero));
+    }
+
+    // r < b (this also rules out b = 0)
+    Bn254Fr::assert_equal(&lt(&r, b), &Bn254Fr::from_u32(1));
+
+    (q, r)
+}
+
+/// Division with remainder: returns `(q, r)` with `a = q·b + r`, `r < b`.
+/// Adds constraints.
+pub fn div_qr<const L: usize>(a: &BigUintN<L>, b: &BigUintN<L>) -> (BigUintN<L>, BigUintN<L>) {
+    let (q, r) = div_rem_limbs(&a.limbs, b);
+    (BigUintN { limbs: std::array::from_fn(|i| q[i].clone()) }, r)
+}
+
+/// `a mod m`, adds constraints
+pub fn modulo<const L: usize>(a: &BigUintN<L>, m: &BigUintN<L>) -> BigUintN<L> {
+    div_rem_limbs(&a.limbs, m).1
+}
+
+/// Double-width value reduced modulo `m`, adds constraints
+pub fn wide_mod<const L: usize>(wide: &BigUintNWide<L>, m: &BigUintN<L>) -> BigUintN<L> {
+    let limbs: Vec<Bn254Fr> = wide.lo.limbs.iter().chain(wide.hi.limbs.iter()).cloned().collect();
+    div_rem_limbs(&limbs, m).1
+}
+
+/// `a·b mod m`, adds constraints
+pub fn mulmod<const L: usize>(a: &BigUintN<L>, b: &BigUintN<L>, m: &BigUintN<L>) -> BigUintN<L> {
+    wide_mod(&mul_wide(a, b), m)
+}
+
+/// Modular inverse `a⁻¹ mod m`, adds constraints.
+/// The proof fails if `a` is not invertible modulo `m`.
+pub fn invmod<const L: usize>(a: &BigUintN<L>, m: &BigUintN<L>) -> BigUintN<L> {
+    let mut inv = BigUintN::<L>::new();
+    bn254fr::bigint_invmod(&mut inv.limbs, &a.limbs, &m.limbs, LIMB_BITS as u32);
+    for limb in &inv.limbs {
+        let _ = limb.to_bits(LIMB_BITS);
+    }
+
+    assert_equal(&mulmod(&inv, a, m), &BigUintN::from_u64(1));
+    inv
+}
+
+/// Modular exponentiation `base^e mod m`, with the exponent given as
+/// boolean-constrained bits, least significant first. Adds constraints.
+pub fn powmod_bits<const L: usize>(base: &BigUintN<L>, exp_bits: &[Bn254Fr], m: &BigUintN<L>) -> BigUintN<L> {
+    let mut acc = BigUintN::from_u64(1);
+    for bit in exp_bits.iter().rev() {
+        acc = mulmod(&acc, &acc, m);
+        let prod = mulmod(&acc, base, m);
+        acc = mux(bit, &acc, &prod);
+    }
+    acc
+}
+
+// ============= Carry-Save Accumulation =============
+
+/// Largest limb bound the accumulator lets build up before normalizing.
+/// Carry propagation splits each limb into three 64-bit parts.
+const MAX_ACC_BITS: u32 = 3 * LIMB_BITS as u32 - 1;
+
+/// Accumulator of unreduced limbs.
+///
+/// Values and limb products are added without carry propagation; the
+/// accumulator tracks a bound on the limb width and normalizes (one
+/// constrained carry chain) only when that bound would become too large or
+/// when [`LimbAccumulator::finish`] is called. All inputs must be arrays of
+/// range-checked 64-bit limbs.
+///
+/// The accumulated value must fit in `width` limbs; normalizing constrains
+/// the carry out of the top limb to be zero.
+pub struct LimbAccumulator {
+    limbs: Vec<Bn254Fr>,
+    bound_bits: u32,
+}
+
+impl LimbAccumulator {
+    /// Zero accumulator of `width` limbs
+    pub fn new(width: usize) -> Self {
+        assert!(width > 0, "LimbAccumulator: zero width");
+        LimbAccumulator {
+            limbs: (0..width).map(|_| Bn254Fr::from_u32(0)).collect(),
+            bound_bits: 0,
+        }
+    }
+
+    /// Number of limbs
+    pub fn width(&self) -> usize {
+        self.limbs.len()
+    }
+
+    /// Current bound on the bit width of each limb
+    pub fn bound_bits(&self) -> u32 {
+        self.bound_bits
+    }
+
+    /// Normalize first if adding terms of `term_bits` bits would exceed the
+    /// limb bound, then account for the addition
+    fn reserve(&mut self, term_bits: u32) {
+        assert!(term_bits < MAX_ACC_BITS, "LimbAccumulator: term too wide");
+        if self.bound_bits.max(term_bits) + 1 > MAX_ACC_BITS {
+            self.normalize();
+        }
+        self.bound_bits = self.bound_bits.max(term_bits) + 1;
+    }
+
+    /// Add `x · 2^(64·offset)`
+    pub fn add(&mut self, x: &[Bn254Fr], offset: usize) {
+        assert!(offset + x.len() <= self.width(), "LimbAccumulator: value too wide");
+        self.reserve(LIMB_BITS as u32);
+        for (acc, xi) in self.limbs[offset..].iter_mut().zip(x) {
+            acc.addmod_checked(xi);
+        }
+    }
+
+    /// Add `a · b · 2^(64·offset)` without carry propagation
+    pub fn add_product(&mut self, a: &[Bn254Fr], b: &[Bn254Fr], offset: usize) {
+        assert!(offset + a.len() + b.len() <= self.width() + 1,
+                "LimbAccumulator: product too wide");
+        let mut no_carry: Vec<Bn254Fr> = (0..a.len() + b.len() - 1).map(|_| Bn254Fr::new()).collect();
+        bn254fr::bigint_mul_checked_no_carry(&mut no_carry, a, b);
+
+        self.reserve(product_bits(a.len(), b.len()));
+        for (acc, c) in self.limbs[offset..].iter_mut().zip(&no_carry) {
+            acc.addmod_checked(c);
+        }
+    }
+
+    /// Add a [`BigUintN`]
+    pub fn add_biguint<const L: usize>(&mut self, x: &BigUintN<L>) {
+        self.add(&x.limbs, 0);
+    }
+
+    /// Add the product of two [`BigUintN`] values
+    pub fn add_mul<const L: usize>(&mut self, a: &BigUintN<L>, b: &BigUintN<L>) {
+        self.add_product(&a.limbs, &b.limbs, 0);
+    }
+
+    /// Propagate carries so every limb is a range-checked 64-bit value.
+    /// Constrains the carry out of the top limb to be zero.
+    pub fn normalize(&mut self) {
+        if self.bound_bits <= LIMB_BITS as u32 {
+            return;
+        }
+
+        let mut out: Vec<Bn254Fr> = (0..self.width() + 1).map(|_| Bn254Fr::new()).collect();
+        bn254fr::bigint_convert_to_proper_representation_checked(
+            &mut out, &mut self.limbs, LIMB_BITS as u32, self.bound_bits);
+
+        let top = out.pop().unwrap();
+        Bn254Fr::assert_equal(&top, &Bn254Fr::from_u32(0));
+        self.limbs = out;
+        self.bound_bits = LIMB_BITS as u32;
+    }
+
+    /// Normalize and return the limbs, least significant first
+    pub fn finish(mut self) -> Vec<Bn254Fr> {
+        self.normalize();
+        if self.bound_bits < LIMB_BITS as u32 {
+            // Only 64-bit values have been added: range check them once
+            for limb in &self.limbs {
+                let _ = limb.to_bits(LIMB_BITS);
+            }
+        }
+        self.limbs
+    }
+
+    /// Normalize into a [`BigUintN`]; the width must be `L`
+    pub fn finish_biguint<const L: usize>(self) -> BigUintN<L> {
+        assert_eq!(self.width(), L, "LimbAccumulator: width mismatch");
+        let limbs = self.finish();
+        BigUintN { limbs: std::array::from_fn(|i| limbs[i].clone()) }
+    }
+}
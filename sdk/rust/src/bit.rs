@@ -0,0 +1,149 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Boolean Field Elements for Ligetron
+//!
+//! A [`Bit`] is a `Bn254Fr` that is known to be constrained to {0, 1}.
+//! Booleanness is enforced once, when the bit is created, and preserved by
+//! the logic gadgets, so consumers such as [`Bit::mux`] do not need to
+//! re-check their selector. Gated assertions (`assert_equal_if`, ...) only
+//! take effect when their flag bit is set.
+
+use crate::bn254fr::{Bn254Fr, addmod_checked, submod_checked, mulmod_checked};
+use crate::word::{bit_and, bit_andn, bit_not, bit_or, bit_xor};
+
+/// A field element constrained to be either 0 or 1
+#[derive(Clone)]
+pub struct Bit(Bn254Fr);
+
+impl Bit {
+    /// Wrap a field element, asserting x * (x - 1) == 0
+    pub fn new_checked(x: Bn254Fr) -> Self {
+        Bn254Fr::assert_bit(&x);
+        Bit(x)
+    }
+
+    /// Wrap a field element that is already constrained to be boolean
+    /// (e.g. an output of `to_bits` or of a comparison gadget).
+    /// No constraint is added; the caller is responsible for booleanness.
+    pub fn from_constrained(x: Bn254Fr) -> Self {
+        Bit(x)
+    }
+
+    /// Create a constant bit
+    pub fn from_bool(b: bool) -> Self {
+        Bit(Bn254Fr::from_u32(b as u32))
+    }
+
+    /// Get the underlying field element
+    #[inline(always)]
+    pub fn as_fr(&self) -> &Bn254Fr {
+        &self.0
+    }
+
+    /// Unwrap into the underlying field element
+    #[inline(always)]
+    pub fn into_fr(self) -> Bn254Fr {
+        self.0
+    }
+
+    /// Get the bit value (no constraints)
+    pub fn value(&self) -> bool {
+        self.0.get_u64() != 0
+    }
+
+    /// out = self & other
+    pub fn and(&self, other: &Bit) -> Bit {
+        Bit(bit_and(&self.0, &other.0))
+    }
+
+    /// out = self | other
+    pub fn or(&self, other: &Bit) -> Bit {
+        Bit(bit_or(&self.0, &other.0))
+    }
+
+    /// out = self ^ other
+    pub fn xor(&self, other: &Bit) -> Bit {
+        Bit(bit_xor(&self.0, &other.0))
+    }
+
+    /// out = !self & other
+    pub fn andn(&self, other: &Bit) -> Bit {
+        Bit(bit_andn(&self.0, &other.0))
+    }
+
+    /// out = !self
+    pub fn not(&self) -> Bit {
+        Bit(bit_not(&self.0))
+    }
+
+    /// Conditional selection: returns self ? a1 : a0, with constraints.
+    /// The selector is already boolean, so no extra range check is emitted.
+    pub fn mux(&self, a0: &Bn254Fr, a1: &Bn254Fr) -> Bn254Fr {
+        // out = a0 + self * (a1 - a0)
+        let mut tmp = Bn254Fr::new();
+        submod_checked(&mut tmp, a1, a0);
+        tmp.mulmod_checked(&self.0);
+
+        let mut out = Bn254Fr::new();
+        addmod_checked(&mut out, a0, &tmp);
+        out
+    }
+
+    /// Assert the bit equals `b` in the constraint system
+    pub fn assert_equal(a: &Bit, b: &Bit) {
+        Bn254Fr::assert_equal(&a.0, &b.0);
+    }
+}
+
+impl From<Bit> for Bn254Fr {
+    fn from(b: Bit) -> Bn254Fr {
+        b.0
+    }
+}
+
+/// Return 1 if all bits are set (with constraints)
+pub fn all(bits: &[Bit]) -> Bit {
+    let mut acc = Bn254Fr::from_u32(1);
+    for b in bits {
+        let mut t = Bn254Fr::new();
+        mulmod_checked(&mut t, &acc, &b.0);
+        acc = t;
+    }
+    Bit(acc)
+}
+
+// ============= Gated Assertions =============
+
+/// Assert a == b only when `flag` is 1, enforced as flag * (a - b) == 0
+pub fn assert_equal_if(flag: &Bit, a: &Bn254Fr, b: &Bn254Fr) {
+    let zero = Bn254Fr::from_u32(0);
+    let mut diff = Bn254Fr::new();
+    submod_checked(&mut diff, a, b);
+    Bn254Fr::assert_mul(&zero, &flag.0, &diff);
+}
+
+/// Assert cond == 1 only when `flag` is 1, enforced as flag * (cond - 1) == 0
+pub fn assert_one_if(flag: &Bit, cond: &Bn254Fr) {
+    let one = Bn254Fr::from_u32(1);
+    assert_equal_if(flag, cond, &one);
+}
+
+/// Assert x == 0 only when `flag` is 1, enforced as flag * x == 0
+pub fn assert_zero_if(flag: &Bit, x: &Bn254Fr) {
+    let zero = Bn254Fr::from_u32(0);
+    Bn254Fr::assert_mul(&zero, &flag.0, x);
+}
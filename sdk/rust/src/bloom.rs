@@ -0,0 +1,137 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Constrained Bloom Filter for Ligetron
+//!
+//! A filter of `2^log_size` bits committed with Poseidon2 (bits packed 253
+//! per field element). Element positions are derived with a keyed Poseidon2
+//! PRF, `index_i = low_bits(Poseidon2(key, element, i))`, and each probe
+//! selects the filter bit through a multiplexer tree, so the probed positions
+//! stay private.
+//!
+//! Bloom filters give cheap probabilistic denylist checks: "definitely not a
+//! member" is exact, "maybe a member" has a false-positive rate.
+
+use crate::bn254fr::{Bn254Fr, mux};
+use crate::poseidon2::poseidon2_hash;
+
+/// Number of filter bits packed into one field element of the commitment
+const PACK_BITS: usize = 253;
+
+/// A committed Bloom filter
+pub struct BloomFilter {
+    bits: Vec<Bn254Fr>,
+    log_size: usize,
+    hashes: u32,
+    key: Bn254Fr,
+}
+
+impl BloomFilter {
+    /// Create an empty filter of `2^log_size` bits probed by `hashes`
+    /// PRF outputs keyed with `key`
+    pub fn new(log_size: usize, hashes: u32, key: Bn254Fr) -> Self {
+        assert!((1..=24).contains(&log_size), "BloomFilter: invalid size");
+        assert!(hashes > 0, "BloomFilter: at least one hash is required");
+        let bits = (0..1usize << log_size).map(|_| Bn254Fr::from_u32(0)).collect();
+        BloomFilter { bits, log_size, hashes, key }
+    }
+
+    /// Wrap private filter bits, constraining each to {0, 1}
+    pub fn from_bits(bits: Vec<Bn254Fr>, hashes: u32, key: Bn254Fr) -> Self {
+        assert!(bits.len().is_power_of_two(), "BloomFilter: size must be a power of two");
+        for b in &bits {
+            Bn254Fr::assert_bit(b);
+        }
+        let log_size = bits.len().trailing_zeros() as usize;
+        BloomFilter { bits, log_size, hashes, key }
+    }
+
+    /// Filter bits
+    pub fn bits(&self) -> &[Bn254Fr] {
+        &self.bits
+    }
+
+    /// Poseidon2 commitment to the filter bits
+    pub fn commit(&self) -> Bn254Fr {
+        let packed: Vec<Bn254Fr> = self.bits.chunks(PACK_BITS)
+            .map(Bn254Fr::from_bits_checked)
+            .collect();
+        poseidon2_hash(&packed)
+    }
+
+    /// Little-endian bits of the `i`-th probe position of `element`
+    fn probe_bits(&self, element: &Bn254Fr, i: u32) -> Vec<Bn254Fr> {
+        let h = poseidon2_hash(&[self.key.clone(), element.clone(), Bn254Fr::from_u32(i)]);
+        let mut bits = h.to_bits_canonical();
+        bits.truncate(self.log_size);
+        bits
+    }
+
+    /// Select the filter bit at a private position given as index bits
+    fn select(&self, index: &[Bn254Fr]) -> Bn254Fr {
+        let mut level: Vec<Bn254Fr> = self.bits.clone();
+        for b in index {
+            level = level.chunks(2)
+                .map(|p| {
+                    let mut out = Bn254Fr::new();
+                    mux(&mut out, b, &p[0], &p[1]);
+                    out
+                })
+                .collect();
+        }
+        level.pop().unwrap()
+    }
+
+    /// Probe positions of an element (for building the filter)
+    fn positions(&self, element: &Bn254Fr) -> Vec<usize> {
+        let mask = (1u64 << self.log_size) - 1;
+        (0..self.hashes)
+            .map(|i| {
+                let h = poseidon2_hash(&[self.key.clone(), element.clone(), Bn254Fr::from_u32(i)]);
+                (h.get_u64() & mask) as usize
+            })
+            .collect()
+    }
+
+    /// Insert an element (sets its probe bits; no constraints on the filter
+    /// contents, which are bound through [`BloomFilter::commit`])
+    pub fn insert(&mut self, element: &Bn254Fr) {
+        for p in self.positions(element) {
+            self.bits[p] = Bn254Fr::from_u32(1);
+        }
+    }
+
+    /// Assert that all probe bits of `element` are set (the element may be
+    /// in the filter)
+    pub fn assert_maybe_member(&self, element: &Bn254Fr) {
+        let one = Bn254Fr::from_u32(1);
+        for i in 0..self.hashes {
+            let bit = self.select(&self.probe_bits(element, i));
+            Bn254Fr::assert_equal(&bit, &one);
+        }
+    }
+
+    /// Assert that some probe bit of `element` is clear (the element is
+    /// definitely not in the filter)
+    pub fn assert_definitely_not_member(&self, element: &Bn254Fr) {
+        let mut prod = Bn254Fr::from_u32(1);
+        for i in 0..self.hashes {
+            let bit = self.select(&self.probe_bits(element, i));
+            prod.mulmod_checked(&bit);
+        }
+        Bn254Fr::assert_equal(&prod, &Bn254Fr::from_u32(0));
+    }
+}
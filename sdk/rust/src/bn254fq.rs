@@ -0,0 +1,235 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! BN254 Base Field (Fq) Arithmetic for Ligetron
+//!
+//! The BN254 base field `q = 0x30644e72...d87cfd47` is larger than the
+//! scalar field the VM works in, so elements are emulated as [`Uint256`]
+//! values (4 x 64-bit limbs) kept in canonical form `0 <= x < q`.
+//!
+//! - Addition and subtraction use a carry chain and a conditional
+//!   correction by `q`.
+//! - Multiplication computes the 512-bit product and witnesses
+//!   `a * b = k * q + r`, checking the identity limb-wise and `r < q`.
+//!
+//! This is enough to manipulate points on BN254 G1 (`y^2 = x^3 + 3` over
+//! Fq) and other curves defined over Fq.
+
+use crate::bn254fr::Bn254Fr;
+use crate::uint256::{self, Uint256, Uint256Wide, add_cc, sub_cc, mul_wide};
+
+/// BN254 base field modulus q (hex, without prefix)
+pub const BN254FQ_MODULUS: &str =
+    "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+
+/// The modulus as a Uint256
+fn modulus() -> Uint256 {
+    const LIMBS: [u64; 4] = uint256::parse_hex_limbs(BN254FQ_MODULUS);
+    Uint256::from_limbs_u64(LIMBS)
+}
+
+/// Assert that `v < q`
+fn assert_canonical(v: &Uint256) {
+    // v - q borrows iff v < q
+    let d = sub_cc(v, &modulus());
+    Bn254Fr::assert_equal(&d.carry, &Bn254Fr::from_u32(1));
+}
+
+/// Reduce a 512-bit value modulo q with constraints
+fn reduce_wide(w: &Uint256Wide) -> Uint256 {
+    let q = modulus();
+    let zero = Bn254Fr::from_u32(0);
+
+    // Witness w = k * q + r; k < 2^256 because w < q^2
+    let (k, k_hi, r) = w.divide_qr_normalized(&q);
+    Bn254Fr::assert_equal(&k_hi, &zero);
+
+    let kq = mul_wide(&k, &q);
+    let lo = add_cc(&kq.lo, &r);
+    let mut carry = Uint256::new();
+    carry.set_words(&[lo.carry.clone(), zero.clone(), zero.clone(), zero.clone()]);
+    let hi = add_cc(&kq.hi, &carry);
+    Bn254Fr::assert_equal(&hi.carry, &zero);
+    uint256::assert_equal(&lo.val, &w.lo);
+    uint256::assert_equal(&hi.val, &w.hi);
+
+    assert_canonical(&r);
+    r
+}
+
+/// An element of the BN254 base field
+#[derive(Clone)]
+pub struct Bn254Fq {
+    value: Uint256,
+}
+
+impl Bn254Fq {
+    pub fn zero() -> Self {
+        Bn254Fq { value: Uint256::from_u64(0) }
+    }
+
+    pub fn one() -> Self {
+        Bn254Fq { value: Uint256::from_u64(1) }
+    }
+
+    pub fn from_u64(v: u64) -> Self {
+        Bn254Fq { value: Uint256::from_u64(v) }
+    }
+
+    /// Construct a constant from a string in the given base.
+    /// The value must already be reduced.
+    pub fn from_str(s: &str, base: u32) -> Self {
+        Bn254Fq { value: Uint256::from_str(s, base) }
+    }
+
+    /// Wrap a private Uint256, asserting that it is reduced modulo q
+    pub fn from_uint256_checked(v: &Uint256) -> Self {
+        assert_canonical(v);
+        Bn254Fq { value: v.clone() }
+    }
+
+    /// Embed a scalar field element (p < q, so every element is canonical)
+    pub fn from_fr_checked(x: &Bn254Fr) -> Self {
+        let mut bits = x.to_bits_canonical();
+        bits.resize_with(256, || Bn254Fr::from_u32(0));
+        Bn254Fq { value: Uint256::from_bits(&bits) }
+    }
+
+    /// Underlying canonical integer
+    pub fn value(&self) -> &Uint256 {
+        &self.value
+    }
+
+    /// Constrained little-endian bits of the canonical value (254 bits, q < 2^254)
+    pub fn to_bits(&self) -> Vec<Bn254Fr> {
+        let mut bits = self.value.to_bits();
+        bits.truncate(254);
+        bits
+    }
+
+    /// self + other mod q
+    pub fn add(&self, other: &Bn254Fq) -> Bn254Fq {
+        // a + b < 2q < 2^256, subtract q unless that borrows
+        let s = add_cc(&self.value, &other.value);
+        let t = sub_cc(&s.val, &modulus());
+        Bn254Fq { value: uint256::mux(&t.carry, &t.val, &s.val) }
+    }
+
+    /// self - other mod q
+    pub fn sub(&self, other: &Bn254Fq) -> Bn254Fq {
+        // add q back if the subtraction borrowed
+        let d = sub_cc(&self.value, &other.value);
+        let t = add_cc(&d.val, &modulus());
+        Bn254Fq { value: uint256::mux(&d.carry, &d.val, &t.val) }
+    }
+
+    /// -self mod q
+    pub fn neg(&self) -> Bn254Fq {
+        Bn254Fq::zero().sub(self)
+    }
+
+    /// self * other mod q
+    pub fn mul(&self, other: &Bn254Fq) -> Bn254Fq {
+        Bn254Fq { value: reduce_wide(&mul_wide(&self.value, &other.value)) }
+    }
+
+    /// self^2 mod q
+    pub fn square(&self) -> Bn254Fq {
+        self.mul(self)
+    }
+
+    /// self^(-1) mod q; fails for zero
+    pub fn inv(&self) -> Bn254Fq {
+        let inv = Bn254Fq { value: uint256::invmod(&self.value, &modulus()) };
+        assert_canonical(&inv.value);
+        Bn254Fq::assert_equal(&self.mul(&inv), &Bn254Fq::one());
+        inv
+    }
+
+    /// self / other mod q; fails for a zero divisor
+    pub fn div(&self, other: &Bn254Fq) -> Bn254Fq {
+        self.mul(&other.inv())
+    }
+
+    /// Return 1 if a == b, 0 otherwise (with constraints)
+    pub fn eq_checked(a: &Bn254Fq, b: &Bn254Fq) -> Bn254Fr {
+        uint256::eq(&a.value, &b.value)
+    }
+
+    /// Constrain a == b
+    pub fn assert_equal(a: &Bn254Fq, b: &Bn254Fq) {
+        uint256::assert_equal(&a.value, &b.value);
+    }
+}
+
+/// Assert that (x, y) lies on BN254 G1: y^2 = x^3 + 3
+pub fn assert_on_g1(x: &Bn254Fq, y: &Bn254Fq) {
+    let rhs = x.square().mul(x).add(&Bn254Fq::from_u64(3));
+    Bn254Fq::assert_equal(&y.square(), &rhs);
+}
+
+#[cfg(all(test, feature = "native-sim"))]
+mod tests {
+    use super::*;
+
+    fn fq(hex: &str) -> Bn254Fq {
+        Bn254Fq::from_str(hex, 16)
+    }
+
+    /// q - 1
+    const Q_MINUS_1: &str = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46";
+    /// q - 2
+    const Q_MINUS_2: &str = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45";
+    /// q - 3
+    const Q_MINUS_3: &str = "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd44";
+
+    #[test]
+    fn add() {
+        let one = Bn254Fq::one();
+        Bn254Fq::assert_equal(&one.add(&Bn254Fq::from_u64(2)), &Bn254Fq::from_u64(3));
+        Bn254Fq::assert_equal(&fq(Q_MINUS_1).add(&one), &Bn254Fq::zero());
+        Bn254Fq::assert_equal(&fq(Q_MINUS_1).add(&Bn254Fq::from_u64(2)), &one);
+        Bn254Fq::assert_equal(&fq(Q_MINUS_1).add(&fq(Q_MINUS_1)), &fq(Q_MINUS_2));
+    }
+
+    #[test]
+    fn sub() {
+        let two = Bn254Fq::from_u64(2);
+        Bn254Fq::assert_equal(&Bn254Fq::from_u64(5).sub(&two), &Bn254Fq::from_u64(3));
+        Bn254Fq::assert_equal(&two.sub(&two), &Bn254Fq::zero());
+        Bn254Fq::assert_equal(&two.sub(&Bn254Fq::from_u64(5)), &fq(Q_MINUS_3));
+        Bn254Fq::assert_equal(&Bn254Fq::zero().sub(&fq(Q_MINUS_1)), &Bn254Fq::one());
+    }
+
+    #[test]
+    fn neg() {
+        Bn254Fq::assert_equal(&Bn254Fq::zero().neg(), &Bn254Fq::zero());
+        Bn254Fq::assert_equal(&Bn254Fq::one().neg(), &fq(Q_MINUS_1));
+        let x = Bn254Fq::from_u64(12345);
+        Bn254Fq::assert_equal(&x.add(&x.neg()), &Bn254Fq::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_wrong_sum_fails() {
+        Bn254Fq::assert_equal(&Bn254Fq::one().add(&Bn254Fq::one()), &Bn254Fq::from_u64(3));
+    }
+
+    #[test]
+    fn g1_generator_on_curve() {
+        assert_on_g1(&Bn254Fq::from_u64(1), &Bn254Fq::from_u64(2));
+    }
+}
@@ -17,18 +17,60 @@
 //! BN254 Scalar Field Operations for Ligetron
 
 use crate::api::*;
+use crate::backend::{FieldBackend, FieldHandle};
 use std::ffi::CString;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 /// A BN254 scalar field element
 ///
 /// This is an opaque handle to a field element managed by the Ligetron backend.
-#[repr(C)]
-#[derive(Copy, Clone, Default)]
-struct bn254fr_t {
-    pub handle: u64,
+#[allow(non_camel_case_types)]
+type bn254fr_t = FieldHandle;
+
+/// Host backend for the BN254 scalar field (`bn254fr` import module)
+pub struct Bn254Backend;
+
+crate::impl_field_backend!(Bn254Backend, bn254fr, "bn254fr", BN254FR_MODULUS, 254);
+
+type Backend = Bn254Backend;
+
+/// BN254 scalar field modulus p
+pub const BN254FR_MODULUS: &str =
+    "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+
+/// (p - 1) / 2, the largest "non-negative" field element
+pub const BN254FR_HALF_MODULUS: &str =
+    "0x183227397098d014dc2822db40c0ac2e9419f4243cdcb848a1f0fac9f8000000";
+
+/// Field element constant from a decimal or 0x-prefixed hex literal.
+///
+/// The literal is parsed and checked to be below the modulus at compile
+/// time, then loaded from bytes, so no string is parsed at runtime:
+///
+/// ```ignore
+/// let beta = fr!("0x2a");
+/// let big = fr!("21888242871839275222246405745257275088548364400416034343698204186575808495616");
+/// ```
+#[macro_export]
+macro_rules! fr {
+    ($lit:expr) => {{
+        const BYTES: [u8; 32] = $crate::bn254fr::parse_literal_bytes_big($lit);
+        $crate::bn254fr::Bn254Fr::from_bytes_big(&BYTES)
+    }};
+}
+
+/// Error returned when a field element does not fit in the target integer type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromFrError;
+
+impl std::fmt::Display for TryFromFrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("field element out of range for target integer type")
+    }
 }
 
+impl std::error::Error for TryFromFrError {}
+
 #[repr(C)]
 pub struct Bn254Fr {
     data: bn254fr_t,
@@ -38,7 +80,7 @@ pub struct Bn254Fr {
 impl Drop for Bn254Fr {
     fn drop(&mut self) {
         unsafe {
-            _bn254fr_free(&mut self.data);
+            Backend::free(&mut self.data);
         }
     }
 }
@@ -47,12 +89,12 @@ impl Clone for Bn254Fr {
     fn clone(&self) -> Self {
         let mut out = Bn254Fr::new();
         unsafe {
-            _bn254fr_copy(&mut out.data, &self.data);
+            Backend::copy(&mut out.data, &self.data);
         }
 
         if self.is_constrained() {
             unsafe {
-                _bn254fr_assert_equal(&out.data, &self.data);
+                Backend::assert_equal(&out.data, &self.data);
             }
             out.set_constrained(true);
         }
@@ -76,17 +118,13 @@ macro_rules! bn254fr_unary {
         if $out.is_constrained() {
             let mut tmp = Bn254Fr::new();
             unsafe {
-                paste::paste! {
-                    [<_bn254fr_ $op>](&mut tmp.data, &$a.data);
-                }
+                Backend::$op(&mut tmp.data, &$a.data);
             }
             handle_swap(&mut tmp.data, &mut $out.data);
             $out.set_constrained(false);
         } else {
             unsafe {
-                paste::paste! {
-                    [<_bn254fr_ $op>](&mut $out.data, &$a.data);
-                }
+                Backend::$op(&mut $out.data, &$a.data);
             }
         }
     };
@@ -97,17 +135,13 @@ macro_rules! bn254fr_binary {
         if $out.is_constrained() {
             let mut tmp = Bn254Fr::new();
             unsafe {
-                paste::paste! {
-                    [<_bn254fr_ $op>](&mut tmp.data, &$a.data, &$b.data);
-                }
+                Backend::$op(&mut tmp.data, &$a.data, &$b.data);
             }
             handle_swap(&mut tmp.data, &mut $out.data);
             $out.set_constrained(false);
         } else {
             unsafe {
-                paste::paste! {
-                    [<_bn254fr_ $op>](&mut $out.data, &$a.data, &$b.data);
-                }
+                Backend::$op(&mut $out.data, &$a.data, &$b.data);
             }
         }
     };
@@ -140,7 +174,7 @@ impl Bn254Fr {
             constrained: false.into(),
         };
         unsafe {
-            _bn254fr_alloc(&mut out.data);
+            Backend::alloc(&mut out.data);
         };
         out
     }
@@ -165,8 +199,8 @@ impl Bn254Fr {
     pub fn from_u32(value: u32) -> Self {
         let mut out = Bn254Fr::new();
         unsafe {
-            _bn254fr_alloc(&mut out.data);
-            _bn254fr_set_u32(&mut out.data, value);
+            Backend::alloc(&mut out.data);
+            Backend::set_u32(&mut out.data, value);
         }
         out
     }
@@ -175,8 +209,8 @@ impl Bn254Fr {
     pub fn from_u64(value: u64) -> Self {
         let mut out = Bn254Fr::new();
         unsafe {
-            _bn254fr_alloc(&mut out.data);
-            _bn254fr_set_u64(&mut out.data, value);
+            Backend::alloc(&mut out.data);
+            Backend::set_u64(&mut out.data, value);
         }
         out
     }
@@ -185,8 +219,8 @@ impl Bn254Fr {
     pub fn from_c_str(str_ptr: *const i8) -> Self {
         let mut out = Bn254Fr::new();
         unsafe {
-            _bn254fr_alloc(&mut out.data);
-            _bn254fr_set_str(&mut out.data, str_ptr, 0);
+            Backend::alloc(&mut out.data);
+            Backend::set_str(&mut out.data, str_ptr, 0);
         }
         out
     }
@@ -197,11 +231,19 @@ impl Bn254Fr {
         Self::from_c_str(c_str.as_ptr())
     }
 
+    /// Construct field element from 32 big-endian bytes, e.g. a literal
+    /// parsed by [`fr!`](crate::fr)
+    pub fn from_bytes_big(bytes: &[u8; 32]) -> Self {
+        let mut out = Bn254Fr::new();
+        out.set_bytes_big(bytes);
+        out
+    }
+
     /// Construct field element as a copy (does not copy constraints)
     pub fn copy_from(src: Self) -> Self {
         let mut out = Bn254Fr::new();
         unsafe {
-            _bn254fr_copy(&mut out.data, &src.data);
+            Backend::copy(&mut out.data, &src.data);
         }
         out
     }
@@ -210,7 +252,7 @@ impl Bn254Fr {
     /// Mirrors C++ bn254fr_copy
     pub fn copy(&mut self, src: &Bn254Fr) {
         unsafe {
-            _bn254fr_copy(&mut self.data, &src.data);
+            Backend::copy(&mut self.data, &src.data);
         }
     }
 
@@ -226,8 +268,8 @@ impl Bn254Fr {
     pub fn clear(&mut self) {
         if self.is_constrained() {
             unsafe {
-                _bn254fr_free(&mut self.data);
-                _bn254fr_alloc(&mut self.data);
+                Backend::free(&mut self.data);
+                Backend::alloc(&mut self.data);
             }
             self.set_constrained(false);
         }
@@ -237,7 +279,7 @@ impl Bn254Fr {
     pub fn set_u32(&mut self, value: u32) {
         self.clear();
         unsafe {
-            _bn254fr_set_u32(&mut self.data, value);
+            Backend::set_u32(&mut self.data, value);
         }
     }
 
@@ -245,7 +287,7 @@ impl Bn254Fr {
     pub fn set_u64(&mut self, value: u64) {
         self.clear();
         unsafe {
-            _bn254fr_set_u64(&mut self.data, value);
+            Backend::set_u64(&mut self.data, value);
         }
     }
 
@@ -254,7 +296,7 @@ impl Bn254Fr {
         self.clear();
         let c_str = CString::new(s).expect("Error parsing numeric string");
         unsafe {
-            _bn254fr_set_str(&mut self.data, c_str.as_ptr(), base);
+            Backend::set_str(&mut self.data, c_str.as_ptr(), base);
         }
     }
 
@@ -262,7 +304,7 @@ impl Bn254Fr {
     pub fn set_bytes_little(&mut self, bytes: &[u8]) {
         self.clear();
         unsafe {
-            _bn254fr_set_bytes(&mut self.data, bytes.as_ptr(), bytes.len() as u32, -1);
+            Backend::set_bytes(&mut self.data, bytes.as_ptr(), bytes.len() as u32, -1);
         }
     }
 
@@ -270,26 +312,60 @@ impl Bn254Fr {
     pub fn set_bytes_big(&mut self, bytes: &[u8]) {
         self.clear();
         unsafe {
-            _bn254fr_set_bytes(&mut self.data, bytes.as_ptr(), bytes.len() as u32, 1);
+            Backend::set_bytes(&mut self.data, bytes.as_ptr(), bytes.len() as u32, 1);
         }
     }
 
     /// Get field element as u64 (may truncate for large values)
     pub fn get_u64(&self) -> u64 {
-        unsafe { _bn254fr_get_u64(&self.data) }
+        unsafe { Backend::get_u64(&self.data) }
+    }
+
+    /// Get field element as 32 little-endian bytes, without constraints
+    pub fn get_bytes_little(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        unsafe { Backend::to_bytes(out.as_mut_ptr(), &self.data, 32, -1) }
+        out
+    }
+
+    /// Get field element as 32 big-endian bytes, without constraints
+    pub fn get_bytes_big(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        unsafe { Backend::to_bytes(out.as_mut_ptr(), &self.data, 32, 1) }
+        out
+    }
+
+    /// Get field element as u64, range constraining it to 64 bits.
+    /// Returns an error (and adds no constraint) if the value does not fit.
+    pub fn try_to_u64_checked(&self) -> Result<u64, TryFromFrError> {
+        if !lt(self, &Bn254Fr::from_str("0x10000000000000000")) {
+            return Err(TryFromFrError);
+        }
+        let _ = self.to_bits(64);
+        Ok(self.get_u64())
+    }
+
+    /// Get field element as u32, range constraining it to 32 bits.
+    /// Returns an error (and adds no constraint) if the value does not fit.
+    pub fn try_to_u32_checked(&self) -> Result<u32, TryFromFrError> {
+        if !lt(self, &Bn254Fr::from_u64(1 << 32)) {
+            return Err(TryFromFrError);
+        }
+        let _ = self.to_bits(32);
+        Ok(self.get_u64() as u32)
     }
 
     /// Print field element for debugging (base 10 or 16)
     pub fn print_dec(&self) {
         unsafe {
-            _bn254fr_print(&self.data, 10);
+            Backend::print(&self.data, 10);
         }
     }
 
     /// Print field element for debugging (base 10 or 16)
     pub fn print_hex(&self) {
         unsafe {
-            _bn254fr_print(&self.data, 16);
+            Backend::print(&self.data, 16);
         }
     }
 
@@ -308,7 +384,7 @@ impl Bn254Fr {
                 out_buff[i].handle = bits[i].data.handle;
             }
 
-            _bn254fr_to_bits_checked(&mut out_buff[0], &self.data, count as u32);
+            Backend::to_bits_checked(&mut out_buff[0], &self.data, count as u32);
         }
 
         for i in 0..count {
@@ -334,7 +410,7 @@ impl Bn254Fr {
             for i in 0..count {
                 bits_buff[i].handle = bits[i].data.handle;
             }
-            _bn254fr_from_bits_checked(
+            Backend::from_bits_checked(
                 &out.data as *const bn254fr_t as *mut bn254fr_t,
                 &bits_buff[0],
                 count as u32,
@@ -348,6 +424,107 @@ impl Bn254Fr {
         out
     }
 
+    /// Decompose field element into `count` bytes with constraints
+    /// (little-endian, byte 0 is the least significant).
+    ///
+    /// Each byte is composed from 8 constrained bits, so it is guaranteed to be
+    /// < 256, and the bytes recompose to `self`. For `count == 32` the
+    /// canonical 254-bit decomposition is used, so the bytes encode the
+    /// unique value below the modulus and the top byte holds 6 bits.
+    pub fn to_bytes_checked(&self, count: usize) -> Vec<Bn254Fr> {
+        if !(1..=32).contains(&count) {
+            assert_one(0);
+            return (0..count).map(|_| Bn254Fr::new()).collect();
+        }
+
+        let bits = if count == 32 {
+            self.to_bits_canonical()
+        } else {
+            self.to_bits(count * 8)
+        };
+        bits.chunks(8)
+            .map(Bn254Fr::from_bits_checked)
+            .collect()
+    }
+
+    /// Decompose field element into its canonical 254-bit representation.
+    ///
+    /// Unlike `to_bits(254)`, this also constrains the bits to encode a value
+    /// strictly below the modulus, so the decomposition is unique.
+    pub fn to_bits_canonical(&self) -> Vec<Bn254Fr> {
+        let bits = self.to_bits(254);
+        let one = Bn254Fr::from_u32(1);
+        let lt = lt_constant_checked(&bits, BN254FR_MODULUS);
+        Bn254Fr::assert_equal(&lt, &one);
+        bits
+    }
+
+    /// Return 1 if the canonical representation of self is odd, 0 otherwise
+    pub fn is_odd_checked(&self) -> Bn254Fr {
+        let bits = self.to_bits_canonical();
+        bits[0].clone()
+    }
+
+    /// Return 1 if self > (p - 1) / 2 (i.e. self is "negative"), 0 otherwise
+    pub fn sign_checked(&self) -> Bn254Fr {
+        let bits = self.to_bits_canonical();
+        gt_constant_checked(&bits, BN254FR_HALF_MODULUS)
+    }
+
+    /// Return |self|, interpreting values above (p - 1) / 2 as negative
+    pub fn abs_checked(&self) -> Bn254Fr {
+        let sign = self.sign_checked();
+        let mut neg = Bn254Fr::new();
+        negmod_checked(&mut neg, self);
+
+        let mut out = Bn254Fr::new();
+        mux(&mut out, &sign, self, &neg);
+        out
+    }
+
+    /// Decompose field element into bits without adding any constraints.
+    ///
+    /// # Soundness
+    /// The returned bits are plain witness values: nothing forces them to be
+    /// boolean or to recompose to `self`. Only use this for prover-side scratch
+    /// computation whose result is later bound by other constraints (or never
+    /// influences a checked value). Use [`Bn254Fr::to_bits`] otherwise.
+    pub fn to_bits_unchecked(&self, count: usize) -> Vec<Bn254Fr> {
+        let bits: Vec<Bn254Fr> = (0..count).map(|_| Bn254Fr::new()).collect();
+
+        if !(1..=254).contains(&count) {
+            assert_one(0);
+            return bits;
+        }
+
+        let mut out_buff: Vec<bn254fr_t> = bits.iter().map(|b| b.data).collect();
+        unsafe {
+            Backend::to_bits(out_buff.as_mut_ptr(), &self.data, count as u32);
+        }
+        bits
+    }
+
+    /// Compose field element from bits without adding any constraints.
+    ///
+    /// # Soundness
+    /// The output is not tied to `bits` in the constraint system, and the bits
+    /// are not checked to be boolean. See [`Bn254Fr::to_bits_unchecked`];
+    /// use [`Bn254Fr::from_bits_checked`] when the result must be sound.
+    pub fn from_bits_unchecked(bits: &[Bn254Fr]) -> Bn254Fr {
+        let count = bits.len();
+        if !(1..=254).contains(&count) {
+            assert_one(0);
+            return Bn254Fr::new();
+        }
+
+        let mut out = Bn254Fr::new();
+        let bits_buff: Vec<bn254fr_t> = bits.iter().map(|b| b.data).collect();
+        unsafe {
+            Backend::from_bits(&mut out.data, bits_buff.as_ptr(), count as u32);
+        }
+        out
+    }
+
     // ============= In-place Arithmetic Operations =============
 
     /// self = self + a mod p
@@ -479,7 +656,7 @@ impl Bn254Fr {
 
     /// Return true if self == 0
     pub fn is_zero(&mut self) -> bool {
-        unsafe { _bn254fr_eqz(&self.data) }
+        unsafe { Backend::eqz(&self.data) }
     }
 
     // ============= Arithmetic Constraint Assertions =============
@@ -487,17 +664,45 @@ impl Bn254Fr {
     /// Assert a == b in the constraint system
     pub fn assert_equal(a: &Bn254Fr, b: &Bn254Fr) {
         unsafe {
-            _bn254fr_assert_equal(&a.data, &b.data);
+            Backend::assert_equal(&a.data, &b.data);
         }
         a.set_constrained(true);
         b.set_constrained(true);
     }
 
+    /// Assert self == c for a u64 constant, without allocating a field
+    /// element for `c`
+    pub fn assert_equal_const(&self, c: u64) {
+        unsafe {
+            Backend::assert_equal_u64(&self.data, c);
+        }
+        self.set_constrained(true);
+    }
+
+    /// Assert self == c for a constant given as a string (decimal or hex
+    /// with 0x prefix). Panics if the constant is not below the modulus.
+    pub fn assert_equal_const_str(&self, s: &str) {
+        let bytes = parse_literal_bytes_big(s);
+        unsafe {
+            Backend::assert_equal_bytes(&self.data, bytes.as_ptr(), 32, 1);
+        }
+        self.set_constrained(true);
+    }
+
+    /// Assert x is boolean, i.e. x * (x - 1) == 0 in the constraint system
+    pub fn assert_bit(x: &Bn254Fr) {
+        let zero = Bn254Fr::from_u32(0);
+        let one = Bn254Fr::from_u32(1);
+        let mut x_minus_one = Bn254Fr::new();
+        submod_checked(&mut x_minus_one, x, &one);
+        Bn254Fr::assert_mul(&zero, x, &x_minus_one);
+    }
+
     /// Assert self == a + b (enforces a linear constraint)
     #[inline(always)]
     pub fn assert_add(out: &Bn254Fr, a: &Bn254Fr, b: &Bn254Fr) {
         unsafe {
-            _bn254fr_assert_add(&out.data, &a.data, &b.data);
+            Backend::assert_add(&out.data, &a.data, &b.data);
         }
         a.set_constrained(true);
         b.set_constrained(true);
@@ -508,7 +713,7 @@ impl Bn254Fr {
     #[inline(always)]
     pub fn assert_mul(out: &Bn254Fr, a: &Bn254Fr, b: &Bn254Fr) {
         unsafe {
-            _bn254fr_assert_mul(&out.data, &a.data, &b.data);
+            Backend::assert_mul(&out.data, &a.data, &b.data);
         }
         a.set_constrained(true);
         b.set_constrained(true);
@@ -519,7 +724,7 @@ impl Bn254Fr {
     #[inline(always)]
     pub fn assert_mulc(out: &Bn254Fr, a: &Bn254Fr, k: &Bn254Fr) {
         unsafe {
-            _bn254fr_assert_mulc(&out.data, &a.data, &k.data);
+            Backend::assert_mulc(&out.data, &a.data, &k.data);
         }
         a.set_constrained(true);
         out.set_constrained(true);
@@ -563,6 +768,71 @@ pub fn powmod(out: &mut Bn254Fr, a: &Bn254Fr, b: &Bn254Fr) {
     bn254fr_binary!(powmod, out, a, b);
 }
 
+// p - 1 = 2^28 * Q with Q odd; 5 is a quadratic non-residue
+const SQRT_TWO_ADICITY: u32 = 28;
+const SQRT_Q: &str = "81540058820840996586704275553141814055101440848469862132140264610111";
+const SQRT_Q_PLUS_ONE_HALF: &str = "40770029410420498293352137776570907027550720424234931066070132305056";
+const SQRT_NONRESIDUE_POW_Q: &str =
+    "19103219067921713944291392827692070036145651957329286315305642004821462161904";
+
+/// out = sqrt(a) mod p (Tonelli-Shanks, unconstrained).
+/// Returns false and leaves `out` unspecified if `a` is not a square.
+pub fn sqrtmod(out: &mut Bn254Fr, a: &Bn254Fr) -> bool {
+    let one = Bn254Fr::from_u32(1);
+    let zero = Bn254Fr::from_u32(0);
+    if eq(a, &zero) {
+        out.set_u32(0);
+        return true;
+    }
+
+    let mut euler = Bn254Fr::new();
+    powmod(&mut euler, a, &Bn254Fr::from_str(BN254FR_HALF_MODULUS));
+    if !eq(&euler, &one) {
+        return false;
+    }
+
+    let mut m = SQRT_TWO_ADICITY;
+    let mut c = Bn254Fr::from_str(SQRT_NONRESIDUE_POW_Q);
+    let mut t = Bn254Fr::new();
+    let mut r = Bn254Fr::new();
+    powmod(&mut t, a, &Bn254Fr::from_str(SQRT_Q));
+    powmod(&mut r, a, &Bn254Fr::from_str(SQRT_Q_PLUS_ONE_HALF));
+
+    while !eq(&t, &one) {
+        // Least i with t^(2^i) == 1
+        let mut i = 0;
+        let mut t2 = t.clone();
+        while !eq(&t2, &one) {
+            let mut sq = Bn254Fr::new();
+            mulmod(&mut sq, &t2, &t2);
+            t2 = sq;
+            i += 1;
+        }
+
+        // b = c^(2^(m - i - 1))
+        let mut b = c.clone();
+        for _ in 0..(m - i - 1) {
+            let mut sq = Bn254Fr::new();
+            mulmod(&mut sq, &b, &b);
+            b = sq;
+        }
+
+        m = i;
+        let mut tmp = Bn254Fr::new();
+        mulmod(&mut tmp, &b, &b);
+        c = tmp;
+        let mut tmp = Bn254Fr::new();
+        mulmod(&mut tmp, &t, &c);
+        t = tmp;
+        let mut tmp = Bn254Fr::new();
+        mulmod(&mut tmp, &r, &b);
+        r = tmp;
+    }
+
+    *out = r;
+    true
+}
+
 /// out = a / b (integer division)
 pub fn idiv(out: &mut Bn254Fr, a: &Bn254Fr, b: &Bn254Fr) {
     bn254fr_binary!(idiv, out, a, b);
@@ -577,39 +847,39 @@ pub fn irem(out: &mut Bn254Fr, a: &Bn254Fr, b: &Bn254Fr) {
 
 /// Return true if self == other
 pub fn eq(a: &Bn254Fr, b: &Bn254Fr) -> bool {
-    unsafe { _bn254fr_eq(&a.data, &b.data) }
+    unsafe { Backend::eq(&a.data, &b.data) }
 }
 
 /// Return true if self < other
 pub fn lt(a: &Bn254Fr, b: &Bn254Fr) -> bool {
-    unsafe { _bn254fr_lt(&a.data, &b.data) }
+    unsafe { Backend::lt(&a.data, &b.data) }
 }
 
 /// Return true if self <= other
 pub fn lte(a: &Bn254Fr, b: &Bn254Fr) -> bool {
-    unsafe { _bn254fr_lte(&a.data, &b.data) }
+    unsafe { Backend::lte(&a.data, &b.data) }
 }
 
 /// Return true if self > other
 pub fn gt(a: &Bn254Fr, b: &Bn254Fr) -> bool {
-    unsafe { _bn254fr_gt(&a.data, &b.data) }
+    unsafe { Backend::gt(&a.data, &b.data) }
 }
 
 /// Return true if self >= other
 pub fn gte(a: &Bn254Fr, b: &Bn254Fr) -> bool {
-    unsafe { _bn254fr_gte(&a.data, &b.data) }
+    unsafe { Backend::gte(&a.data, &b.data) }
 }
 
 // ============= Logical Operations =============
 
 ///  Return true if both a and b are nonzero.
 pub fn land(a: &Bn254Fr, b: &Bn254Fr) -> bool {
-    unsafe { _bn254fr_land(&a.data, &b.data) }
+    unsafe { Backend::land(&a.data, &b.data) }
 }
 
 ///  Return true if both a and b are nonzero.
 pub fn lor(a: &Bn254Fr, b: &Bn254Fr) -> bool {
-    unsafe { _bn254fr_lor(&a.data, &b.data) }
+    unsafe { Backend::lor(&a.data, &b.data) }
 }
 
 // ============= Bitwise Operations =============
@@ -706,6 +976,71 @@ pub fn invmod_checked(out: &mut Bn254Fr, a: &Bn254Fr) {
     Bn254Fr::assert_mul(&one, out, a);
 }
 
+// ============= Operator Overloads (with constraints) =============
+
+macro_rules! impl_checked_op {
+    ($trait:ident, $method:ident, $func:ident) => {
+        impl std::ops::$trait<&Bn254Fr> for &Bn254Fr {
+            type Output = Bn254Fr;
+
+            fn $method(self, rhs: &Bn254Fr) -> Bn254Fr {
+                let mut out = Bn254Fr::new();
+                $func(&mut out, self, rhs);
+                out
+            }
+        }
+
+        impl std::ops::$trait<Bn254Fr> for &Bn254Fr {
+            type Output = Bn254Fr;
+
+            fn $method(self, rhs: Bn254Fr) -> Bn254Fr {
+                std::ops::$trait::$method(self, &rhs)
+            }
+        }
+
+        impl std::ops::$trait<&Bn254Fr> for Bn254Fr {
+            type Output = Bn254Fr;
+
+            fn $method(self, rhs: &Bn254Fr) -> Bn254Fr {
+                std::ops::$trait::$method(&self, rhs)
+            }
+        }
+
+        impl std::ops::$trait<Bn254Fr> for Bn254Fr {
+            type Output = Bn254Fr;
+
+            fn $method(self, rhs: Bn254Fr) -> Bn254Fr {
+                std::ops::$trait::$method(&self, &rhs)
+            }
+        }
+    };
+}
+
+// `a + b` is `addmod_checked`, `a / b` is `divmod_checked`, and so on;
+// every operator adds its constraint.
+impl_checked_op!(Add, add, addmod_checked);
+impl_checked_op!(Sub, sub, submod_checked);
+impl_checked_op!(Mul, mul, mulmod_checked);
+impl_checked_op!(Div, div, divmod_checked);
+
+impl std::ops::Neg for &Bn254Fr {
+    type Output = Bn254Fr;
+
+    fn neg(self) -> Bn254Fr {
+        let mut out = Bn254Fr::new();
+        negmod_checked(&mut out, self);
+        out
+    }
+}
+
+impl std::ops::Neg for Bn254Fr {
+    type Output = Bn254Fr;
+
+    fn neg(self) -> Bn254Fr {
+        -&self
+    }
+}
+
 /// out = 1 if x == 0, 0 otherwise (with constraints)
 /// Implements the technique: out = -x * inv + 1 where inv = 1/x if x != 0, else 0
 pub fn eqz_checked(out: &mut Bn254Fr, x: &Bn254Fr) {
@@ -741,6 +1076,135 @@ pub fn eq_checked(out: &mut Bn254Fr, a: &Bn254Fr, b: &Bn254Fr) {
     eqz_checked(out, &sub_res);
 }
 
+// ============= Comparisons with Constants =============
+
+/// Parse the digits of `s` from `start` in `radix` into 32 big-endian bytes
+const fn parse_digits_big(s: &[u8], start: usize, radix: u32) -> [u8; 32] {
+    assert!(s.len() > start, "Error parsing numeric string");
+
+    let mut out = [0u8; 32];
+    let mut i = start;
+    while i < s.len() {
+        let digit = match s[i] {
+            c @ b'0'..=b'9' => c - b'0',
+            c @ b'a'..=b'f' => c - b'a' + 10,
+            c @ b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("Error parsing numeric string"),
+        } as u32;
+        assert!(digit < radix, "Error parsing numeric string");
+
+        // out = out * radix + digit
+        let mut carry = digit;
+        let mut j = out.len();
+        while j > 0 {
+            j -= 1;
+            let v = out[j] as u32 * radix + carry;
+            out[j] = v as u8;
+            carry = v >> 8;
+        }
+        assert!(carry == 0, "Numeric constant does not fit in 256 bits");
+        i += 1;
+    }
+    out
+}
+
+const fn has_hex_prefix(s: &[u8]) -> bool {
+    s.len() >= 2 && s[0] == b'0' && s[1] == b'x'
+}
+
+/// Parse a decimal or 0x-prefixed hex literal into 32 big-endian bytes
+pub(crate) const fn parse_bytes_big(s: &str) -> [u8; 32] {
+    let s = s.as_bytes();
+    if has_hex_prefix(s) { parse_digits_big(s, 2, 16) } else { parse_digits_big(s, 0, 10) }
+}
+
+/// Parse a hex constant (with optional 0x prefix) into 32 big-endian bytes
+pub(crate) const fn parse_hex_bytes_big(s: &str) -> [u8; 32] {
+    let s = s.as_bytes();
+    parse_digits_big(s, if has_hex_prefix(s) { 2 } else { 0 }, 16)
+}
+
+const MODULUS_BYTES_BIG: [u8; 32] = parse_bytes_big(BN254FR_MODULUS);
+
+/// Parse a decimal or 0x-prefixed hex literal into 32 big-endian bytes and
+/// check that it is below the modulus. In a constant context a bad literal
+/// is a compile error; this is what [`fr!`](crate::fr) uses.
+pub const fn parse_literal_bytes_big(s: &str) -> [u8; 32] {
+    let bytes = parse_bytes_big(s);
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != MODULUS_BYTES_BIG[i] {
+            assert!(bytes[i] < MODULUS_BYTES_BIG[i], "Numeric constant is not below the field modulus");
+            return bytes;
+        }
+        i += 1;
+    }
+    panic!("Numeric constant is not below the field modulus")
+}
+
+/// Parse a hex constant (with optional 0x prefix) into `count` little-endian bits
+pub(crate) fn hex_to_bits(c: &str, count: usize) -> Vec<bool> {
+    let digits = c.trim_start_matches("0x").as_bytes();
+    (0..count)
+        .map(|i| {
+            let pos = i / 4;
+            if pos >= digits.len() {
+                return false;
+            }
+            let nibble = (digits[digits.len() - 1 - pos] as char)
+                .to_digit(16)
+                .expect("Error parsing hex constant");
+            (nibble >> (i % 4)) & 1 == 1
+        })
+        .collect()
+}
+
+/// Return 1 if the number encoded by constrained `bits` (little-endian) is
+/// strictly less than the hex constant `c`, 0 otherwise (with constraints).
+pub fn lt_constant_checked(bits: &[Bn254Fr], c: &str) -> Bn254Fr {
+    let c_bits = hex_to_bits(c, bits.len());
+    let mut eq = Bn254Fr::from_u32(1);
+    let mut lt = Bn254Fr::from_u32(0);
+
+    // Scan from the MSB, tracking whether the prefix is still equal
+    for (b, &ci) in bits.iter().zip(c_bits.iter()).rev() {
+        let mut t = Bn254Fr::new();
+        mulmod_checked(&mut t, &eq, b);
+        if ci {
+            // lt += eq * (1 - b), eq = eq * b
+            lt.addmod_checked(&eq);
+            lt.submod_checked(&t);
+            eq = t;
+        } else {
+            // eq = eq * (1 - b)
+            eq.submod_checked(&t);
+        }
+    }
+    lt
+}
+
+/// Return 1 if the number encoded by constrained `bits` (little-endian) is
+/// strictly greater than the hex constant `c`, 0 otherwise (with constraints).
+pub fn gt_constant_checked(bits: &[Bn254Fr], c: &str) -> Bn254Fr {
+    let c_bits = hex_to_bits(c, bits.len());
+    let mut eq = Bn254Fr::from_u32(1);
+    let mut gt = Bn254Fr::from_u32(0);
+
+    for (b, &ci) in bits.iter().zip(c_bits.iter()).rev() {
+        let mut t = Bn254Fr::new();
+        mulmod_checked(&mut t, &eq, b);
+        if ci {
+            // eq = eq * b
+            eq = t;
+        } else {
+            // gt += eq * b, eq = eq * (1 - b)
+            gt.addmod_checked(&t);
+            eq.submod_checked(&t);
+        }
+    }
+    gt
+}
+
 // ============= Misc =============
 
 /// Conditional selection: out = cond ? a1 : a0, sets constraints
@@ -785,14 +1249,51 @@ pub fn oblivious_if(out: &mut Bn254Fr, cond: bool, t: &Bn254Fr, f: &Bn254Fr) {
 
 // ============= Bigint Operations (for uint256) =============
 
+/// Multiplication strategy for [`bigint_mul_checked_no_carry_with`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BigintMulStrategy {
+    /// Host computes the product and checks it as a polynomial identity
+    /// at `a_count + b_count - 1` points
+    Host,
+    /// Guest schoolbook multiplication, `a_count · b_count` products
+    Schoolbook,
+    /// Guest Karatsuba multiplication, about `n^1.58` products for equal
+    /// lengths; unequal lengths fall back to schoolbook
+    Karatsuba,
+}
+
+/// Strategy used by [`bigint_mul_checked_no_carry`]
+pub const DEFAULT_BIGINT_MUL_STRATEGY: BigintMulStrategy = BigintMulStrategy::Host;
+
+/// Limb count at or below which Karatsuba switches to schoolbook
+const KARATSUBA_THRESHOLD: usize = 2;
+
 /// Compute product of two big integers without carry propagation.
 /// Used internally by uint256 multiplication.
-/// out must have length 2*count-1, a and b must have length count.
+/// out must have length a.len() + b.len() - 1.
 pub fn bigint_mul_checked_no_carry(out: &mut [Bn254Fr], a: &[Bn254Fr], b: &[Bn254Fr]) {
-    let count = a.len();
-    assert_eq!(b.len(), count);
-    assert_eq!(out.len(), 2 * count - 1);
+    bigint_mul_checked_no_carry_with(out, a, b, DEFAULT_BIGINT_MUL_STRATEGY);
+}
+
+/// Compute product of two big integers without carry propagation using
+/// the given strategy. out must have length a.len() + b.len() - 1.
+pub fn bigint_mul_checked_no_carry_with(out: &mut [Bn254Fr], a: &[Bn254Fr], b: &[Bn254Fr],
+                                        strategy: BigintMulStrategy) {
+    assert!(!a.is_empty() && !b.is_empty());
+    assert_eq!(out.len(), a.len() + b.len() - 1);
+
+    let product = match strategy {
+        BigintMulStrategy::Host => return bigint_mul_host(out, a, b),
+        BigintMulStrategy::Schoolbook => schoolbook_mul(a, b),
+        BigintMulStrategy::Karatsuba if a.len() == b.len() => karatsuba_mul(a, b),
+        BigintMulStrategy::Karatsuba => schoolbook_mul(a, b),
+    };
+    for (o, p) in out.iter_mut().zip(product) {
+        *o = p;
+    }
+}
 
+fn bigint_mul_host(out: &mut [Bn254Fr], a: &[Bn254Fr], b: &[Bn254Fr]) {
     // Extract handles into contiguous arrays
     let a_handles: Vec<u64> = a.iter().map(|f| f.raw_handle()).collect();
     let b_handles: Vec<u64> = b.iter().map(|f| f.raw_handle()).collect();
@@ -803,7 +1304,8 @@ pub fn bigint_mul_checked_no_carry(out: &mut [Bn254Fr], a: &[Bn254Fr], b: &[Bn25
             out_handles.as_mut_ptr(),
             a_handles.as_ptr(),
             b_handles.as_ptr(),
-            count as u32,
+            a.len() as u32,
+            b.len() as u32,
         );
     }
 
@@ -813,6 +1315,64 @@ pub fn bigint_mul_checked_no_carry(out: &mut [Bn254Fr], a: &[Bn254Fr], b: &[Bn25
     }
 }
 
+fn schoolbook_mul(a: &[Bn254Fr], b: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    let mut out: Vec<Bn254Fr> = (0..a.len() + b.len() - 1).map(|_| Bn254Fr::from_u32(0)).collect();
+    let mut t = Bn254Fr::new();
+    for (i, ai) in a.iter().enumerate() {
+        for (j, bj) in b.iter().enumerate() {
+            mulmod_checked(&mut t, ai, bj);
+            out[i + j].addmod_checked(&t);
+        }
+    }
+    out
+}
+
+fn karatsuba_mul(a: &[Bn254Fr], b: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    let n = a.len();
+    if n <= KARATSUBA_THRESHOLD {
+        return schoolbook_mul(a, b);
+    }
+
+    // a = a0 + x^m·a1, b = b0 + x^m·b1 with len(a1) >= len(a0)
+    let m = n / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let z0 = karatsuba_mul(a0, b0);
+    let z2 = karatsuba_mul(a1, b1);
+
+    let sum = |lo: &[Bn254Fr], hi: &[Bn254Fr]| -> Vec<Bn254Fr> {
+        hi.iter().enumerate().map(|(i, h)| {
+            let mut s = h.clone();
+            if let Some(l) = lo.get(i) {
+                s.addmod_checked(l);
+            }
+            s
+        }).collect()
+    };
+
+    // z1 = (a0 + a1)(b0 + b1) - z0 - z2
+    let mut z1 = karatsuba_mul(&sum(a0, a1), &sum(b0, b1));
+    for (i, z) in z1.iter_mut().enumerate() {
+        if let Some(v) = z0.get(i) {
+            z.submod_checked(v);
+        }
+        z.submod_checked(&z2[i]);
+    }
+
+    let mut out: Vec<Bn254Fr> = (0..2 * n - 1).map(|_| Bn254Fr::from_u32(0)).collect();
+    for (i, z) in z0.iter().enumerate() {
+        out[i].addmod_checked(z);
+    }
+    for (i, z) in z1.iter().enumerate() {
+        out[i + m].addmod_checked(z);
+    }
+    for (i, z) in z2.iter().enumerate() {
+        out[i + 2 * m].addmod_checked(z);
+    }
+    out
+}
+
 /// Convert big integer to proper representation with carry propagation.
 /// Used internally by uint256 multiplication.
 /// out must have length count+1, inp must have length count.
@@ -846,10 +1406,105 @@ pub fn bigint_convert_to_proper_representation(
     }
 }
 
+/// Convert big integer to proper representation with constraints.
+///
+/// Calls [`bigint_convert_to_proper_representation`] for the values, then
+/// range checks every output limb to `bits` bits and ties the output to the
+/// input through a constrained carry chain:
+/// `inp[i] + carry[i-1] = out[i] + carry[i]·2^bits`, `out[count] = carry[count-1]`.
+/// The input limbs must be below `2^in_bits`, with `in_bits < 3·bits` and
+/// `in_bits < 252`.
+/// out must have length count+1, inp must have length count.
+pub fn bigint_convert_to_proper_representation_checked(
+    out: &mut [Bn254Fr],
+    inp: &mut [Bn254Fr],
+    bits: u32,
+    in_bits: u32,
+) {
+    assert!(in_bits < 3 * bits && in_bits < 252, "bigint: input limbs too wide");
+    bigint_convert_to_proper_representation(out, inp, bits);
+
+    let count = inp.len();
+    for limb in &out[..count] {
+        let _ = limb.to_bits(bits as usize);
+    }
+
+    let mut pow = Bn254Fr::new();
+    shlmod(&mut pow, &Bn254Fr::from_u32(1), &Bn254Fr::from_u32(bits));
+    let carry_bits = (in_bits.max(bits) - bits + 1) as usize;
+
+    let mut carry = Bn254Fr::from_u32(0);
+    for (i, o) in out[..count].iter().enumerate() {
+        // carry = (inp[i] - out[i] + carry) / 2^bits
+        let mut diff = Bn254Fr::new();
+        submod_checked(&mut diff, &inp[i], o);
+        diff.addmod_checked(&carry);
+        divmod_constant_checked(&mut carry, &diff, &pow);
+        let _ = carry.to_bits(carry_bits);
+    }
+    Bn254Fr::assert_equal(&out[count], &carry);
+}
+
+/// Big integer division without constraints: a = q·b + r.
+/// q must have length a.len(), r must have length b.len().
+pub fn bigint_idiv(q: &mut [Bn254Fr], r: &mut [Bn254Fr], a: &[Bn254Fr], b: &[Bn254Fr], bits: u32) {
+    assert_eq!(q.len(), a.len());
+    assert_eq!(r.len(), b.len());
+
+    let a_handles: Vec<u64> = a.iter().map(|f| f.raw_handle()).collect();
+    let b_handles: Vec<u64> = b.iter().map(|f| f.raw_handle()).collect();
+    let mut q_handles: Vec<u64> = q.iter().map(|f| f.raw_handle()).collect();
+    let mut r_handles: Vec<u64> = r.iter().map(|f| f.raw_handle()).collect();
+
+    unsafe {
+        Backend::bigint_idiv(
+            q_handles.as_mut_ptr(),
+            r_handles.as_mut_ptr(),
+            a_handles.as_ptr(),
+            b_handles.as_ptr(),
+            a.len() as u32,
+            b.len() as u32,
+            bits,
+        );
+    }
+
+    for (i, h) in q_handles.into_iter().enumerate() {
+        q[i].set_raw_handle(h);
+    }
+    for (i, h) in r_handles.into_iter().enumerate() {
+        r[i].set_raw_handle(h);
+    }
+}
+
+/// Big integer modular inverse without constraints: out = a^-1 mod m.
+/// out must have length m.len().
+pub fn bigint_invmod(out: &mut [Bn254Fr], a: &[Bn254Fr], m: &[Bn254Fr], bits: u32) {
+    assert_eq!(out.len(), m.len());
+
+    let a_handles: Vec<u64> = a.iter().map(|f| f.raw_handle()).collect();
+    let m_handles: Vec<u64> = m.iter().map(|f| f.raw_handle()).collect();
+    let mut out_handles: Vec<u64> = out.iter().map(|f| f.raw_handle()).collect();
+
+    unsafe {
+        Backend::bigint_invmod(
+            out_handles.as_mut_ptr(),
+            a_handles.as_ptr(),
+            m_handles.as_ptr(),
+            a.len() as u32,
+            m.len() as u32,
+            bits,
+        );
+    }
+
+    for (i, h) in out_handles.into_iter().enumerate() {
+        out[i].set_raw_handle(h);
+    }
+}
+
 // Import declarations for all BN254FR functions
 // Order matches C++ bn254fr.hpp initialize() for consistency
 #[link(wasm_import_module = "bn254fr")]
-extern "C" {
+extern "C-unwind" {
     // Memory management
     #[link_name = "bn254fr_alloc"]
     fn _bn254fr_alloc(fr: *mut bn254fr_t);
@@ -874,6 +1529,9 @@ extern "C" {
     #[link_name = "bn254fr_get_u64"]
     fn _bn254fr_get_u64(x: *const bn254fr_t) -> u64;
 
+    #[link_name = "bn254fr_to_bytes"]
+    fn _bn254fr_to_bytes(out: *mut u8, x: *const bn254fr_t, len: u32, order: i32);
+
     // Copy / Print
     #[link_name = "bn254fr_copy"]
     fn _bn254fr_copy(dest: *mut bn254fr_t, src: *const bn254fr_t);
@@ -885,6 +1543,12 @@ extern "C" {
     #[link_name = "bn254fr_assert_equal"]
     fn _bn254fr_assert_equal(a: *const bn254fr_t, b: *const bn254fr_t);
 
+    #[link_name = "bn254fr_assert_equal_u64"]
+    fn _bn254fr_assert_equal_u64(a: *const bn254fr_t, b: u64);
+
+    #[link_name = "bn254fr_assert_equal_bytes"]
+    fn _bn254fr_assert_equal_bytes(a: *const bn254fr_t, bytes: *const u8, len: u32, order: i32);
+
     #[link_name = "bn254fr_assert_add"]
     fn _bn254fr_assert_add(out: *const bn254fr_t, a: *const bn254fr_t, b: *const bn254fr_t);
 
@@ -988,7 +1652,29 @@ extern "C" {
         out: *mut u64,
         a: *const u64,
         b: *const u64,
-        count: u32,
+        a_count: u32,
+        b_count: u32,
+    );
+
+    #[link_name = "bn254fr_bigint_idiv"]
+    fn _bn254fr_bigint_idiv(
+        q: *mut u64,
+        r: *mut u64,
+        a: *const u64,
+        b: *const u64,
+        a_count: u32,
+        b_count: u32,
+        bits: u32,
+    );
+
+    #[link_name = "bn254fr_bigint_invmod"]
+    fn _bn254fr_bigint_invmod(
+        out: *mut u64,
+        a: *const u64,
+        m: *const u64,
+        a_count: u32,
+        m_count: u32,
+        bits: u32,
     );
 
     #[link_name = "bn254fr_bigint_convert_to_proper_representation"]
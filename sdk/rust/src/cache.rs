@@ -0,0 +1,132 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Memoized Constant Subcircuits for Ligetron
+//!
+//! The prover streams every constraint as the guest executes it, so there
+//! is no way to generate a gadget instance once and reference it several
+//! times. What a guest can do is avoid regenerating subcircuits whose
+//! outputs depend only on public constants: generator derivation,
+//! fixed-base tables, precomputed round constants, ... Their outputs are
+//! computed once and the resulting (constrained) values are reused.
+//!
+//! [`SubcircuitCache`] is an explicit cache owned by the caller, and
+//! [`memoize`] a program-wide one keyed by a namespace.
+//!
+//! Keys must be public: a key derived from private data makes the circuit
+//! shape (which subcircuits are generated) depend on the witness.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cache of subcircuit outputs keyed by their public parameters
+pub struct SubcircuitCache<K, V> {
+    entries: HashMap<K, V>,
+    hits: u64,
+}
+
+impl<K: Eq + Hash, V: Clone> Default for SubcircuitCache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> SubcircuitCache<K, V> {
+    pub fn new() -> Self {
+        Self { entries: HashMap::new(), hits: 0 }
+    }
+
+    /// Output for `key`, generating it with `f` on first use
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> V {
+        if let Some(v) = self.entries.get(&key) {
+            self.hits += 1;
+            return v.clone();
+        }
+        let v = f();
+        self.entries.insert(key, v.clone());
+        v
+    }
+
+    /// Number of cached subcircuits
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of lookups served from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Drop all cached outputs
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+thread_local! {
+    static GLOBAL: RefCell<HashMap<&'static str, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+fn with_namespace<K, V, R>(namespace: &'static str, f: impl FnOnce(&mut SubcircuitCache<K, V>) -> R) -> R
+where
+    K: Eq + Hash + 'static,
+    V: Clone + 'static,
+{
+    GLOBAL.with(|global| {
+        let mut global = global.borrow_mut();
+        let cache = global
+            .entry(namespace)
+            .or_insert_with(|| Box::new(SubcircuitCache::<K, V>::new()))
+            .downcast_mut::<SubcircuitCache<K, V>>()
+            .expect("memoize: namespace reused with different key or value types");
+        f(cache)
+    })
+}
+
+/// Program-wide memoization of a constant subcircuit.
+///
+/// Returns the output cached under `(namespace, key)`, running `f` on
+/// first use. `f` may itself call `memoize`. Each namespace must always be
+/// used with the same key and value types.
+pub fn memoize<K, V>(namespace: &'static str, key: K, f: impl FnOnce() -> V) -> V
+where
+    K: Clone + Eq + Hash + 'static,
+    V: Clone + 'static,
+{
+    // The global cache is not borrowed while `f` runs, so `f` may memoize
+    let cached = with_namespace(namespace, |cache: &mut SubcircuitCache<K, V>| {
+        let v = cache.entries.get(&key).cloned();
+        if v.is_some() {
+            cache.hits += 1;
+        }
+        v
+    });
+    if let Some(v) = cached {
+        return v;
+    }
+
+    let v = f();
+    with_namespace(namespace, |cache: &mut SubcircuitCache<K, V>| {
+        cache.entries.insert(key, v.clone());
+    });
+    v
+}
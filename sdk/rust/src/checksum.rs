@@ -0,0 +1,117 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! CRC32 and Adler-32 Checksum Gadgets for Ligetron
+//!
+//! Constrained checksums over private bytes (one `Bn254Fr` per byte), so
+//! proofs about zip/png/gzip containers can validate their embedded
+//! checksums.
+//!
+//! - CRC32 (IEEE 802.3, reflected, as used by zip/png/gzip) runs bit-serially
+//!   on a [`Word32`]: shifts are rewiring and the polynomial XOR costs one
+//!   constraint per set polynomial bit.
+//! - Adler-32 accumulates both sums without reduction and reduces them modulo
+//!   65521 once at the end.
+
+use crate::bn254fr::{Bn254Fr, lt_constant_checked};
+use crate::word::{Word32, bit_xor};
+
+/// Reflected CRC32 polynomial
+const CRC32_POLY: u32 = 0xEDB88320;
+
+/// Adler-32 modulus
+const ADLER_MOD: u32 = 65521;
+
+/// Maximum input length for Adler-32 (keeps the unreduced sums in 64 bits)
+pub const ADLER32_MAX_LEN: usize = 1 << 24;
+
+/// CRC32 of private bytes with constraints
+pub fn crc32_checked(bytes: &[Bn254Fr]) -> Bn254Fr {
+    let mut crc = Word32::from_u64(0xFFFFFFFF);
+
+    for byte in bytes {
+        let bits = byte.to_bits(8);
+
+        // crc ^= byte
+        let mut cur: Vec<Bn254Fr> = crc.bits().to_vec();
+        for (c, b) in cur.iter_mut().zip(bits.iter()) {
+            *c = bit_xor(c, b);
+        }
+
+        // 8 rounds of: crc = (crc >> 1) ^ (lsb ? POLY : 0)
+        for _ in 0..8 {
+            let lsb = cur[0].clone();
+            let mut next: Vec<Bn254Fr> = cur[1..].to_vec();
+            next.push(Bn254Fr::from_u32(0));
+            for (i, n) in next.iter_mut().enumerate() {
+                if (CRC32_POLY >> i) & 1 == 1 {
+                    *n = bit_xor(n, &lsb);
+                }
+            }
+            cur = next;
+        }
+        crc = Word32::from_bits(cur);
+    }
+
+    crc.not().to_fr()
+}
+
+/// Constrained reduction modulo a small constant: returns x mod m.
+/// `x` must be below 2^64.
+fn reduce(x: &Bn254Fr, m: u32) -> Bn254Fr {
+    let v = x.get_u64();
+    let q = Bn254Fr::from_u64(v / m as u64);
+    let r = Bn254Fr::from_u64(v % m as u64);
+
+    // x = q * m + r, q < 2^48, r < m
+    let mut qm = q.clone();
+    qm.mulmod_constant_checked(&Bn254Fr::from_u32(m));
+    Bn254Fr::assert_add(x, &qm, &r);
+    let _ = q.to_bits(48);
+    let lt = lt_constant_checked(&r.to_bits(16), &format!("{:#x}", m));
+    Bn254Fr::assert_equal(&lt, &Bn254Fr::from_u32(1));
+    r
+}
+
+/// Adler-32 of private bytes with constraints
+pub fn adler32_checked(bytes: &[Bn254Fr]) -> Bn254Fr {
+    assert!(bytes.len() <= ADLER32_MAX_LEN, "adler32_checked: input too long");
+
+    // a_i = 1 + sum_{j<=i} d_j, b = sum_i a_i
+    let mut a = Bn254Fr::from_u32(1);
+    let mut b = Bn254Fr::from_u32(0);
+    for byte in bytes {
+        let _ = byte.to_bits(8);
+        a.addmod_checked(byte);
+        b.addmod_checked(&a);
+    }
+
+    let a = reduce(&a, ADLER_MOD);
+    let mut out = reduce(&b, ADLER_MOD);
+    out.mulmod_constant_checked(&Bn254Fr::from_u32(1 << 16));
+    out.addmod_checked(&a);
+    out
+}
+
+/// Assert that the CRC32 of private bytes equals `expected`
+pub fn assert_crc32(bytes: &[Bn254Fr], expected: &Bn254Fr) {
+    Bn254Fr::assert_equal(&crc32_checked(bytes), expected);
+}
+
+/// Assert that the Adler-32 of private bytes equals `expected`
+pub fn assert_adler32(bytes: &[Bn254Fr], expected: &Bn254Fr) {
+    Bn254Fr::assert_equal(&adler32_checked(bytes), expected);
+}
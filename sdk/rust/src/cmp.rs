@@ -0,0 +1,155 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Constrained Comparisons for Ligetron
+//!
+//! Comparisons of `bits`-bit values returning a constrained 0/1 result.
+//! Two strategies are available:
+//!
+//! - [`CmpStrategy::RangeCheck`]: decompose `b - a + 2^bits` into `bits + 1`
+//!   bits; the top bit is `a <= b`. Costs `bits + 1` boolean constraints and
+//!   requires `a, b < 2^bits` to be enforced elsewhere.
+//! - [`CmpStrategy::Bitwise`]: decompose both operands and scan from the
+//!   most significant bit. Costs `2 * bits` boolean constraints plus
+//!   `4 * bits` multiplications, but range checks both operands itself and
+//!   reuses existing decompositions through [`lt_bits_checked`].
+//!
+//! ## Cost
+//!
+//! Constraints emitted by one [`lte_checked_with`] call, counted from the
+//! checked operations each strategy performs (linear constraints are `3`
+//! for the range check and `6 * bits + 3` for the bitwise scan):
+//!
+//! | bits | RangeCheck boolean / mul | Bitwise boolean / mul |
+//! |------|--------------------------|-----------------------|
+//! | 8    | 9 / 0                    | 16 / 32               |
+//! | 32   | 33 / 0                   | 64 / 128              |
+//! | 64   | 65 / 0                   | 128 / 256             |
+//! | 128  | 129 / 0                  | 256 / 512             |
+//! | 252  | 253 / 0                  | 504 / 1008            |
+//!
+//! The default is the range check. Even when both operands still need
+//! their own `bits`-bit range checks it totals `3 * bits + 1` boolean
+//! constraints and no multiplications, against `2 * bits` booleans and
+//! `4 * bits` multiplications for the bitwise scan, so the bitwise scan is
+//! never cheaper. It remains for comparisons of operands whose bits are
+//! already at hand, through [`lt_bits_checked`].
+
+use crate::bn254fr::{Bn254Fr, submod_checked, mulmod_checked, shlmod};
+use crate::word::bit_xor;
+
+/// Comparator implementation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CmpStrategy {
+    RangeCheck,
+    Bitwise,
+}
+
+/// Strategy used by [`lt_checked`], [`lte_checked`], [`gt_checked`] and
+/// [`gte_checked`]
+pub const DEFAULT_STRATEGY: CmpStrategy = CmpStrategy::RangeCheck;
+
+/// 2^bits as a field constant
+fn pow2(bits: usize) -> Bn254Fr {
+    let mut out = Bn254Fr::new();
+    shlmod(&mut out, &Bn254Fr::from_u32(1), &Bn254Fr::from_u64(bits as u64));
+    out
+}
+
+/// 1 - x
+fn not(x: &Bn254Fr) -> Bn254Fr {
+    let mut out = Bn254Fr::new();
+    submod_checked(&mut out, &Bn254Fr::from_u32(1), x);
+    out
+}
+
+/// Return 1 if the numbers encoded by constrained little-endian bits
+/// satisfy `a < b`, 0 otherwise
+pub fn lt_bits_checked(a: &[Bn254Fr], b: &[Bn254Fr]) -> Bn254Fr {
+    assert_eq!(a.len(), b.len(), "lt_bits_checked: width mismatch");
+    let mut eq = Bn254Fr::from_u32(1);
+    let mut lt = Bn254Fr::from_u32(0);
+
+    // lt += eq * (1 - a_i) * b_i, eq = eq * (1 - (a_i ^ b_i))
+    for (ai, bi) in a.iter().zip(b.iter()).rev() {
+        let mut nab = Bn254Fr::new();
+        mulmod_checked(&mut nab, &not(ai), bi);
+        let mut t = Bn254Fr::new();
+        mulmod_checked(&mut t, &eq, &nab);
+        lt.addmod_checked(&t);
+
+        let mut next = Bn254Fr::new();
+        mulmod_checked(&mut next, &eq, &not(&bit_xor(ai, bi)));
+        eq = next;
+    }
+    lt
+}
+
+/// Return 1 if `a <= b`, 0 otherwise, for `a, b < 2^bits`
+pub fn lte_checked_with(a: &Bn254Fr, b: &Bn254Fr, bits: usize, strategy: CmpStrategy) -> Bn254Fr {
+    assert!(bits > 0 && bits < 253, "lte_checked: invalid bit width");
+    match strategy {
+        CmpStrategy::RangeCheck => {
+            // b - a + 2^bits has its top bit set iff a <= b
+            let mut t = Bn254Fr::new();
+            submod_checked(&mut t, b, a);
+            t.addmod_checked(&pow2(bits));
+            let diff_bits = t.to_bits(bits + 1);
+            diff_bits[bits].clone()
+        }
+        CmpStrategy::Bitwise => not(&lt_bits_checked(&b.to_bits(bits), &a.to_bits(bits))),
+    }
+}
+
+/// Return 1 if `a < b`, 0 otherwise, for `a, b < 2^bits`
+pub fn lt_checked_with(a: &Bn254Fr, b: &Bn254Fr, bits: usize, strategy: CmpStrategy) -> Bn254Fr {
+    not(&lte_checked_with(b, a, bits, strategy))
+}
+
+/// Return 1 if `a <= b`, 0 otherwise, for `a, b < 2^bits`
+pub fn lte_checked(a: &Bn254Fr, b: &Bn254Fr, bits: usize) -> Bn254Fr {
+    lte_checked_with(a, b, bits, DEFAULT_STRATEGY)
+}
+
+/// Return 1 if `a < b`, 0 otherwise, for `a, b < 2^bits`
+pub fn lt_checked(a: &Bn254Fr, b: &Bn254Fr, bits: usize) -> Bn254Fr {
+    lt_checked_with(a, b, bits, DEFAULT_STRATEGY)
+}
+
+/// Return 1 if `a > b`, 0 otherwise, for `a, b < 2^bits`
+pub fn gt_checked(a: &Bn254Fr, b: &Bn254Fr, bits: usize) -> Bn254Fr {
+    lt_checked(b, a, bits)
+}
+
+/// Return 1 if `a >= b`, 0 otherwise, for `a, b < 2^bits`
+pub fn gte_checked(a: &Bn254Fr, b: &Bn254Fr, bits: usize) -> Bn254Fr {
+    lte_checked(b, a, bits)
+}
+
+/// Assert that `a <= b` for `a, b < 2^bits` (range check on `b - a`)
+pub fn assert_lte(a: &Bn254Fr, b: &Bn254Fr, bits: usize) {
+    let mut t = Bn254Fr::new();
+    submod_checked(&mut t, b, a);
+    let _ = t.to_bits(bits);
+}
+
+/// Assert that `a < b` for `a, b < 2^bits` (range check on `b - a - 1`)
+pub fn assert_lt(a: &Bn254Fr, b: &Bn254Fr, bits: usize) {
+    let mut t = Bn254Fr::new();
+    submod_checked(&mut t, b, a);
+    t.submod_checked(&Bn254Fr::from_u32(1));
+    let _ = t.to_bits(bits);
+}
@@ -0,0 +1,121 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Hiding Poseidon2 Commitments for Ligetron
+//!
+//! A commitment to a vector of field elements is
+//!
+//! `Commit(v; r) = Poseidon2(DOMAIN, len(v), r, v_0, ..., v_{n-1})`
+//!
+//! The domain tag keeps commitments distinct from every other use of
+//! Poseidon2 in a circuit (a commitment can never collide with a raw hash or
+//! a Merkle node), and the length prefix keeps vectors of different lengths
+//! apart. Hiding relies on `r` being a fresh uniformly random field element
+//! per commitment.
+//!
+//! ## Commit-Reveal
+//!
+//! Sealed values (bids, moves) are committed as
+//!
+//! `Poseidon2(REVEAL_DOMAIN, salt, expiry, value)`
+//!
+//! and revealed later by opening the commitment. `expiry` is part of the
+//! commitment, so it cannot be changed at reveal time; `0` means the
+//! commitment never expires. Expiring commitments can only be revealed
+//! while `now < expiry`, with both times below `2^EXPIRY_BITS` (Unix
+//! seconds, block heights, ...).
+
+use crate::bn254fr::Bn254Fr;
+use crate::cmp::assert_lt;
+use crate::poseidon2::{Poseidon2Context, poseidon2_hash};
+
+/// Domain separation tag, encoded big-endian as a field element
+pub const COMMIT_DOMAIN: &[u8] = b"ligetron.commit.v1";
+
+/// The domain tag as a field element
+pub fn domain_tag() -> Bn254Fr {
+    let mut tag = Bn254Fr::new();
+    tag.set_bytes_big(COMMIT_DOMAIN);
+    tag
+}
+
+/// Commit to `values` with the random `blinding`
+pub fn commit(values: &[Bn254Fr], blinding: &Bn254Fr) -> Bn254Fr {
+    let mut ctx = Poseidon2Context::new();
+    ctx.digest_update(&domain_tag());
+    ctx.digest_update(&Bn254Fr::from_u64(values.len() as u64));
+    ctx.digest_update(blinding);
+    for v in values {
+        ctx.digest_update(v);
+    }
+    ctx.digest_final()
+}
+
+/// Assert that `(values, blinding)` opens `commitment`
+pub fn open(commitment: &Bn254Fr, values: &[Bn254Fr], blinding: &Bn254Fr) {
+    Bn254Fr::assert_equal(&commit(values, blinding), commitment);
+}
+
+/// Commit to each vector with its own blinding
+pub fn commit_batch(values: &[Vec<Bn254Fr>], blindings: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    assert_eq!(values.len(), blindings.len(), "commit_batch: blinding count mismatch");
+    values.iter().zip(blindings.iter())
+        .map(|(v, r)| commit(v, r))
+        .collect()
+}
+
+/// Assert that every `(values[i], blindings[i])` opens `commitments[i]`
+pub fn open_batch(commitments: &[Bn254Fr], values: &[Vec<Bn254Fr>], blindings: &[Bn254Fr]) {
+    assert_eq!(commitments.len(), values.len(), "open_batch: opening count mismatch");
+    for (c, com) in commit_batch(values, blindings).iter().zip(commitments.iter()) {
+        Bn254Fr::assert_equal(c, com);
+    }
+}
+
+// ============= Commit-Reveal =============
+
+/// Domain separation tag for commit-reveal commitments
+pub const REVEAL_DOMAIN: &[u8] = b"ligetron.reveal.v1";
+
+/// Bit width of expiry and current times
+pub const EXPIRY_BITS: usize = 64;
+
+/// Commit to `value` with `salt` and the given `expiry` (0 = never expires)
+pub fn commit_phase_with_expiry(value: &Bn254Fr, salt: &Bn254Fr, expiry: &Bn254Fr) -> Bn254Fr {
+    let mut tag = Bn254Fr::new();
+    tag.set_bytes_big(REVEAL_DOMAIN);
+    poseidon2_hash(&[tag, salt.clone(), expiry.clone(), value.clone()])
+}
+
+/// Commit to `value` with `salt`, without expiry
+pub fn commit_phase(value: &Bn254Fr, salt: &Bn254Fr) -> Bn254Fr {
+    commit_phase_with_expiry(value, salt, &Bn254Fr::from_u32(0))
+}
+
+/// Assert that `(value, salt)` opens the non-expiring `commitment`
+pub fn reveal_phase(commitment: &Bn254Fr, value: &Bn254Fr, salt: &Bn254Fr) {
+    Bn254Fr::assert_equal(&commit_phase(value, salt), commitment);
+}
+
+/// Assert that `(value, salt, expiry)` opens `commitment` and that the
+/// commitment has not expired at time `now`
+pub fn reveal_phase_before(commitment: &Bn254Fr, value: &Bn254Fr, salt: &Bn254Fr,
+                           expiry: &Bn254Fr, now: &Bn254Fr) {
+    Bn254Fr::assert_equal(&commit_phase_with_expiry(value, salt, expiry), commitment);
+    let _ = expiry.to_bits(EXPIRY_BITS);
+    let _ = now.to_bits(EXPIRY_BITS);
+    assert_lt(now, expiry, EXPIRY_BITS);
+}
@@ -0,0 +1,247 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Signed Attribute Credentials for Ligetron
+//!
+//! A credential is a list of `(key, value)` attributes whose blinded
+//! Poseidon2 commitment is signed by an issuer with EdDSA over Baby Jubjub.
+//! Attribute keys are the Poseidon2 hash of the attribute name, values are
+//! field elements (numbers up to 64 bits for the numeric predicates, dates as
+//! days since 1970-01-01).
+//!
+//! Holders disclose selectively: individual attributes can be revealed
+//! (asserted equal to a public value) or only proven to satisfy a predicate,
+//! while the rest stay private. A credential carrying a `holder` attribute
+//! (the Poseidon2 hash of a holder secret) can also derive per-scope
+//! nullifiers to prevent the same credential from being presented twice.
+//!
+//! The predicate helpers verify the issuer signature on first use, so
+//!
+//! ```ignore
+//! credentials::assert_attribute_gte(&mut cred, "score", &Bn254Fr::from_u32(700));
+//! credentials::assert_age_at_least(&mut cred, "dob", &today, 18);
+//! ```
+//!
+//! is a complete proof that an issuer-signed credential satisfies both.
+
+use crate::babyjubjub::JubjubPoint;
+use crate::bn254fr::{Bn254Fr, submod_checked};
+use crate::cmp;
+use crate::eddsa::EddsaSignature;
+use crate::nullifier;
+use crate::poseidon2::{Poseidon2Context, poseidon2_hash, poseidon2_hash_bytes};
+use crate::time::{self, Date};
+
+/// Bit width of numeric attribute values used in comparisons
+pub const ATTRIBUTE_BITS: usize = 64;
+
+/// Name of the attribute holding the commitment to the holder secret
+pub const HOLDER_ATTRIBUTE: &str = "holder";
+
+/// Derive the attribute key from its name
+pub fn attribute_key(name: &str) -> Bn254Fr {
+    poseidon2_hash_bytes(name.as_bytes())
+}
+
+/// An issuer-signed attribute credential.
+///
+/// The fields are read-only once built, so the cached result of
+/// [`Credential::verify`] always covers the current attributes.
+#[derive(Clone)]
+pub struct Credential {
+    keys: Vec<Bn254Fr>,
+    values: Vec<Bn254Fr>,
+    signature: EddsaSignature,
+    issuer: JubjubPoint,
+    blinding: Bn254Fr,
+    verified: bool,
+}
+
+impl Credential {
+    pub fn new(
+        keys: Vec<Bn254Fr>,
+        values: Vec<Bn254Fr>,
+        signature: EddsaSignature,
+        issuer: JubjubPoint,
+    ) -> Self {
+        assert_eq!(keys.len(), values.len(), "Credential: keys and values differ in length");
+        Credential {
+            keys,
+            values,
+            signature,
+            issuer,
+            blinding: Bn254Fr::from_u32(0),
+            verified: false,
+        }
+    }
+
+    /// Set the blinding factor mixed into the signed commitment
+    pub fn with_blinding(mut self, blinding: Bn254Fr) -> Self {
+        self.blinding = blinding;
+        self.verified = false;
+        self
+    }
+
+    /// Attribute keys
+    pub fn keys(&self) -> &[Bn254Fr] {
+        &self.keys
+    }
+
+    /// Attribute values, in the order of [`Credential::keys`]
+    pub fn values(&self) -> &[Bn254Fr] {
+        &self.values
+    }
+
+    /// Issuer signature over [`Credential::digest`]
+    pub fn signature(&self) -> &EddsaSignature {
+        &self.signature
+    }
+
+    /// Issuer public key
+    pub fn issuer(&self) -> &JubjubPoint {
+        &self.issuer
+    }
+
+    /// Blinding factor of the signed commitment
+    pub fn blinding(&self) -> &Bn254Fr {
+        &self.blinding
+    }
+
+    /// Blinded Poseidon2 commitment to the attributes (the signed message)
+    pub fn digest(&self) -> Bn254Fr {
+        commit_attributes(&self.keys, &self.values, &self.blinding)
+    }
+
+    /// Verify the issuer signature over the attribute digest.
+    /// Subsequent calls are no-ops.
+    pub fn verify(&mut self) {
+        if self.verified {
+            return;
+        }
+        let message = self.digest();
+        let mut challenge = signature_challenge(&self.signature.r, &self.issuer, &message);
+
+        EddsaSignature::verify(&mut self.signature, &mut self.issuer, &mut challenge);
+        self.verified = true;
+    }
+
+    /// Get the value of the attribute called `name`, asserting that the
+    /// attribute at that position carries the expected key. The position of
+    /// an attribute is part of the (public) credential schema.
+    pub fn attribute(&self, name: &str) -> &Bn254Fr {
+        let key = attribute_key(name);
+        let index = self.keys.iter()
+            .position(|k| crate::bn254fr::eq(k, &key))
+            .expect("Credential: attribute not found");
+        Bn254Fr::assert_equal(&self.keys[index], &key);
+        &self.values[index]
+    }
+
+    /// Reveal attribute `name` by asserting it equals the public `value`
+    pub fn reveal(&mut self, name: &str, value: &Bn254Fr) {
+        self.verify();
+        Bn254Fr::assert_equal(self.attribute(name), value);
+    }
+
+    /// Derive the nullifier of this credential for `scope`.
+    ///
+    /// Asserts that `secret` opens the `holder` attribute, then returns
+    /// [`nullifier::derive`]`(secret, scope)`. The nullifier is stable for a
+    /// given scope and unlinkable across scopes, so verifiers can reject
+    /// replays.
+    pub fn nullifier(&mut self, secret: &Bn254Fr, scope: &Bn254Fr) -> Bn254Fr {
+        self.verify();
+        Bn254Fr::assert_equal(self.attribute(HOLDER_ATTRIBUTE), &holder_commitment(secret));
+        nullifier::derive(secret, scope)
+    }
+}
+
+// ============= Issuance =============
+
+/// Blinded commitment to a list of attributes.
+/// The issuer signs this value when issuing a credential.
+pub fn commit_attributes(keys: &[Bn254Fr], values: &[Bn254Fr], blinding: &Bn254Fr) -> Bn254Fr {
+    let mut ctx = Poseidon2Context::new();
+    ctx.digest_update(blinding);
+    for (k, v) in keys.iter().zip(values.iter()) {
+        ctx.digest_update(k);
+        ctx.digest_update(v);
+    }
+    ctx.digest_final()
+}
+
+/// EdDSA challenge hash(R, A, M) the issuer signs over
+pub fn signature_challenge(r: &JubjubPoint, issuer: &JubjubPoint, message: &Bn254Fr) -> Bn254Fr {
+    let mut ctx = Poseidon2Context::new();
+    ctx.digest_update(&r.x);
+    ctx.digest_update(&r.y);
+    ctx.digest_update(&issuer.x);
+    ctx.digest_update(&issuer.y);
+    ctx.digest_update(message);
+    ctx.digest_final()
+}
+
+/// Value of the `holder` attribute for a holder secret
+pub fn holder_commitment(secret: &Bn254Fr) -> Bn254Fr {
+    poseidon2_hash(std::slice::from_ref(secret))
+}
+
+// ============= Predicates =============
+
+/// Assert that attribute `name` of an issuer-signed credential is at least
+/// `threshold`. Both must fit in [`ATTRIBUTE_BITS`] bits.
+pub fn assert_attribute_gte(credential: &mut Credential, name: &str, threshold: &Bn254Fr) {
+    credential.verify();
+    let value = credential.attribute(name);
+    let _ = value.to_bits(ATTRIBUTE_BITS);
+    let _ = threshold.to_bits(ATTRIBUTE_BITS);
+    cmp::assert_lte(threshold, value, ATTRIBUTE_BITS);
+}
+
+/// Assert that attribute `name` of an issuer-signed credential is at most
+/// `threshold`. Both must fit in [`ATTRIBUTE_BITS`] bits.
+pub fn assert_attribute_lte(credential: &mut Credential, name: &str, threshold: &Bn254Fr) {
+    credential.verify();
+    let value = credential.attribute(name);
+    let _ = value.to_bits(ATTRIBUTE_BITS);
+    let _ = threshold.to_bits(ATTRIBUTE_BITS);
+    cmp::assert_lte(value, threshold, ATTRIBUTE_BITS);
+}
+
+/// Assert that attribute `name` of an issuer-signed credential is one of
+/// the values in `set`, without revealing which one.
+pub fn assert_attribute_in(credential: &mut Credential, name: &str, set: &[Bn254Fr]) {
+    assert!(!set.is_empty(), "assert_attribute_in: empty set");
+    credential.verify();
+    let value = credential.attribute(name);
+
+    // prod(value - s_i) == 0
+    let mut prod = Bn254Fr::from_u32(1);
+    for s in set {
+        let mut d = Bn254Fr::new();
+        submod_checked(&mut d, value, s);
+        prod.mulmod_checked(&d);
+    }
+    Bn254Fr::assert_equal(&prod, &Bn254Fr::from_u32(0));
+}
+
+/// Assert that the holder of an issuer-signed credential, born on the date
+/// stored in attribute `dob_name`, is at least `years` old on `today`.
+pub fn assert_age_at_least(credential: &mut Credential, dob_name: &str, today: &Date, years: u32) {
+    credential.verify();
+    let birth = Date::from_days_checked(credential.attribute(dob_name));
+    time::assert_age_at_least(&birth, today, years);
+}
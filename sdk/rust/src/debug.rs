@@ -0,0 +1,124 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Debug Printing for Ligetron
+//!
+//! Labelled, multi-value dumps of field elements, 256-bit integers, Baby
+//! Jubjub points and vector lanes, one line per value:
+//!
+//! ```ignore
+//! debug::dump("leaves", &leaves, Radix::Hex);
+//! debug::dump_lanes("acc", &acc, 4, Radix::Dec);
+//! ```
+//!
+//! Values are read back from the host without constraints and always
+//! printed most significant digit first, whatever the limb order of the
+//! type. Nothing here affects the circuit.
+
+use crate::api::println_str;
+use crate::babyjubjub::GenericJubjubPoint;
+use crate::bn254fr::Bn254Fr;
+use crate::uint256::{Uint256, UINT256_NLIMBS};
+use crate::vbn254fr::VBn254Fr;
+
+/// Output radix
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Radix {
+    Hex,
+    Dec,
+}
+
+/// Format a big-endian integer
+pub fn format_be(bytes: &[u8], radix: Radix) -> String {
+    match radix {
+        Radix::Hex => {
+            let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            let digits = hex.trim_start_matches('0');
+            format!("0x{}", if digits.is_empty() { "0" } else { digits })
+        }
+        Radix::Dec => {
+            // Schoolbook division by 10 over the bytes
+            let mut n = bytes.to_vec();
+            let mut digits = Vec::new();
+            while n.iter().any(|&b| b != 0) {
+                let mut rem = 0u32;
+                for b in n.iter_mut() {
+                    let cur = (rem << 8) | *b as u32;
+                    *b = (cur / 10) as u8;
+                    rem = cur % 10;
+                }
+                digits.push(b'0' + rem as u8);
+            }
+            if digits.is_empty() {
+                digits.push(b'0');
+            }
+            digits.reverse();
+            String::from_utf8(digits).unwrap()
+        }
+    }
+}
+
+/// Values that can be rendered by [`dump`]
+pub trait Dump {
+    fn render(&self, radix: Radix) -> String;
+}
+
+impl Dump for Bn254Fr {
+    fn render(&self, radix: Radix) -> String {
+        format_be(&self.get_bytes_big(), radix)
+    }
+}
+
+impl Dump for Uint256 {
+    fn render(&self, radix: Radix) -> String {
+        let bytes: Vec<u8> = (0..UINT256_NLIMBS)
+            .rev()
+            .flat_map(|i| self.limb(i).get_u64().to_be_bytes())
+            .collect();
+        format_be(&bytes, radix)
+    }
+}
+
+impl Dump for GenericJubjubPoint<Bn254Fr> {
+    fn render(&self, radix: Radix) -> String {
+        format!("({}, {})", self.x.render(radix), self.y.render(radix))
+    }
+}
+
+/// Print `label[i] = value` for every value
+pub fn dump<T: Dump>(label: &str, values: &[T], radix: Radix) {
+    for (i, v) in values.iter().enumerate() {
+        println_str(format!("{}[{}] = {}", label, i, v.render(radix)).as_bytes());
+    }
+}
+
+/// Print `label = value` for a single value
+pub fn dump_one<T: Dump>(label: &str, value: &T, radix: Radix) {
+    println_str(format!("{} = {}", label, value.render(radix)).as_bytes());
+}
+
+/// Print the first `lanes` lanes of a vector as `label<lane> = value`
+pub fn dump_lanes(label: &str, v: &VBn254Fr, lanes: usize, radix: Radix) {
+    for (i, bytes) in v.get_bytes_unchecked(lanes).iter().enumerate() {
+        println_str(format!("{}<{}> = {}", label, i, format_be(bytes, radix)).as_bytes());
+    }
+}
+
+/// Print `label = [len bytes] hex` for a memory region
+pub fn dump_bytes(label: &str, data: &[u8]) {
+    let hex: String = data.iter().map(|b| format!("{:02x}", b)).collect();
+    println_str(format!("{} = [{} bytes] {}", label, data.len(), hex).as_bytes());
+}
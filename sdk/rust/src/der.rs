@@ -0,0 +1,250 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Constrained ASN.1 DER Parsing for Ligetron
+//!
+//! A minimal DER reader over a private byte string (one `Bn254Fr` per byte,
+//! see [`string::string_from_bytes`](crate::string::string_from_bytes)),
+//! enough to pull `(r, s)` out of ECDSA signatures and the to-be-signed
+//! part and public key out of X.509 certificates.
+//!
+//! The element layout (tags, offsets and lengths) decides which bytes the
+//! circuit looks at, so it is read off-circuit and becomes part of the
+//! circuit shape: every header byte is asserted equal to the tag and
+//! length it was parsed as, and children are checked to tile their parent
+//! exactly. Element contents stay private. Only definite, minimal length
+//! encodings up to 2^24 - 1 bytes are accepted, as DER requires.
+
+use std::ops::Range;
+
+use crate::bn254fr::Bn254Fr;
+use crate::uint256::{Uint256, UINT256_NLIMBS};
+
+pub const TAG_INTEGER: u8 = 0x02;
+pub const TAG_BIT_STRING: u8 = 0x03;
+pub const TAG_OCTET_STRING: u8 = 0x04;
+pub const TAG_OID: u8 = 0x06;
+pub const TAG_SEQUENCE: u8 = 0x30;
+pub const TAG_SET: u8 = 0x31;
+/// `[0]` constructed, context specific (e.g. the X.509 version)
+pub const TAG_CONTEXT_0: u8 = 0xa0;
+
+/// One parsed element
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerElement {
+    pub tag: u8,
+    /// Offset of the tag byte
+    pub start: usize,
+    /// Offset of the first content byte
+    pub content_start: usize,
+    /// Content length
+    pub len: usize,
+}
+
+impl DerElement {
+    /// Offset one past the last content byte
+    pub fn end(&self) -> usize {
+        self.content_start + self.len
+    }
+
+    /// The whole element, header included
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end()
+    }
+
+    /// The content bytes
+    pub fn content(&self) -> Range<usize> {
+        self.content_start..self.end()
+    }
+}
+
+fn byte(bytes: &[Bn254Fr], i: usize) -> Option<u8> {
+    let v = bytes.get(i)?.get_u64();
+    (v < 256).then_some(v as u8)
+}
+
+fn assert_byte(b: &Bn254Fr, value: u8) {
+    Bn254Fr::assert_equal(b, &Bn254Fr::from_u32(value as u32));
+}
+
+/// Parse the element starting at `pos` and constrain its header.
+/// Returns `None` on malformed or non-DER input.
+pub fn parse_element(bytes: &[Bn254Fr], pos: usize) -> Option<DerElement> {
+    let tag = byte(bytes, pos)?;
+    if tag & 0x1f == 0x1f {
+        // High tag numbers are not used by the supported structures
+        return None;
+    }
+
+    let first = byte(bytes, pos + 1)?;
+    let (len, header) = if first < 0x80 {
+        (first as usize, 2)
+    } else {
+        let n = (first & 0x7f) as usize;
+        if n == 0 || n > 3 {
+            return None;
+        }
+        let mut len = 0usize;
+        for i in 0..n {
+            len = (len << 8) | byte(bytes, pos + 2 + i)? as usize;
+        }
+        // Minimal encoding: no leading zero byte, long form only from 128
+        if byte(bytes, pos + 2)? == 0 || len < 0x80 {
+            return None;
+        }
+        (len, 2 + n)
+    };
+    if pos + header + len > bytes.len() {
+        return None;
+    }
+
+    for i in 0..header {
+        assert_byte(&bytes[pos + i], byte(bytes, pos + i)?);
+    }
+    Some(DerElement { tag, start: pos, content_start: pos + header, len })
+}
+
+/// Parse the element at `pos` and require the given tag
+pub fn expect(bytes: &[Bn254Fr], pos: usize, tag: u8) -> Option<DerElement> {
+    parse_element(bytes, pos).filter(|e| e.tag == tag)
+}
+
+/// Parse the children of a constructed element, which must fill its
+/// content exactly
+pub fn children(bytes: &[Bn254Fr], parent: &DerElement) -> Option<Vec<DerElement>> {
+    let mut out = Vec::new();
+    let mut pos = parent.content_start;
+    while pos < parent.end() {
+        let child = parse_element(bytes, pos)?;
+        if child.end() > parent.end() {
+            return None;
+        }
+        pos = child.end();
+        out.push(child);
+    }
+    Some(out)
+}
+
+/// Value of a non-negative INTEGER of at most 256 bits, with constraints:
+/// content bytes are range checked and a leading zero byte is only
+/// accepted (and constrained) when the next byte has its top bit set.
+pub fn integer_to_uint256(bytes: &[Bn254Fr], el: &DerElement) -> Option<Uint256> {
+    if el.tag != TAG_INTEGER || el.len == 0 {
+        return None;
+    }
+    let mut content = el.content();
+    let lead = byte(bytes, content.start)?;
+    if lead & 0x80 != 0 {
+        // Negative
+        return None;
+    }
+    if lead == 0 && el.len > 1 {
+        if byte(bytes, content.start + 1)? & 0x80 == 0 {
+            return None;
+        }
+        assert_byte(&bytes[content.start], 0);
+        let top = bytes[content.start + 1].to_bits(8);
+        Bn254Fr::assert_equal(&top[7], &Bn254Fr::from_u32(1));
+        content.start += 1;
+    } else {
+        // Top bit of a positive integer is clear
+        let first = bytes[content.start].to_bits(8);
+        Bn254Fr::assert_equal(&first[7], &Bn254Fr::from_u32(0));
+    }
+    if content.len() > 32 {
+        return None;
+    }
+    Some(bytes_to_uint256(&bytes[content]))
+}
+
+/// Big-endian bytes (range checked) to a Uint256
+fn bytes_to_uint256(be: &[Bn254Fr]) -> Uint256 {
+    let mut out = Uint256::from_u64(0);
+    let base = Bn254Fr::from_u32(256);
+    for (i, chunk) in be.rchunks(8).enumerate().take(UINT256_NLIMBS) {
+        let mut limb = Bn254Fr::from_u32(0);
+        for b in chunk {
+            let _ = b.to_bits(8);
+            limb.mulmod_constant_checked(&base);
+            limb.addmod_checked(b);
+        }
+        *out.limb_mut(i) = limb;
+    }
+    out
+}
+
+/// Content of a BIT STRING without unused bits, excluding the leading
+/// unused-bits byte (constrained to 0)
+pub fn bit_string_content(bytes: &[Bn254Fr], el: &DerElement) -> Option<Range<usize>> {
+    if el.tag != TAG_BIT_STRING || el.len == 0 || byte(bytes, el.content_start)? != 0 {
+        return None;
+    }
+    assert_byte(&bytes[el.content_start], 0);
+    Some(el.content_start + 1..el.end())
+}
+
+/// Parse an ECDSA signature `SEQUENCE { INTEGER r, INTEGER s }` spanning
+/// all of `bytes`
+pub fn ecdsa_signature(bytes: &[Bn254Fr]) -> Option<(Uint256, Uint256)> {
+    let seq = expect(bytes, 0, TAG_SEQUENCE)?;
+    if seq.end() != bytes.len() {
+        return None;
+    }
+    match children(bytes, &seq)?.as_slice() {
+        [r, s] => Some((integer_to_uint256(bytes, r)?, integer_to_uint256(bytes, s)?)),
+        _ => None,
+    }
+}
+
+/// Byte ranges of interest in an X.509 certificate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X509Parts {
+    /// The whole `tbsCertificate` element, which the issuer signs
+    pub tbs: Range<usize>,
+    /// `subjectPublicKeyInfo.subjectPublicKey` (e.g. a SEC1 point)
+    pub public_key: Range<usize>,
+    /// `signatureValue` (e.g. a DER ECDSA signature)
+    pub signature: Range<usize>,
+}
+
+/// Locate the signed part, subject public key and signature of a DER
+/// X.509 certificate spanning all of `bytes`
+pub fn x509_certificate(bytes: &[Bn254Fr]) -> Option<X509Parts> {
+    let cert = expect(bytes, 0, TAG_SEQUENCE)?;
+    if cert.end() != bytes.len() {
+        return None;
+    }
+    let parts = children(bytes, &cert)?;
+    let [tbs, alg, sig] = parts.as_slice() else {
+        return None;
+    };
+    if tbs.tag != TAG_SEQUENCE || alg.tag != TAG_SEQUENCE {
+        return None;
+    }
+    let signature = bit_string_content(bytes, sig)?;
+
+    // TBSCertificate: [0] version (optional), serialNumber, signature,
+    // issuer, validity, subject, subjectPublicKeyInfo, ...
+    let fields = children(bytes, tbs)?;
+    let skip = fields.first().map_or(0, |f| (f.tag == TAG_CONTEXT_0) as usize);
+    let spki = fields.get(skip + 5).filter(|f| f.tag == TAG_SEQUENCE)?;
+    let key = match children(bytes, spki)?.as_slice() {
+        [key_alg, key] if key_alg.tag == TAG_SEQUENCE => bit_string_content(bytes, key)?,
+        _ => return None,
+    };
+
+    Some(X509Parts { tbs: tbs.range(), public_key: key, signature })
+}
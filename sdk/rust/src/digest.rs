@@ -0,0 +1,106 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Constrained Reduction of Hash Digests for Ligetron
+//!
+//! Signature and commitment schemes turn a 32-byte digest into a scalar by
+//! reducing it modulo a group order. Setting a field element from 32 bytes
+//! reduces off-circuit without constraints; these helpers prove the
+//! reduction instead. Digests are read as big-endian integers.
+//!
+//! - [`reduce_digest_to_fr`]: modulo the BN254 scalar field. The digest is
+//!   split into two range-checked 128-bit halves and recombined in the
+//!   field, which is the reduction.
+//! - [`reduce_digest_to_scalar_mod_order`]: modulo a smaller order `n`
+//!   (e.g. the Baby Jubjub subgroup). The quotient is witnessed and
+//!   `q·n + x == digest` and `x < n` are checked with 256-bit arithmetic.
+
+use crate::bn254fr::{Bn254Fr, BN254FR_MODULUS};
+use crate::uint256::{self, Uint256, Uint256Wide, add_cc, sub_cc, mul_wide};
+
+/// `2^128` as a field constant
+const TWO_POW_128: &str = "0x100000000000000000000000000000000";
+
+/// `2^64` as a field constant
+const TWO_POW_64: &str = "0x10000000000000000";
+
+/// Reduce a big-endian digest modulo the BN254 scalar field
+pub fn reduce_digest_to_fr(digest: &[u8; 32]) -> Bn254Fr {
+    let mut hi = Bn254Fr::new();
+    let mut lo = Bn254Fr::new();
+    hi.set_bytes_big(&digest[..16]);
+    lo.set_bytes_big(&digest[16..]);
+    let _ = hi.to_bits(128);
+    let _ = lo.to_bits(128);
+
+    let mut out = Bn254Fr::new();
+    out.copy(&hi);
+    out.mulmod_constant_checked(&Bn254Fr::from_str(TWO_POW_128));
+    out.addmod_checked(&lo);
+    out
+}
+
+/// Reduce a big-endian digest modulo `order`, given as a hex string with or
+/// without a `0x` prefix. `order` must not exceed the BN254 scalar field
+/// modulus and must have a non-zero top 64-bit limb.
+pub fn reduce_digest_to_scalar_mod_order(digest: &[u8; 32], order: &str) -> Bn254Fr {
+    let order_hex = order.trim_start_matches("0x");
+    assert!(hex_le(order_hex, BN254FR_MODULUS.trim_start_matches("0x")),
+            "reduce_digest_to_scalar_mod_order: order exceeds the scalar field");
+    let n = Uint256::from_str(order_hex, 16);
+
+    let mut d = Uint256::new();
+    d.set_bytes_big(digest);
+    let x = reduce_uint256(&d, &n);
+    uint256_to_fr(&x)
+}
+
+/// Reduce `d` modulo `n` with a witnessed quotient: `q·n + x == d`, `x < n`
+fn reduce_uint256(d: &Uint256, n: &Uint256) -> Uint256 {
+    let zero = Bn254Fr::from_u32(0);
+    let wide = Uint256Wide { lo: d.clone(), hi: Uint256::from_u64(0) };
+    let (q, q_hi, x) = wide.divide_qr_normalized(n);
+    Bn254Fr::assert_equal(&q_hi, &zero);
+
+    let qn = mul_wide(&q, n);
+    uint256::assert_equal(&qn.hi, &Uint256::from_u64(0));
+    let sum = add_cc(&qn.lo, &x);
+    Bn254Fr::assert_equal(&sum.carry, &zero);
+    uint256::assert_equal(&sum.val, d);
+
+    // x - n borrows iff x < n
+    let diff = sub_cc(&x, n);
+    Bn254Fr::assert_equal(&diff.carry, &Bn254Fr::from_u32(1));
+    x
+}
+
+/// Recombine the limbs of a value below the field modulus
+fn uint256_to_fr(x: &Uint256) -> Bn254Fr {
+    let base = Bn254Fr::from_str(TWO_POW_64);
+    let mut out = x.limb(3).clone();
+    for i in (0..3).rev() {
+        out.mulmod_constant_checked(&base);
+        out.addmod_checked(x.limb(i));
+    }
+    out
+}
+
+/// `a <= b` for hex strings without prefix
+fn hex_le(a: &str, b: &str) -> bool {
+    let a = a.trim_start_matches('0').to_ascii_lowercase();
+    let b = b.trim_start_matches('0').to_ascii_lowercase();
+    (a.len(), a) <= (b.len(), b)
+}
@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Baby Jubjub Diffie-Hellman for Ligetron
+//!
+//! Key agreement in the prime-order subgroup of Baby Jubjub:
+//!
+//! - public key: `sk·B` for the base point `B`
+//! - shared point: `sk·PK` for the peer's public key `PK`
+//! - shared secret: `Poseidon2(ECDH_DOMAIN, S.x, S.y)` for the shared point `S`
+//!
+//! The private scalar is decomposed into its canonical bits: a prover-chosen
+//! decomposition of `sk + p` would give a different point, since the curve
+//! order is not `p`.
+//!
+//! The peer's key is checked to be on the curve, in the prime-order
+//! subgroup and not the identity, so a malicious key cannot confine the
+//! shared point to a small subgroup and leak bits of `sk`.
+
+use crate::babyjubjub::JubjubPoint;
+use crate::bn254fr::Bn254Fr;
+use crate::poseidon2::poseidon2_hash;
+
+/// Domain separation tag for shared secret derivation
+pub const ECDH_DOMAIN: &[u8] = b"ligetron.ecdh.v1";
+
+/// Public key `private_scalar·B`
+pub fn public_key(private_scalar: &Bn254Fr) -> JubjubPoint {
+    JubjubPoint::base_point().scalar_mul_bits(&private_scalar.to_bits_canonical())
+}
+
+/// Constrain `public` to be a valid public key: on the curve, in the
+/// prime-order subgroup and not the identity
+pub fn assert_valid_public_key(public: &JubjubPoint) {
+    public.assert_on_curve();
+    public.assert_in_subgroup();
+
+    // x = 0 only for the identity and the order-2 point (0, -1)
+    let mut inv = Bn254Fr::new();
+    inv.invmod_checked(&public.x);
+}
+
+/// Shared point `private_scalar·their_public`, validating the peer's key
+pub fn shared_point(private_scalar: &Bn254Fr, their_public: &JubjubPoint) -> JubjubPoint {
+    assert_valid_public_key(their_public);
+    their_public.scalar_mul_bits(&private_scalar.to_bits_canonical())
+}
+
+/// Shared secret with the holder of `their_public`
+pub fn shared_secret(private_scalar: &Bn254Fr, their_public: &JubjubPoint) -> Bn254Fr {
+    let s = shared_point(private_scalar, their_public);
+    let mut tag = Bn254Fr::new();
+    tag.set_bytes_big(ECDH_DOMAIN);
+    poseidon2_hash(&[tag, s.x, s.y])
+}
@@ -28,74 +28,197 @@
 //! - A is the public key point
 //! - hash(R,A,M) is the challenge hash
 //!
+//! [`GenericEddsaSignature::verify`] constrains `A` and `R` to lie on the
+//! curve before the equation check. Keys in this scheme are multiples of the
+//! full-order generator `G`, so they are generally not in the prime-order
+//! subgroup. [`GenericEddsaSignature::verify_strict`] is for signatures made
+//! over the subgroup base point `B = 8·G` (`A = sk·B`): it checks
+//! **S·B = R + hash(R,A,M)·A** and additionally requires `A` and `R` to be
+//! in the prime-order subgroup and `A` not to be the identity, which rules
+//! out small-order keys and nonces.
+//!
+//! `S` and the challenge are decomposed once into canonical bits (below
+//! the field modulus p), and the same bits drive the scalar multiplication,
+//! so neither can be replaced by an alias `x + p`. Since `8·l > p`, this
+//! already makes `S` unique modulo the order of `G` in `verify`;
+//! `verify_strict` additionally requires `S < l`, so a signature cannot be
+//! re-randomized by adding a multiple of the order to `S`.
+//!
+//! [`GenericEddsaSignature::verify_report`] performs the same checks as
+//! `verify` but returns a 0/1 validity flag instead of asserting, so a batch
+//! of signatures can be accepted under "at least k valid" rules. For
+//! [`EddsaSignatureVec`] the flag is a per-lane mask. The vectorized backend
+//! has no cross-lane operations, so an aggregate count is only available
+//! for scalar batches through [`EddsaSignature::verify_batch_report`].
+//!
+//! [`EddsaSignature::verify_bytes`] derives the challenge itself from a raw
+//! byte message: `R.x, R.y, A.x, A.y` are absorbed as field elements and the
+//! message as 31-byte chunks with `0x80` padding, using the selected
+//! [`ChallengeHash`]. MiMC is not available in the SDK.
+//!
 //! ```
 
-use crate::babyjubjub::{JubjubPoint, JubjubPointVec};
+use crate::babyjubjub::{self, GenericJubjubPoint, JubjubPoint};
 use crate::bn254fr::Bn254Fr;
+use crate::cmp;
+use crate::field::{self, LigetronField};
+use crate::poseidon::PoseidonContext5;
+use crate::poseidon2::Poseidon2Context;
 use crate::vbn254fr::VBn254Fr;
 
-const GENERATOR_X: &str =
-    "995203441582195749578291179787384436505546430278305826713579947235728471134";
-const GENERATOR_Y: &str =
-    "5472060717959818805561601436314318772137091100104008585924551046643952123905";
+/// Canonical bits of `S` and of the challenge, shared by the range check
+/// and the scalar multiplication so neither can use an alias `x + p`
+fn canonical_bits<F: LigetronField>(s: &F, h: &F) -> (Vec<F>, Vec<F>) {
+    (field::bits_canonical(s), field::bits_canonical(h))
+}
+
+/// Outcome of verifying a batch of scalar signatures
+pub struct EddsaReport {
+    /// 0/1 validity flag per signature
+    pub valid: Vec<Bn254Fr>,
+    /// Number of valid signatures
+    pub count: Bn254Fr,
+}
+
+impl EddsaReport {
+    /// Constrain at least `k` signatures to be valid
+    pub fn assert_at_least(&self, k: u32) {
+        cmp::assert_lte(&Bn254Fr::from_u32(k), &self.count, 32);
+    }
+}
 
 #[derive(Clone)]
-pub struct EddsaSignature {
-    pub r: JubjubPoint,
-    pub s: Bn254Fr,
+pub struct GenericEddsaSignature<F: LigetronField> {
+    pub r: GenericJubjubPoint<F>,
+    pub s: F,
 }
 
-impl EddsaSignature {
-    pub fn new(r: JubjubPoint, s: Bn254Fr) -> Self {
-        EddsaSignature { r, s }
+pub type EddsaSignature = GenericEddsaSignature<Bn254Fr>;
+
+pub type EddsaSignatureVec = GenericEddsaSignature<VBn254Fr>;
+
+impl<F: LigetronField> GenericEddsaSignature<F> {
+    pub fn new(r: GenericJubjubPoint<F>, s: F) -> Self {
+        GenericEddsaSignature { r, s }
     }
 
-    pub fn generator() -> JubjubPoint {
-        JubjubPoint::new(
-            Bn254Fr::from_str(GENERATOR_X),
-            Bn254Fr::from_str(GENERATOR_Y),
-        )
+    pub fn generator() -> GenericJubjubPoint<F> {
+        babyjubjub::params().generator()
     }
 
-    pub fn verify(sig: &mut EddsaSignature, public_key: &mut JubjubPoint, message: &mut Bn254Fr) {
-        let g = Self::generator();
+    pub fn verify(sig: &mut Self, public_key: &mut GenericJubjubPoint<F>, message: &mut F) {
+        public_key.assert_on_curve();
+        sig.r.assert_on_curve();
+        let (s_bits, h_bits) = canonical_bits(&sig.s, message);
 
-        let mut sg = g.scalar_mul(&sig.s);
-        let mut p = public_key.scalar_mul(message);
-        p = JubjubPoint::twisted_edward_add(&sig.r, &p);
+        // s·G - h·A == R, with the doublings shared between both scalars
+        let mut lhs = GenericJubjubPoint::double_scalar_mul_bits(
+            &s_bits, &Self::generator(), &h_bits, &public_key.neg());
+        let mut r = sig.r.clone();
 
-        JubjubPoint::assert_equal(&mut sg, &mut p);
+        GenericJubjubPoint::assert_equal(&mut lhs, &mut r);
+    }
+
+    /// Check a signature like [`Self::verify`], returning 1 if it is valid
+    /// and 0 otherwise instead of asserting (with constraints). For the
+    /// vectorized backend the result is a per-lane mask.
+    pub fn verify_report(sig: &Self, public_key: &GenericJubjubPoint<F>, message: &F) -> F {
+        let mut valid = public_key.is_on_curve();
+        valid.mul_assign_checked(&sig.r.is_on_curve());
+        let (s_bits, h_bits) = canonical_bits(&sig.s, message);
+
+        let lhs = GenericJubjubPoint::double_scalar_mul_bits(
+            &s_bits, &Self::generator(), &h_bits, &public_key.neg());
+
+        valid.mul_assign_checked(&field::is_equal_checked(&lhs.x, &sig.r.x));
+        valid.mul_assign_checked(&field::is_equal_checked(&lhs.y, &sig.r.y));
+        valid
+    }
+
+    /// Verify a signature over the prime-order subgroup, validating that
+    /// `A` and `R` are on the curve and in the subgroup and that `A` is not
+    /// the identity
+    pub fn verify_strict(sig: &Self, public_key: &GenericJubjubPoint<F>, message: &F) {
+        public_key.assert_on_curve();
+        public_key.assert_in_subgroup();
+        sig.r.assert_on_curve();
+        sig.r.assert_in_subgroup();
+        let (s_bits, h_bits) = canonical_bits(&sig.s, message);
+        F::assert_equal(&field::lt_constant_checked(&s_bits, babyjubjub::params().subgroup_order),
+                        &F::from_u32(1));
+
+        // In the subgroup, x = 0 only for the identity
+        let mut inv = F::new();
+        F::div_checked(&mut inv, &F::from_u32(1), &public_key.x);
+
+        let mut lhs = GenericJubjubPoint::double_scalar_mul_bits(
+            &s_bits, &GenericJubjubPoint::base_point(), &h_bits, &public_key.neg());
+        let mut r = sig.r.clone();
+
+        GenericJubjubPoint::assert_equal(&mut lhs, &mut r);
     }
 }
 
-#[derive(Clone)]
-pub struct EddsaSignatureVec {
-    pub r: JubjubPointVec,
-    pub s: VBn254Fr,
+/// Hash used to derive the challenge from `R || A || M`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChallengeHash {
+    /// Poseidon2 (t=2)
+    #[default]
+    Poseidon2,
+    /// Poseidon (t=5)
+    Poseidon,
 }
 
-impl EddsaSignatureVec {
-    pub fn new(r: JubjubPointVec, s: VBn254Fr) -> Self {
-        EddsaSignatureVec { r, s }
+impl EddsaSignature {
+    /// Challenge `hash(R, A, M)` over a raw byte message
+    pub fn challenge_bytes(r: &JubjubPoint, public_key: &JubjubPoint, msg: &[u8],
+                           hasher: ChallengeHash) -> Bn254Fr {
+        let points = [&r.x, &r.y, &public_key.x, &public_key.y];
+        match hasher {
+            ChallengeHash::Poseidon2 => {
+                let mut ctx = Poseidon2Context::new();
+                for v in points {
+                    ctx.digest_update(v);
+                }
+                ctx.digest_update_bytes(msg);
+                ctx.digest_final()
+            }
+            ChallengeHash::Poseidon => {
+                let mut ctx = PoseidonContext5::new();
+                for v in points {
+                    ctx.update(v);
+                }
+                ctx.update_bytes(msg);
+                ctx.finalize_bytes()
+            }
+        }
     }
 
-    pub fn generator() -> JubjubPointVec {
-        let x = VBn254Fr::from_str_scalar(GENERATOR_X);
-        let y = VBn254Fr::from_str_scalar(GENERATOR_Y);
-        JubjubPointVec::new(x, y)
-    }
+    /// Check a batch of signatures, returning the validity of each and the
+    /// number of valid ones (with constraints)
+    pub fn verify_batch_report(sigs: &[Self], public_keys: &[JubjubPoint],
+                               messages: &[Bn254Fr]) -> EddsaReport {
+        assert_eq!(sigs.len(), public_keys.len(), "verify_batch_report: length mismatch");
+        assert_eq!(sigs.len(), messages.len(), "verify_batch_report: length mismatch");
 
-    pub fn verify(
-        sig: &mut EddsaSignatureVec,
-        public_key: &mut JubjubPointVec,
-        message: &mut VBn254Fr,
-    ) {
-        let g = Self::generator();
+        let mut count = Bn254Fr::from_u32(0);
+        let valid: Vec<Bn254Fr> = sigs.iter()
+            .zip(public_keys)
+            .zip(messages)
+            .map(|((sig, pk), msg)| {
+                let v = Self::verify_report(sig, pk, msg);
+                count.addmod_checked(&v);
+                v
+            })
+            .collect();
 
-        let mut sg = g.scalar_mul(&sig.s);
-        let mut p = public_key.scalar_mul(message);
-        p = JubjubPointVec::twisted_edward_add(&sig.r, &p);
+        EddsaReport { valid, count }
+    }
 
-        JubjubPointVec::assert_equal(&mut sg, &mut p);
+    /// Verify a signature over a raw byte message, deriving the challenge
+    /// with `hasher`
+    pub fn verify_bytes(sig: &Self, public_key: &JubjubPoint, msg: &[u8], hasher: ChallengeHash) {
+        let mut challenge = Self::challenge_bytes(&sig.r, public_key, msg, hasher);
+        Self::verify(&mut sig.clone(), &mut public_key.clone(), &mut challenge);
     }
 }
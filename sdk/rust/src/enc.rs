@@ -0,0 +1,164 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Poseidon Duplex Encryption for Ligetron
+//!
+//! Field-native authenticated encryption for encrypting notes in-circuit.
+//! Plaintexts and ciphertexts are vectors of BN254 field elements, so
+//! encryption costs a handful of Poseidon permutations instead of a bitwise
+//! block cipher.
+//!
+//! ## Construction
+//!
+//! A duplex sponge over the Poseidon t=5 permutation with rate 4 and
+//! capacity 1:
+//!
+//! 1. The state is initialised to `[key, nonce, 0, 0, domain]`, where the
+//!    capacity element `domain` encodes the associated data and message
+//!    lengths, and permuted.
+//! 2. Associated data is absorbed in blocks of 4 elements.
+//! 3. Each message block is encrypted as `c_i = m_i + s_i` and the
+//!    ciphertext overwrites the rate, followed by a permutation.
+//! 4. The key is absorbed once more and the first state element after the
+//!    final permutation is the authentication tag.
+//!
+//! A `(key, nonce)` pair must never be reused.
+
+use crate::bn254fr::{Bn254Fr, submod_checked};
+use crate::poseidon::PoseidonContext5;
+
+/// Number of field elements absorbed or encrypted per permutation
+pub const RATE: usize = 4;
+
+/// Field elements per ciphertext plus its tag
+#[derive(Clone)]
+pub struct Ciphertext {
+    pub body: Vec<Bn254Fr>,
+    pub tag: Bn254Fr,
+}
+
+/// Duplex sponge over the Poseidon t=5 permutation
+pub struct PoseidonDuplex {
+    ctx: PoseidonContext5,
+}
+
+impl PoseidonDuplex {
+    /// Initialise the duplex with a key, a nonce and a domain separator
+    /// placed in the capacity element
+    pub fn new(key: &Bn254Fr, nonce: &Bn254Fr, domain: &Bn254Fr) -> Self {
+        let mut ctx = PoseidonContext5::new();
+        let state = ctx.state_mut();
+        state[0].addmod_checked(key);
+        state[1].addmod_checked(nonce);
+        state[RATE].addmod_checked(domain);
+        ctx.permute();
+        PoseidonDuplex { ctx }
+    }
+
+    /// Absorb field elements, permuting after every full block and after a
+    /// final partial block
+    pub fn absorb(&mut self, data: &[Bn254Fr]) {
+        for block in data.chunks(RATE) {
+            let state = self.ctx.state_mut();
+            for (s, d) in state.iter_mut().zip(block.iter()) {
+                s.addmod_checked(d);
+            }
+            self.ctx.permute();
+        }
+    }
+
+    /// Encrypt a message, overwriting the rate with the ciphertext
+    pub fn encrypt(&mut self, msg: &[Bn254Fr]) -> Vec<Bn254Fr> {
+        let mut out = Vec::with_capacity(msg.len());
+        for block in msg.chunks(RATE) {
+            let state = self.ctx.state_mut();
+            for (s, m) in state.iter_mut().zip(block.iter()) {
+                s.addmod_checked(m);
+                out.push(s.clone());
+            }
+            self.ctx.permute();
+        }
+        out
+    }
+
+    /// Decrypt a ciphertext, overwriting the rate with the ciphertext
+    pub fn decrypt(&mut self, body: &[Bn254Fr]) -> Vec<Bn254Fr> {
+        let mut out = Vec::with_capacity(body.len());
+        for block in body.chunks(RATE) {
+            let state = self.ctx.state_mut();
+            for (s, c) in state.iter_mut().zip(block.iter()) {
+                let mut m = Bn254Fr::new();
+                submod_checked(&mut m, c, s);
+                out.push(m);
+                *s = c.clone();
+            }
+            self.ctx.permute();
+        }
+        out
+    }
+
+    /// Squeeze the first rate element as an output
+    pub fn squeeze(&mut self) -> Bn254Fr {
+        let out = self.ctx.state()[0].clone();
+        self.ctx.permute();
+        out
+    }
+}
+
+/// Capacity domain separator: 2^64 + ad_len * 2^32 + msg_len
+fn domain(ad_len: usize, msg_len: usize) -> Bn254Fr {
+    assert!(ad_len < 1 << 32 && msg_len < 1 << 32, "enc: input too long");
+    let mut d = Bn254Fr::from_str("18446744073709551616"); // 2^64
+    d.addmod_checked(&Bn254Fr::from_u64(((ad_len as u64) << 32) | msg_len as u64));
+    d
+}
+
+/// Authentication tag: absorb the key and squeeze
+fn tag(duplex: &mut PoseidonDuplex, key: &Bn254Fr) -> Bn254Fr {
+    duplex.absorb(std::slice::from_ref(key));
+    duplex.squeeze()
+}
+
+/// Authenticated encryption of `msg` with associated data `ad`
+pub fn encrypt(key: &Bn254Fr, nonce: &Bn254Fr, ad: &[Bn254Fr], msg: &[Bn254Fr]) -> Ciphertext {
+    let mut duplex = PoseidonDuplex::new(key, nonce, &domain(ad.len(), msg.len()));
+    duplex.absorb(ad);
+    let body = duplex.encrypt(msg);
+    let tag = tag(&mut duplex, key);
+    Ciphertext { body, tag }
+}
+
+/// Authenticated decryption; asserts that the tag verifies
+pub fn decrypt(key: &Bn254Fr, nonce: &Bn254Fr, ad: &[Bn254Fr], ct: &Ciphertext) -> Vec<Bn254Fr> {
+    let mut duplex = PoseidonDuplex::new(key, nonce, &domain(ad.len(), ct.body.len()));
+    duplex.absorb(ad);
+    let msg = duplex.decrypt(&ct.body);
+    let tag = tag(&mut duplex, key);
+    Bn254Fr::assert_equal(&tag, &ct.tag);
+    msg
+}
+
+/// Unauthenticated stream encryption: `c_i = m_i + keystream_i`
+pub fn stream_encrypt(key: &Bn254Fr, nonce: &Bn254Fr, msg: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    let mut duplex = PoseidonDuplex::new(key, nonce, &Bn254Fr::from_u32(0));
+    duplex.encrypt(msg)
+}
+
+/// Inverse of [`stream_encrypt`]
+pub fn stream_decrypt(key: &Bn254Fr, nonce: &Bn254Fr, body: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    let mut duplex = PoseidonDuplex::new(key, nonce, &Bn254Fr::from_u32(0));
+    duplex.decrypt(body)
+}
@@ -0,0 +1,178 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Output Encodings for Ligetron
+//!
+//! Nullifiers, commitments and roots are field elements, while contracts
+//! and indexers expect 32-byte words or hex strings. These helpers derive
+//! the standard encodings with constraints binding them to the field value:
+//!
+//! - bytes: the canonical value as 32 big-endian bytes (`bytes32` in
+//!   Solidity, `abi.encode(uint256)`)
+//! - hex: the same bytes as 64 lowercase ASCII hex digits, optionally
+//!   prefixed with `0x`
+//! - base64: any constrained byte string in the padded standard alphabet
+//!   (RFC 4648), as used by DKIM and JWT-style headers
+//!
+//! The encodings use the canonical decomposition (value below the
+//! modulus), so each field element has exactly one encoding.
+
+use crate::bn254fr::{Bn254Fr, addmod_checked, submod_checked};
+use crate::word::{bit_and, bit_or};
+
+/// Canonical value as 32 constrained big-endian bytes
+pub fn to_bytes32_checked(x: &Bn254Fr) -> Vec<Bn254Fr> {
+    let mut bits = x.to_bits_canonical();
+    bits.resize(256, Bn254Fr::from_u32(0));
+    bits.chunks(8).rev().map(Bn254Fr::from_bits_checked).collect()
+}
+
+/// ASCII character of a 4-bit nibble given as its bits (least significant
+/// first): `'0' + n`, plus 39 for `n >= 10` to reach `'a'`
+fn hex_char(nibble: &[Bn254Fr]) -> Bn254Fr {
+    // n >= 10 iff b3 & (b2 | b1)
+    let alpha = bit_and(&nibble[3], &bit_or(&nibble[2], &nibble[1]));
+    let mut offset = alpha.clone();
+    offset.mulmod_constant_checked(&Bn254Fr::from_u32(39));
+    offset.addmod_checked(&Bn254Fr::from_u32(b'0' as u32));
+
+    let mut out = Bn254Fr::new();
+    addmod_checked(&mut out, &Bn254Fr::from_bits_checked(nibble), &offset);
+    out
+}
+
+/// Canonical value as 64 constrained lowercase ASCII hex digits, most
+/// significant first
+pub fn to_hex_checked(x: &Bn254Fr) -> Vec<Bn254Fr> {
+    let mut bits = x.to_bits_canonical();
+    bits.resize(256, Bn254Fr::from_u32(0));
+    bits.chunks(4).rev().map(hex_char).collect()
+}
+
+/// Like [`to_hex_checked`], prefixed with the constant characters `0x`
+pub fn to_prefixed_hex_checked(x: &Bn254Fr) -> Vec<Bn254Fr> {
+    let mut out = vec![Bn254Fr::from_u32(b'0' as u32), Bn254Fr::from_u32(b'x' as u32)];
+    out.extend(to_hex_checked(x));
+    out
+}
+
+/// Assert that the public `bytes` are the 32-byte big-endian encoding of `x`
+pub fn assert_bytes32(x: &Bn254Fr, bytes: &[u8; 32]) {
+    for (b, &e) in to_bytes32_checked(x).iter().zip(bytes.iter()) {
+        b.assert_equal_const(e as u64);
+    }
+}
+
+/// Assert that the public `hex` string is the lowercase hex encoding of
+/// `x`, with or without a `0x` prefix
+pub fn assert_hex(x: &Bn254Fr, hex: &str) {
+    let digits = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    assert_eq!(digits.len(), 64, "assert_hex: expected 64 hex digits");
+    for (c, &e) in to_hex_checked(x).iter().zip(digits.iter()) {
+        c.assert_equal_const(e as u64);
+    }
+}
+
+/// ASCII character of a 6-bit value given as its bits (least significant
+/// first): `'A' + v`, shifted by 6 from `v = 26` to reach `'a'`, by -75
+/// from `v = 52` to reach `'0'`, then to `'+'` and `'/'` for 62 and 63
+fn base64_char(sextet: &[Bn254Fr]) -> Bn254Fr {
+    let [b0, b1, b2, b3, b4, b5] = sextet else { unreachable!() };
+
+    // v >= 26 iff b5 | (b4 & b3 & (b2 | b1))
+    let ge26 = bit_or(b5, &bit_and(&bit_and(b4, b3), &bit_or(b2, b1)));
+    // v >= 52 iff b5 & b4 & (b3 | b2)
+    let ge52 = bit_and(&bit_and(b5, b4), &bit_or(b3, b2));
+    // v >= 62 iff b5..b1 are all set
+    let ge62 = bit_and(&bit_and(&bit_and(b5, b4), &bit_and(b3, b2)), b1);
+    let eq63 = bit_and(&ge62, b0);
+
+    let term = |flag: &Bn254Fr, k: u32| {
+        let mut t = flag.clone();
+        t.mulmod_constant_checked(&Bn254Fr::from_u32(k));
+        t
+    };
+    let mut up = term(&ge26, 6);
+    up.addmod_checked(&term(&eq63, 3));
+    up.addmod_checked(&Bn254Fr::from_u32(b'A' as u32));
+    let mut down = term(&ge52, 75);
+    down.addmod_checked(&term(&ge62, 15));
+
+    let mut offset = Bn254Fr::new();
+    submod_checked(&mut offset, &up, &down);
+    let mut out = Bn254Fr::new();
+    addmod_checked(&mut out, &Bn254Fr::from_bits_checked(sextet), &offset);
+    out
+}
+
+/// Padded base64 encoding (RFC 4648, standard alphabet) of bytes given as
+/// field elements, as constrained ASCII characters. Each byte is range
+/// checked to 8 bits.
+pub fn to_base64_checked(bytes: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    let zero = Bn254Fr::from_u32(0);
+    let pad = Bn254Fr::from_u32(b'=' as u32);
+
+    let mut out = Vec::with_capacity(bytes.len().div_ceil(3) * 4);
+    for group in bytes.chunks(3) {
+        // Bits of the group, most significant first, zero padded to 24
+        let mut bits: Vec<Bn254Fr> = group.iter()
+            .flat_map(|b| b.to_bits(8).into_iter().rev())
+            .collect();
+        bits.resize(24, zero.clone());
+
+        let chars = group.len() + 1;
+        for sextet in bits.chunks(6).take(chars) {
+            let le: Vec<Bn254Fr> = sextet.iter().rev().cloned().collect();
+            out.push(base64_char(&le));
+        }
+        out.extend(std::iter::repeat_n(pad.clone(), 4 - chars));
+    }
+    out
+}
+
+/// Read encoded bytes or characters back into wasm memory, e.g. to print
+/// or return them (no constraints)
+pub fn to_host_bytes(encoded: &[Bn254Fr]) -> Vec<u8> {
+    encoded.iter().map(|b| b.get_u64() as u8).collect()
+}
+
+#[cfg(all(test, feature = "native-sim"))]
+mod tests {
+    use super::*;
+
+    fn base64(s: &[u8]) -> Vec<u8> {
+        let bytes: Vec<Bn254Fr> = s.iter().map(|&b| Bn254Fr::from_u32(b as u32)).collect();
+        to_host_bytes(&to_base64_checked(&bytes))
+    }
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(base64(b""), b"");
+        assert_eq!(base64(b"f"), b"Zg==");
+        assert_eq!(base64(b"fo"), b"Zm8=");
+        assert_eq!(base64(b"foo"), b"Zm9v");
+        assert_eq!(base64(b"foobar"), b"Zm9vYmFy");
+    }
+
+    #[test]
+    fn base64_alphabet() {
+        // Every 6-bit value once: 0..64 packed into 48 bytes
+        let bits: Vec<u8> = (0..64u8).flat_map(|v| (0..6).rev().map(move |i| (v >> i) & 1)).collect();
+        let bytes: Vec<u8> = bits.chunks(8).map(|c| c.iter().fold(0, |acc, b| acc << 1 | b)).collect();
+        assert_eq!(base64(&bytes),
+                   b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
+    }
+}
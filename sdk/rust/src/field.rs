@@ -0,0 +1,408 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Generic Field Interface for Ligetron
+//!
+//! [`LigetronField`] abstracts over the scalar [`Bn254Fr`] and the
+//! vectorized [`VBn254Fr`] backends, so gadgets such as Poseidon, Poseidon2,
+//! Baby Jubjub and EdDSA are written once and instantiated for both.
+//!
+//! All arithmetic methods add constraints. Constants use the backend's
+//! native constant type: field elements for the scalar backend and
+//! [`VBn254FrConstant`] (applied with the cheaper constant operations) for
+//! the vectorized backend.
+
+use crate::bn254fr::{self, Bn254Fr, BN254FR_MODULUS, BN254FR_HALF_MODULUS, hex_to_bits,
+                     addmod_checked, submod_checked, mulmod_checked, divmod_checked};
+use crate::vbn254fr::{self, VBn254Fr, VBn254FrConstant};
+
+/// Field operations shared by the scalar and vectorized backends
+pub trait LigetronField: Clone {
+    /// Constant operand type
+    type Constant;
+
+    /// Allocate a new element
+    fn new() -> Self;
+
+    /// Element holding a small integer
+    fn from_u32(value: u32) -> Self;
+
+    /// Element parsed from a decimal or 0x-prefixed hex string
+    fn from_str(s: &str) -> Self;
+
+    /// Constant parsed from a decimal or 0x-prefixed hex string
+    fn constant(s: &str) -> Self::Constant;
+
+    fn set_u32(&mut self, value: u32);
+
+    /// Set from big-endian bytes
+    fn set_bytes_big(&mut self, bytes: &[u8]);
+
+    /// out = a + b
+    fn add_checked(out: &mut Self, a: &Self, b: &Self);
+
+    /// out = a - b
+    fn sub_checked(out: &mut Self, a: &Self, b: &Self);
+
+    /// out = a * b
+    fn mul_checked(out: &mut Self, a: &Self, b: &Self);
+
+    /// out = a / b
+    fn div_checked(out: &mut Self, a: &Self, b: &Self);
+
+    /// out = a + k
+    fn add_constant(out: &mut Self, a: &Self, k: &Self::Constant);
+
+    /// out = a - k
+    fn sub_constant(out: &mut Self, a: &Self, k: &Self::Constant);
+
+    /// out = k - a
+    fn constant_sub(out: &mut Self, k: &Self::Constant, a: &Self);
+
+    /// out = a * k
+    fn mul_constant(out: &mut Self, a: &Self, k: &Self::Constant);
+
+    /// out = a * k with `k` in Montgomery form (vectorized backend only)
+    fn mont_mul_constant(out: &mut Self, a: &Self, k: &Self::Constant);
+
+    /// self = self + x
+    fn add_assign_checked(&mut self, x: &Self);
+
+    /// self = self - x
+    fn sub_assign_checked(&mut self, x: &Self);
+
+    /// self = self * x
+    fn mul_assign_checked(&mut self, x: &Self);
+
+    /// self = self / x
+    fn div_assign_checked(&mut self, x: &Self);
+
+    /// self = self + k
+    fn add_constant_assign(&mut self, k: &Self::Constant);
+
+    /// self = self - k
+    fn sub_constant_assign(&mut self, k: &Self::Constant);
+
+    /// self = self * k
+    fn mul_constant_assign(&mut self, k: &Self::Constant);
+
+    /// out = cond ? a1 : a0
+    fn mux(out: &mut Self, cond: &Self, a0: &Self, a1: &Self);
+
+    /// 4-way selection by the selector bits (s0, s1)
+    fn mux2(out: &mut Self, s0: &Self, s1: &Self, a0: &Self, a1: &Self, a2: &Self, a3: &Self);
+
+    /// Constrain a == b
+    fn assert_equal(a: &Self, b: &Self);
+
+    /// Full 254-bit little-endian decomposition
+    fn bits(&self) -> Vec<Self>;
+}
+
+impl LigetronField for Bn254Fr {
+    type Constant = Bn254Fr;
+
+    fn new() -> Self {
+        Bn254Fr::new()
+    }
+
+    fn from_u32(value: u32) -> Self {
+        Bn254Fr::from_u32(value)
+    }
+
+    fn from_str(s: &str) -> Self {
+        Bn254Fr::from_str(s)
+    }
+
+    fn constant(s: &str) -> Bn254Fr {
+        Bn254Fr::from_str(s)
+    }
+
+    fn set_u32(&mut self, value: u32) {
+        Bn254Fr::set_u32(self, value);
+    }
+
+    fn set_bytes_big(&mut self, bytes: &[u8]) {
+        Bn254Fr::set_bytes_big(self, bytes);
+    }
+
+    fn add_checked(out: &mut Self, a: &Self, b: &Self) {
+        addmod_checked(out, a, b);
+    }
+
+    fn sub_checked(out: &mut Self, a: &Self, b: &Self) {
+        submod_checked(out, a, b);
+    }
+
+    fn mul_checked(out: &mut Self, a: &Self, b: &Self) {
+        mulmod_checked(out, a, b);
+    }
+
+    fn div_checked(out: &mut Self, a: &Self, b: &Self) {
+        divmod_checked(out, a, b);
+    }
+
+    fn add_constant(out: &mut Self, a: &Self, k: &Bn254Fr) {
+        addmod_checked(out, a, k);
+    }
+
+    fn sub_constant(out: &mut Self, a: &Self, k: &Bn254Fr) {
+        submod_checked(out, a, k);
+    }
+
+    fn constant_sub(out: &mut Self, k: &Bn254Fr, a: &Self) {
+        submod_checked(out, k, a);
+    }
+
+    fn mul_constant(out: &mut Self, a: &Self, k: &Bn254Fr) {
+        mulmod_checked(out, k, a);
+    }
+
+    fn mont_mul_constant(_out: &mut Self, _a: &Self, _k: &Bn254Fr) {
+        panic!("Montgomery constants are only supported by the vectorized backend");
+    }
+
+    fn add_assign_checked(&mut self, x: &Self) {
+        self.addmod_checked(x);
+    }
+
+    fn sub_assign_checked(&mut self, x: &Self) {
+        self.submod_checked(x);
+    }
+
+    fn mul_assign_checked(&mut self, x: &Self) {
+        self.mulmod_checked(x);
+    }
+
+    fn div_assign_checked(&mut self, x: &Self) {
+        self.divmod_checked(x);
+    }
+
+    fn add_constant_assign(&mut self, k: &Bn254Fr) {
+        self.addmod_checked(k);
+    }
+
+    fn sub_constant_assign(&mut self, k: &Bn254Fr) {
+        self.submod_checked(k);
+    }
+
+    fn mul_constant_assign(&mut self, k: &Bn254Fr) {
+        self.mulmod_checked(k);
+    }
+
+    fn mux(out: &mut Self, cond: &Self, a0: &Self, a1: &Self) {
+        bn254fr::mux(out, cond, a0, a1);
+    }
+
+    fn mux2(out: &mut Self, s0: &Self, s1: &Self, a0: &Self, a1: &Self, a2: &Self, a3: &Self) {
+        bn254fr::mux2(out, s0, s1, a0, a1, a2, a3);
+    }
+
+    fn assert_equal(a: &Self, b: &Self) {
+        Bn254Fr::assert_equal(a, b);
+    }
+
+    fn bits(&self) -> Vec<Self> {
+        self.to_bits(254)
+    }
+}
+
+impl LigetronField for VBn254Fr {
+    type Constant = VBn254FrConstant;
+
+    fn new() -> Self {
+        VBn254Fr::new()
+    }
+
+    fn from_u32(value: u32) -> Self {
+        VBn254Fr::from_ui_scalar(value)
+    }
+
+    fn from_str(s: &str) -> Self {
+        VBn254Fr::from_str_scalar(s)
+    }
+
+    fn constant(s: &str) -> VBn254FrConstant {
+        VBn254FrConstant::from_str(s)
+    }
+
+    fn set_u32(&mut self, value: u32) {
+        self.set_ui_scalar(value);
+    }
+
+    fn set_bytes_big(&mut self, bytes: &[u8]) {
+        self.set_bytes_scalar(bytes);
+    }
+
+    fn add_checked(out: &mut Self, a: &Self, b: &Self) {
+        vbn254fr::addmod_vec(out, a, b);
+    }
+
+    fn sub_checked(out: &mut Self, a: &Self, b: &Self) {
+        vbn254fr::submod_vec(out, a, b);
+    }
+
+    fn mul_checked(out: &mut Self, a: &Self, b: &Self) {
+        vbn254fr::mulmod_vec(out, a, b);
+    }
+
+    fn div_checked(out: &mut Self, a: &Self, b: &Self) {
+        vbn254fr::divmod_vec(out, a, b);
+    }
+
+    fn add_constant(out: &mut Self, a: &Self, k: &VBn254FrConstant) {
+        vbn254fr::addmod_constant(out, a, k);
+    }
+
+    fn sub_constant(out: &mut Self, a: &Self, k: &VBn254FrConstant) {
+        vbn254fr::submod_constant(out, a, k);
+    }
+
+    fn constant_sub(out: &mut Self, k: &VBn254FrConstant, a: &Self) {
+        vbn254fr::constant_submod(out, k, a);
+    }
+
+    fn mul_constant(out: &mut Self, a: &Self, k: &VBn254FrConstant) {
+        vbn254fr::mulmod_constant(out, a, k);
+    }
+
+    fn mont_mul_constant(out: &mut Self, a: &Self, k: &VBn254FrConstant) {
+        vbn254fr::mont_mul_constant(out, a, k);
+    }
+
+    fn add_assign_checked(&mut self, x: &Self) {
+        self.addmod_vec(x);
+    }
+
+    fn sub_assign_checked(&mut self, x: &Self) {
+        self.submod_vec(x);
+    }
+
+    fn mul_assign_checked(&mut self, x: &Self) {
+        self.mulmod_vec(x);
+    }
+
+    fn div_assign_checked(&mut self, x: &Self) {
+        self.divmod_vec(x);
+    }
+
+    fn add_constant_assign(&mut self, k: &VBn254FrConstant) {
+        self.addmod_constant(k);
+    }
+
+    fn sub_constant_assign(&mut self, k: &VBn254FrConstant) {
+        self.submod_constant(k);
+    }
+
+    fn mul_constant_assign(&mut self, k: &VBn254FrConstant) {
+        self.mulmod_constant(k);
+    }
+
+    fn mux(out: &mut Self, cond: &Self, a0: &Self, a1: &Self) {
+        vbn254fr::mux_vec(out, cond, a0, a1);
+    }
+
+    fn mux2(out: &mut Self, s0: &Self, s1: &Self, a0: &Self, a1: &Self, a2: &Self, a3: &Self) {
+        vbn254fr::mux2_vec(out, s0, s1, a0, a1, a2, a3);
+    }
+
+    fn assert_equal(a: &Self, b: &Self) {
+        VBn254Fr::assert_equal(a, b);
+    }
+
+    fn bits(&self) -> Vec<Self> {
+        self.bit_decompose()
+    }
+}
+
+// ============= Comparisons with Constants =============
+
+/// Return 1 if the number encoded by constrained `bits` (little-endian) is
+/// strictly less than the hex constant `c`, 0 otherwise
+pub fn lt_constant_checked<F: LigetronField>(bits: &[F], c: &str) -> F {
+    let c_bits = hex_to_bits(c, bits.len());
+    let mut eq = F::from_u32(1);
+    let mut lt = F::from_u32(0);
+
+    // Scan from the MSB, tracking whether the prefix is still equal
+    for (b, &ci) in bits.iter().zip(c_bits.iter()).rev() {
+        let mut t = F::new();
+        F::mul_checked(&mut t, &eq, b);
+        if ci {
+            // lt += eq * (1 - b), eq = eq * b
+            lt.add_assign_checked(&eq);
+            lt.sub_assign_checked(&t);
+            eq = t;
+        } else {
+            // eq = eq * (1 - b)
+            eq.sub_assign_checked(&t);
+        }
+    }
+    lt
+}
+
+/// Return 1 if the number encoded by constrained `bits` (little-endian) is
+/// strictly greater than the hex constant `c`, 0 otherwise
+pub fn gt_constant_checked<F: LigetronField>(bits: &[F], c: &str) -> F {
+    let c_bits = hex_to_bits(c, bits.len());
+    let mut eq = F::from_u32(1);
+    let mut gt = F::from_u32(0);
+
+    for (b, &ci) in bits.iter().zip(c_bits.iter()).rev() {
+        let mut t = F::new();
+        F::mul_checked(&mut t, &eq, b);
+        if ci {
+            // eq = eq * b
+            eq = t;
+        } else {
+            // gt += eq * b, eq = eq * (1 - b)
+            gt.add_assign_checked(&t);
+            eq.sub_assign_checked(&t);
+        }
+    }
+    gt
+}
+
+/// Constrained canonical 254-bit decomposition (the bits encode a value
+/// below the modulus)
+pub fn bits_canonical<F: LigetronField>(x: &F) -> Vec<F> {
+    let bits = x.bits();
+    F::assert_equal(&lt_constant_checked(&bits, BN254FR_MODULUS), &F::from_u32(1));
+    bits
+}
+
+/// Return 1 if `x > (p - 1) / 2` (i.e. `x` is "negative"), 0 otherwise
+pub fn sign_checked<F: LigetronField>(x: &F) -> F {
+    gt_constant_checked(&bits_canonical(x), BN254FR_HALF_MODULUS)
+}
+
+/// Return 1 if `x == 0`, 0 otherwise (with constraints)
+pub fn is_zero_checked<F: LigetronField>(x: &F) -> F {
+    let mut out = F::from_u32(1);
+    for b in bits_canonical(x) {
+        let mut nb = F::new();
+        F::constant_sub(&mut nb, &F::constant("1"), &b);
+        out.mul_assign_checked(&nb);
+    }
+    out
+}
+
+/// Return 1 if `a == b`, 0 otherwise (with constraints)
+pub fn is_equal_checked<F: LigetronField>(a: &F, b: &F) -> F {
+    let mut diff = F::new();
+    F::sub_checked(&mut diff, a, b);
+    is_zero_checked(&diff)
+}
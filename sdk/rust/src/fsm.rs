@@ -0,0 +1,149 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Finite State Machines for Ligetron
+//!
+//! Runs a public deterministic automaton over a private input sequence.
+//! The current state is kept as a one-hot vector; each step witnesses the
+//! input symbol as a one-hot vector (checked against the symbol value) and
+//! computes the next state with one product per table entry:
+//!
+//! `next[t] = Σ_{T[s][a] = t} state[s] · symbol[a]`
+//!
+//! Exactly one product is 1, so the next state stays one-hot without
+//! further checks. A step costs `states · symbols` multiplications, which
+//! suits small protocol and parser automata; map large alphabets to symbol
+//! classes first.
+
+use crate::bn254fr::{Bn254Fr, mulmod_checked};
+
+/// Deterministic automaton with a public transition table
+#[derive(Clone, Debug)]
+pub struct Fsm {
+    num_states: usize,
+    num_symbols: usize,
+    /// `table[s * num_symbols + a]` is the successor of state `s` on `a`
+    table: Vec<usize>,
+    initial: usize,
+}
+
+impl Fsm {
+    /// Automaton with `table[s][a]` as the successor of state `s` on symbol
+    /// `a`, starting in `initial`
+    pub fn new(table: &[Vec<usize>], initial: usize) -> Self {
+        let num_states = table.len();
+        assert!(num_states > 0, "Fsm: no states");
+        let num_symbols = table[0].len();
+        assert!(num_symbols > 0, "Fsm: no symbols");
+        assert!(initial < num_states, "Fsm: initial state out of range");
+        for row in table {
+            assert_eq!(row.len(), num_symbols, "Fsm: ragged transition table");
+            assert!(row.iter().all(|&t| t < num_states), "Fsm: successor out of range");
+        }
+        Fsm { num_states, num_symbols, table: table.concat(), initial }
+    }
+
+    pub fn num_states(&self) -> usize {
+        self.num_states
+    }
+
+    pub fn num_symbols(&self) -> usize {
+        self.num_symbols
+    }
+
+    /// One-hot encoding of a state
+    fn one_hot(&self, state: usize) -> Vec<Bn254Fr> {
+        (0..self.num_states).map(|s| Bn254Fr::from_u32((s == state) as u32)).collect()
+    }
+
+    /// Witness the one-hot encoding of `symbol` and constrain it to be
+    /// boolean, to sum to 1 and to select `symbol`
+    fn symbol_one_hot(&self, symbol: &Bn254Fr) -> Vec<Bn254Fr> {
+        let value = symbol.get_u64() as usize;
+        let bits: Vec<Bn254Fr> = (0..self.num_symbols)
+            .map(|a| Bn254Fr::from_u32((a == value) as u32))
+            .collect();
+
+        let mut sum = Bn254Fr::from_u32(0);
+        let mut selected = Bn254Fr::from_u32(0);
+        for (a, b) in bits.iter().enumerate() {
+            Bn254Fr::assert_bit(b);
+            sum.addmod_checked(b);
+            let mut t = b.clone();
+            t.mulmod_constant_checked(&Bn254Fr::from_u64(a as u64));
+            selected.addmod_checked(&t);
+        }
+        Bn254Fr::assert_equal(&sum, &Bn254Fr::from_u32(1));
+        Bn254Fr::assert_equal(&selected, symbol);
+        bits
+    }
+
+    /// One transition from the one-hot `state` on `symbol`
+    pub fn step(&self, state: &[Bn254Fr], symbol: &Bn254Fr) -> Vec<Bn254Fr> {
+        assert_eq!(state.len(), self.num_states, "Fsm::step: wrong state width");
+        let sym = self.symbol_one_hot(symbol);
+
+        let mut next: Vec<Bn254Fr> = (0..self.num_states).map(|_| Bn254Fr::from_u32(0)).collect();
+        let mut t = Bn254Fr::new();
+        for (s, st) in state.iter().enumerate() {
+            for (a, sa) in sym.iter().enumerate() {
+                mulmod_checked(&mut t, st, sa);
+                next[self.table[s * self.num_symbols + a]].addmod_checked(&t);
+            }
+        }
+        next
+    }
+
+    /// Run over the private `input` symbols and return the one-hot final
+    /// state (with constraints)
+    pub fn run(&self, input: &[Bn254Fr]) -> Vec<Bn254Fr> {
+        let mut state = self.one_hot(self.initial);
+        for symbol in input {
+            state = self.step(&state, symbol);
+        }
+        state
+    }
+
+    /// Index of the final state after `input` (with constraints)
+    pub fn final_state(&self, input: &[Bn254Fr]) -> Bn254Fr {
+        let mut out = Bn254Fr::from_u32(0);
+        for (s, st) in self.run(input).iter().enumerate() {
+            let mut t = st.clone();
+            t.mulmod_constant_checked(&Bn254Fr::from_u64(s as u64));
+            out.addmod_checked(&t);
+        }
+        out
+    }
+
+    /// Return 1 if `input` ends in one of the `accepting` states, 0
+    /// otherwise (with constraints)
+    pub fn accepts(&self, input: &[Bn254Fr], accepting: &[usize]) -> Bn254Fr {
+        let state = self.run(input);
+        assert!(accepting.iter().all(|&s| s < self.num_states), "Fsm::accepts: state out of range");
+        let mut out = Bn254Fr::from_u32(0);
+        for (s, st) in state.iter().enumerate() {
+            if accepting.contains(&s) {
+                out.addmod_checked(st);
+            }
+        }
+        out
+    }
+
+    /// Assert that `input` ends in one of the `accepting` states
+    pub fn assert_accepts(&self, input: &[Bn254Fr], accepting: &[usize]) {
+        Bn254Fr::assert_equal(&self.accepts(input, accepting), &Bn254Fr::from_u32(1));
+    }
+}
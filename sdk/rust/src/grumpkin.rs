@@ -0,0 +1,574 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Grumpkin Curve Operations for Ligetron
+//!
+//! Grumpkin is the short Weierstrass curve `y^2 = x^3 - 17` over the BN254
+//! scalar field, forming a cycle with BN254 (its group order is the BN254
+//! base field modulus, cofactor 1). Coordinates are native [`Bn254Fr`]
+//! elements, so point arithmetic costs a few field multiplications.
+//!
+//! Points are kept in projective coordinates `(X : Y : Z)` with the
+//! identity `(0 : 1 : 0)`, and added with the complete formulas of
+//! Renes-Costello-Batina (2016, Algorithm 7), so doubling, the identity and
+//! inverse points need no special cases.
+//!
+//! ## Generators
+//!
+//! - Generator: (1, 17631683881184975370165255887551781615748388533673675138860)
+//! - [`GrumpkinGenerators`] derives independent generators by hashing to
+//!   the curve, for Pedersen and inner-product-argument commitments.
+//!
+//! ## Serialization
+//!
+//! Points use the SEC1 encodings with 32-byte big-endian coordinates:
+//! `0x00` for the identity, `0x02`/`0x03 || x` compressed (by the parity
+//! of y) and `0x04 || x || y` uncompressed.
+//!
+//! ## GLV Scalar Multiplication
+//!
+//! Grumpkin has `j = 0`, so `φ(x, y) = (β·x, y)` with `β` a cube root of
+//! unity in the base field acts as multiplication by a cube root of unity
+//! `λ` in the scalar field (the BN254 base field `Fq`).
+//! [`GrumpkinPoint::scalar_mul_glv`] splits `k = k1 + k2·λ (mod n)` with
+//! `|k1|, |k2| < 2^128` and runs one joint double-and-add over the two
+//! halves, halving the number of doublings. The split is computed
+//! off-circuit and checked in emulated `Fq` arithmetic.
+
+use crate::bn254fr::{self, Bn254Fr, BN254FR_MODULUS, addmod_checked, submod_checked, mulmod_checked, divmod_checked,
+                     mux, mulmod, addmod, submod, sqrtmod};
+use crate::bn254fq::Bn254Fq;
+use crate::cache::memoize;
+use crate::poseidon2::poseidon2_hash;
+
+/// Curve coefficient b = -17, and 3b for the addition formulas
+const COEF_B: &str = "-17";
+const COEF_B3: &str = "-51";
+
+const GENERATOR_X: &str = "1";
+const GENERATOR_Y: &str = "17631683881184975370165255887551781615748388533673675138860";
+
+/// Cube root of unity β in the base field, `φ(x, y) = (β·x, y)`
+pub const GLV_BETA: &str = "0xb3c4d79d41a917585bfc41088d8daaa78b17ea66b99c90dd";
+
+/// Cube root of unity λ in the scalar field (hex, without prefix), with
+/// `φ(P) = λ·P`
+pub const GLV_LAMBDA: &str = "59e26bcea0d48bacd4f263f1acdb5c4f5763473177fffffe";
+
+/// Bit width of the GLV half scalars
+pub const GLV_SCALAR_BITS: usize = 128;
+
+/// GLV lattice basis and rounding constants of a curve, as little-endian limbs
+pub(crate) struct GlvBasis {
+    /// Reduced basis `(a1, b1), (a2, b2)` of the lattice
+    /// `{(x, y) : x + y·λ ≡ 0 (mod n)}` as magnitudes; `b1` is negative, the
+    /// others positive
+    pub a1: [u64; 3],
+    pub b1_neg: [u64; 3],
+    pub a2: [u64; 3],
+    pub b2: [u64; 3],
+    /// `round(2^384·b2 / n)` and `round(-2^384·b1 / n)`
+    pub g1: [u64; 5],
+    pub g2: [u64; 5],
+}
+
+const GLV_BASIS: GlvBasis = GlvBasis {
+    a1: [0x89d3256894d213e2, 0, 0],
+    b1_neg: [0x8211bbeb7d4f1129, 0x6f4d8248eeb859fc, 0],
+    a2: [0x0be4e1541221250b, 0x6f4d8248eeb859fd, 0],
+    b2: [0x89d3256894d213e2, 0, 0],
+    g1: [0x5236df9ec85147d0, 0x247280ee539a2471, 0xd91d232ec7e0b3d2, 0x2, 0],
+    g2: [0xa08c11266972c2b8, 0xa5e38cfb5eaa26e6, 0x7a7bd9d4391eb18d, 0x4ccef014a773d2cf, 0x2],
+};
+
+/// Domain separation tag for generator derivation
+pub const GRUMPKIN_DOMAIN: &[u8] = b"ligetron.grumpkin.v1";
+
+/// Field constant for a (possibly negative) decimal string
+fn constant(s: &str) -> Bn254Fr {
+    match s.strip_prefix('-') {
+        Some(abs) => {
+            let mut out = Bn254Fr::new();
+            submod(&mut out, &Bn254Fr::from_u32(0), &Bn254Fr::from_str(abs));
+            out
+        }
+        None => Bn254Fr::from_str(s),
+    }
+}
+
+fn add(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    let mut out = Bn254Fr::new();
+    addmod_checked(&mut out, a, b);
+    out
+}
+
+fn sub(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    let mut out = Bn254Fr::new();
+    submod_checked(&mut out, a, b);
+    out
+}
+
+fn mul(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    let mut out = Bn254Fr::new();
+    mulmod_checked(&mut out, a, b);
+    out
+}
+
+/// Grumpkin point in projective coordinates
+#[derive(Clone)]
+pub struct GrumpkinPoint {
+    pub x: Bn254Fr,
+    pub y: Bn254Fr,
+    pub z: Bn254Fr,
+}
+
+impl GrumpkinPoint {
+    /// Point from affine coordinates (not checked to be on the curve, see
+    /// [`GrumpkinPoint::from_affine_checked`])
+    pub fn from_affine(x: Bn254Fr, y: Bn254Fr) -> Self {
+        GrumpkinPoint { x, y, z: Bn254Fr::from_u32(1) }
+    }
+
+    /// Point from affine coordinates, asserting y^2 = x^3 - 17
+    pub fn from_affine_checked(x: Bn254Fr, y: Bn254Fr) -> Self {
+        let x3 = mul(&mul(&x, &x), &x);
+        Bn254Fr::assert_equal(&mul(&y, &y), &add(&x3, &constant(COEF_B)));
+        GrumpkinPoint::from_affine(x, y)
+    }
+
+    /// The identity (0 : 1 : 0)
+    pub fn identity() -> Self {
+        GrumpkinPoint {
+            x: Bn254Fr::from_u32(0),
+            y: Bn254Fr::from_u32(1),
+            z: Bn254Fr::from_u32(0),
+        }
+    }
+
+    pub fn generator() -> Self {
+        GrumpkinPoint::from_affine(Bn254Fr::from_str(GENERATOR_X), Bn254Fr::from_str(GENERATOR_Y))
+    }
+
+    /// Affine coordinates; fails for the identity
+    pub fn to_affine(&self) -> (Bn254Fr, Bn254Fr) {
+        let mut x = Bn254Fr::new();
+        let mut y = Bn254Fr::new();
+        divmod_checked(&mut x, &self.x, &self.z);
+        divmod_checked(&mut y, &self.y, &self.z);
+        (x, y)
+    }
+
+    /// Complete addition (RCB16 Algorithm 7, a = 0)
+    pub fn add(p: &GrumpkinPoint, q: &GrumpkinPoint) -> GrumpkinPoint {
+        let b3 = constant(COEF_B3);
+
+        let t0 = mul(&p.x, &q.x);
+        let t1 = mul(&p.y, &q.y);
+        let t2 = mul(&p.z, &q.z);
+        let t3 = sub(&mul(&add(&p.x, &p.y), &add(&q.x, &q.y)), &add(&t0, &t1));
+        let t4 = sub(&mul(&add(&p.y, &p.z), &add(&q.y, &q.z)), &add(&t1, &t2));
+        let y3 = sub(&mul(&add(&p.x, &p.z), &add(&q.x, &q.z)), &add(&t0, &t2));
+        let t0 = add(&add(&t0, &t0), &t0);
+        let t2 = mul(&b3, &t2);
+        let z3 = add(&t1, &t2);
+        let t1 = sub(&t1, &t2);
+        let y3 = mul(&b3, &y3);
+
+        let x3 = sub(&mul(&t3, &t1), &mul(&t4, &y3));
+        let y3n = add(&mul(&t1, &z3), &mul(&y3, &t0));
+        let z3n = add(&mul(&z3, &t4), &mul(&t0, &t3));
+        GrumpkinPoint { x: x3, y: y3n, z: z3n }
+    }
+
+    /// 2 * self
+    pub fn double(&self) -> GrumpkinPoint {
+        GrumpkinPoint::add(self, self)
+    }
+
+    /// -self
+    pub fn neg(&self) -> GrumpkinPoint {
+        GrumpkinPoint { x: self.x.clone(), y: sub(&Bn254Fr::from_u32(0), &self.y), z: self.z.clone() }
+    }
+
+    /// Select b0 if cond == 0, b1 if cond == 1
+    pub fn mux(cond: &Bn254Fr, b0: &GrumpkinPoint, b1: &GrumpkinPoint) -> GrumpkinPoint {
+        let mut out = GrumpkinPoint::identity();
+        mux(&mut out.x, cond, &b0.x, &b1.x);
+        mux(&mut out.y, cond, &b0.y, &b1.y);
+        mux(&mut out.z, cond, &b0.z, &b1.z);
+        out
+    }
+
+    /// Double-and-add scalar multiplication by a field element,
+    /// decomposed into its canonical bits
+    pub fn scalar_mul(&self, k: &Bn254Fr) -> GrumpkinPoint {
+        self.scalar_mul_bits(&k.to_bits_canonical())
+    }
+
+    /// Double-and-add scalar multiplication by constrained little-endian bits
+    pub fn scalar_mul_bits(&self, bits: &[Bn254Fr]) -> GrumpkinPoint {
+        let identity = GrumpkinPoint::identity();
+        let mut acc = GrumpkinPoint::identity();
+        for b in bits.iter().rev() {
+            acc = acc.double();
+            let t = GrumpkinPoint::mux(b, &identity, self);
+            acc = GrumpkinPoint::add(&acc, &t);
+        }
+        acc
+    }
+
+    /// SEC1 encoding, compressed (33 bytes) or uncompressed (65 bytes).
+    /// The affine conversion is constrained; the identity encodes as `0x00`.
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        if bn254fr::eq(&self.z, &Bn254Fr::from_u32(0)) {
+            return vec![0];
+        }
+        let (x, y) = self.to_affine();
+        let y_bytes = to_bytes_be_unchecked(&y);
+        let mut out = Vec::with_capacity(65);
+        if compressed {
+            out.push(0x02 | (y_bytes[31] & 1));
+            out.extend_from_slice(&to_bytes_be_unchecked(&x));
+        } else {
+            out.push(0x04);
+            out.extend_from_slice(&to_bytes_be_unchecked(&x));
+            out.extend_from_slice(&y_bytes);
+        }
+        out
+    }
+
+    /// Decode a SEC1 encoding, constraining the point to be on the curve
+    /// and, when compressed, y to have the encoded parity.
+    /// Returns `None` if the bytes do not encode a curve point.
+    pub fn from_sec1(bytes: &[u8]) -> Option<GrumpkinPoint> {
+        match (bytes.first()?, bytes.len()) {
+            (0x00, 1) => Some(GrumpkinPoint::identity()),
+            (0x04, 65) => {
+                let x = from_bytes_be(&bytes[1..33])?;
+                let y = from_bytes_be(&bytes[33..65])?;
+                let mut x3 = Bn254Fr::new();
+                mulmod(&mut x3, &x, &x);
+                x3.mulmod(&x);
+                let mut rhs = Bn254Fr::new();
+                addmod(&mut rhs, &x3, &constant(COEF_B));
+                let mut y2 = Bn254Fr::new();
+                mulmod(&mut y2, &y, &y);
+                if !bn254fr::eq(&y2, &rhs) {
+                    return None;
+                }
+                Some(GrumpkinPoint::from_affine_checked(x, y))
+            }
+            (&tag @ (0x02 | 0x03), 33) => {
+                let odd = tag == 0x03;
+                let x = from_bytes_be(&bytes[1..33])?;
+                let mut x3 = Bn254Fr::new();
+                mulmod(&mut x3, &x, &x);
+                x3.mulmod(&x);
+                let mut rhs = Bn254Fr::new();
+                addmod(&mut rhs, &x3, &constant(COEF_B));
+                let mut y = Bn254Fr::new();
+                if !sqrtmod(&mut y, &rhs) {
+                    return None;
+                }
+                if (y.get_u64() & 1 == 1) != odd {
+                    y.negmod();
+                }
+                let p = GrumpkinPoint::from_affine_checked(x, y);
+                Bn254Fr::assert_equal(&p.y.is_odd_checked(), &Bn254Fr::from_u32(odd as u32));
+                Some(p)
+            }
+            _ => None,
+        }
+    }
+
+    /// The endomorphism `φ(P) = (β·x, y) = λ·P`
+    pub fn endomorphism(&self) -> GrumpkinPoint {
+        GrumpkinPoint { x: mul(&self.x, &Bn254Fr::from_str(GLV_BETA)), y: self.y.clone(), z: self.z.clone() }
+    }
+
+    /// Joint double-and-add `a·p + b·q` by constrained little-endian bits,
+    /// sharing the doublings between both scalars
+    pub fn dual_scalar_mul_bits(p: &GrumpkinPoint, a_bits: &[Bn254Fr],
+                                q: &GrumpkinPoint, b_bits: &[Bn254Fr]) -> GrumpkinPoint {
+        let identity = GrumpkinPoint::identity();
+        let pq = GrumpkinPoint::add(p, q);
+        let zero = Bn254Fr::from_u32(0);
+        let len = a_bits.len().max(b_bits.len());
+
+        let mut acc = GrumpkinPoint::identity();
+        for i in (0..len).rev() {
+            let a = a_bits.get(i).unwrap_or(&zero);
+            let b = b_bits.get(i).unwrap_or(&zero);
+            acc = acc.double();
+            let t0 = GrumpkinPoint::mux(a, &identity, p);
+            let t1 = GrumpkinPoint::mux(a, q, &pq);
+            acc = GrumpkinPoint::add(&acc, &GrumpkinPoint::mux(b, &t0, &t1));
+        }
+        acc
+    }
+
+    /// Scalar multiplication by a field element with the GLV split: about
+    /// 128 doublings and 128 additions instead of 254 of each
+    pub fn scalar_mul_glv(&self, k: &Bn254Fr) -> GrumpkinPoint {
+        let d = GlvDecomposition::new(k);
+        let p = GrumpkinPoint::mux(&d.k1_neg, self, &self.neg());
+        let q = self.endomorphism();
+        let q = GrumpkinPoint::mux(&d.k2_neg, &q, &q.neg());
+        GrumpkinPoint::dual_scalar_mul_bits(&p, &d.k1_bits, &q, &d.k2_bits)
+    }
+
+    /// Constrain p == q as projective points
+    pub fn assert_equal(p: &GrumpkinPoint, q: &GrumpkinPoint) {
+        Bn254Fr::assert_equal(&mul(&p.x, &q.z), &mul(&q.x, &p.z));
+        Bn254Fr::assert_equal(&mul(&p.y, &q.z), &mul(&q.y, &p.z));
+    }
+}
+
+/// Big-endian bytes of a field element (unconstrained)
+fn to_bytes_be_unchecked(x: &Bn254Fr) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, bit) in x.to_bits_unchecked(254).iter().enumerate() {
+        bytes[31 - i / 8] |= (bit.get_u64() as u8) << (i % 8);
+    }
+    bytes
+}
+
+/// Field element from 32 big-endian bytes, `None` if not below the modulus
+fn from_bytes_be(bytes: &[u8]) -> Option<Bn254Fr> {
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    if hex.as_str() >= BN254FR_MODULUS.trim_start_matches("0x") {
+        return None;
+    }
+    Some(Bn254Fr::from_str(&format!("0x{}", hex)))
+}
+
+/// Split of a scalar `k = ±|k1| ± |k2|·λ (mod n)`
+#[derive(Clone)]
+pub struct GlvDecomposition {
+    /// Little-endian bits of `|k1|`
+    pub k1_bits: Vec<Bn254Fr>,
+    /// 1 if `k1` is negative
+    pub k1_neg: Bn254Fr,
+    /// Little-endian bits of `|k2|`
+    pub k2_bits: Vec<Bn254Fr>,
+    /// 1 if `k2` is negative
+    pub k2_neg: Bn254Fr,
+}
+
+impl GlvDecomposition {
+    /// Split `k` and constrain the halves to `GLV_SCALAR_BITS` bits and
+    /// `k ≡ k1 + k2·λ (mod n)`
+    pub fn new(k: &Bn254Fr) -> Self {
+        let (k1, k1_neg, k2, k2_neg) = glv_split(k);
+        let k1 = Bn254Fr::from_str(&format!("0x{:x}", k1));
+        let k2 = Bn254Fr::from_str(&format!("0x{:x}", k2));
+        let k1_neg = Bn254Fr::from_u32(k1_neg as u32);
+        let k2_neg = Bn254Fr::from_u32(k2_neg as u32);
+        Bn254Fr::assert_bit(&k1_neg);
+        Bn254Fr::assert_bit(&k2_neg);
+
+        let d = GlvDecomposition {
+            k1_bits: k1.to_bits(GLV_SCALAR_BITS),
+            k1_neg,
+            k2_bits: k2.to_bits(GLV_SCALAR_BITS),
+            k2_neg,
+        };
+
+        let lambda = Bn254Fq::from_str(GLV_LAMBDA, 16);
+        let e1 = signed_fq(&k1, &d.k1_neg);
+        let e2 = signed_fq(&k2, &d.k2_neg);
+        Bn254Fq::assert_equal(&Bn254Fq::from_fr_checked(k), &e1.add(&e2.mul(&lambda)));
+        d
+    }
+}
+
+/// `±v` in Fq for a small `v` and a sign bit
+fn signed_fq(v: &Bn254Fr, neg: &Bn254Fr) -> Bn254Fq {
+    let p = mul(neg, v);
+    let n = sub(v, &p);
+    Bn254Fq::from_fr_checked(&n).sub(&Bn254Fq::from_fr_checked(&p))
+}
+
+/// Unconstrained GLV split of `k` into `(|k1|, k1 < 0, |k2|, k2 < 0)`
+fn glv_split(k: &Bn254Fr) -> (u128, bool, u128, bool) {
+    let bits = k.to_bits_unchecked(254);
+    let mut kl = [0u64; 4];
+    for (i, b) in bits.iter().enumerate() {
+        kl[i / 64] |= b.get_u64() << (i % 64);
+    }
+    glv_split_limbs(&kl, &GLV_BASIS)
+}
+
+/// Unconstrained GLV split of the little-endian limbs of `k`:
+/// `c1 = round(k·g1 / 2^384)`, `c2 = round(k·g2 / 2^384)`,
+/// `k1 = k - c1·a1 - c2·a2`, `k2 = -c1·b1 - c2·b2`, computed mod 2^256
+pub(crate) fn glv_split_limbs(kl: &[u64; 4], basis: &GlvBasis) -> (u128, bool, u128, bool) {
+    let round_shift = |g: &[u64; 5]| -> [u64; 4] {
+        let p = mul_limbs(kl, g);
+        let mut c = [0u64; 4];
+        c[..3].copy_from_slice(&p[6..9]);
+        add_limbs(&c, &[p[5] >> 63, 0, 0, 0])
+    };
+    let c1 = round_shift(&basis.g1);
+    let c2 = round_shift(&basis.g2);
+
+    let k1 = sub_limbs(&sub_limbs(kl, &mul_trunc(&c1, &basis.a1)), &mul_trunc(&c2, &basis.a2));
+    let k2 = sub_limbs(&mul_trunc(&c1, &basis.b1_neg), &mul_trunc(&c2, &basis.b2));
+    let (k1, k1_neg) = abs_limbs(&k1);
+    let (k2, k2_neg) = abs_limbs(&k2);
+    (k1, k1_neg, k2, k2_neg)
+}
+
+/// Full product of little-endian limbs
+fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let t = out[i + j] as u128 + x as u128 * y as u128 + carry;
+            out[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        out[i + b.len()] = carry as u64;
+    }
+    out
+}
+
+/// `a·b mod 2^256`
+fn mul_trunc(a: &[u64; 4], b: &[u64; 3]) -> [u64; 4] {
+    let p = mul_limbs(a, b);
+    [p[0], p[1], p[2], p[3]]
+}
+
+/// `a + b mod 2^256`
+fn add_limbs(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        out[i] = s;
+        carry = c1 || c2;
+    }
+    out
+}
+
+/// `a - b mod 2^256`
+fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let neg_b = add_limbs(&[!b[0], !b[1], !b[2], !b[3]], &[1, 0, 0, 0]);
+    add_limbs(a, &neg_b)
+}
+
+/// Magnitude and sign of a two's complement value below 2^128 in magnitude
+fn abs_limbs(a: &[u64; 4]) -> (u128, bool) {
+    let neg = a[3] >> 63 == 1;
+    let m = if neg { sub_limbs(&[0; 4], a) } else { *a };
+    assert!(m[2] == 0 && m[3] == 0, "glv_split: half scalar out of range");
+    (m[0] as u128 | (m[1] as u128) << 64, neg)
+}
+
+/// Hash to a Grumpkin point with try-and-increment on the x coordinate
+pub fn hash_to_curve(index: u64) -> GrumpkinPoint {
+    hash_to_curve_with_domain(GRUMPKIN_DOMAIN, index)
+}
+
+/// [`hash_to_curve`] under a caller-chosen domain separation tag.
+/// Points are derived once per program and then reused.
+pub fn hash_to_curve_with_domain(tag: &[u8], index: u64) -> GrumpkinPoint {
+    memoize("grumpkin::hash_to_curve", (tag.to_vec(), index), || hash_to_curve_uncached(tag, index))
+}
+
+fn hash_to_curve_uncached(tag: &[u8], index: u64) -> GrumpkinPoint {
+    let mut domain = Bn254Fr::new();
+    domain.set_bytes_big(tag);
+    let b = constant(COEF_B);
+
+    for counter in 0u32.. {
+        let x = poseidon2_hash(&[domain.clone(), Bn254Fr::from_u64(index), Bn254Fr::from_u32(counter)]);
+        let mut x2 = Bn254Fr::new();
+        let mut x3 = Bn254Fr::new();
+        let mut rhs = Bn254Fr::new();
+        let mut y = Bn254Fr::new();
+        mulmod(&mut x2, &x, &x);
+        mulmod(&mut x3, &x2, &x);
+        addmod(&mut rhs, &x3, &b);
+        if sqrtmod(&mut y, &rhs) {
+            return GrumpkinPoint::from_affine_checked(x, y);
+        }
+    }
+    unreachable!()
+}
+
+/// Independent Grumpkin generators for vector commitments
+#[derive(Clone)]
+pub struct GrumpkinGenerators {
+    pub g: Vec<GrumpkinPoint>,
+    pub h: GrumpkinPoint,
+}
+
+impl GrumpkinGenerators {
+    /// Derive `n` vector generators and a blinding generator
+    pub fn new(n: usize) -> Self {
+        let g = (0..n as u64).map(hash_to_curve).collect();
+        let h = hash_to_curve(u64::MAX);
+        GrumpkinGenerators { g, h }
+    }
+
+    /// Pedersen vector commitment `sum_i v_i·G_i + r·H`
+    pub fn commit(&self, values: &[Bn254Fr], blinding: &Bn254Fr) -> GrumpkinPoint {
+        assert_eq!(values.len(), self.g.len(), "GrumpkinGenerators::commit: length mismatch");
+        let mut acc = self.h.scalar_mul(blinding);
+        for (g, v) in self.g.iter().zip(values.iter()) {
+            acc = GrumpkinPoint::add(&acc, &g.scalar_mul(v));
+        }
+        acc
+    }
+}
+
+#[cfg(all(test, feature = "native-sim"))]
+mod tests {
+    use super::*;
+
+    fn assert_glv_matches(k: &Bn254Fr) {
+        let g = GrumpkinPoint::generator();
+        GrumpkinPoint::assert_equal(&g.scalar_mul_glv(k), &g.scalar_mul(k));
+    }
+
+    #[test]
+    fn glv_zero_and_one() {
+        assert_glv_matches(&Bn254Fr::from_u32(0));
+        assert_glv_matches(&Bn254Fr::from_u32(1));
+    }
+
+    #[test]
+    fn glv_lambda() {
+        let lambda = Bn254Fr::from_str(&format!("0x{}", GLV_LAMBDA));
+        assert_glv_matches(&lambda);
+        let g = GrumpkinPoint::generator();
+        GrumpkinPoint::assert_equal(&g.endomorphism(), &g.scalar_mul(&lambda));
+    }
+
+    #[test]
+    fn glv_max_scalar() {
+        assert_glv_matches(&constant("-1"));
+    }
+
+    #[test]
+    fn glv_arbitrary_scalar() {
+        assert_glv_matches(&Bn254Fr::from_str(
+            "0x1d4c6a8b3f0e92a7c5b1e8f34d2a69c07b3e5f1a8c2d4e6f9a0b1c3d5e7f8091"));
+    }
+}
@@ -0,0 +1,157 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Poseidon / Poseidon2 Digest Bridge for Ligetron
+//!
+//! Systems migrating from Poseidon (t=3) trees to the Poseidon2 trees of
+//! [`merkle`] end up with mixed-generation paths: the lower levels of a
+//! path are hashed with one function and the upper levels with the other.
+//! A digest crosses generations by being rehashed with a domain tag:
+//!
+//! - Poseidon2 digest into a Poseidon tree: `Poseidon(P2_TO_P_TAG, d)`
+//! - Poseidon digest into a Poseidon2 tree: `Poseidon2(P_TO_P2_TAG, d)`
+//! - Poseidon tree node: `Poseidon(left, right)`
+//! - Poseidon2 tree node: [`merkle::node`]
+//!
+//! The tags keep a wrapped digest from colliding with a native digest or a
+//! node of the target tree. Which generation hashes each level is public
+//! structure of the path.
+
+use crate::bn254fr::{Bn254Fr, mux};
+use crate::merkle;
+use crate::poseidon::poseidon_t3_hash;
+use crate::poseidon2::poseidon2_hash;
+
+/// Domain separation tag for Poseidon2 digests absorbed into Poseidon trees
+pub const P2_TO_P_DOMAIN: &[u8] = b"ligetron.bridge.p2-to-p.v1";
+
+/// Domain separation tag for Poseidon digests absorbed into Poseidon2 trees
+pub const P_TO_P2_DOMAIN: &[u8] = b"ligetron.bridge.p-to-p2.v1";
+
+fn tag(domain: &[u8]) -> Bn254Fr {
+    let mut t = Bn254Fr::new();
+    t.set_bytes_big(domain);
+    t
+}
+
+/// Hash function generation of a digest or tree level
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashGen {
+    /// Poseidon with t=3
+    Poseidon,
+    /// Poseidon2, as used by [`merkle`]
+    Poseidon2,
+}
+
+/// Wrap a Poseidon2 digest for use in a Poseidon tree
+pub fn poseidon2_to_poseidon(digest: &Bn254Fr) -> Bn254Fr {
+    poseidon_t3_hash(&[tag(P2_TO_P_DOMAIN), digest.clone()])
+}
+
+/// Wrap a Poseidon digest for use in a Poseidon2 tree
+pub fn poseidon_to_poseidon2(digest: &Bn254Fr) -> Bn254Fr {
+    poseidon2_hash(&[tag(P_TO_P2_DOMAIN), digest.clone()])
+}
+
+/// Convert a digest of generation `from` for use under generation `to`.
+/// Digests already of the target generation are returned unchanged.
+pub fn convert(digest: &Bn254Fr, from: HashGen, to: HashGen) -> Bn254Fr {
+    match (from, to) {
+        (HashGen::Poseidon2, HashGen::Poseidon) => poseidon2_to_poseidon(digest),
+        (HashGen::Poseidon, HashGen::Poseidon2) => poseidon_to_poseidon2(digest),
+        _ => digest.clone(),
+    }
+}
+
+/// Node of a Poseidon (t=3) tree
+pub fn poseidon_node(left: &Bn254Fr, right: &Bn254Fr) -> Bn254Fr {
+    poseidon_t3_hash(&[left.clone(), right.clone()])
+}
+
+/// Node of a tree level hashed with `generation`
+pub fn node(generation: HashGen, left: &Bn254Fr, right: &Bn254Fr) -> Bn254Fr {
+    match generation {
+        HashGen::Poseidon => poseidon_node(left, right),
+        HashGen::Poseidon2 => merkle::node(left, right),
+    }
+}
+
+/// Authentication path whose levels may use different hash generations
+#[derive(Clone)]
+pub struct MixedPath {
+    /// Sibling digests from the leaf level up, each of its level's generation
+    pub siblings: Vec<Bn254Fr>,
+    /// Direction bits from the leaf level up (1 = the node is a right child)
+    pub path: Vec<Bn254Fr>,
+    /// Generation hashing each level, from the leaf level up
+    pub levels: Vec<HashGen>,
+}
+
+impl MixedPath {
+    /// Path for leaf `index` given its siblings and level generations
+    pub fn new(siblings: Vec<Bn254Fr>, index: u64, levels: Vec<HashGen>) -> Self {
+        assert_eq!(siblings.len(), levels.len(), "MixedPath: one generation per level");
+        let merkle::MerklePath { siblings, path } = merkle::MerklePath::new(siblings, index);
+        MixedPath { siblings, path, levels }
+    }
+
+    /// Path whose lowest `legacy_levels` levels are Poseidon and the rest
+    /// Poseidon2, the usual shape when legacy subtrees are kept and new
+    /// levels are added above them
+    pub fn migrated(siblings: Vec<Bn254Fr>, index: u64, legacy_levels: usize) -> Self {
+        let levels = (0..siblings.len())
+            .map(|i| if i < legacy_levels { HashGen::Poseidon } else { HashGen::Poseidon2 })
+            .collect();
+        Self::new(siblings, index, levels)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+}
+
+/// Recompute the root of a mixed-generation tree with constraints.
+///
+/// `leaf_hash` is a digest of generation `leaf_gen`. Whenever the running
+/// digest enters a level of the other generation it is converted with
+/// [`convert`]. Returns the root and its generation.
+pub fn compute_root(leaf_hash: &Bn254Fr, leaf_gen: HashGen, path: &MixedPath) -> (Bn254Fr, HashGen) {
+    assert_eq!(path.siblings.len(), path.path.len(), "compute_root: malformed path");
+    assert_eq!(path.siblings.len(), path.levels.len(), "compute_root: malformed path");
+
+    let mut cur = leaf_hash.clone();
+    let mut cur_gen = leaf_gen;
+    for ((sib, bit), &level) in path.siblings.iter().zip(path.path.iter()).zip(path.levels.iter()) {
+        cur = convert(&cur, cur_gen, level);
+        cur_gen = level;
+
+        Bn254Fr::assert_bit(bit);
+        let mut l = Bn254Fr::new();
+        let mut r = Bn254Fr::new();
+        mux(&mut l, bit, &cur, sib);
+        mux(&mut r, bit, sib, &cur);
+        cur = node(level, &l, &r);
+    }
+    (cur, cur_gen)
+}
+
+/// Assert that `leaf_hash` is included under `root`, where `root` is a
+/// digest of generation `root_gen` (converted to if the path ends in the
+/// other generation)
+pub fn verify_path(root: &Bn254Fr, root_gen: HashGen, leaf_hash: &Bn254Fr, leaf_gen: HashGen, path: &MixedPath) {
+    let (computed, computed_gen) = compute_root(leaf_hash, leaf_gen, path);
+    Bn254Fr::assert_equal(&convert(&computed, computed_gen, root_gen), root);
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Oblivious Priority Queue for Ligetron
+//!
+//! A constrained min-priority queue over private `(key, value)` entries.
+//! The number of entries is public (it follows the program's control flow),
+//! the keys, values and their order are not.
+//!
+//! Entries are kept sorted by key. `insert` compares the new key against
+//! every entry and rebuilds the array with multiplexers (O(n) comparisons),
+//! so the insertion position stays hidden; `extract_min` pops the front,
+//! which is pure rewiring. Entries with equal keys are extracted in
+//! insertion order.
+
+use crate::bn254fr::{Bn254Fr, mux};
+use crate::cmp::lt_checked;
+
+/// Bit width of priority keys
+pub const KEY_BITS: usize = 64;
+
+/// A queue entry
+#[derive(Clone)]
+pub struct HeapEntry {
+    pub key: Bn254Fr,
+    pub value: Bn254Fr,
+}
+
+/// Oblivious min-priority queue
+#[derive(Clone, Default)]
+pub struct MinHeap {
+    entries: Vec<HeapEntry>,
+}
+
+impl MinHeap {
+    pub fn new() -> Self {
+        MinHeap { entries: Vec::new() }
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Insert an entry, range checking its key to [`KEY_BITS`] bits
+    pub fn insert(&mut self, key: &Bn254Fr, value: &Bn254Fr) {
+        let _ = key.to_bits(KEY_BITS);
+        let n = self.entries.len();
+
+        // g_i = (key < entries[i].key), monotone 0..0 1..1 since entries are
+        // sorted; g_n = 1. The new entry lands where g first becomes 1.
+        let mut g: Vec<Bn254Fr> = self.entries.iter().map(|e| lt_checked(key, &e.key, KEY_BITS)).collect();
+        g.push(Bn254Fr::from_u32(1));
+
+        let mut out = Vec::with_capacity(n + 1);
+        for i in 0..=n {
+            // shifted = g_{i-1} ? entries[i-1] : new entry
+            let (sk, sv) = if i == 0 {
+                (key.clone(), value.clone())
+            } else {
+                let mut sk = Bn254Fr::new();
+                let mut sv = Bn254Fr::new();
+                mux(&mut sk, &g[i - 1], key, &self.entries[i - 1].key);
+                mux(&mut sv, &g[i - 1], value, &self.entries[i - 1].value);
+                (sk, sv)
+            };
+
+            // out_i = g_i ? shifted : entries[i]
+            if i == n {
+                out.push(HeapEntry { key: sk, value: sv });
+            } else {
+                let mut k = Bn254Fr::new();
+                let mut v = Bn254Fr::new();
+                mux(&mut k, &g[i], &self.entries[i].key, &sk);
+                mux(&mut v, &g[i], &self.entries[i].value, &sv);
+                out.push(HeapEntry { key: k, value: v });
+            }
+        }
+        self.entries = out;
+    }
+
+    /// Entry with the smallest key, without removing it
+    pub fn peek_min(&self) -> &HeapEntry {
+        assert!(!self.entries.is_empty(), "MinHeap::peek_min: empty queue");
+        &self.entries[0]
+    }
+
+    /// Remove and return the entry with the smallest key
+    pub fn extract_min(&mut self) -> HeapEntry {
+        assert!(!self.entries.is_empty(), "MinHeap::extract_min: empty queue");
+        self.entries.remove(0)
+    }
+}
@@ -0,0 +1,218 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Inner-Product Argument Verification for Ligetron
+//!
+//! Verifies Bulletproofs inner-product arguments over Grumpkin. A proof
+//! shows knowledge of vectors `a, b` of length `n = 2^k` with
+//!
+//! `P = <a, G> + <b, H> + <a, b>·U`
+//!
+//! and consists of the round commitments `L_j, R_j` and the final scalars
+//! `a, b`. The verifier derives challenges `u_j` and checks
+//!
+//! `P + sum_j (u_j^2·L_j + u_j^-2·R_j) == a·<s, G> + b·<s^-1, H> + a·b·U`
+//!
+//! where `s_i = prod_j u_j^(+-1)`, the sign following bit `j` of `i` (most
+//! significant bit first). Scalars live in the Grumpkin scalar field, which
+//! is the BN254 base field, so they are emulated with [`Bn254Fq`]; point
+//! coordinates are native.
+//!
+//! Challenges come from a Poseidon2 transcript over the affine coordinates
+//! of `P`, `L_j` and `R_j` ([`IpaVerifier::verify`]). Proofs produced with
+//! another transcript are checked with [`IpaVerifier::verify_with_challenges`]
+//! after recomputing that transcript in the circuit.
+//!
+//! Baby Jubjub is not supported: its scalar field needs arithmetic modulo
+//! the subgroup order, which the SDK does not emulate.
+
+use crate::bn254fq::Bn254Fq;
+use crate::bn254fr::Bn254Fr;
+use crate::grumpkin::{GrumpkinPoint, hash_to_curve_with_domain};
+use crate::poseidon2::poseidon2_hash;
+
+/// Domain separation tag for generators and transcripts
+pub const IPA_DOMAIN: &[u8] = b"ligetron.ipa.v1";
+
+/// Index of the inner-product generator `U`
+const U_INDEX: u64 = u64::MAX;
+
+/// Multi-scalar multiplication with shared doublings over decomposed scalars
+fn msm(points: &[GrumpkinPoint], scalars: &[Vec<Bn254Fr>]) -> GrumpkinPoint {
+    let bits = scalars.iter().map(|s| s.len()).max().unwrap_or(0);
+    let identity = GrumpkinPoint::identity();
+
+    let mut acc = GrumpkinPoint::identity();
+    for b in (0..bits).rev() {
+        acc = acc.double();
+        for (p, sbits) in points.iter().zip(scalars.iter()) {
+            if b < sbits.len() {
+                let t = GrumpkinPoint::mux(&sbits[b], &identity, p);
+                acc = GrumpkinPoint::add(&acc, &t);
+            }
+        }
+    }
+    acc
+}
+
+/// Generators `G`, `H` and `U` of an inner-product argument
+#[derive(Clone)]
+pub struct IpaGenerators {
+    pub g: Vec<GrumpkinPoint>,
+    pub h: Vec<GrumpkinPoint>,
+    pub u: GrumpkinPoint,
+}
+
+impl IpaGenerators {
+    /// Derive generators for vectors of length `n` (a power of two)
+    pub fn new(n: usize) -> Self {
+        assert!(n.is_power_of_two(), "IpaGenerators::new: length must be a power of two");
+        let g = (0..n as u64).map(|i| hash_to_curve_with_domain(IPA_DOMAIN, 2 * i)).collect();
+        let h = (0..n as u64).map(|i| hash_to_curve_with_domain(IPA_DOMAIN, 2 * i + 1)).collect();
+        let u = hash_to_curve_with_domain(IPA_DOMAIN, U_INDEX);
+        IpaGenerators { g, h, u }
+    }
+
+    /// Use externally derived generators
+    pub fn from_points(g: Vec<GrumpkinPoint>, h: Vec<GrumpkinPoint>, u: GrumpkinPoint) -> Self {
+        assert_eq!(g.len(), h.len(), "IpaGenerators::from_points: length mismatch");
+        assert!(g.len().is_power_of_two(), "IpaGenerators::from_points: length must be a power of two");
+        IpaGenerators { g, h, u }
+    }
+
+    /// Vector length
+    pub fn len(&self) -> usize {
+        self.g.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.g.is_empty()
+    }
+}
+
+/// An inner-product argument; points are affine (z = 1)
+#[derive(Clone)]
+pub struct IpaProof {
+    pub l: Vec<GrumpkinPoint>,
+    pub r: Vec<GrumpkinPoint>,
+    pub a: Bn254Fq,
+    pub b: Bn254Fq,
+}
+
+impl IpaProof {
+    /// Build a proof from affine round commitments, checking they lie on
+    /// the curve
+    pub fn new(l: &[(Bn254Fr, Bn254Fr)], r: &[(Bn254Fr, Bn254Fr)], a: Bn254Fq, b: Bn254Fq) -> Self {
+        assert_eq!(l.len(), r.len(), "IpaProof::new: round count mismatch");
+        let point = |(x, y): &(Bn254Fr, Bn254Fr)| GrumpkinPoint::from_affine_checked(x.clone(), y.clone());
+        IpaProof {
+            l: l.iter().map(point).collect(),
+            r: r.iter().map(point).collect(),
+            a,
+            b,
+        }
+    }
+
+    /// Number of folding rounds
+    pub fn rounds(&self) -> usize {
+        self.l.len()
+    }
+}
+
+/// Verifier for inner-product arguments over fixed generators
+pub struct IpaVerifier {
+    gens: IpaGenerators,
+}
+
+impl IpaVerifier {
+    pub fn new(gens: IpaGenerators) -> Self {
+        IpaVerifier { gens }
+    }
+
+    pub fn generators(&self) -> &IpaGenerators {
+        &self.gens
+    }
+
+    /// Fiat-Shamir challenges for `proof` against the affine commitment `p`
+    pub fn challenges(&self, p: &GrumpkinPoint, proof: &IpaProof) -> Vec<Bn254Fq> {
+        let mut tag = Bn254Fr::new();
+        tag.set_bytes_big(IPA_DOMAIN);
+        let mut state = poseidon2_hash(&[
+            tag,
+            Bn254Fr::from_u64(self.gens.len() as u64),
+            p.x.clone(),
+            p.y.clone(),
+        ]);
+
+        let mut out = Vec::with_capacity(proof.rounds());
+        for (l, r) in proof.l.iter().zip(proof.r.iter()) {
+            state = poseidon2_hash(&[state, l.x.clone(), l.y.clone(), r.x.clone(), r.y.clone()]);
+            out.push(Bn254Fq::from_fr_checked(&state));
+        }
+        out
+    }
+
+    /// Assert that `proof` opens the affine commitment `p`
+    pub fn verify(&self, p: &GrumpkinPoint, proof: &IpaProof) {
+        let u = self.challenges(p, proof);
+        self.verify_with_challenges(p, proof, &u);
+    }
+
+    /// Assert that `proof` opens `p` under caller-derived challenges.
+    /// The challenges must be bound to `p` and the proof by the caller.
+    pub fn verify_with_challenges(&self, p: &GrumpkinPoint, proof: &IpaProof, u: &[Bn254Fq]) {
+        let n = self.gens.len();
+        let k = proof.rounds();
+        assert_eq!(1usize << k, n, "IpaVerifier: proof has the wrong number of rounds");
+        assert_eq!(u.len(), k, "IpaVerifier: challenge count mismatch");
+
+        let u_inv: Vec<Bn254Fq> = u.iter().map(Bn254Fq::inv).collect();
+
+        // s_i = prod_j u_j^(+-1), built most significant round first
+        let mut s = vec![Bn254Fq::one()];
+        for (uj, uj_inv) in u.iter().zip(u_inv.iter()) {
+            s = s.iter()
+                .flat_map(|x| [x.mul(uj_inv), x.mul(uj)])
+                .collect();
+        }
+
+        // Left: P + sum_j u_j^2·L_j + u_j^-2·R_j
+        let mut lhs_points = Vec::with_capacity(2 * k);
+        let mut lhs_scalars = Vec::with_capacity(2 * k);
+        for j in 0..k {
+            lhs_points.push(proof.l[j].clone());
+            lhs_scalars.push(u[j].square().to_bits());
+            lhs_points.push(proof.r[j].clone());
+            lhs_scalars.push(u_inv[j].square().to_bits());
+        }
+        let lhs = GrumpkinPoint::add(p, &msm(&lhs_points, &lhs_scalars));
+
+        // Right: a·<s, G> + b·<s^-1, H> + a·b·U, where s_i^-1 = s_{n-1-i}
+        let mut rhs_points = Vec::with_capacity(2 * n + 1);
+        let mut rhs_scalars = Vec::with_capacity(2 * n + 1);
+        for i in 0..n {
+            rhs_points.push(self.gens.g[i].clone());
+            rhs_scalars.push(proof.a.mul(&s[i]).to_bits());
+            rhs_points.push(self.gens.h[i].clone());
+            rhs_scalars.push(proof.b.mul(&s[n - 1 - i]).to_bits());
+        }
+        rhs_points.push(self.gens.u.clone());
+        rhs_scalars.push(proof.a.mul(&proof.b).to_bits());
+        let rhs = msm(&rhs_points, &rhs_scalars);
+
+        GrumpkinPoint::assert_equal(&lhs, &rhs);
+    }
+}
@@ -0,0 +1,146 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! HKDF and PBKDF2 Key Derivation for Ligetron
+//!
+//! HMAC-SHA-256 based key derivation, so that key schedules of real
+//! protocols (TLS exporters, encrypted backups, ...) can be reproduced
+//! inside a proof:
+//!
+//! - [`hkdf_extract`] / [`hkdf_expand`] / [`hkdf`]: RFC 5869
+//! - [`pbkdf2_hmac_sha256`]: RFC 8018
+//!
+//! Like [`sha2_256`], everything runs as plain wasm over the secret bytes
+//! and is proven as part of the program's execution.
+//!
+//! PBKDF2 is deliberately slow: every iteration costs two SHA-256
+//! compressions per 32 output bytes, so iteration counts chosen for
+//! password storage (hundreds of thousands) make for very large proofs.
+//! Use [`pbkdf2_cost`] to budget them up front.
+//!
+//! [`sha2_256`]: crate::sha2::sha2_256
+
+use crate::sha2::Sha256Context;
+
+/// SHA-256 digest length in bytes
+pub const HASH_LEN: usize = 32;
+
+/// SHA-256 block length in bytes
+const BLOCK_LEN: usize = 64;
+
+/// HMAC-SHA-256 keyed once, with the padded key blocks already absorbed
+#[derive(Clone)]
+struct HmacKey {
+    inner: Sha256Context,
+    outer: Sha256Context,
+}
+
+impl HmacKey {
+    fn new(key: &[u8]) -> Self {
+        let mut block = [0u8; BLOCK_LEN];
+        if key.len() > BLOCK_LEN {
+            let mut ctx = Sha256Context::new();
+            ctx.update(key);
+            block[..HASH_LEN].copy_from_slice(&ctx.finalize());
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = Sha256Context::new();
+        let mut outer = Sha256Context::new();
+        inner.update(&block.map(|b| b ^ 0x36));
+        outer.update(&block.map(|b| b ^ 0x5c));
+        Self { inner, outer }
+    }
+
+    fn mac(&self, parts: &[&[u8]]) -> [u8; HASH_LEN] {
+        let mut inner = self.inner.clone();
+        for p in parts {
+            inner.update(p);
+        }
+        let mut outer = self.outer.clone();
+        outer.update(&inner.finalize());
+        outer.finalize()
+    }
+}
+
+/// HMAC-SHA-256 (RFC 2104) of `msg` under `key`
+pub fn hmac_sha256(key: &[u8], msg: &[u8]) -> [u8; HASH_LEN] {
+    HmacKey::new(key).mac(&[msg])
+}
+
+// ============= HKDF =============
+
+/// HKDF-Extract: pseudorandom key from input keying material `ikm`.
+///
+/// An empty `salt` is equivalent to `HASH_LEN` zero bytes.
+pub fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> [u8; HASH_LEN] {
+    hmac_sha256(salt, ikm)
+}
+
+/// HKDF-Expand: `len` bytes of output keying material bound to `info`
+pub fn hkdf_expand(prk: &[u8], info: &[u8], len: usize) -> Vec<u8> {
+    assert!(len <= 255 * HASH_LEN, "hkdf_expand: output longer than 255 blocks");
+
+    let key = HmacKey::new(prk);
+    let mut okm = Vec::with_capacity(len);
+    let mut t: Vec<u8> = Vec::new();
+    for counter in 1..=len.div_ceil(HASH_LEN) as u8 {
+        t = key.mac(&[&t, info, &[counter]]).to_vec();
+        okm.extend_from_slice(&t);
+    }
+    okm.truncate(len);
+    okm
+}
+
+/// HKDF extract-then-expand
+pub fn hkdf(salt: &[u8], ikm: &[u8], info: &[u8], len: usize) -> Vec<u8> {
+    hkdf_expand(&hkdf_extract(salt, ikm), info, len)
+}
+
+// ============= PBKDF2 =============
+
+/// Approximate number of SHA-256 compressions PBKDF2 spends on `len`
+/// output bytes
+pub fn pbkdf2_cost(iterations: u32, len: usize) -> u64 {
+    2 * iterations as u64 * len.div_ceil(HASH_LEN) as u64
+}
+
+/// PBKDF2-HMAC-SHA-256 with a configurable iteration count.
+///
+/// The cost grows linearly with `iterations` (see [`pbkdf2_cost`]); the
+/// counts recommended for password storage, in the hundreds of thousands,
+/// produce very large proofs.
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, len: usize) -> Vec<u8> {
+    assert!(iterations > 0, "pbkdf2_hmac_sha256: zero iterations");
+    assert!(len > 0, "pbkdf2_hmac_sha256: empty output");
+
+    let key = HmacKey::new(password);
+    let mut dk = Vec::with_capacity(len);
+    for block in 1..=len.div_ceil(HASH_LEN) as u32 {
+        let mut u = key.mac(&[salt, &block.to_be_bytes()]);
+        let mut t = u;
+        for _ in 1..iterations {
+            u = key.mac(&[&u]);
+            for (ti, ui) in t.iter_mut().zip(&u) {
+                *ti ^= ui;
+            }
+        }
+        dk.extend_from_slice(&t);
+    }
+    dk.truncate(len);
+    dk
+}
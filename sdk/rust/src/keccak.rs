@@ -0,0 +1,253 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Keccak-256 for Ligetron
+//!
+//! Ethereum's Keccak-256: the `Keccak-f[1600]` sponge with rate 136 bytes
+//! and the original `0x01 .. 0x80` padding (not the SHA-3 `0x06` padding).
+//!
+//! - [`keccak256`] hashes bytes in plain wasm, e.g. to compute a public
+//!   digest.
+//! - [`Keccak256Gadget`] runs the permutation over constrained
+//!   [`Word64`] lanes, so a digest can be computed from private bytes and
+//!   bound to a public value inside the circuit.
+//!
+//! Lanes are indexed `x + 5y` and loaded little-endian. Rotations and the
+//! pi permutation are rewiring; theta and chi cost one quadratic
+//! constraint per bit per XOR/AND, and iota flips constant bits for free.
+
+use crate::bn254fr::Bn254Fr;
+use crate::word::{bit_not, Word64};
+
+/// Sponge rate in bytes
+pub const RATE: usize = 136;
+
+/// Round constants for iota
+const RC: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+/// Rho rotation offsets, indexed `x + 5y`
+const ROT: [u32; 25] = [
+     0,  1, 62, 28, 27,
+    36, 44,  6, 55, 20,
+     3, 10, 43, 25, 39,
+    41, 45, 15, 21,  8,
+    18,  2, 61, 56, 14,
+];
+
+// ============= Plain Keccak-256 =============
+
+/// `Keccak-f[1600]` permutation
+pub fn keccak_f1600(a: &mut [u64; 25]) {
+    for rc in RC {
+        // theta
+        let c: Vec<u64> = (0..5).map(|x| a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20]).collect();
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                a[x + 5 * y] ^= d;
+            }
+        }
+
+        // rho and pi
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotate_left(ROT[x + 5 * y]);
+            }
+        }
+
+        // chi
+        for y in 0..5 {
+            for x in 0..5 {
+                a[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // iota
+        a[0] ^= rc;
+    }
+}
+
+/// Compute the Keccak-256 hash of input data
+pub fn keccak256(input: &[u8]) -> [u8; 32] {
+    let mut padded = input.to_vec();
+    padded.push(0x01);
+    padded.resize(padded.len().div_ceil(RATE) * RATE, 0);
+    *padded.last_mut().unwrap() |= 0x80;
+
+    let mut state = [0u64; 25];
+    for block in padded.chunks(RATE) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        keccak_f1600(&mut state);
+    }
+
+    let mut out = [0u8; 32];
+    for (chunk, lane) in out.chunks_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+    out
+}
+
+// ============= Constrained Keccak-256 =============
+
+/// out = w ^ c for a constant `c`: set bits of `c` negate, others pass
+fn xor_const(w: &Word64, c: u64) -> Word64 {
+    let bits = w.bits().iter().enumerate()
+        .map(|(i, b)| if (c >> i) & 1 == 1 { bit_not(b) } else { b.clone() })
+        .collect();
+    Word64::from_bits(bits)
+}
+
+/// `Keccak-f[1600]` permutation over constrained lanes, indexed `x + 5y`
+pub fn keccak_f1600_checked(a: &mut [Word64]) {
+    assert_eq!(a.len(), 25, "keccak_f1600_checked: state must have 25 lanes");
+
+    for rc in RC {
+        // theta
+        let c: Vec<Word64> = (0..5)
+            .map(|x| a[x].xor(&a[x + 5]).xor(&a[x + 10]).xor(&a[x + 15]).xor(&a[x + 20]))
+            .collect();
+        for x in 0..5 {
+            let d = c[(x + 4) % 5].xor(&c[(x + 1) % 5].rotl(1));
+            for y in 0..5 {
+                a[x + 5 * y] = a[x + 5 * y].xor(&d);
+            }
+        }
+
+        // rho and pi
+        let mut b = a.to_vec();
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotl(ROT[x + 5 * y] as usize);
+            }
+        }
+
+        // chi
+        for y in 0..5 {
+            for x in 0..5 {
+                let t = b[(x + 1) % 5 + 5 * y].andn(&b[(x + 2) % 5 + 5 * y]);
+                a[x + 5 * y] = b[x + 5 * y].xor(&t);
+            }
+        }
+
+        // iota
+        a[0] = xor_const(&a[0], rc);
+    }
+}
+
+/// Constant byte as 8 bits, least significant first
+fn const_byte_bits(b: u8) -> Vec<Bn254Fr> {
+    (0..8).map(|i| Bn254Fr::from_u32(((b >> i) & 1) as u32)).collect()
+}
+
+/// Incremental Keccak-256 over constrained bytes.
+///
+/// Each input byte is a `Bn254Fr` range checked to 8 bits. The message
+/// length is public structure; the byte values may be private. The digest
+/// is returned as 32 constrained byte field elements, in the same order as
+/// [`keccak256`].
+#[derive(Clone)]
+pub struct Keccak256Gadget {
+    state: Vec<Word64>,
+    /// Pending bytes as bits, least significant first
+    buf: Vec<Vec<Bn254Fr>>,
+}
+
+impl Default for Keccak256Gadget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Keccak256Gadget {
+    pub fn new() -> Self {
+        Keccak256Gadget {
+            state: (0..25).map(|_| Word64::from_u64(0)).collect(),
+            buf: Vec::with_capacity(RATE),
+        }
+    }
+
+    /// Absorb bytes given as field elements, range checking each to 8 bits
+    pub fn update(&mut self, bytes: &[Bn254Fr]) {
+        for b in bytes {
+            self.absorb_bits(b.to_bits(8));
+        }
+    }
+
+    /// Absorb wasm bytes (private if they come from private inputs)
+    pub fn update_bytes(&mut self, data: &[u8]) {
+        let bytes: Vec<Bn254Fr> = data.iter().map(|&b| Bn254Fr::from_u32(b as u32)).collect();
+        self.update(&bytes);
+    }
+
+    fn absorb_bits(&mut self, bits: Vec<Bn254Fr>) {
+        self.buf.push(bits);
+        if self.buf.len() == RATE {
+            let block = std::mem::take(&mut self.buf);
+            for (lane, bytes) in self.state.iter_mut().zip(block.chunks(8)) {
+                // Little-endian load: the lane's low bits come from the first byte
+                let w = Word64::from_bits(bytes.iter().flatten().cloned().collect());
+                *lane = lane.xor(&w);
+            }
+            keccak_f1600_checked(&mut self.state);
+        }
+    }
+
+    /// Pad and return the first four lanes of the squeezed state
+    pub fn finalize_words(mut self) -> Vec<Word64> {
+        // Padding is public: its bits are constants
+        let pad_len = RATE - self.buf.len();
+        for i in 0..pad_len {
+            let mut b = if i == 0 { 0x01 } else { 0x00 };
+            if i == pad_len - 1 {
+                b |= 0x80;
+            }
+            self.absorb_bits(const_byte_bits(b));
+        }
+        self.state.truncate(4);
+        self.state
+    }
+
+    /// Pad and return the digest as 32 constrained bytes
+    pub fn finalize(self) -> Vec<Bn254Fr> {
+        self.finalize_words().iter()
+            .flat_map(|w| (0..8).map(move |j| w.extract(8 * j, 8)))
+            .collect()
+    }
+
+    /// Pad and assert that the digest equals the public `expected` bytes
+    pub fn assert_digest(self, expected: &[u8; 32]) {
+        for (d, &e) in self.finalize().iter().zip(expected.iter()) {
+            d.assert_equal_const(e as u64);
+        }
+    }
+
+    /// One-shot constrained Keccak-256 of bytes given as field elements
+    pub fn digest(bytes: &[Bn254Fr]) -> Vec<Bn254Fr> {
+        let mut g = Keccak256Gadget::new();
+        g.update(bytes);
+        g.finalize()
+    }
+}
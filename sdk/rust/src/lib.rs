@@ -5,27 +5,139 @@
 //! ## Modules
 //!
 //! - [`api`] - Core API functions
+//! - [`accumulator`] - Fixed-depth Merkle accumulator with constant-size witnesses
+//! - [`anemoi`] - Anemoi hash function and Jive compression
+//! - [`backend`] - Host field backend abstraction
+//! - [`bit`] - Boolean field elements and logic gadgets
+//! - [`biguint`] - Arbitrary-width unsigned integers (384-bit, 2048-bit, ...)
 //! - [`sha2`] - SHA-256 hash function
+//! - [`checksum`] - CRC32 and Adler-32 checksum gadgets
+//! - [`bloom`] - Committed Bloom filter membership checks
+//! - [`cache`] - Memoization of constant subcircuits (generators, fixed tables)
+//! - [`bn254fq`] - Emulated BN254 base field arithmetic
 //! - [`bn254fr`] - BN254 scalar field arithmetic
 //! - [`vbn254fr`] - Vectorized BN254 operations
 //! - [`uint256`] - 256-bit unsigned integer arithmetic
+//! - [`fsm`] - Public finite state machines over private input sequences
+//! - [`grumpkin`] - Grumpkin curve operations and generators
+//! - [`field`] - Generic field trait over the scalar and vector backends
+//! - [`heap`] - Oblivious min-priority queue
+//! - [`hash_bridge`] - Domain-tagged conversion between Poseidon and Poseidon2 digests for mixed trees
+//! - [`ipa`] - Bulletproofs inner-product argument verification over Grumpkin
+//! - [`kdf`] - HKDF and PBKDF2 key derivation over HMAC-SHA-256
+//! - [`keccak`] - Keccak-256 hash function and constrained gadget
+//! - [`merkle`] - Domain-separated Poseidon2 Merkle paths and hiding leaves
+//! - [`merkle_sum`] - Merkle sum tree with sum-carrying inclusion proofs
+//! - [`mmr`] - Poseidon2 Merkle mountain ranges for append-only logs
+//! - [`otp`] - HOTP/TOTP one-time password verification for committed secrets
+//! - [`nullifier`] - Nullifier derivation and spent-nullifier registry
+//! - [`oblivious`] - Constrained oblivious selection, min and max for integers
+//! - [`parse`] - Constrained decimal/hex string parsing
+//! - [`pedersen`] - Pedersen vector commitments over Baby Jubjub
+//! - [`policy`] - Weighted-score policy checks with fixed-point weights
 //! - [`poseidon`] - Poseidon hash function (t=3, t=5)
 //! - [`poseidon2`] - Poseidon2 hash function (t=2)
 //! - [`babyjubjub`] - Baby Jubjub elliptic curve operations
+//! - [`ecdh`] - Baby Jubjub Diffie-Hellman key agreement
 //! - [`eddsa`] - Edwards-curve Digital Signature Algorithm
+//! - [`enc`] - Poseidon duplex authenticated encryption
+//! - [`encoding`] - Constrained bytes32 and hex encodings of field elements
+//! - [`cmp`] - Constrained comparisons with selectable strategies
+//! - [`commitment`] - Hiding Poseidon2 commitments and commit-reveal helpers
+//! - [`credentials`] - Issuer-signed attribute credentials and predicates
+//! - [`digest`] - Constrained reduction of hash digests to scalars
+//! - [`der`] - Constrained ASN.1 DER parsing for signatures and certificates
+//! - [`debug`] - Labelled dumps of field elements, integers, points and vector lanes
+//! - [`range`] - Range checks by bit decomposition
+//! - [`scan`] - Constrained prefix sums and segmented sums
+//! - [`secp256k1`] - secp256k1 arithmetic and ECDSA verification over emulated fields
+//! - [`seal`] - Encryption to a Baby Jubjub public key (ECDH KEM + Poseidon duplex DEM)
+//! - [`shamir`] - Shamir secret sharing and reconstruction checks
+//! - [`solvency`] - Proof of solvency over a Merkle sum tree
+//! - [`srp`] - SRP-6a password verifier proofs
+//! - [`sim`] - Native simulation of the host modules for tests (feature `native-sim`)
+//! - [`shuffle`] - Verifiable re-encryption shuffles of ElGamal ciphertexts
+//! - [`stats`] - Private mean, variance, order statistics and histograms
+//! - [`string`] - Committed private strings and substring proofs
+//! - [`time`] - Constrained timestamp and calendar date arithmetic
+//! - [`transcript`] - In-guest Poseidon duplex Fiat-Shamir transcripts
+//! - [`verifier_checks`] - Constrained row checks of the Ligetron verifier (not a proof verifier)
+//! - [`voting`] - Ballot validity, nullifiers and encrypted tallies
+//! - [`witness`] - Witness serialization and replay across runs
+//! - [`word`] - Fixed-width word bit-manipulation gadgets
+//! - [`zkemail`] - DKIM verification for zk-email proofs
+//! - [`zkvm`] - Constrained interpreter for a tiny register machine (feature `zkvm`)
 
 pub mod api;
+pub mod accumulator;
+pub mod anemoi;
+pub mod backend;
 pub mod babyjubjub;
+pub mod biguint;
+pub mod bit;
+pub mod bloom;
+pub mod cache;
+pub mod bn254fq;
 pub mod bn254fr;
+pub mod checksum;
+pub mod cmp;
+pub mod commitment;
+pub mod credentials;
+pub mod der;
+pub mod debug;
+pub mod digest;
+pub mod ecdh;
 pub mod eddsa;
+pub mod enc;
+pub mod encoding;
+pub mod field;
+pub mod fsm;
+pub mod grumpkin;
+pub mod heap;
+pub mod hash_bridge;
+pub mod ipa;
+pub mod kdf;
+pub mod keccak;
+pub mod merkle;
+pub mod merkle_sum;
+pub mod mmr;
+pub mod otp;
+pub mod nullifier;
+pub mod oblivious;
+pub mod parse;
+pub mod pedersen;
+pub mod policy;
 pub mod poseidon;
 pub mod poseidon2;
+pub mod range;
 pub mod sha2;
+pub mod scan;
+pub mod secp256k1;
+pub mod seal;
+pub mod shamir;
+pub mod solvency;
+pub mod srp;
+#[cfg(feature = "native-sim")]
+pub mod sim;
+pub mod shuffle;
+pub mod stats;
+pub mod string;
+pub mod time;
+pub mod transcript;
 pub mod uint256;
 pub mod vbn254fr;
+pub mod verifier_checks;
+pub mod voting;
+pub mod witness;
+pub mod word;
+pub mod zkemail;
+#[cfg(feature = "zkvm")]
+pub mod zkvm;
 // private modules
+mod anemoi_constant;
 mod poseidon2_constant;
 mod poseidon_constant;
+mod poseidon_gen;
 
 // Re-export core types and functions for convenience
 pub use api::*;
@@ -0,0 +1,268 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Poseidon2 Merkle Trees for Ligetron
+//!
+//! Leaves and nodes are hashed with distinct domain tags:
+//!
+//! - leaf: `Poseidon2(LEAF_TAG, value)`
+//! - hiding leaf: `Poseidon2(HIDING_LEAF_TAG, blinding, value)`
+//! - node: `Poseidon2(NODE_TAG, left, right)`
+//!
+//! A plain leaf reveals equality: two proofs over the same value share the
+//! same leaf hash. A hiding leaf mixes in a fresh random blinding per
+//! insertion, so equal values yield unrelated leaves and a membership proof
+//! says nothing about other leaves holding the same value.
+//!
+//! [`MerkleTree`] builds a whole tree with constraints and hands out
+//! paths that [`verify_inclusion`] checks against a root. Trees over the
+//! older Poseidon t=3 node hash are covered by [`crate::hash_bridge`].
+//!
+//! [`build_packed`] hashes whole tree levels on the vector unit, one
+//! subtree per [`VBn254Fr`] lane.
+
+use crate::bn254fr::{Bn254Fr, mux};
+use crate::poseidon2::{poseidon2_hash, vposeidon2_hash};
+use crate::vbn254fr::{VBn254Fr, lane_count};
+
+/// Domain separation tag for plain leaves
+pub const LEAF_DOMAIN: &[u8] = b"ligetron.merkle.leaf.v1";
+
+/// Domain separation tag for hiding (blinded) leaves
+pub const HIDING_LEAF_DOMAIN: &[u8] = b"ligetron.merkle.hiding.v1";
+
+/// Domain separation tag for internal nodes
+pub const NODE_DOMAIN: &[u8] = b"ligetron.merkle.node.v1";
+
+fn tag(domain: &[u8]) -> Bn254Fr {
+    let mut t = Bn254Fr::new();
+    t.set_bytes_big(domain);
+    t
+}
+
+/// Hash of a plain leaf holding `value`
+pub fn leaf(value: &Bn254Fr) -> Bn254Fr {
+    poseidon2_hash(&[tag(LEAF_DOMAIN), value.clone()])
+}
+
+/// Hash of a hiding leaf holding `value` under the random `blinding`
+pub fn hiding_leaf(value: &Bn254Fr, blinding: &Bn254Fr) -> Bn254Fr {
+    poseidon2_hash(&[tag(HIDING_LEAF_DOMAIN), blinding.clone(), value.clone()])
+}
+
+/// Hash of an internal node
+pub fn node(left: &Bn254Fr, right: &Bn254Fr) -> Bn254Fr {
+    poseidon2_hash(&[tag(NODE_DOMAIN), left.clone(), right.clone()])
+}
+
+/// Authentication path for one leaf
+#[derive(Clone)]
+pub struct MerklePath {
+    /// Sibling hashes from the leaf level up
+    pub siblings: Vec<Bn254Fr>,
+    /// Direction bits from the leaf level up (1 = the node is a right child)
+    pub path: Vec<Bn254Fr>,
+}
+
+impl MerklePath {
+    /// Path for leaf `index` given its siblings from the leaf level up
+    pub fn new(siblings: Vec<Bn254Fr>, index: u64) -> Self {
+        let path = (0..siblings.len())
+            .map(|i| Bn254Fr::from_u32(((index >> i) & 1) as u32))
+            .collect();
+        MerklePath { siblings, path }
+    }
+
+    /// Tree depth
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+}
+
+/// Recompute the root from a leaf hash along `path` with constraints.
+/// Path bits are checked to be boolean.
+pub fn compute_root(leaf_hash: &Bn254Fr, path: &MerklePath) -> Bn254Fr {
+    assert_eq!(path.siblings.len(), path.path.len(), "compute_root: malformed path");
+
+    let mut cur = leaf_hash.clone();
+    for (sib, bit) in path.siblings.iter().zip(path.path.iter()) {
+        Bn254Fr::assert_bit(bit);
+        let mut l = Bn254Fr::new();
+        let mut r = Bn254Fr::new();
+        mux(&mut l, bit, &cur, sib);
+        mux(&mut r, bit, sib, &cur);
+        cur = node(&l, &r);
+    }
+    cur
+}
+
+/// Assert that the leaf hash `leaf_hash` is included under `root`
+pub fn verify_path(root: &Bn254Fr, leaf_hash: &Bn254Fr, path: &MerklePath) {
+    Bn254Fr::assert_equal(&compute_root(leaf_hash, path), root);
+}
+
+/// Assert that the hiding leaf for `(value, blinding)` is included under `root`
+pub fn verify_hiding_path(root: &Bn254Fr, value: &Bn254Fr, blinding: &Bn254Fr, path: &MerklePath) {
+    verify_path(root, &hiding_leaf(value, blinding), path);
+}
+
+/// Assert that the plain leaf holding `value` is included under `root`
+pub fn verify_inclusion(root: &Bn254Fr, value: &Bn254Fr, path: &MerklePath) {
+    verify_path(root, &leaf(value), path);
+}
+
+/// Complete binary Merkle tree over leaf hashes.
+///
+/// Nodes are computed with constraints when the tree is built. Trees whose
+/// leaf count is not a power of two are padded with `0` leaf hashes, the
+/// empty slot of [`crate::accumulator`].
+#[derive(Clone)]
+pub struct MerkleTree {
+    /// `levels[0]` holds the leaf hashes, the last level the root
+    levels: Vec<Vec<Bn254Fr>>,
+}
+
+impl MerkleTree {
+    /// Tree over plain leaves holding `values`
+    pub fn new(values: &[Bn254Fr]) -> Self {
+        Self::from_leaf_hashes(values.iter().map(leaf).collect())
+    }
+
+    /// Tree over already hashed leaves (e.g. [`hiding_leaf`]s)
+    pub fn from_leaf_hashes(mut leaves: Vec<Bn254Fr>) -> Self {
+        assert!(!leaves.is_empty(), "MerkleTree: at least one leaf required");
+        leaves.resize(leaves.len().next_power_of_two(), Bn254Fr::from_u32(0));
+
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels.last().unwrap()
+                .chunks(2)
+                .map(|pair| node(&pair[0], &pair[1]))
+                .collect();
+            levels.push(next);
+        }
+        MerkleTree { levels }
+    }
+
+    /// Number of levels above the leaves
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Number of leaf slots, including padding
+    pub fn capacity(&self) -> u64 {
+        self.levels[0].len() as u64
+    }
+
+    pub fn root(&self) -> &Bn254Fr {
+        &self.levels[self.depth()][0]
+    }
+
+    /// Authentication path for the leaf at `index`
+    pub fn path(&self, index: u64) -> MerklePath {
+        assert!(index < self.capacity(), "MerkleTree::path: leaf index out of range");
+        let siblings = self.levels[..self.depth()].iter()
+            .enumerate()
+            .map(|(h, level)| level[((index >> h) ^ 1) as usize].clone())
+            .collect();
+        MerklePath::new(siblings, index)
+    }
+}
+
+// ============= Packed Trees =============
+
+fn vtag(domain: &[u8]) -> VBn254Fr {
+    let mut t = VBn254Fr::new();
+    t.set_bytes_scalar(domain);
+    t
+}
+
+/// Subtrees built by [`build_packed`], one per vector lane
+pub struct PackedForest {
+    /// Lane `i` holds the root of the subtree over leaves
+    /// `[i·2^depth, (i+1)·2^depth)`
+    pub roots: VBn254Fr,
+    /// Depth of each subtree
+    pub depth: usize,
+}
+
+impl PackedForest {
+    /// Assert that the subtree roots equal the public `roots`, one per lane
+    pub fn assert_subtree_roots(&self, roots: &[&str]) {
+        assert_eq!(roots.len(), lane_count(), "PackedForest: need one root per lane");
+        VBn254Fr::assert_equal(&self.roots, &VBn254Fr::from_str(roots));
+    }
+}
+
+/// Build the bottom of a Merkle tree on the vector unit.
+///
+/// `leaves` are 32-byte big-endian values below the field modulus; their
+/// count must be [`lane_count`] times a power of two. Vector `j` of the
+/// leaf level gathers leaf `i·2^depth + j` into lane `i`, so pairing
+/// vectors `2j` and `2j + 1` lane-wise hashes sibling leaves and every lane
+/// builds one contiguous subtree: a whole level of `lane_count` sibling
+/// pairs costs one vector permutation. Leaves and nodes use the same
+/// domain tags as [`leaf`] and [`node`], so each lane root equals the root
+/// the scalar functions compute for that subtree.
+///
+/// The VM has no cross-lane operations, so the top `log2(lane_count)`
+/// levels cannot be hashed here. They are usually public: check the lane
+/// roots with [`PackedForest::assert_subtree_roots`] and combine the same
+/// public values with [`root_of_subtrees`].
+pub fn build_packed(leaves: &[[u8; 32]]) -> PackedForest {
+    let lanes = lane_count();
+    assert!(!leaves.is_empty() && leaves.len().is_multiple_of(lanes),
+            "build_packed: leaf count must be a multiple of the lane count");
+    let per_lane = leaves.len() / lanes;
+    assert!(per_lane.is_power_of_two(), "build_packed: leaves per lane must be a power of two");
+
+    let buffer = leaves.concat();
+    let leaf_tag = vtag(LEAF_DOMAIN);
+    let node_tag = vtag(NODE_DOMAIN);
+
+    let mut level: Vec<VBn254Fr> = (0..per_lane)
+        .map(|j| {
+            let offsets: Vec<u64> = (0..lanes).map(|i| (32 * (i * per_lane + j)) as u64).collect();
+            let mut values = VBn254Fr::new();
+            values.set_from_offsets(&buffer, &offsets, 32);
+            vposeidon2_hash(&[leaf_tag.clone(), values])
+        })
+        .collect();
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| vposeidon2_hash(&[node_tag.clone(), pair[0].clone(), pair[1].clone()]))
+            .collect();
+    }
+
+    PackedForest {
+        roots: level.pop().unwrap(),
+        depth: per_lane.trailing_zeros() as usize,
+    }
+}
+
+/// Root of the tree whose level of subtree roots is `roots`, left to right.
+/// The number of roots must be a power of two.
+pub fn root_of_subtrees(roots: &[Bn254Fr]) -> Bn254Fr {
+    assert!(roots.len().is_power_of_two(), "root_of_subtrees: count must be a power of two");
+
+    let mut level = roots.to_vec();
+    while level.len() > 1 {
+        level = level.chunks(2).map(|pair| node(&pair[0], &pair[1])).collect();
+    }
+    level.pop().unwrap()
+}
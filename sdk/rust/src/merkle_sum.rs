@@ -0,0 +1,171 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Merkle Sum Tree for Ligetron
+//!
+//! Every node commits to the Poseidon2 hash and the sum of its subtree:
+//!
+//! - leaf: `(Poseidon2(id, value), value)`
+//! - node: `(Poseidon2(left.hash, left.sum, right.hash, right.sum), left.sum + right.sum)`
+//!
+//! Inclusion proofs recompute the root from a leaf along a private path,
+//! range checking sibling sums so no subtree can carry a negative amount,
+//! and expose the leaf's contribution to the root sum. This is the standard
+//! structure for proof-of-reserves / liabilities.
+
+use crate::bn254fr::{Bn254Fr, addmod_checked, mux};
+use crate::poseidon2::poseidon2_hash;
+
+/// Bit width of a single leaf value
+pub const VALUE_BITS: usize = 64;
+
+/// Bit width of subtree sums (up to 2^32 leaves)
+pub const SUM_BITS: usize = VALUE_BITS + 32;
+
+/// A Merkle sum tree node
+#[derive(Clone)]
+pub struct SumNode {
+    pub hash: Bn254Fr,
+    pub sum: Bn254Fr,
+}
+
+impl SumNode {
+    /// Leaf committing to `value` owned by `id`
+    pub fn leaf(id: &Bn254Fr, value: &Bn254Fr) -> Self {
+        SumNode {
+            hash: poseidon2_hash(&[id.clone(), value.clone()]),
+            sum: value.clone(),
+        }
+    }
+
+    /// Padding node `(0, 0)`
+    pub fn empty() -> Self {
+        SumNode { hash: Bn254Fr::from_u32(0), sum: Bn254Fr::from_u32(0) }
+    }
+
+    /// Combine two children into their parent
+    pub fn parent(left: &SumNode, right: &SumNode) -> Self {
+        let hash = poseidon2_hash(&[
+            left.hash.clone(), left.sum.clone(),
+            right.hash.clone(), right.sum.clone(),
+        ]);
+        let mut sum = Bn254Fr::new();
+        addmod_checked(&mut sum, &left.sum, &right.sum);
+        SumNode { hash, sum }
+    }
+
+    /// Conditional swap: returns (a, b) if cond = 0, (b, a) if cond = 1
+    fn swap(cond: &Bn254Fr, a: &SumNode, b: &SumNode) -> (SumNode, SumNode) {
+        let mut l = SumNode { hash: Bn254Fr::new(), sum: Bn254Fr::new() };
+        let mut r = SumNode { hash: Bn254Fr::new(), sum: Bn254Fr::new() };
+        mux(&mut l.hash, cond, &a.hash, &b.hash);
+        mux(&mut l.sum, cond, &a.sum, &b.sum);
+        mux(&mut r.hash, cond, &b.hash, &a.hash);
+        mux(&mut r.sum, cond, &b.sum, &a.sum);
+        (l, r)
+    }
+}
+
+/// Inclusion proof for one leaf
+#[derive(Clone)]
+pub struct MerkleSumProof {
+    /// Sibling nodes from the leaf level up
+    pub siblings: Vec<SumNode>,
+    /// Direction bits from the leaf level up (1 = the node is a right child)
+    pub path: Vec<Bn254Fr>,
+}
+
+/// A Merkle sum tree over a power-of-two number of leaves
+pub struct MerkleSumTree {
+    levels: Vec<Vec<SumNode>>,
+}
+
+impl MerkleSumTree {
+    /// Build the tree over `(id, value)` leaves, range checking every value.
+    /// Missing leaves up to the next power of two are empty nodes.
+    pub fn new(ids: &[Bn254Fr], values: &[Bn254Fr]) -> Self {
+        assert_eq!(ids.len(), values.len(), "MerkleSumTree: length mismatch");
+        assert!(!ids.is_empty(), "MerkleSumTree: no leaves");
+        assert!((ids.len() as u64) <= 1u64 << (SUM_BITS - VALUE_BITS),
+                "MerkleSumTree: too many leaves");
+
+        let mut leaves: Vec<SumNode> = ids.iter().zip(values.iter())
+            .map(|(id, v)| {
+                let _ = v.to_bits(VALUE_BITS);
+                SumNode::leaf(id, v)
+            })
+            .collect();
+        leaves.resize_with(ids.len().next_power_of_two(), SumNode::empty);
+
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels.last().unwrap()
+                .chunks(2)
+                .map(|p| SumNode::parent(&p[0], &p[1]))
+                .collect();
+            levels.push(next);
+        }
+        MerkleSumTree { levels }
+    }
+
+    /// Root node (hash and total sum)
+    pub fn root(&self) -> &SumNode {
+        &self.levels.last().unwrap()[0]
+    }
+
+    /// Tree depth (number of levels above the leaves)
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Inclusion proof for leaf `index`
+    pub fn proof(&self, index: usize) -> MerkleSumProof {
+        let mut siblings = Vec::with_capacity(self.depth());
+        let mut path = Vec::with_capacity(self.depth());
+        let mut i = index;
+        for level in &self.levels[..self.depth()] {
+            siblings.push(level[i ^ 1].clone());
+            path.push(Bn254Fr::from_u32((i & 1) as u32));
+            i >>= 1;
+        }
+        MerkleSumProof { siblings, path }
+    }
+}
+
+/// Recompute the root from `leaf` along `proof` with constraints.
+/// Path bits and sibling sums are range checked.
+pub fn compute_root(leaf: &SumNode, proof: &MerkleSumProof) -> SumNode {
+    assert_eq!(proof.siblings.len(), proof.path.len(), "compute_root: malformed proof");
+    let _ = leaf.sum.to_bits(VALUE_BITS);
+
+    let mut cur = leaf.clone();
+    for (sib, bit) in proof.siblings.iter().zip(proof.path.iter()) {
+        Bn254Fr::assert_bit(bit);
+        let _ = sib.sum.to_bits(SUM_BITS);
+        let (l, r) = SumNode::swap(bit, &cur, sib);
+        cur = SumNode::parent(&l, &r);
+    }
+    cur
+}
+
+/// Verify that `leaf` is included under `root` and return the leaf's
+/// contribution to the root sum.
+pub fn verify_inclusion(leaf: &SumNode, proof: &MerkleSumProof, root: &SumNode) -> Bn254Fr {
+    let computed = compute_root(leaf, proof);
+    Bn254Fr::assert_equal(&computed.hash, &root.hash);
+    Bn254Fr::assert_equal(&computed.sum, &root.sum);
+    leaf.sum.clone()
+}
@@ -0,0 +1,178 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Poseidon2 Merkle Mountain Ranges for Ligetron
+//!
+//! An MMR over `n` leaves is a list of perfect Merkle trees ("mountains"),
+//! one for each set bit of `n`, largest first. Appending a leaf merges equal
+//! height mountains, so roots can be extended one leaf at a time and old
+//! leaves keep short inclusion proofs without fixing a tree depth.
+//!
+//! Nodes are hashed with [`merkle::node`]. The peaks are bagged right to
+//! left with the same node hash, and the root binds the leaf count:
+//!
+//! - root: `Poseidon2(MMR_TAG, n, bag(peaks))`
+//!
+//! Leaf indices and the leaf count are public structure: they select which
+//! peak and which path bits a proof uses.
+
+use crate::bn254fr::Bn254Fr;
+use crate::merkle::{self, MerklePath};
+use crate::poseidon2::poseidon2_hash;
+
+/// Domain separation tag for MMR roots
+pub const MMR_DOMAIN: &[u8] = b"ligetron.mmr.root.v1";
+
+fn tag(domain: &[u8]) -> Bn254Fr {
+    let mut t = Bn254Fr::new();
+    t.set_bytes_big(domain);
+    t
+}
+
+/// Heights of the mountains of an MMR with `size` leaves, largest first
+fn peak_heights(size: u64) -> impl Iterator<Item = usize> {
+    (0..64).rev().filter(move |h| (size >> h) & 1 == 1)
+}
+
+/// Root of an MMR with `size` leaves from its peaks, largest first
+pub fn bag_peaks(peaks: &[Bn254Fr], size: u64) -> Bn254Fr {
+    assert_eq!(peaks.len(), size.count_ones() as usize, "bag_peaks: wrong number of peaks");
+
+    let bag = match peaks.split_last() {
+        Some((last, rest)) => rest.iter().rev().fold(last.clone(), |acc, p| merkle::node(p, &acc)),
+        None => Bn254Fr::from_u32(0),
+    };
+    poseidon2_hash(&[tag(MMR_DOMAIN), Bn254Fr::from_u64(size), bag])
+}
+
+/// Inclusion proof for one leaf of an MMR
+#[derive(Clone)]
+pub struct MmrProof {
+    /// Number of leaves in the MMR
+    pub size: u64,
+    /// Position of the leaf
+    pub leaf_index: u64,
+    /// Siblings from the leaf up to its mountain's peak
+    pub siblings: Vec<Bn254Fr>,
+    /// All peaks, largest first
+    pub peaks: Vec<Bn254Fr>,
+}
+
+impl MmrProof {
+    /// Index of the leaf's mountain and the leaf's index within it
+    fn locate(&self) -> (usize, u64) {
+        assert!(self.leaf_index < self.size, "MmrProof: leaf index out of range");
+        let mut start = 0u64;
+        for (i, h) in peak_heights(self.size).enumerate() {
+            let width = 1u64 << h;
+            if self.leaf_index < start + width {
+                assert_eq!(self.siblings.len(), h, "MmrProof: wrong path length");
+                return (i, self.leaf_index - start);
+            }
+            start += width;
+        }
+        unreachable!()
+    }
+}
+
+/// Assert that `leaf_hash` is included under the MMR `root`
+pub fn verify(root: &Bn254Fr, leaf_hash: &Bn254Fr, proof: &MmrProof) {
+    let (peak, local_index) = proof.locate();
+    let path = MerklePath::new(proof.siblings.clone(), local_index);
+    Bn254Fr::assert_equal(&merkle::compute_root(leaf_hash, &path), &proof.peaks[peak]);
+    Bn254Fr::assert_equal(&bag_peaks(&proof.peaks, proof.size), root);
+}
+
+/// Append-only Merkle mountain range.
+///
+/// Keeps every node so that proofs can be produced for any leaf. Nodes are
+/// computed with constraints as leaves are appended.
+#[derive(Clone, Default)]
+pub struct Mmr {
+    /// `levels[h][i]` covers leaves `[i·2^h, (i+1)·2^h)`
+    levels: Vec<Vec<Bn254Fr>>,
+}
+
+impl Mmr {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of leaves
+    pub fn size(&self) -> u64 {
+        self.levels.first().map_or(0, |l| l.len() as u64)
+    }
+
+    /// Append a leaf hash, returning its index
+    pub fn append(&mut self, leaf_hash: &Bn254Fr) -> u64 {
+        let index = self.size();
+        let mut h = 0;
+        let mut cur = leaf_hash.clone();
+        loop {
+            if self.levels.len() == h {
+                self.levels.push(Vec::new());
+            }
+            self.levels[h].push(cur);
+
+            // Merge while the new node completes a pair
+            let level = &self.levels[h];
+            if level.len() % 2 == 1 {
+                break;
+            }
+            cur = merkle::node(&level[level.len() - 2], &level[level.len() - 1]);
+            h += 1;
+        }
+        index
+    }
+
+    /// Peaks, largest mountain first
+    pub fn peaks(&self) -> Vec<Bn254Fr> {
+        let size = self.size();
+        peak_heights(size)
+            .map(|h| self.levels[h][((size >> h) - 1) as usize].clone())
+            .collect()
+    }
+
+    /// Current root
+    pub fn root(&self) -> Bn254Fr {
+        bag_peaks(&self.peaks(), self.size())
+    }
+
+    /// Inclusion proof for the leaf at `leaf_index`
+    pub fn prove(&self, leaf_index: u64) -> MmrProof {
+        let size = self.size();
+        assert!(leaf_index < size, "Mmr::prove: leaf index out of range");
+
+        let mut proof = MmrProof {
+            size,
+            leaf_index,
+            siblings: Vec::new(),
+            peaks: self.peaks(),
+        };
+
+        // Climb until the node is a peak, i.e. has no complete sibling
+        let mut i = leaf_index;
+        for level in &self.levels {
+            let sibling = i ^ 1;
+            if sibling >= level.len() as u64 {
+                break;
+            }
+            proof.siblings.push(level[sibling as usize].clone());
+            i >>= 1;
+        }
+        proof
+    }
+}
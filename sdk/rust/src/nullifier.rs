@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Nullifier Registry for Ligetron
+//!
+//! Spent nullifiers are kept in a sparse Merkle tree of depth `d` built
+//! with the [`merkle`](crate::merkle) hashing convention. The slot of a
+//! nullifier is given by the low `d` bits of its canonical encoding; an
+//! empty slot holds `0` and an occupied slot holds `merkle::leaf(nullifier)`.
+//!
+//! Spending proves, with one authentication path, that the slot is empty
+//! under the old root (non-membership) and returns the root after writing
+//! the nullifier into it (insertion). The siblings are supplied by whoever
+//! maintains the tree off-circuit.
+//!
+//! Nullifiers are derived with a domain-separated Poseidon2 PRF keyed by
+//! the owner's secret, `Poseidon2(NULLIFIER_TAG, secret, scope)`; the SDK
+//! has no separate PRF module.
+//!
+//! Two distinct nullifiers sharing their low `d` bits map to the same slot,
+//! so the second one cannot be spent. This only affects liveness; choose
+//! `d` so that collisions are negligible for the expected number of spends
+//! ([`DEFAULT_DEPTH`] gives about a 2^-25 chance after 2^20 spends).
+
+use crate::bn254fr::Bn254Fr;
+use crate::merkle::{self, MerklePath};
+use crate::poseidon2::poseidon2_hash;
+
+/// Domain separation tag for nullifier derivation
+pub const NULLIFIER_DOMAIN: &[u8] = b"ligetron.nullifier.v1";
+
+/// Default depth of the spent-nullifier tree
+pub const DEFAULT_DEPTH: usize = 64;
+
+/// Nullifier of `secret` in `scope` (an election, a pool, an epoch, ...)
+pub fn derive(secret: &Bn254Fr, scope: &Bn254Fr) -> Bn254Fr {
+    let mut tag = Bn254Fr::new();
+    tag.set_bytes_big(NULLIFIER_DOMAIN);
+    poseidon2_hash(&[tag, secret.clone(), scope.clone()])
+}
+
+/// Root of the empty tree of the given depth
+pub fn empty_root(depth: usize) -> Bn254Fr {
+    let mut root = Bn254Fr::from_u32(0);
+    for _ in 0..depth {
+        root = merkle::node(&root, &root);
+    }
+    root
+}
+
+/// Authentication path of the slot of `nullifier`
+fn slot_path(nullifier: &Bn254Fr, siblings: &[Bn254Fr]) -> MerklePath {
+    let depth = siblings.len();
+    assert!((1..=254).contains(&depth), "nullifier: depth must be between 1 and 254");
+
+    let bits = nullifier.to_bits_canonical();
+    MerklePath { siblings: siblings.to_vec(), path: bits[..depth].to_vec() }
+}
+
+/// Assert that `nullifier` is not in the tree with the given `root`
+pub fn assert_not_spent(root: &Bn254Fr, nullifier: &Bn254Fr, siblings: &[Bn254Fr]) {
+    let path = slot_path(nullifier, siblings);
+    merkle::verify_path(root, &Bn254Fr::from_u32(0), &path);
+}
+
+/// Assert that `nullifier` is not in the tree with the given `root` and
+/// return the root after inserting it
+pub fn insert(root: &Bn254Fr, nullifier: &Bn254Fr, siblings: &[Bn254Fr]) -> Bn254Fr {
+    let path = slot_path(nullifier, siblings);
+    merkle::verify_path(root, &Bn254Fr::from_u32(0), &path);
+    merkle::compute_root(&merkle::leaf(nullifier), &path)
+}
+
+/// Derive the nullifier of `secret` in `scope`, insert it into the tree
+/// with the given `root` and return `(nullifier, new_root)`
+pub fn spend(root: &Bn254Fr, secret: &Bn254Fr, scope: &Bn254Fr, siblings: &[Bn254Fr]) -> (Bn254Fr, Bn254Fr) {
+    let nullifier = derive(secret, scope);
+    let new_root = insert(root, &nullifier, siblings);
+    (nullifier, new_root)
+}
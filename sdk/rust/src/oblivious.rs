@@ -0,0 +1,111 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Field-Backed Oblivious Selection for Ligetron
+//!
+//! [`api::oblivious_if`](crate::api::oblivious_if) and its `min`/`max`
+//! siblings are branch-free arithmetic on plain Rust integers. They are
+//! only oblivious as long as the compiler keeps them branch-free, and they
+//! add no constraints of their own. Use them where the condition is public
+//! or the generated code has been checked to contain no branch on it.
+//!
+//! The helpers here lift integers into range-checked [`Bn254Fr`] values and
+//! select with the constrained [`mux`], so the result is bound to the
+//! inputs and the condition by constraints and there is no control flow
+//! for the compiler to introduce:
+//!
+//! - [`select`], [`min`], [`max`] take Rust integers and return field
+//!   elements holding the result;
+//! - [`select_checked`], [`min_checked`], [`max_checked`] work on values
+//!   already in the field.
+//!
+//! Results stay in the field: reading one back with `get_u64` yields an
+//! unconstrained native value.
+
+use crate::bn254fr::{Bn254Fr, mux};
+use crate::cmp::lt_checked;
+
+/// Unsigned integer types that can be lifted into range-checked field
+/// elements
+pub trait FieldInt: Copy {
+    /// Bit width of the type
+    const BITS: usize;
+
+    fn to_u64(self) -> u64;
+}
+
+macro_rules! impl_field_int {
+    ($($t:ty),*) => {$(
+        impl FieldInt for $t {
+            const BITS: usize = <$t>::BITS as usize;
+
+            fn to_u64(self) -> u64 {
+                self as u64
+            }
+        }
+    )*};
+}
+
+impl_field_int!(u8, u16, u32, u64);
+
+impl FieldInt for bool {
+    const BITS: usize = 1;
+
+    fn to_u64(self) -> u64 {
+        self as u64
+    }
+}
+
+/// Lift `x` into a field element range checked to `T::BITS` bits
+pub fn lift<T: FieldInt>(x: T) -> Bn254Fr {
+    let v = Bn254Fr::from_u64(x.to_u64());
+    v.to_bits(T::BITS);
+    v
+}
+
+/// `cond ? t : f` for field elements; `cond` is asserted boolean
+pub fn select_checked(cond: &Bn254Fr, t: &Bn254Fr, f: &Bn254Fr) -> Bn254Fr {
+    Bn254Fr::assert_bit(cond);
+    let mut out = Bn254Fr::new();
+    mux(&mut out, cond, f, t);
+    out
+}
+
+/// Minimum of two field elements of at most `bits` bits
+pub fn min_checked(a: &Bn254Fr, b: &Bn254Fr, bits: usize) -> Bn254Fr {
+    select_checked(&lt_checked(a, b, bits), a, b)
+}
+
+/// Maximum of two field elements of at most `bits` bits
+pub fn max_checked(a: &Bn254Fr, b: &Bn254Fr, bits: usize) -> Bn254Fr {
+    select_checked(&lt_checked(a, b, bits), b, a)
+}
+
+/// `cond ? t : f` for integers, with the condition and both inputs
+/// constrained
+pub fn select<T: FieldInt>(cond: bool, t: T, f: T) -> Bn254Fr {
+    select_checked(&lift(cond), &lift(t), &lift(f))
+}
+
+/// Minimum of two integers
+pub fn min<T: FieldInt>(a: T, b: T) -> Bn254Fr {
+    min_checked(&lift(a), &lift(b), T::BITS)
+}
+
+/// Maximum of two integers
+pub fn max<T: FieldInt>(a: T, b: T) -> Bn254Fr {
+    max_checked(&lift(a), &lift(b), T::BITS)
+}
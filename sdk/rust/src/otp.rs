@@ -0,0 +1,283 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! HOTP/TOTP One-Time Password Verification for Ligetron
+//!
+//! Proves knowledge of a secret that produces a given one-time password,
+//! following RFC 4226 (HOTP) and RFC 6238 (TOTP), without revealing the
+//! secret. The secret is bound to a hiding [`commitment`](crate::commitment)
+//! so that a verifier who holds the commitment learns only that the code is
+//! valid for the public counter or timestamp.
+//!
+//! The HMAC runs as plain wasm over the secret bytes, like [`sha2_256`].
+//! Dynamic truncation and the reduction to decimal digits are constrained
+//! over the digest bytes:
+//!
+//! - `offset = digest[len - 1] & 0xf`
+//! - `binary = (digest[offset] & 0x7f) << 24 | digest[offset + 1] << 16 | ...`
+//! - `code = binary mod 10^digits`
+//!
+//! HMAC-SHA-1 (the RFC 4226 default used by authenticator apps) and
+//! HMAC-SHA-256 are supported.
+//!
+//! [`sha2_256`]: crate::sha2::sha2_256
+
+use crate::bn254fr::{Bn254Fr, addmod_checked, eq_checked, mulmod_checked};
+use crate::commitment;
+use crate::sha2::Sha256Context;
+
+/// Hash function underlying the HMAC
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OtpAlgorithm {
+    /// HMAC-SHA-1, 20-byte digests
+    Sha1,
+    /// HMAC-SHA-256, 32-byte digests
+    Sha256,
+}
+
+/// Algorithm used by RFC 4226 and most authenticator apps
+pub const DEFAULT_OTP_ALGORITHM: OtpAlgorithm = OtpAlgorithm::Sha1;
+
+/// Largest supported number of digits; `10^9` still fits in 31 bits
+pub const MAX_DIGITS: u32 = 9;
+
+/// RFC 6238 parameters
+#[derive(Clone, Copy, Debug)]
+pub struct TotpParams {
+    pub algorithm: OtpAlgorithm,
+    /// Number of decimal digits of the code
+    pub digits: u32,
+    /// Time step in seconds
+    pub step: u64,
+    /// Unix time at which counting starts
+    pub t0: u64,
+}
+
+impl Default for TotpParams {
+    fn default() -> Self {
+        Self {
+            algorithm: DEFAULT_OTP_ALGORITHM,
+            digits: 6,
+            step: 30,
+            t0: 0,
+        }
+    }
+}
+
+impl TotpParams {
+    /// HOTP counter for the Unix time `timestamp`
+    pub fn counter(&self, timestamp: u64) -> u64 {
+        assert!(self.step > 0, "TotpParams: zero time step");
+        assert!(timestamp >= self.t0, "TotpParams: timestamp before t0");
+        (timestamp - self.t0) / self.step
+    }
+}
+
+// ============= HMAC =============
+
+const BLOCK_LEN: usize = 64;
+
+/// SHA-1 digest (FIPS 180-4), only used inside HMAC-SHA-1
+fn sha1(input: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+    let mut msg = input.to_vec();
+    msg.push(0x80);
+    while msg.len() % BLOCK_LEN != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&((input.len() as u64) * 8).to_be_bytes());
+
+    for block in msg.chunks(BLOCK_LEN) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, wi) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let t = a.rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*wi);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = t;
+        }
+
+        for (hi, v) in h.iter_mut().zip([a, b, c, d, e]) {
+            *hi = hi.wrapping_add(v);
+        }
+    }
+
+    let mut out = [0u8; 20];
+    for (chunk, hi) in out.chunks_mut(4).zip(h) {
+        chunk.copy_from_slice(&hi.to_be_bytes());
+    }
+    out
+}
+
+fn digest(algorithm: OtpAlgorithm, parts: &[&[u8]]) -> Vec<u8> {
+    match algorithm {
+        OtpAlgorithm::Sha1 => sha1(&parts.concat()).to_vec(),
+        OtpAlgorithm::Sha256 => {
+            let mut ctx = Sha256Context::new();
+            for p in parts {
+                ctx.update(p);
+            }
+            ctx.finalize().to_vec()
+        }
+    }
+}
+
+/// HMAC (RFC 2104) of `msg` under `key`
+pub fn hmac(algorithm: OtpAlgorithm, key: &[u8], msg: &[u8]) -> Vec<u8> {
+    let mut block = [0u8; BLOCK_LEN];
+    if key.len() > BLOCK_LEN {
+        let hashed = digest(algorithm, &[key]);
+        block[..hashed.len()].copy_from_slice(&hashed);
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let ipad: Vec<u8> = block.iter().map(|b| b ^ 0x36).collect();
+    let opad: Vec<u8> = block.iter().map(|b| b ^ 0x5c).collect();
+    let inner = digest(algorithm, &[&ipad, msg]);
+    digest(algorithm, &[&opad, &inner])
+}
+
+// ============= HOTP / TOTP =============
+
+fn assert_digits(digits: u32) {
+    assert!((1..=MAX_DIGITS).contains(&digits), "otp: digits must be in 1..=9");
+}
+
+/// HOTP value computed natively, e.g. by the party issuing the code
+pub fn hotp(secret: &[u8], counter: u64, digits: u32, algorithm: OtpAlgorithm) -> u32 {
+    assert_digits(digits);
+    let mac = hmac(algorithm, secret, &counter.to_be_bytes());
+    let offset = (mac[mac.len() - 1] & 0xf) as usize;
+    let binary = u32::from_be_bytes([mac[offset] & 0x7f, mac[offset + 1], mac[offset + 2], mac[offset + 3]]);
+    binary % 10u32.pow(digits)
+}
+
+/// TOTP value computed natively
+pub fn totp(secret: &[u8], timestamp: u64, params: &TotpParams) -> u32 {
+    hotp(secret, params.counter(timestamp), params.digits, params.algorithm)
+}
+
+/// Constrained dynamic truncation of an HMAC digest to a `digits`-digit code
+fn truncate_checked(mac: &[u8], digits: u32) -> Bn254Fr {
+    // Range-checked digest bytes; the last byte keeps its bits for the offset
+    let bytes: Vec<Bn254Fr> = mac.iter().map(|&b| Bn254Fr::from_u32(b as u32)).collect();
+    for b in &bytes[..bytes.len() - 1] {
+        b.to_bits(8);
+    }
+    let last_bits = bytes[bytes.len() - 1].to_bits(8);
+    let offset = Bn254Fr::from_bits_checked(&last_bits[..4]);
+
+    // One-hot selector over the 16 possible offsets
+    let sel: Vec<Bn254Fr> = (0..16)
+        .map(|i| {
+            let mut s = Bn254Fr::new();
+            eq_checked(&mut s, &offset, &Bn254Fr::from_u32(i));
+            s
+        })
+        .collect();
+
+    let mut binary = Bn254Fr::from_u32(0);
+    for k in 0..4 {
+        let mut picked = Bn254Fr::from_u32(0);
+        for (i, s) in sel.iter().enumerate() {
+            let mut term = Bn254Fr::new();
+            mulmod_checked(&mut term, s, &bytes[i + k]);
+            picked.addmod_checked(&term);
+        }
+        if k == 0 {
+            // Clear the top bit of the first byte
+            let bits = picked.to_bits(8);
+            picked = Bn254Fr::from_bits_checked(&bits[..7]);
+        }
+        binary.mulmod_constant_checked(&Bn254Fr::from_u32(256));
+        binary.addmod_checked(&picked);
+    }
+
+    // binary = q·10^digits + code with code < 10^digits
+    let modulus = 10u64.pow(digits);
+    let value = binary.get_u64();
+    let q = Bn254Fr::from_u64(value / modulus);
+    let code = Bn254Fr::from_u64(value % modulus);
+    q.to_bits(31);
+    code.to_bits(30);
+    crate::cmp::assert_lt(&code, &Bn254Fr::from_u64(modulus), 30);
+
+    let mut recomposed = Bn254Fr::new();
+    mulmod_checked(&mut recomposed, &q, &Bn254Fr::from_u64(modulus));
+    let mut sum = Bn254Fr::new();
+    addmod_checked(&mut sum, &recomposed, &code);
+    Bn254Fr::assert_equal(&sum, &binary);
+    code
+}
+
+/// HOTP value of `secret` at the public `counter`, with constrained truncation
+pub fn hotp_checked(secret: &[u8], counter: u64, digits: u32, algorithm: OtpAlgorithm) -> Bn254Fr {
+    assert_digits(digits);
+    truncate_checked(&hmac(algorithm, secret, &counter.to_be_bytes()), digits)
+}
+
+/// Assert that `secret` produces the public HOTP `code` at `counter`
+pub fn assert_hotp(secret: &[u8], counter: u64, digits: u32, algorithm: OtpAlgorithm, code: u32) {
+    let computed = hotp_checked(secret, counter, digits, algorithm);
+    Bn254Fr::assert_equal(&computed, &Bn254Fr::from_u32(code));
+}
+
+/// Hiding commitment to an OTP secret: its byte length followed by the
+/// bytes packed 31 per field element
+pub fn commit_secret(secret: &[u8], blinding: &Bn254Fr) -> Bn254Fr {
+    let mut values = vec![Bn254Fr::from_u64(secret.len() as u64)];
+    values.extend(secret.chunks(31).map(|chunk| {
+        let mut limb = Bn254Fr::new();
+        limb.set_bytes_big(chunk);
+        limb
+    }));
+    commitment::commit(&values, blinding)
+}
+
+/// Assert that the secret opening `commitment` produces the public TOTP
+/// `code` at the public Unix time `timestamp`
+pub fn assert_totp(
+    commitment: &Bn254Fr,
+    secret: &[u8],
+    blinding: &Bn254Fr,
+    timestamp: u64,
+    params: &TotpParams,
+    code: u32,
+) {
+    Bn254Fr::assert_equal(&commit_secret(secret, blinding), commitment);
+    assert_hotp(secret, params.counter(timestamp), params.digits, params.algorithm, code);
+}
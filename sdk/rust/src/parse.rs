@@ -0,0 +1,190 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Constrained ASCII Number Parsing for Ligetron
+//!
+//! Parses private ASCII decimal / hexadecimal strings into `Bn254Fr` or
+//! `Uint256` values, proving that every character is a valid digit and that
+//! the result is the positional value of those digits.
+//!
+//! Strings are given without sign or `0x` prefix. The number of digits is
+//! public (it is the length of the input).
+
+use crate::bn254fr::{Bn254Fr, addmod_checked, submod_checked, lt_constant_checked};
+use crate::uint256::{self, Uint256, UINT256_NLIMBS};
+
+/// Maximum number of decimal digits that always fit below the field modulus
+pub const MAX_DECIMAL_DIGITS_FR: usize = 76;
+
+/// Maximum number of hex digits that always fit below the field modulus
+pub const MAX_HEX_DIGITS_FR: usize = 63;
+
+/// Maximum number of decimal digits that always fit in 256 bits
+pub const MAX_DECIMAL_DIGITS_U256: usize = 77;
+
+/// Maximum number of hex digits in 256 bits
+pub const MAX_HEX_DIGITS_U256: usize = 64;
+
+/// Number of decimal digits accumulated per 64-bit chunk (10^19 < 2^64)
+const DECIMAL_CHUNK_DIGITS: usize = 19;
+
+/// Convert an ASCII decimal digit character into its value with constraints.
+/// Asserts that `c` is in '0'..='9'.
+pub fn decimal_digit_checked(c: &Bn254Fr) -> Bn254Fr {
+    let ascii_zero = Bn254Fr::from_u32(b'0' as u32);
+    let one = Bn254Fr::from_u32(1);
+
+    let mut d = Bn254Fr::new();
+    submod_checked(&mut d, c, &ascii_zero);
+
+    // d < 16 via 4-bit decomposition, then d < 10
+    let bits = d.to_bits(4);
+    let lt10 = lt_constant_checked(&bits, "0xa");
+    Bn254Fr::assert_equal(&lt10, &one);
+    d
+}
+
+/// Convert an ASCII hex digit character ('0'-'9', 'a'-'f', 'A'-'F') into its
+/// value with constraints.
+pub fn hex_digit_checked(c: &Bn254Fr) -> Bn254Fr {
+    let one = Bn254Fr::from_u32(1);
+    let ch = c.get_u64() as u8;
+
+    // Witness the digit value and its character class
+    let (v, is_dig, is_low, is_up) = match ch {
+        b'0'..=b'9' => (ch - b'0', 1, 0, 0),
+        b'a'..=b'f' => (ch - b'a' + 10, 0, 1, 0),
+        b'A'..=b'F' => (ch - b'A' + 10, 0, 0, 1),
+        _ => (0, 1, 0, 0),
+    };
+    let v = Bn254Fr::from_u32(v as u32);
+    let s_dig = Bn254Fr::from_u32(is_dig);
+    let s_low = Bn254Fr::from_u32(is_low);
+    let s_up = Bn254Fr::from_u32(is_up);
+
+    // Exactly one class is selected
+    Bn254Fr::assert_bit(&s_dig);
+    Bn254Fr::assert_bit(&s_low);
+    Bn254Fr::assert_bit(&s_up);
+    let mut sel = Bn254Fr::new();
+    addmod_checked(&mut sel, &s_dig, &s_low);
+    sel.addmod_checked(&s_up);
+    Bn254Fr::assert_equal(&sel, &one);
+
+    // v in [0, 16) and (v < 10) <=> class is a decimal digit
+    let bits = v.to_bits(4);
+    let lt10 = lt_constant_checked(&bits, "0xa");
+    Bn254Fr::assert_equal(&lt10, &s_dig);
+
+    // c = v + 48 * s_dig + 87 * s_low + 55 * s_up
+    let mut expect = v.clone();
+    for (s, offset) in [(&s_dig, b'0' as u32), (&s_low, b'a' as u32 - 10), (&s_up, b'A' as u32 - 10)] {
+        let mut t = s.clone();
+        t.mulmod_constant_checked(&Bn254Fr::from_u32(offset));
+        expect.addmod_checked(&t);
+    }
+    Bn254Fr::assert_equal(&expect, c);
+    v
+}
+
+/// Horner accumulation: acc = sum(d_i * base^(n-1-i))
+fn accumulate(digits: &[Bn254Fr], base: u32) -> Bn254Fr {
+    let k = Bn254Fr::from_u32(base);
+    let mut acc = Bn254Fr::from_u32(0);
+    for d in digits {
+        acc.mulmod_constant_checked(&k);
+        acc.addmod_checked(d);
+    }
+    acc
+}
+
+fn load_chars(s: &[u8]) -> Vec<Bn254Fr> {
+    s.iter().map(|&c| Bn254Fr::from_u32(c as u32)).collect()
+}
+
+/// Parse an ASCII decimal string into a field element with constraints.
+/// At most [`MAX_DECIMAL_DIGITS_FR`] digits are accepted so the result
+/// cannot wrap around the modulus.
+pub fn parse_decimal_checked(s: &[u8]) -> Bn254Fr {
+    assert!(!s.is_empty() && s.len() <= MAX_DECIMAL_DIGITS_FR, "invalid decimal string length");
+    let digits: Vec<Bn254Fr> = load_chars(s).iter().map(decimal_digit_checked).collect();
+    accumulate(&digits, 10)
+}
+
+/// Parse an ASCII hex string into a field element with constraints.
+/// At most [`MAX_HEX_DIGITS_FR`] digits are accepted so the result
+/// cannot wrap around the modulus.
+pub fn parse_hex_checked(s: &[u8]) -> Bn254Fr {
+    assert!(!s.is_empty() && s.len() <= MAX_HEX_DIGITS_FR, "invalid hex string length");
+    let digits: Vec<Bn254Fr> = load_chars(s).iter().map(hex_digit_checked).collect();
+    accumulate(&digits, 16)
+}
+
+/// Parse an ASCII hex string into a Uint256 with constraints.
+/// Every 16 digits (counted from the end) form one 64-bit limb.
+pub fn parse_hex_u256_checked(s: &[u8]) -> Uint256 {
+    assert!(!s.is_empty() && s.len() <= MAX_HEX_DIGITS_U256, "invalid hex string length");
+    let digits: Vec<Bn254Fr> = load_chars(s).iter().map(hex_digit_checked).collect();
+
+    let mut words = [
+        Bn254Fr::from_u32(0),
+        Bn254Fr::from_u32(0),
+        Bn254Fr::from_u32(0),
+        Bn254Fr::from_u32(0),
+    ];
+    for (i, chunk) in digits.rchunks(16).enumerate() {
+        words[i] = accumulate(chunk, 16);
+    }
+
+    let mut out = Uint256::new();
+    out.set_words(&words);
+    out
+}
+
+/// Parse an ASCII decimal string into a Uint256 with constraints.
+/// The digits are accumulated in 19-digit chunks, combined with
+/// constrained 256-bit multiply-add, and overflow is rejected.
+pub fn parse_decimal_u256_checked(s: &[u8]) -> Uint256 {
+    assert!(!s.is_empty() && s.len() <= MAX_DECIMAL_DIGITS_U256, "invalid decimal string length");
+    let digits: Vec<Bn254Fr> = load_chars(s).iter().map(decimal_digit_checked).collect();
+
+    let zero = Bn254Fr::from_u32(0);
+    let mut acc = Uint256::from_u64(0);
+    let head = digits.len() % DECIMAL_CHUNK_DIGITS;
+    let (first, rest) = digits.split_at(head);
+
+    let mut chunks: Vec<&[Bn254Fr]> = Vec::new();
+    if !first.is_empty() {
+        chunks.push(first);
+    }
+    chunks.extend(rest.chunks(DECIMAL_CHUNK_DIGITS));
+
+    for chunk in chunks {
+        let scale = Uint256::from_u64(10u64.pow(chunk.len() as u32));
+        let mut value = Uint256::new();
+        value.set_bn254(&accumulate(chunk, 10));
+
+        // acc = acc * 10^len + value, without overflowing 256 bits
+        let wide = uint256::mul_wide(&acc, &scale);
+        for i in 0..UINT256_NLIMBS {
+            Bn254Fr::assert_equal(wide.hi.limb(i), &zero);
+        }
+        let sum = uint256::add_cc(&wide.lo, &value);
+        Bn254Fr::assert_equal(&sum.carry, &zero);
+        acc = sum.val;
+    }
+    acc
+}
@@ -0,0 +1,263 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Pedersen Vector Commitments over Baby Jubjub
+//!
+//! `C = v_0·G_0 + ... + v_{n-1}·G_{n-1} + r_0·H_0 + r_1·H_1 + r_2·H_2`
+//!
+//! Generators are derived by hashing to the curve (try-and-increment on
+//! `Poseidon2(DOMAIN, index, counter)` followed by cofactor clearing), so
+//! nobody knows discrete logarithms between them.
+//!
+//! ## Scalar bounds
+//!
+//! Scalars live in the BN254 scalar field but act modulo the (smaller)
+//! Baby Jubjub subgroup order, so linear relations are only sound when no
+//! scalar wraps modulo p. Values are therefore range checked to
+//! [`VALUE_BITS`] bits, and the blinding is given as [`BLINDING_LIMBS`]
+//! limbs of [`VALUE_BITS`] bits each (384 random bits, statistically close
+//! to uniform modulo the group order).
+//!
+//! ## Batched opening
+//!
+//! [`PedersenGenerators::batch_open`] checks many commitments at once with a
+//! random linear combination: with 64-bit Fiat-Shamir challenges `rho_j`
+//! derived from all commitments and claimed openings,
+//!
+//! `sum_j rho_j·C_j == sum_i (sum_j rho_j·v_ji)·G_i + ...`
+//!
+//! which costs one multi-scalar multiplication over the generators instead
+//! of one per commitment.
+
+use crate::babyjubjub::{self, JubjubPoint};
+use crate::bn254fr::{Bn254Fr, mulmod_checked, mulmod, submod, divmod, sqrtmod};
+use crate::cache::memoize;
+use crate::poseidon2::poseidon2_hash;
+
+/// Bit width of committed values and of each blinding limb
+pub const VALUE_BITS: usize = 128;
+
+/// Number of blinding limbs
+pub const BLINDING_LIMBS: usize = 3;
+
+/// Bit width of batch opening challenges
+const CHALLENGE_BITS: usize = 64;
+
+/// Maximum number of commitments in one batch opening
+pub const MAX_BATCH: usize = 1 << 30;
+
+/// Domain separation tag for generator derivation
+pub const PEDERSEN_DOMAIN: &[u8] = b"ligetron.pedersen.v1";
+
+/// Index offset of the blinding generators
+const BLINDING_INDEX: u64 = 1 << 32;
+
+/// Hash to a Baby Jubjub point in the prime-order subgroup, once per program
+fn hash_to_curve(index: u64) -> JubjubPoint {
+    memoize("pedersen::hash_to_curve", index, || hash_to_curve_uncached(index))
+}
+
+fn hash_to_curve_uncached(index: u64) -> JubjubPoint {
+    let mut domain = Bn254Fr::new();
+    domain.set_bytes_big(PEDERSEN_DOMAIN);
+    let a = Bn254Fr::from_str(babyjubjub::params().a);
+    let d = Bn254Fr::from_str(babyjubjub::params().d);
+    let one = Bn254Fr::from_u32(1);
+
+    for counter in 0u32.. {
+        let y = poseidon2_hash(&[domain.clone(), Bn254Fr::from_u64(index), Bn254Fr::from_u32(counter)]);
+
+        // a·x^2 + y^2 = 1 + d·x^2·y^2  =>  x^2 = (1 - y^2) / (a - d·y^2)
+        let mut y2 = Bn254Fr::new();
+        let mut num = Bn254Fr::new();
+        let mut dy2 = Bn254Fr::new();
+        let mut den = Bn254Fr::new();
+        let mut x2 = Bn254Fr::new();
+        let mut x = Bn254Fr::new();
+        mulmod(&mut y2, &y, &y);
+        submod(&mut num, &one, &y2);
+        mulmod(&mut dy2, &d, &y2);
+        submod(&mut den, &a, &dy2);
+        divmod(&mut x2, &num, &den);
+        if !sqrtmod(&mut x, &x2) {
+            continue;
+        }
+
+        // Clear the cofactor 8
+        let mut p = JubjubPoint::new(x, y);
+        for _ in 0..3 {
+            p = JubjubPoint::twisted_edward_add(&p, &p);
+        }
+        return p;
+    }
+    unreachable!()
+}
+
+/// Multi-scalar multiplication with shared doublings over `bits`-bit
+/// scalars (range checks every scalar)
+fn msm(points: &[JubjubPoint], scalars: &[Bn254Fr], bits: usize) -> JubjubPoint {
+    let decomposed: Vec<Vec<Bn254Fr>> = scalars.iter().map(|s| s.to_bits(bits)).collect();
+    let identity = JubjubPoint::identity();
+
+    let mut acc = JubjubPoint::identity();
+    for b in (0..bits).rev() {
+        acc = JubjubPoint::twisted_edward_add(&acc, &acc);
+        for (p, sbits) in points.iter().zip(decomposed.iter()) {
+            let t = JubjubPoint::mux(&sbits[b], &identity, p);
+            acc = JubjubPoint::twisted_edward_add(&acc, &t);
+        }
+    }
+    acc
+}
+
+/// Pedersen generators for vectors of a fixed length
+#[derive(Clone)]
+pub struct PedersenGenerators {
+    pub g: Vec<JubjubPoint>,
+    pub h: Vec<JubjubPoint>,
+}
+
+impl PedersenGenerators {
+    /// Derive generators for vectors of length `n`
+    pub fn new(n: usize) -> Self {
+        let g = (0..n as u64).map(hash_to_curve).collect();
+        let h = (0..BLINDING_LIMBS as u64).map(|i| hash_to_curve(BLINDING_INDEX + i)).collect();
+        PedersenGenerators { g, h }
+    }
+
+    /// Vector length
+    pub fn len(&self) -> usize {
+        self.g.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.g.is_empty()
+    }
+
+    fn all(&self) -> Vec<JubjubPoint> {
+        self.g.iter().chain(self.h.iter()).cloned().collect()
+    }
+
+    fn check_shape(&self, values: &[Bn254Fr], blinding: &[Bn254Fr]) {
+        assert_eq!(values.len(), self.g.len(), "Pedersen: vector length mismatch");
+        assert_eq!(blinding.len(), BLINDING_LIMBS, "Pedersen: expected {} blinding limbs", BLINDING_LIMBS);
+    }
+
+    /// Commit to `values` with the blinding limbs `blinding`
+    pub fn commit(&self, values: &[Bn254Fr], blinding: &[Bn254Fr]) -> JubjubPoint {
+        self.check_shape(values, blinding);
+        let scalars: Vec<Bn254Fr> = values.iter().chain(blinding.iter()).cloned().collect();
+        msm(&self.all(), &scalars, VALUE_BITS)
+    }
+
+    /// Assert that `(values, blinding)` opens `commitment`
+    pub fn open(&self, commitment: &JubjubPoint, values: &[Bn254Fr], blinding: &[Bn254Fr]) {
+        let mut c = self.commit(values, blinding);
+        let mut expected = commitment.clone();
+        JubjubPoint::assert_equal(&mut c, &mut expected);
+    }
+
+    /// Assert that every `(values[j], blindings[j])` opens `commitments[j]`
+    /// with a single multi-scalar multiplication over the generators
+    pub fn batch_open(&self, commitments: &[JubjubPoint],
+                      values: &[Vec<Bn254Fr>], blindings: &[Vec<Bn254Fr>]) {
+        let m = commitments.len();
+        assert!(m > 0 && m <= MAX_BATCH, "Pedersen::batch_open: invalid batch size");
+        assert_eq!(values.len(), m, "Pedersen::batch_open: opening count mismatch");
+        assert_eq!(blindings.len(), m, "Pedersen::batch_open: blinding count mismatch");
+
+        // rho_j = low 64 bits of Poseidon2(seed, j), seed binds all
+        // commitments and all claimed openings, so the openings cannot be
+        // chosen after the challenges
+        for j in 0..m {
+            self.check_shape(&values[j], &blindings[j]);
+        }
+        let transcript: Vec<Bn254Fr> = commitments.iter()
+            .flat_map(|c| [c.x.clone(), c.y.clone()])
+            .chain(values.iter().flatten().cloned())
+            .chain(blindings.iter().flatten().cloned())
+            .collect();
+        let seed = poseidon2_hash(&transcript);
+        let rho: Vec<Bn254Fr> = (0..m)
+            .map(|j| {
+                let h = poseidon2_hash(&[seed.clone(), Bn254Fr::from_u64(j as u64)]);
+                Bn254Fr::from_bits_checked(&h.to_bits_canonical()[..CHALLENGE_BITS])
+            })
+            .collect();
+
+        // Combined scalars a_i = sum_j rho_j·s_ji stay below
+        // 2^(VALUE_BITS + CHALLENGE_BITS + 30) < p
+        let n = self.g.len() + BLINDING_LIMBS;
+        let mut combined: Vec<Bn254Fr> = (0..n).map(|_| Bn254Fr::from_u32(0)).collect();
+        for j in 0..m {
+            let scalars = values[j].iter().chain(blindings[j].iter());
+            for (a, s) in combined.iter_mut().zip(scalars) {
+                let _ = s.to_bits(VALUE_BITS);
+                let mut t = Bn254Fr::new();
+                mulmod_checked(&mut t, &rho[j], s);
+                a.addmod_checked(&t);
+            }
+        }
+
+        let mut lhs = msm(commitments, &rho, CHALLENGE_BITS);
+        let mut rhs = msm(&self.all(), &combined, VALUE_BITS + CHALLENGE_BITS + 30);
+        JubjubPoint::assert_equal(&mut lhs, &mut rhs);
+    }
+}
+
+#[cfg(all(test, feature = "native-sim"))]
+mod tests {
+    use super::*;
+    use crate::bn254fr::{addmod, submod};
+
+    fn opening(v: u64, r: u64) -> (Vec<Bn254Fr>, Vec<Bn254Fr>) {
+        (vec![Bn254Fr::from_u64(v)], (0..BLINDING_LIMBS as u64).map(|i| Bn254Fr::from_u64(r + i)).collect())
+    }
+
+    fn setup() -> (PedersenGenerators, Vec<JubjubPoint>, Vec<Vec<Bn254Fr>>, Vec<Vec<Bn254Fr>>) {
+        let gens = PedersenGenerators::new(1);
+        let (v1, r1) = opening(1000, 7);
+        let (v2, r2) = opening(u64::MAX, 11);
+        let commitments = vec![gens.commit(&v1, &r1), gens.commit(&v2, &r2)];
+        (gens, commitments, vec![v1, v2], vec![r1, r2])
+    }
+
+    #[test]
+    fn batch_open_accepts_openings() {
+        let (gens, commitments, values, blindings) = setup();
+        gens.batch_open(&commitments, &values, &blindings);
+    }
+
+    #[test]
+    #[should_panic(expected = "constraint failed")]
+    fn batch_open_rejects_shifted_openings() {
+        let (gens, commitments, mut values, blindings) = setup();
+
+        // v1 + rho_2, v2 - rho_1 cancel in the combination when the
+        // challenges depend on the commitments alone
+        let coords: Vec<Bn254Fr> = commitments.iter().flat_map(|c| [c.x.clone(), c.y.clone()]).collect();
+        let seed = poseidon2_hash(&coords);
+        let rho: Vec<Bn254Fr> = (0..2u64)
+            .map(|j| Bn254Fr::from_bits_checked(
+                &poseidon2_hash(&[seed.clone(), Bn254Fr::from_u64(j)]).to_bits_canonical()[..CHALLENGE_BITS]))
+            .collect();
+        let (v1, v2) = (values[0][0].clone(), values[1][0].clone());
+        addmod(&mut values[0][0], &v1, &rho[1]);
+        submod(&mut values[1][0], &v2, &rho[0]);
+
+        gens.batch_open(&commitments, &values, &blindings);
+    }
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Linear Policy Evaluation for Ligetron
+//!
+//! Proves that a weighted score over private inputs clears a public
+//! threshold, as in credit-scoring style policies:
+//!
+//! `score = Σ w_i · x_i >= threshold`
+//!
+//! Inputs are unsigned integers of up to [`VALUE_BITS`] bits and are range
+//! checked. Weights are public signed fixed-point numbers with
+//! [`WEIGHT_FRAC_BITS`] fractional bits (see [`to_fixed`]), so the score and
+//! the threshold are in units of `2^-WEIGHT_FRAC_BITS`. Positive and
+//! negative weights are summed separately so the comparison never wraps
+//! around the field:
+//!
+//! ```ignore
+//! let weights = [policy::to_fixed(0.35), policy::to_fixed(-1.5), policy::to_fixed(2.0)];
+//! policy::assert_weighted_score(&[income, debts, years], &weights, policy::to_fixed(600.0));
+//! ```
+
+use crate::bn254fr::{Bn254Fr, mulmod_checked};
+use crate::cmp::{assert_lte, lte_checked};
+
+/// Bit width of an input value
+pub const VALUE_BITS: usize = 64;
+
+/// Fractional bits of the fixed-point weights
+pub const WEIGHT_FRAC_BITS: u32 = 16;
+
+/// Maximum magnitude of a weight, in fixed-point units
+pub const MAX_WEIGHT: i64 = (1 << 48) - 1;
+
+/// Maximum number of inputs
+pub const MAX_INPUTS: usize = 1 << 16;
+
+/// Bit width of either side of the comparison
+const SCORE_BITS: usize = VALUE_BITS + 48 + 16 + 1;
+
+/// Fixed-point encoding of `w`, rounded to nearest
+pub fn to_fixed(w: f64) -> i64 {
+    (w * (1u64 << WEIGHT_FRAC_BITS) as f64).round() as i64
+}
+
+/// Positive and negative parts of `score - threshold` as
+/// `(Σ_{w>0} w·x + max(-t, 0), Σ_{w<0} |w|·x + max(t, 0))`
+fn score_sides(values: &[Bn254Fr], weights: &[i64], threshold: i64) -> (Bn254Fr, Bn254Fr) {
+    assert_eq!(values.len(), weights.len(), "policy: weight count mismatch");
+    assert!(values.len() <= MAX_INPUTS, "policy: too many inputs");
+
+    let mut pos = Bn254Fr::from_u64(threshold.min(0).unsigned_abs());
+    let mut neg = Bn254Fr::from_u64(threshold.max(0).unsigned_abs());
+    for (x, &w) in values.iter().zip(weights) {
+        assert!(w.unsigned_abs() <= MAX_WEIGHT as u64, "policy: weight out of range");
+        let _ = x.to_bits(VALUE_BITS);
+
+        let mut t = Bn254Fr::new();
+        mulmod_checked(&mut t, x, &Bn254Fr::from_u64(w.unsigned_abs()));
+        if w >= 0 {
+            pos.addmod_checked(&t);
+        } else {
+            neg.addmod_checked(&t);
+        }
+    }
+    (pos, neg)
+}
+
+/// Return 1 if `Σ w_i·x_i >= threshold` and 0 otherwise (with constraints)
+pub fn weighted_score_at_least(values: &[Bn254Fr], weights: &[i64], threshold: i64) -> Bn254Fr {
+    let (pos, neg) = score_sides(values, weights, threshold);
+    lte_checked(&neg, &pos, SCORE_BITS)
+}
+
+/// Assert that `Σ w_i·x_i >= threshold`
+pub fn assert_weighted_score(values: &[Bn254Fr], weights: &[i64], threshold: i64) {
+    let (pos, neg) = score_sides(values, weights, threshold);
+    assert_lte(&neg, &pos, SCORE_BITS);
+}
@@ -25,15 +25,27 @@
 //! - **Full Rounds**: Apply S-box to all state elements
 //! - **Partial Rounds**: Apply S-box only to first element (efficiency)
 //!
+//! Partial rounds use the optimized form of the permutation: round constants
+//! are folded into a single constant per partial round and the MDS matrix is
+//! replaced by sparse matrices, so a partial round costs about `2t`
+//! multiplications instead of `t^2`. The output is identical to the
+//! textbook permutation.
+//!
 //! ## Security Parameters
 //!
 //! - **t=3**: 8 full rounds, 57 partial rounds
 //! - **t=5**: 8 full rounds, 60 partial rounds
+//!
+//! Constants are generated at build time by [`generate_params`]; set
+//! `LIGETRON_POSEIDON_WIDTHS` (e.g. `8,12`) to also build parameter sets
+//! for other state widths.
 
-use crate::bn254fr::{Bn254Fr, mulmod_checked};
-use crate::vbn254fr::{VBn254Fr, VBn254FrConstant, mulmod_vec, mulmod_constant, mont_mul_constant};
+use crate::bn254fr::Bn254Fr;
+use crate::field::LigetronField;
+use crate::vbn254fr::VBn254Fr;
 use crate::poseidon_constant::*;
-use lazy_static::lazy_static;
+
+pub use crate::poseidon_gen::{generate_params, PoseidonParams};
 
 pub trait PoseidonParam {
     const R_F: usize;  // Full rounds
@@ -48,116 +60,122 @@ pub trait PoseidonParam {
 
     /// Get the mds_const Montgomery matrix string literals
     fn mds_montgomery_str() -> &'static [&'static str];
-}
 
-/// Parameters for Poseidon with t=5 (state size 5)
-pub struct PoseidonPermx5Bit254T5;
+    /// Get the folded round constant string literals (`R_F * T + R_P`)
+    fn arc_optimized_str() -> &'static [&'static str];
 
-impl PoseidonParam for PoseidonPermx5Bit254T5 {
-    const R_F: usize = 8;
-    const R_P: usize = 60;
-    const T: usize = 5;
+    /// Get the pre-sparse matrix string literals
+    fn pre_sparse_str() -> &'static [&'static str];
 
-    fn arc_str() -> &'static [&'static str] {
-        &POSEIDON_5_ARC_STR
-    }
+    /// Get the pre-sparse Montgomery matrix string literals
+    fn pre_sparse_montgomery_str() -> &'static [&'static str];
 
-    fn mds_str() -> &'static [&'static str] {
-        &POSEIDON_5_MDS_STR
-    }
+    /// Get the sparse matrix string literals (`2T - 1` per partial round)
+    fn sparse_str() -> &'static [&'static str];
 
-    fn mds_montgomery_str() -> &'static [&'static str] {
-        &POSEIDON_5_MDS_MONTGOMERY_STR
-    }
+    /// Get the sparse Montgomery matrix string literals
+    fn sparse_montgomery_str() -> &'static [&'static str];
 }
-/// Parameters for Poseidon with t=3 (state size 3)
-pub struct PoseidonPermx5Bit254T3;
-
-impl PoseidonParam for PoseidonPermx5Bit254T3 {
-    const R_F: usize = 8;
-    const R_P: usize = 57;
-    const T: usize = 3;
-
-    fn arc_str() -> &'static [&'static str] {
-        &POSEIDON_3_ARC_STR
-    }
-
-    fn mds_str() -> &'static [&'static str] {
-        &POSEIDON_3_MDS_STR
-    }
 
-    fn mds_montgomery_str() -> &'static [&'static str] {
-        &POSEIDON_3_MDS_MONTGOMERY_STR
-    }
-}
+/// Define the parameter set `PoseidonPermx5Bit254T<t>` over the tables
+/// generated by the build script
+macro_rules! poseidon_param {
+    ($t:literal, $r_f:literal, $r_p:literal) => {
+        paste::paste! {
+            #[doc = concat!("Parameters for Poseidon with t=", stringify!($t), " (state size ", stringify!($t), ")")]
+            pub struct [<PoseidonPermx5Bit254T $t>];
+
+            impl PoseidonParam for [<PoseidonPermx5Bit254T $t>] {
+                const R_F: usize = $r_f;
+                const R_P: usize = $r_p;
+                const T: usize = $t;
+
+                fn arc_str() -> &'static [&'static str] {
+                    [<POSEIDON_ $t _ARC_STR>]
+                }
 
+                fn mds_str() -> &'static [&'static str] {
+                    [<POSEIDON_ $t _MDS_STR>]
+                }
 
-lazy_static! {
-    static ref VPOSEIDON_3_ARC_INIT: Vec<VBn254FrConstant> = {
-        PoseidonPermx5Bit254T3::arc_str().iter()
-            .map(|s| VBn254FrConstant::from_str(s))
-            .collect()
-    };
+                fn mds_montgomery_str() -> &'static [&'static str] {
+                    [<POSEIDON_ $t _MDS_MONTGOMERY_STR>]
+                }
 
-    static ref VPOSEIDON_5_ARC_INIT: Vec<VBn254FrConstant> = {
-        PoseidonPermx5Bit254T5::arc_str().iter()
-            .map(|s| VBn254FrConstant::from_str(s))
-            .collect()
-    };
+                fn arc_optimized_str() -> &'static [&'static str] {
+                    [<POSEIDON_ $t _ARC_OPT_STR>]
+                }
 
-    static ref VPOSEIDON_3_MDS_INIT: Vec<VBn254FrConstant> = {
-        PoseidonPermx5Bit254T3::mds_str().iter()
-            .map(|s| VBn254FrConstant::from_str(s))
-            .collect()
-    };
+                fn pre_sparse_str() -> &'static [&'static str] {
+                    [<POSEIDON_ $t _PRE_SPARSE_STR>]
+                }
 
-    static ref VPOSEIDON_5_MDS_INIT: Vec<VBn254FrConstant> = {
-        PoseidonPermx5Bit254T5::mds_str().iter()
-            .map(|s| VBn254FrConstant::from_str(s))
-            .collect()
-    };
+                fn pre_sparse_montgomery_str() -> &'static [&'static str] {
+                    [<POSEIDON_ $t _PRE_SPARSE_MONTGOMERY_STR>]
+                }
 
-    static ref VPOSEIDON_3_MDS_MONTGOMERY_INIT: Vec<VBn254FrConstant> = {
-        PoseidonPermx5Bit254T3::mds_montgomery_str().iter()
-            .map(|s| VBn254FrConstant::from_str(s))
-            .collect()
-    };
+                fn sparse_str() -> &'static [&'static str] {
+                    [<POSEIDON_ $t _SPARSE_STR>]
+                }
 
-    static ref VPOSEIDON_5_MDS_MONTGOMERY_INIT: Vec<VBn254FrConstant> = {
-        PoseidonPermx5Bit254T5::mds_montgomery_str().iter()
-            .map(|s| VBn254FrConstant::from_str(s))
-            .collect()
+                fn sparse_montgomery_str() -> &'static [&'static str] {
+                    [<POSEIDON_ $t _SPARSE_MONTGOMERY_STR>]
+                }
+            }
+        }
     };
 }
 
+include!(concat!(env!("OUT_DIR"), "/poseidon_params.rs"));
 
 
-pub struct PoseidonContext<P: PoseidonParam> {
-    state: Vec<Bn254Fr>,
+/// Poseidon sponge context, generic over the parameter set and the field
+/// backend. `USE_MONTGOMERY` selects MDS constants in Montgomery form
+/// (vectorized backend only).
+pub struct GenericPoseidonContext<P: PoseidonParam, F: LigetronField, const USE_MONTGOMERY: bool = false> {
+    state: Vec<F>,
     curr: usize,
-    arc_const: Vec<Bn254Fr>,
-    mds_const: Vec<Bn254Fr>,
+    buffer: [u8; 31],
+    buffer_len: usize,
+    arc_const: Vec<F::Constant>,
+    mds_const: Vec<F::Constant>,
+    pre_sparse_const: Vec<F::Constant>,
+    sparse_const: Vec<F::Constant>,
     _params: std::marker::PhantomData<P>,
 }
 
+/// Poseidon context over BN254 field elements
+pub type PoseidonContext<P> = GenericPoseidonContext<P, Bn254Fr, false>;
+
+/// Poseidon context over vectorized BN254 field elements
+pub type VPoseidonContext<P, const USE_MONTGOMERY: bool = false> = GenericPoseidonContext<P, VBn254Fr, USE_MONTGOMERY>;
+
 
-impl<P: PoseidonParam> PoseidonContext<P> {
+impl<P: PoseidonParam, F: LigetronField, const USE_MONTGOMERY: bool> GenericPoseidonContext<P, F, USE_MONTGOMERY> {
     pub fn new() -> Self {
-        let arc_const = P::arc_str().iter()
-            .map(|s| Bn254Fr::from_str(s))
-            .collect();
-        let mds_const = P::mds_str().iter()
-            .map(|s| Bn254Fr::from_str(s))
-            .collect();
-
-        let ctx = PoseidonContext {
-            state: vec![Bn254Fr::from_u32(0); P::T],
+        let constants = |strs: &[&str]| strs.iter().map(|s| F::constant(s)).collect::<Vec<_>>();
+
+        let arc_const = constants(P::arc_optimized_str());
+        let (mds_str, pre_sparse_str, sparse_str) = if USE_MONTGOMERY {
+            (P::mds_montgomery_str(), P::pre_sparse_montgomery_str(), P::sparse_montgomery_str())
+        } else {
+            (P::mds_str(), P::pre_sparse_str(), P::sparse_str())
+        };
+        let mds_const = constants(mds_str);
+        let pre_sparse_const = constants(pre_sparse_str);
+        let sparse_const = constants(sparse_str);
+
+        GenericPoseidonContext {
+            state: (0..P::T).map(|_| F::from_u32(0)).collect(),
             curr: 0,
+            buffer: [0u8; 31],
+            buffer_len: 0,
             arc_const,
             mds_const,
+            pre_sparse_const,
+            sparse_const,
             _params: std::marker::PhantomData,
-        };
-        ctx
+        }
     }
 
     pub fn reset(&mut self) {
@@ -165,11 +183,12 @@ impl<P: PoseidonParam> PoseidonContext<P> {
             self.state[i].set_u32(0);
         }
         self.curr = 0;
+        self.buffer_len = 0;
     }
 
-    pub fn update(&mut self, data: &Bn254Fr) {
+    pub fn update(&mut self, data: &F) {
         // Absorb to the sponge
-        self.state[self.curr].addmod_checked(data);
+        self.state[self.curr].add_assign_checked(data);
 
         self.curr += 1;
 
@@ -179,7 +198,7 @@ impl<P: PoseidonParam> PoseidonContext<P> {
         }
     }
 
-    pub fn finalize(&mut self) -> Bn254Fr {
+    pub fn finalize(&mut self) -> F {
         if self.curr != 0 {
             self.internal_round_update();
             self.curr = 0;
@@ -187,206 +206,166 @@ impl<P: PoseidonParam> PoseidonContext<P> {
         self.state[0].clone()
     }
 
-    fn internal_round_update(&mut self) {
-        let r_f_half = P::R_F / 2;
-        let mut arc_counter = 0;
-
-        // First half full rounds
-        for _ in 0..r_f_half {
-            self.perm(&mut arc_counter, true);
-        }
-
-        // R_P partial rounds
-        for _ in 0..P::R_P {
-            self.perm(&mut arc_counter, false);
-        }
-
-        // Last half full rounds
-        for _ in 0..r_f_half {
-            self.perm(&mut arc_counter, true);
-        }
-    }
-
-    fn perm(&mut self, arc_counter: &mut usize, full_round: bool) {
-        // Add round constants
-        for i in 0..P::T {
-            self.state[i].addmod_checked(&self.arc_const[*arc_counter]);
-            *arc_counter += 1;
-        }
-
-        // Apply S-box (x^5)
-        let num_s_box = if full_round { P::T } else { 1 };
-        for i in 0..num_s_box {
-            self.state[i] = self.pow5(&self.state[i]);
-        }
-
-        let old_state = self.state.clone();
-
-        for i in 0..P::T {
-            let mut sum = Bn254Fr::from_u32(0);
-            for j in 0..P::T {
-                let mut temp = Bn254Fr::new();
-                mulmod_checked(&mut temp, &self.mds_const[i * P::T + j], &old_state[j]);
-                sum.addmod_checked(&temp);
+    /// Absorb bytes in 31-byte big-endian chunks, buffering a partial chunk
+    /// until more data arrives or [`Self::finalize_bytes`] is called
+    pub fn update_bytes(&mut self, data: &[u8]) {
+        let mut data = data;
+
+        // Complete a partially filled chunk first
+        if self.buffer_len > 0 {
+            let take = (31 - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+            if self.buffer_len < 31 {
+                return;
             }
-            self.state[i] = sum;
+            let chunk = self.buffer;
+            self.absorb_chunk(&chunk);
+            self.buffer_len = 0;
         }
-    }
-
-    /// Compute x^5 for field element
-    fn pow5(&self, x: &Bn254Fr) -> Bn254Fr {
-        let mut x2 = Bn254Fr::new();
-        let mut x4 = Bn254Fr::new();
-        let mut result = Bn254Fr::new();
-        mulmod_checked(&mut x2, x, x);          // x^2
-        mulmod_checked(&mut x4, &x2, &x2);      // x^4
-        mulmod_checked(&mut result, &x4, x);    // x^5 = x^4 * x
-        result
-    }
-}
-
-/// Type alias for Poseidon5 context
-pub type PoseidonContext5 = PoseidonContext<PoseidonPermx5Bit254T5>;
-
-/// Type alias for poseidon_t3 context
-pub type PoseidonContext3 = PoseidonContext<PoseidonPermx5Bit254T3>;
-
-// ============= Vectorized Implementation with Montgomery Support =============
-
-pub struct VPoseidonContext<P: PoseidonParam, const USE_MONTGOMERY: bool = false> {
-    state: Vec<VBn254Fr>,
-    curr: usize,
-    _params: std::marker::PhantomData<P>,
-}
 
-impl<P: PoseidonParam, const USE_MONTGOMERY: bool> VPoseidonContext<P, USE_MONTGOMERY> {
-    /// Static arc_const constants
-    fn arc() -> &'static [VBn254FrConstant] {
-        match P::T {
-            3 => &VPOSEIDON_3_ARC_INIT,
-            5 => &VPOSEIDON_5_ARC_INIT,
-            _ => panic!("Unsupported Poseidon state size"),
+        let mut chunks = data.chunks_exact(31);
+        for chunk in &mut chunks {
+            self.absorb_chunk(chunk);
         }
+        let rest = chunks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
     }
 
-    /// Static mds_const constants
-    /// Initialized from P::mds_str() or P::mds_montgomery_str() depending on USE_MONTGOMERY
-    fn mds() -> &'static [VBn254FrConstant] {
-        if USE_MONTGOMERY {
-            match P::T {
-                3 => &VPOSEIDON_3_MDS_MONTGOMERY_INIT,
-                5 => &VPOSEIDON_5_MDS_MONTGOMERY_INIT,
-                _ => panic!("Unsupported Poseidon state size"),
-            }
-        } else {
-            match P::T {
-                3 => &VPOSEIDON_3_MDS_INIT,
-                5 => &VPOSEIDON_5_MDS_INIT,
-                _ => panic!("Unsupported Poseidon state size"),
-            }
+    /// Pad the buffered bytes with 0x80 and zeros to a full chunk, absorb
+    /// it and finalize (same padding as `Poseidon2Context::digest_final`)
+    pub fn finalize_bytes(&mut self) -> F {
+        self.buffer[self.buffer_len] = 0x80;
+        for b in &mut self.buffer[self.buffer_len + 1..] {
+            *b = 0;
         }
+        let chunk = self.buffer;
+        self.absorb_chunk(&chunk);
+        self.buffer_len = 0;
+        self.finalize()
     }
-}
 
-impl<P: PoseidonParam, const USE_MONTGOMERY: bool> VPoseidonContext<P, USE_MONTGOMERY> {
-    pub fn new() -> Self {
-        let ctx = VPoseidonContext {
-            state: (0..P::T).map(|_| VBn254Fr::from_ui_scalar(0)).collect(),
-            curr: 0,
-            _params: std::marker::PhantomData,
-        };
-        ctx
+    fn absorb_chunk(&mut self, chunk: &[u8]) {
+        let mut x = F::new();
+        x.set_bytes_big(chunk);
+        self.update(&x);
     }
 
-    pub fn reset(&mut self) {
-        for i in 0..P::T {
-            self.state[i].set_ui_scalar(0);
-        }
-        self.curr = 0;
+    /// Sponge state, for duplex constructions built on the permutation
+    pub fn state(&self) -> &[F] {
+        &self.state
     }
 
-    pub fn update(&mut self, data: &VBn254Fr) {
-        self.state[self.curr].addmod_vec(data);
-
-        self.curr += 1;
-
-        if self.curr >= P::T {
-            self.internal_round_update();
-            self.curr = 0;
-        }
+    /// Mutable sponge state, for duplex constructions built on the permutation
+    pub fn state_mut(&mut self) -> &mut [F] {
+        &mut self.state
     }
 
-    pub fn finalize(&mut self) -> VBn254Fr {
-        if self.curr != 0 {
-            self.internal_round_update();
-            self.curr = 0;
-        }
-        self.state[0].clone()
+    /// Apply the Poseidon permutation to the state
+    pub fn permute(&mut self) {
+        self.internal_round_update();
     }
 
     fn internal_round_update(&mut self) {
         let r_f_half = P::R_F / 2;
         let mut arc_counter = 0;
 
-        // First half full rounds
-        for _ in 0..r_f_half {
-            self.perm(&mut arc_counter, true);
+        // First half full rounds, the last one mixing with the pre-sparse matrix
+        for r in 0..r_f_half {
+            self.full_round(&mut arc_counter, r + 1 == r_f_half);
         }
 
-        // R_P partial rounds
-        for _ in 0..P::R_P {
-            self.perm(&mut arc_counter, false);
+        // R_P partial rounds with one constant and a sparse matrix each
+        for r in 0..P::R_P {
+            self.partial_round(&mut arc_counter, r);
         }
 
         // Last half full rounds
         for _ in 0..r_f_half {
-            self.perm(&mut arc_counter, true);
+            self.full_round(&mut arc_counter, false);
         }
     }
 
-    fn perm(&mut self, arc_counter: &mut usize, full_round: bool) {
+    fn full_round(&mut self, arc_counter: &mut usize, pre_sparse: bool) {
         // Add round constants
         for i in 0..P::T {
-            self.state[i].addmod_constant(&Self::arc()[*arc_counter]);
+            self.state[i].add_constant_assign(&self.arc_const[*arc_counter]);
             *arc_counter += 1;
         }
 
         // Apply S-box (x^5)
-        let num_s_box = if full_round { P::T } else { 1 };
-        for i in 0..num_s_box {
+        for i in 0..P::T {
             self.state[i] = self.pow5(&self.state[i]);
         }
 
-        // mds_const matrix multiplication with Montgomery support
+        // mds_const matrix multiplication
         let old_state = self.state.clone();
+        let matrix = if pre_sparse { &self.pre_sparse_const } else { &self.mds_const };
         for i in 0..P::T {
-            let mut sum = VBn254Fr::new();
-            sum.set_ui_scalar(0);
+            let mut sum = F::from_u32(0);
             for j in 0..P::T {
-                let mut temp = VBn254Fr::new();
-                if USE_MONTGOMERY {
-                    mont_mul_constant(&mut temp, &old_state[j], &Self::mds()[i * P::T + j]);
-                } else {
-                    mulmod_constant(&mut temp, &old_state[j], &Self::mds()[i * P::T + j]);
-                }
-                sum.addmod_vec(&temp);
+                let mut temp = F::new();
+                Self::mul_constant(&mut temp, &old_state[j], &matrix[i * P::T + j]);
+                sum.add_assign_checked(&temp);
             }
             self.state[i] = sum;
         }
     }
 
-    /// Compute x^5 for vectorized field element
-    fn pow5(&self, x: &VBn254Fr) -> VBn254Fr {
-        let mut x2 = VBn254Fr::new();
-        let mut x4 = VBn254Fr::new();
-        let mut result = VBn254Fr::new();
-        mulmod_vec(&mut x2, x, x);          // x^2
-        mulmod_vec(&mut x4, &x2, &x2);      // x^4
-        mulmod_vec(&mut result, &x4, x);    // x^5 = x^4 * x
+    fn partial_round(&mut self, arc_counter: &mut usize, round: usize) {
+        self.state[0].add_constant_assign(&self.arc_const[*arc_counter]);
+        *arc_counter += 1;
+        self.state[0] = self.pow5(&self.state[0]);
+
+        // Sparse matrix: dense first row, first column, identity elsewhere
+        let width = 2 * P::T - 1;
+        let sparse = &self.sparse_const[round * width..(round + 1) * width];
+        let x0 = self.state[0].clone();
+
+        let mut sum = F::new();
+        Self::mul_constant(&mut sum, &x0, &sparse[0]);
+        for (x, k) in self.state.iter().zip(sparse.iter()).skip(1) {
+            let mut temp = F::new();
+            Self::mul_constant(&mut temp, x, k);
+            sum.add_assign_checked(&temp);
+        }
+
+        for i in 1..P::T {
+            let mut temp = F::new();
+            Self::mul_constant(&mut temp, &x0, &sparse[P::T + i - 1]);
+            self.state[i].add_assign_checked(&temp);
+        }
+        self.state[0] = sum;
+    }
+
+    fn mul_constant(out: &mut F, a: &F, k: &F::Constant) {
+        if USE_MONTGOMERY {
+            F::mont_mul_constant(out, a, k);
+        } else {
+            F::mul_constant(out, a, k);
+        }
+    }
+
+    /// Compute x^5 for field element
+    fn pow5(&self, x: &F) -> F {
+        let mut x2 = F::new();
+        let mut x4 = F::new();
+        let mut result = F::new();
+        F::mul_checked(&mut x2, x, x);          // x^2
+        F::mul_checked(&mut x4, &x2, &x2);      // x^4
+        F::mul_checked(&mut result, &x4, x);    // x^5 = x^4 * x
         result
     }
 }
 
+/// Type alias for Poseidon5 context
+pub type PoseidonContext5 = PoseidonContext<PoseidonPermx5Bit254T5>;
+
+/// Type alias for poseidon_t3 context
+pub type PoseidonContext3 = PoseidonContext<PoseidonPermx5Bit254T3>;
+
 /// Type alias for vectorized Poseidon5 context
 pub type VPoseidonContext5<const USE_MONTGOMERY: bool> = VPoseidonContext<PoseidonPermx5Bit254T5, USE_MONTGOMERY>;
 
@@ -415,6 +394,20 @@ pub fn poseidon_t3_hash(inputs: &[Bn254Fr]) -> Bn254Fr {
     ctx.finalize()
 }
 
+/// Convenience function to compute Poseidon hash with t=5 from bytes
+pub fn poseidon_t5_hash_bytes(data: &[u8]) -> Bn254Fr {
+    let mut ctx = PoseidonContext5::new();
+    ctx.update_bytes(data);
+    ctx.finalize_bytes()
+}
+
+/// Convenience function to compute Poseidon hash with t=3 from bytes
+pub fn poseidon_t3_hash_bytes(data: &[u8]) -> Bn254Fr {
+    let mut ctx = PoseidonContext3::new();
+    ctx.update_bytes(data);
+    ctx.finalize_bytes()
+}
+
 
 // ============= Vectorized Convenience Functions =============
 
@@ -438,4 +431,18 @@ pub fn vposeidon_t3_hash<const USE_MONTGOMERY: bool>(inputs: &[VBn254Fr]) -> VBn
     }
 
     ctx.finalize()
-}
\ No newline at end of file
+}
+
+/// Convenience function to compute vectorized Poseidon hash with t=5 from bytes
+pub fn vposeidon_t5_hash_bytes<const USE_MONTGOMERY: bool>(data: &[u8]) -> VBn254Fr {
+    let mut ctx = VPoseidonContext5::<USE_MONTGOMERY>::new();
+    ctx.update_bytes(data);
+    ctx.finalize_bytes()
+}
+
+/// Convenience function to compute vectorized Poseidon hash with t=3 from bytes
+pub fn vposeidon_t3_hash_bytes<const USE_MONTGOMERY: bool>(data: &[u8]) -> VBn254Fr {
+    let mut ctx = VPoseidonContext3::<USE_MONTGOMERY>::new();
+    ctx.update_bytes(data);
+    ctx.finalize_bytes()
+}
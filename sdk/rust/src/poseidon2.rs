@@ -23,18 +23,21 @@
 //! - **Internal MDS Matrix**: [2, 1; 1, 3]
 //! - **Round Structure**: 8 full rounds, 56 partial rounds
 //! - **S-box**: x^5 power function
-//! ```
 //!
 //! ## Performance Considerations
 //!
 //! - **Byte Processing**: Data is processed in 31-byte chunks (field element size)
 //! - **Padding**: Automatic padding applied to incomplete chunks
-
-use crate::bn254fr::{Bn254Fr, addmod_checked, mulmod_checked};
-use crate::vbn254fr::{VBn254Fr, addmod_vec, mulmod_vec};
+//! - **Compression**: [`Poseidon2Compressor`] hashes pairs of elements
+//!   without rebuilding the context, for Merkle-style workloads
+//! - **Packed Bytes**: [`VPoseidon2Context::digest_update_bytes_packed`]
+//!   loads one chunk per lane, hashing `lane_count` byte streams at once
+
+use crate::bn254fr::Bn254Fr;
+use crate::field::LigetronField;
+use crate::vbn254fr::{VBn254Fr, lane_count};
 use crate::poseidon2_constant::{
-    POSEIDON2_T2_RC_STR, POSEIDON2_T2_RC_VEC,
-    POSEIDON2_BN254_RF, POSEIDON2_BN254_RP, POSEIDON2_BN254_T
+    POSEIDON2_T2_RC_STR, POSEIDON2_BN254_RF, POSEIDON2_BN254_RP, POSEIDON2_BN254_T
 };
 
 /// Constants for Poseidon2 BN254 with t=2 (state size 2)
@@ -54,31 +57,37 @@ impl Default for Poseidon2Params {
     }
 }
 
-/// Poseidon2 hash context for BN254 field elements (t=2)
-pub struct Poseidon2Context {
-    state: [Bn254Fr; 2],
+/// Poseidon2 hash context (t=2), generic over the field backend
+pub struct GenericPoseidon2Context<F: LigetronField> {
+    state: [F; 2],
     params: Poseidon2Params,
     buffer: Vec<u8>,
     buffer_len: usize,
-    temp: Bn254Fr,
-    rc: Vec<Bn254Fr>,
+    temp: F,
+    rc: Vec<F::Constant>,
 }
 
-impl Poseidon2Context {
+/// Poseidon2 hash context for BN254 field elements (t=2)
+pub type Poseidon2Context = GenericPoseidon2Context<Bn254Fr>;
+
+/// Poseidon2 hash context for vectorized BN254 field elements (t=2)
+pub type VPoseidon2Context = GenericPoseidon2Context<VBn254Fr>;
+
+impl<F: LigetronField> GenericPoseidon2Context<F> {
     pub fn new() -> Self {
         let rc = POSEIDON2_T2_RC_STR.iter()
-            .map(|&s| Bn254Fr::from_str(s))
+            .map(|&s| F::constant(s))
             .collect();
 
-        let ctx = Poseidon2Context {
+        let ctx = GenericPoseidon2Context {
             state: [
-                Bn254Fr::from_u32(0),
-                Bn254Fr::from_u32(0),
+                F::from_u32(0),
+                F::from_u32(0),
             ],
             params: Poseidon2Params::default(),
             buffer: vec![0u8; 31],
             buffer_len: 0,
-            temp: Bn254Fr::new(),
+            temp: F::new(),
             rc,
         };
         ctx
@@ -86,16 +95,16 @@ impl Poseidon2Context {
 
     // resets the internal context state
     pub fn digest_init(&mut self) {
-        self.state[0] = Bn254Fr::from_u32(0);
-        self.state[1] = Bn254Fr::from_u32(0);
+        self.state[0].set_u32(0);
+        self.state[1].set_u32(0);
         self.buffer_len = 0;
         for i in 0..31 {
             self.buffer[i] = 0;
         }
     }
 
-    pub fn digest_update(&mut self, data: &Bn254Fr) {
-        self.state[0].addmod_checked(data);
+    pub fn digest_update(&mut self, data: &F) {
+        self.state[0].add_assign_checked(data);
         self.permute();
     }
 
@@ -107,7 +116,7 @@ impl Poseidon2Context {
         while remaining >= 31 {
             let chunk = &data[offset..offset + 31];
             self.temp.set_bytes_big(chunk);
-            self.state[0].addmod_checked(&self.temp);
+            self.state[0].add_assign_checked(&self.temp);
             self.permute();
             offset += 31;
             remaining -= 31;
@@ -120,7 +129,7 @@ impl Poseidon2Context {
 
             if self.buffer_len >= 31 {
                 self.temp.set_bytes_big(&self.buffer[..31]);
-                self.state[0].addmod_checked(&self.temp);
+                self.state[0].add_assign_checked(&self.temp);
                 self.permute();
                 self.buffer_len = 0;
             }
@@ -128,7 +137,13 @@ impl Poseidon2Context {
     }
 
     /// Finalize the hash computation and get the result
-    pub fn digest_final(&mut self) -> Bn254Fr {
+    pub fn digest_final(&mut self) -> F {
+        self.absorb_padding();
+        self.state[0].clone()
+    }
+
+    /// Absorb the padded final chunk, leaving the digest in `state[0]`
+    fn absorb_padding(&mut self) {
         self.buffer[self.buffer_len] = 0x80;
         self.buffer_len += 1;
 
@@ -138,10 +153,8 @@ impl Poseidon2Context {
         }
 
         self.temp.set_bytes_big(&self.buffer[..31]);
-        self.state[0].addmod_checked(&self.temp);
+        self.state[0].add_assign_checked(&self.temp);
         self.permute();
-
-        self.state[0].clone()
     }
 
     /// Internal permutation function for Poseidon2
@@ -178,13 +191,13 @@ impl Poseidon2Context {
 
     /// Add round constants to the state (full rounds)
     fn add_round_constants(&mut self, round: usize) {
-        self.state[0].addmod_checked(&self.rc[round * 2]);
-        self.state[1].addmod_checked(&self.rc[round * 2 + 1]);
+        self.state[0].add_constant_assign(&self.rc[round * 2]);
+        self.state[1].add_constant_assign(&self.rc[round * 2 + 1]);
     }
 
     /// Add round constants to the state (partial rounds - only first element)
     fn add_round_constants_partial(&mut self, round: usize) {
-        self.state[0].addmod_checked(&self.rc[round * 2]);
+        self.state[0].add_constant_assign(&self.rc[round * 2]);
     }
 
     /// Apply S-box (x^5) to all elements
@@ -199,12 +212,12 @@ impl Poseidon2Context {
     }
 
     /// Compute x^5 for field element
-    fn pow5(&self, x: &Bn254Fr) -> Bn254Fr {
-        let mut x2 = Bn254Fr::new();
-        let mut result = Bn254Fr::new();
-        mulmod_checked(&mut x2, x, x);          // x^2
-        mulmod_checked(&mut result, &x2, &x2);  // x^4
-        result.mulmod_checked(x);    // x^5 = x^4 * x
+    fn pow5(&self, x: &F) -> F {
+        let mut x2 = F::new();
+        let mut result = F::new();
+        F::mul_checked(&mut x2, x, x);              // x^2
+        F::mul_checked(&mut result, &x2, &x2);      // x^4
+        result.mul_assign_checked(x);               // x^5 = x^4 * x
         result
     }
 
@@ -212,187 +225,105 @@ impl Poseidon2Context {
     /// External MDS = [2, 1]
     ///                [1, 2]
     fn multiply_external_mds(&mut self) {
-        addmod_checked(&mut self.temp, &self.state[0], &self.state[1]);
-        self.state[0].addmod_checked(&self.temp);
-        self.state[1].addmod_checked(&self.temp);
+        F::add_checked(&mut self.temp, &self.state[0], &self.state[1]);
+        self.state[0].add_assign_checked(&self.temp);
+        self.state[1].add_assign_checked(&self.temp);
     }
 
     /// Internal MDS matrix multiplication for t=2
     /// Internal MDS = [2, 1]
     ///                [1, 3]
     fn multiply_internal_mds(&mut self) {
-        addmod_checked(&mut self.temp, &self.state[0], &self.state[1]);
-        self.state[0].addmod_checked(&self.temp);
-        self.temp.addmod_checked(&self.state[1]);
-        self.state[1].addmod_checked(&self.temp);
+        F::add_checked(&mut self.temp, &self.state[0], &self.state[1]);
+        self.state[0].add_assign_checked(&self.temp);
+        self.temp.add_assign_checked(&self.state[1]);
+        self.state[1].add_assign_checked(&self.temp);
     }
 }
 
-/// Poseidon2 hash context for vectorized BN254 field elements (t=2)
-pub struct VPoseidon2Context {
-    state: [VBn254Fr; 2],
-    params: Poseidon2Params,
-    buffer: Vec<u8>,
-    buffer_len: usize,
-    temp: VBn254Fr,
-}
-
-impl VPoseidon2Context {
-    pub fn new() -> Self {
-        let ctx = VPoseidon2Context {
-            state: [
-                VBn254Fr::from_ui_scalar(0),
-                VBn254Fr::from_ui_scalar(0),
-            ],
-            params: Poseidon2Params::default(),
-            buffer: vec![0u8; 31],
-            buffer_len: 0,
-            temp: VBn254Fr::new(),
-        };
-        ctx
-    }
-
-    // resets the internal context state
-    pub fn digest_init(&mut self) {
-        self.state[0].set_ui_scalar(0);
-        self.state[1].set_ui_scalar(0);
-        self.buffer_len = 0;
-        for i in 0..31 {
-            self.buffer[i] = 0;
-        }
-    }
-
-    pub fn digest_update(&mut self, data: &VBn254Fr) {
-        // Add the data to state[0] and perform permutation
-        self.state[0].addmod_vec(data);
-        self.permute();
-    }
-
-    pub fn digest_update_bytes(&mut self, data: &[u8]) {
-        let mut offset = 0;
-        let mut remaining = data.len();
-
-        // Process 31-byte chunks
-        while remaining >= 31 {
-            let chunk = &data[offset..offset + 31];
-            self.temp.set_bytes_scalar(chunk);
-            self.state[0].addmod_vec(&self.temp);
+impl GenericPoseidon2Context<VBn254Fr> {
+    /// Absorb bytes with one 31-byte chunk per lane.
+    ///
+    /// Each block of `31·lane_count` bytes is loaded with a single
+    /// `set_bytes` call, lane `k` taking chunk `k` of the block, and costs
+    /// one vector permutation. Lane `k` thus hashes the stream of chunks
+    /// `k, k + lane_count, k + 2·lane_count, ...`, and after
+    /// [`digest_final`](Self::digest_final) holds the same digest
+    /// [`poseidon2_hash_bytes`] gives for that stream.
+    ///
+    /// Unlike [`digest_update_bytes`](Self::digest_update_bytes), which
+    /// hashes the same bytes in every lane, this consumes whole blocks only:
+    /// `data.len()` must be a multiple of `31·lane_count` and no unaligned
+    /// bytes may be pending.
+    pub fn digest_update_bytes_packed(&mut self, data: &[u8]) {
+        let lanes = lane_count();
+        let block = 31 * lanes;
+        assert!(data.len().is_multiple_of(block),
+                "digest_update_bytes_packed: length must be a multiple of 31 * lane_count");
+        assert_eq!(self.buffer_len, 0, "digest_update_bytes_packed: unaligned bytes pending");
+
+        for chunk in data.chunks(block) {
+            self.temp.set_bytes(chunk, lanes as u64);
+            self.state[0].add_assign_checked(&self.temp);
             self.permute();
-            offset += 31;
-            remaining -= 31;
-        }
-
-        // Handle remaining bytes
-        for &byte in &data[offset..] {
-            self.buffer[self.buffer_len] = byte;
-            self.buffer_len += 1;
-
-            if self.buffer_len >= 31 {
-                self.temp.set_bytes_scalar(&self.buffer[..31]);
-                self.state[0].addmod_vec(&self.temp);
-                self.permute();
-                self.buffer_len = 0;
-            }
         }
     }
+}
 
-    /// Finalize the hash computation and get the result
-    pub fn digest_final(&mut self) -> VBn254Fr {
-        self.buffer[self.buffer_len] = 0x80;
-        self.buffer_len += 1;
+/// Two-to-one Poseidon2 compression reusing one context, generic over the
+/// field backend.
+///
+/// [`compress_into`](Self::compress_into) gives the same digest as
+/// [`poseidon2_hash`] of `[a, b]`, but the round constants, state and temp
+/// are set up once in [`new`](Self::new) rather than on every call, which
+/// dominates the cost of hashing many small inputs such as Merkle nodes.
+pub struct GenericPoseidon2Compressor<F: LigetronField> {
+    ctx: GenericPoseidon2Context<F>,
+}
 
-        while self.buffer_len < 31 {
-            self.buffer[self.buffer_len] = 0;
-            self.buffer_len += 1;
-        }
+/// Poseidon2 compressor for BN254 field elements
+pub type Poseidon2Compressor = GenericPoseidon2Compressor<Bn254Fr>;
 
-        self.temp.set_bytes_scalar(&self.buffer[..31]);
-        self.state[0].addmod_vec(&self.temp);
-        self.permute();
+/// Poseidon2 compressor for vectorized BN254 field elements
+pub type VPoseidon2Compressor = GenericPoseidon2Compressor<VBn254Fr>;
 
-        self.state[0].clone()
+impl<F: LigetronField> Default for GenericPoseidon2Compressor<F> {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    fn permute(&mut self) {
-        // External MDS multiplication
-        self.multiply_external_mds();
-
-        let mut round = 0;
-
-        // First half of full rounds
-        for _ in 0..4 {
-            self.add_round_constants(round);
-            self.sbox_full();
-            self.multiply_external_mds();
-            round += 1;
-        }
-
-        // Partial rounds
-        for _ in 0..self.params.r_p {
-            self.add_round_constants_partial(round);
-            self.sbox_partial();
-            self.multiply_internal_mds();
-            round += 1;
-        }
-
-        // Second half of full rounds
-        for _ in 0..4 {
-            self.add_round_constants(round);
-            self.sbox_full();
-            self.multiply_external_mds();
-            round += 1;
+impl<F: LigetronField> GenericPoseidon2Compressor<F> {
+    pub fn new() -> Self {
+        GenericPoseidon2Compressor {
+            ctx: GenericPoseidon2Context::new(),
         }
     }
 
-    fn add_round_constants(&mut self, round: usize) {
-        self.state[0].addmod_vec(&POSEIDON2_T2_RC_VEC[round * 2]);
-        self.state[1].addmod_vec(&POSEIDON2_T2_RC_VEC[round * 2 + 1]);
+    /// Write the Poseidon2 hash of `a` and `b` to `out`
+    pub fn compress_into(&mut self, out: &mut F, a: &F, b: &F) {
+        let ctx = &mut self.ctx;
+        ctx.digest_init();
+        ctx.digest_update(a);
+        ctx.digest_update(b);
+        ctx.absorb_padding();
+
+        // Hand the digest's handle to `out` instead of copying it;
+        // the next `digest_init` resets the handle taken in exchange
+        std::mem::swap(out, &mut ctx.state[0]);
     }
 
-    fn add_round_constants_partial(&mut self, round: usize) {
-        self.state[0].addmod_vec(&POSEIDON2_T2_RC_VEC[round * 2]);
-    }
-
-    /// Apply S-box (x^5) to all elements
-    fn sbox_full(&mut self) {
-        self.state[0] = self.pow5(&self.state[0]);
-        self.state[1] = self.pow5(&self.state[1]);
-    }
-
-    /// Apply S-box (x^5) to first element only
-    fn sbox_partial(&mut self) {
-        self.state[0] = self.pow5(&self.state[0]);
-    }
-
-    /// Compute x^5 for vectorized field element
-    fn pow5(&self, x: &VBn254Fr) -> VBn254Fr {
-        let mut result = VBn254Fr::new();
-        let mut square = VBn254Fr::new();
-        mulmod_vec(&mut square, x, x); // square = x^2
-        mulmod_vec(&mut result, &square, &square); // result = x^4
-        result.mulmod_vec(&x); // result = x^5
-        result
-    }
-
-    /// External MDS matrix multiplication for t=2
-    fn multiply_external_mds(&mut self) {
-        addmod_vec(&mut self.temp, &self.state[0], &self.state[1]);
-        self.state[0].addmod_vec(&self.temp);
-        self.state[1].addmod_vec(&self.temp);
-    }
-
-    /// Internal MDS matrix multiplication for t=2
-    fn multiply_internal_mds(&mut self) {
-        addmod_vec(&mut self.temp, &self.state[0], &self.state[1]);
-        self.state[0].addmod_vec(&self.temp);
-        self.temp.addmod_vec(&self.state[1]);
-        self.state[1].addmod_vec(&self.temp);
+    /// Poseidon2 hash of `a` and `b`
+    pub fn compress(&mut self, a: &F, b: &F) -> F {
+        let mut out = F::new();
+        self.compress_into(&mut out, a, b);
+        out
     }
 }
 
-/// Convenience function to compute Poseidon2 hash from field elements
-pub fn poseidon2_hash(inputs: &[Bn254Fr]) -> Bn254Fr {
-    let mut ctx = Poseidon2Context::new();
+/// Compute a Poseidon2 hash of field elements on either backend
+pub fn poseidon2_hash_generic<F: LigetronField>(inputs: &[F]) -> F {
+    let mut ctx = GenericPoseidon2Context::<F>::new();
 
     for input in inputs {
         ctx.digest_update(input);
@@ -401,6 +332,11 @@ pub fn poseidon2_hash(inputs: &[Bn254Fr]) -> Bn254Fr {
     ctx.digest_final()
 }
 
+/// Convenience function to compute Poseidon2 hash from field elements
+pub fn poseidon2_hash(inputs: &[Bn254Fr]) -> Bn254Fr {
+    poseidon2_hash_generic(inputs)
+}
+
 /// Convenience function to compute Poseidon2 hash from bytes
 pub fn poseidon2_hash_bytes(data: &[u8]) -> Bn254Fr {
     let mut ctx = Poseidon2Context::new();
@@ -410,13 +346,7 @@ pub fn poseidon2_hash_bytes(data: &[u8]) -> Bn254Fr {
 
 /// Convenience function to compute vectorized Poseidon2 hash from field elements
 pub fn vposeidon2_hash(inputs: &[VBn254Fr]) -> VBn254Fr {
-    let mut ctx = VPoseidon2Context::new();
-
-    for input in inputs {
-        ctx.digest_update(input);
-    }
-
-    ctx.digest_final()
+    poseidon2_hash_generic(inputs)
 }
 
 /// Convenience function to compute vectorized Poseidon2 hash from bytes
@@ -424,4 +354,4 @@ pub fn vposeidon2_hash_bytes(data: &[u8]) -> VBn254Fr {
     let mut ctx = VPoseidon2Context::new();
     ctx.digest_update_bytes(data);
     ctx.digest_final()
-}
\ No newline at end of file
+}
@@ -14,10 +14,6 @@
  * limitations under the License.
  */
 
-use crate::vbn254fr::VBn254Fr;
-use lazy_static::lazy_static;
-
-
 /// Poseidon2 BN254 parameters
 pub const POSEIDON2_BN254_RF: usize = 8;  // Full rounds
 pub const POSEIDON2_BN254_RP: usize = 56; // Partial rounds
@@ -161,17 +157,3 @@ pub const POSEIDON2_T2_RC_STR: &[&str; POSEIDON2_BN254_T2_RC_LEN] = &[
     "0x0ab4dfe0c2742cde44901031487964ed9b8f4b850405c10ca9ff23859572c8c6",
     "0x0e32db320a044e3197f45f7649a19675ef5eedfea546dea9251de39f9639779a",
 ];
-
-lazy_static! {
-    /// Poseidon2 round constants for vectorized BN254 field elements (t=2)
-    /// Contains 128 constants (64 rounds × 2 constants per round)
-    pub static ref POSEIDON2_T2_RC_VEC: Vec<VBn254Fr> = {
-        POSEIDON2_T2_RC_STR.iter()
-            .map(|&s| {
-                let mut v = VBn254Fr::new();
-                v.set_str_scalar(s, 0);
-                v
-            })
-            .collect()
-    };
-}
\ No newline at end of file
@@ -0,0 +1,492 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Poseidon Parameter Generation
+//!
+//! Derives Poseidon round numbers, round constants and MDS matrices over
+//! the BN254 scalar field following the reference generation script
+//! (`generate_parameters_grain.sage`):
+//!
+//! - Round numbers are the cheapest `(R_F, R_P)` satisfying the statistical,
+//!   interpolation and Groebner-basis bounds for the security level, with
+//!   the standard margin (`R_F + 2`, `R_P * 1.075`). `R_P` is then rounded
+//!   up to a multiple of `t`, as in circomlib.
+//! - Round constants are drawn from the Grain LFSR with rejection sampling.
+//! - The MDS matrix is the Cauchy matrix `1 / (x_i + y_j)` over the next
+//!   `2t` Grain outputs.
+//!
+//! This reproduces the shipped `t = 3` and `t = 5` tables. The reference
+//! script additionally tests the MDS matrix for infinitely long invariant
+//! subspace trails and redraws on failure; that test is not implemented
+//! here, so matrices for new widths should be checked with the reference
+//! tooling before deployment.
+//!
+//! For the partial rounds it also derives the equivalent optimized form
+//! (Poseidon paper, appendix B): round constants are folded so a partial
+//! round adds a single constant, and the MDS matrix is factored into one
+//! dense "pre-sparse" matrix followed by `R_P` sparse matrices of `2t - 1`
+//! non-trivial entries each.
+//!
+//! The module only depends on `std` so the build script can include it.
+
+/// BN254 scalar field modulus, little-endian 64-bit limbs
+const MODULUS: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// Bit length of the modulus
+const FIELD_BITS: usize = 254;
+
+type U256 = [u64; 4];
+
+/// Generated Poseidon parameters; constants are 0x-prefixed hex strings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonParams {
+    pub t: usize,
+    pub alpha: u64,
+    pub r_f: usize,
+    pub r_p: usize,
+    /// `(R_F + R_P) * t` round constants, round-major
+    pub round_constants: Vec<String>,
+    /// Row-major `t x t` MDS matrix
+    pub mds: Vec<String>,
+    /// The MDS matrix in Montgomery form (`m * 2^256 mod p`)
+    pub mds_montgomery: Vec<String>,
+    /// Round constants of the optimized permutation: `t` per full round and
+    /// one per partial round, `R_F * t + R_P` in total
+    pub round_constants_optimized: Vec<String>,
+    /// Row-major `t x t` matrix of the last full round before the partial
+    /// rounds in the optimized permutation
+    pub pre_sparse_mds: Vec<String>,
+    /// The pre-sparse matrix in Montgomery form
+    pub pre_sparse_mds_montgomery: Vec<String>,
+    /// Sparse partial-round matrices, `2t - 1` entries per round: the first
+    /// row followed by the first column below the diagonal
+    pub sparse_mds: Vec<String>,
+    /// The sparse matrices in Montgomery form
+    pub sparse_mds_montgomery: Vec<String>,
+}
+
+// ============= 256-bit modular arithmetic =============
+
+fn geq(a: &U256, b: &U256) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+fn sub_no_borrow(a: &U256, b: &U256) -> U256 {
+    let mut out = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        out[i] = d;
+        borrow = b1 || b2;
+    }
+    out
+}
+
+fn add_mod(a: &U256, b: &U256) -> U256 {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        out[i] = s;
+        carry = c1 || c2;
+    }
+    // a, b < p < 2^254, so the sum never exceeds 256 bits
+    if geq(&out, &MODULUS) {
+        out = sub_no_borrow(&out, &MODULUS);
+    }
+    out
+}
+
+fn sub_mod(a: &U256, b: &U256) -> U256 {
+    add_mod(a, &sub_no_borrow(&MODULUS, b))
+}
+
+/// -p^-1 mod 2^64
+fn mont_inv() -> u64 {
+    let mut inv = 1u64;
+    for _ in 0..6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(MODULUS[0].wrapping_mul(inv)));
+    }
+    inv.wrapping_neg()
+}
+
+/// Montgomery product a * b * 2^-256 mod p
+fn mont_mul(a: &U256, b: &U256) -> U256 {
+    let inv = mont_inv();
+    let mut t = [0u64; 6];
+    for &bi in b.iter() {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let v = t[j] as u128 + (a[j] as u128) * (bi as u128) + carry;
+            t[j] = v as u64;
+            carry = v >> 64;
+        }
+        let v = t[4] as u128 + carry;
+        t[4] = v as u64;
+        t[5] = (v >> 64) as u64;
+
+        let m = t[0].wrapping_mul(inv);
+        let mut carry = ((t[0] as u128) + (m as u128) * (MODULUS[0] as u128)) >> 64;
+        for j in 1..4 {
+            let v = t[j] as u128 + (m as u128) * (MODULUS[j] as u128) + carry;
+            t[j - 1] = v as u64;
+            carry = v >> 64;
+        }
+        let v = t[4] as u128 + carry;
+        t[3] = v as u64;
+        t[4] = t[5] + ((v >> 64) as u64);
+    }
+    let mut out = [t[0], t[1], t[2], t[3]];
+    if t[4] != 0 || geq(&out, &MODULUS) {
+        out = sub_no_borrow(&out, &MODULUS);
+    }
+    out
+}
+
+/// 2^512 mod p, to move values into Montgomery form
+fn r_squared() -> U256 {
+    let mut r = [1u64, 0, 0, 0];
+    for _ in 0..512 {
+        r = add_mod(&r, &r);
+    }
+    r
+}
+
+/// a * b mod p, given `r2 = 2^512 mod p`
+fn mul_mod(a: &U256, b: &U256, r2: &U256) -> U256 {
+    mont_mul(&mont_mul(a, b), r2)
+}
+
+fn to_mont(a: &U256) -> U256 {
+    mont_mul(a, &r_squared())
+}
+
+fn from_mont(a: &U256) -> U256 {
+    mont_mul(a, &[1, 0, 0, 0])
+}
+
+/// a^-1 mod p by Fermat's little theorem
+fn inv_mod(a: &U256) -> U256 {
+    let exp = sub_no_borrow(&MODULUS, &[2, 0, 0, 0]);
+    let base = to_mont(a);
+    let mut acc = to_mont(&[1, 0, 0, 0]);
+    for i in (0..256).rev() {
+        acc = mont_mul(&acc, &acc);
+        if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+            acc = mont_mul(&acc, &base);
+        }
+    }
+    from_mont(&acc)
+}
+
+fn to_hex(a: &U256) -> String {
+    format!("0x{:016x}{:016x}{:016x}{:016x}", a[3], a[2], a[1], a[0])
+}
+
+// ============= Matrices =============
+
+type Matrix = Vec<Vec<U256>>;
+
+const ZERO: U256 = [0; 4];
+const ONE: U256 = [1, 0, 0, 0];
+
+fn mat_mul(a: &Matrix, b: &Matrix, r2: &U256) -> Matrix {
+    (0..a.len())
+        .map(|i| (0..b[0].len())
+            .map(|j| (0..b.len()).fold(ZERO, |acc, k| add_mod(&acc, &mul_mod(&a[i][k], &b[k][j], r2))))
+            .collect())
+        .collect()
+}
+
+/// Inverse by Gauss-Jordan elimination
+fn mat_inv(a: &Matrix, r2: &U256) -> Matrix {
+    let n = a.len();
+    let mut m: Matrix = a.iter().enumerate()
+        .map(|(i, row)| {
+            let mut row = row.clone();
+            row.extend((0..n).map(|j| if i == j { ONE } else { ZERO }));
+            row
+        })
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| m[r][col] != ZERO)
+            .expect("generate_params: singular MDS submatrix");
+        m.swap(col, pivot);
+        let inv = inv_mod(&m[col][col]);
+        for v in m[col].iter_mut() {
+            *v = mul_mod(v, &inv, r2);
+        }
+        let pivot_row = m[col].clone();
+        for (r, row) in m.iter_mut().enumerate() {
+            if r != col && row[col] != ZERO {
+                let f = row[col];
+                for (v, p) in row.iter_mut().zip(pivot_row.iter()) {
+                    *v = sub_mod(v, &mul_mod(&f, p, r2));
+                }
+            }
+        }
+    }
+    m.into_iter().map(|row| row[n..].to_vec()).collect()
+}
+
+/// Optimized partial rounds: returns the folded round constants, the
+/// pre-sparse matrix and the sparse matrices
+fn optimize_partial_rounds(
+    t: usize, r_f: usize, r_p: usize, c: &[U256], mds: &Matrix, r2: &U256,
+) -> (Vec<U256>, Matrix, Vec<Vec<U256>>) {
+    let half = r_f / 2;
+
+    // Partial round r adds c_r before an S-box on the first element only,
+    // so c_r[1..] commutes with the S-box and is carried through the MDS
+    // matrix into the next round's constants.
+    let mut consts = c[..half * t].to_vec();
+    let mut carry = vec![ZERO; t];
+    for r in 0..r_p {
+        let base = (half + r) * t;
+        let e: Vec<U256> = (0..t).map(|i| add_mod(&c[base + i], &carry[i])).collect();
+        consts.push(e[0]);
+        carry = (0..t)
+            .map(|i| (1..t).fold(ZERO, |acc, j| add_mod(&acc, &mul_mod(&mds[i][j], &e[j], r2))))
+            .collect();
+    }
+    let base = (half + r_p) * t;
+    consts.extend((0..t).map(|i| add_mod(&c[base + i], &carry[i])));
+    consts.extend_from_slice(&c[base + t..]);
+
+    // Factor each partial round matrix A = S * D with D = diag(1, A[1.., 1..])
+    // and S sparse. D commutes with the partial S-box and is pushed into the
+    // previous round, whose matrix becomes D * M; the first D lands in the
+    // last full round before the partial rounds.
+    let mut a = mds.clone();
+    let mut sparse = vec![Vec::new(); r_p];
+    for r in (0..r_p).rev() {
+        let hat: Matrix = a[1..].iter().map(|row| row[1..].to_vec()).collect();
+        let hat_inv = mat_inv(&hat, r2);
+
+        // First row (a00, a01^T * hat^-1), then first column a10
+        let mut s = vec![a[0][0]];
+        s.extend((0..t - 1).map(|j| {
+            (0..t - 1).fold(ZERO, |acc, i| add_mod(&acc, &mul_mod(&a[0][i + 1], &hat_inv[i][j], r2)))
+        }));
+        s.extend((1..t).map(|i| a[i][0]));
+        sparse[r] = s;
+
+        let mut d = vec![vec![ZERO; t]; t];
+        d[0][0] = ONE;
+        for i in 1..t {
+            d[i][1..].copy_from_slice(&hat[i - 1]);
+        }
+        a = mat_mul(&d, mds, r2);
+    }
+
+    (consts, a, sparse)
+}
+
+// ============= Grain LFSR =============
+
+struct Grain {
+    state: [bool; 80],
+}
+
+impl Grain {
+    fn new(t: usize, r_f: usize, r_p: usize) -> Self {
+        let mut bits = Vec::with_capacity(80);
+        let mut push = |value: u64, len: usize| {
+            for i in (0..len).rev() {
+                bits.push((value >> i) & 1 == 1);
+            }
+        };
+        push(1, 2);                     // prime field
+        push(0, 4);                     // x^alpha S-box
+        push(FIELD_BITS as u64, 12);
+        push(t as u64, 12);
+        push(r_f as u64, 10);
+        push(r_p as u64, 10);
+        push((1 << 30) - 1, 30);
+
+        let mut grain = Grain { state: [false; 80] };
+        grain.state.copy_from_slice(&bits);
+        for _ in 0..160 {
+            grain.next_bit();
+        }
+        grain
+    }
+
+    fn next_bit(&mut self) -> bool {
+        let s = &self.state;
+        let new = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.copy_within(1.., 0);
+        self.state[79] = new;
+        new
+    }
+
+    /// Self-shrinking output: keep the second bit of each pair whose first
+    /// bit is set
+    fn random_bit(&mut self) -> bool {
+        loop {
+            let b1 = self.next_bit();
+            let b2 = self.next_bit();
+            if b1 {
+                return b2;
+            }
+        }
+    }
+
+    /// FIELD_BITS random bits as a big-endian integer
+    fn random_bits(&mut self) -> U256 {
+        let mut out = [0u64; 4];
+        for i in (0..FIELD_BITS).rev() {
+            if self.random_bit() {
+                out[i / 64] |= 1 << (i % 64);
+            }
+        }
+        out
+    }
+
+    /// Uniform field element by rejection sampling
+    fn field_element(&mut self) -> U256 {
+        loop {
+            let v = self.random_bits();
+            if !geq(&v, &MODULUS) {
+                return v;
+            }
+        }
+    }
+
+    /// Field element reduced modulo p (used for the MDS matrix)
+    fn field_element_reduced(&mut self) -> U256 {
+        let v = self.random_bits();
+        if geq(&v, &MODULUS) { sub_no_borrow(&v, &MODULUS) } else { v }
+    }
+}
+
+// ============= Round numbers =============
+
+fn log2_binomial(n: f64, k: f64) -> f64 {
+    let mut acc = 0.0;
+    let mut i = 0.0;
+    while i < k {
+        acc += (n - i).log2() - (i + 1.0).log2();
+        i += 1.0;
+    }
+    acc
+}
+
+/// Whether (R_F, R_P) meets every attack bound at `security` bits
+fn rounds_secure(t: usize, r_f: usize, r_p: usize, alpha: u64, security: usize) -> bool {
+    let (tf, rf, rp, m) = (t as f64, r_f as f64, r_p as f64, security as f64);
+    let a = alpha as f64;
+    let log_p = FIELD_BITS as f64;
+    let log_a_2 = 1.0 / a.log2();
+
+    let statistical = if m <= ((log_p - (a - 1.0) / 2.0).floor()) * (tf + 1.0) { 6.0 } else { 10.0 };
+    let interpolation = 1.0 + (log_a_2 * m.min(log_p)).ceil() + (tf.ln() / a.ln()).ceil() - rp;
+    let groebner_1 = log_a_2 * m.min(log_p) - rp;
+    let groebner_2 = tf - 1.0 + log_a_2 * (m / (tf + 1.0)).min(log_p / 2.0) - rp;
+    let groebner_3 = (tf - 2.0 + m / (2.0 * a.log2()) - rp) / (tf - 1.0);
+    let r_f_max = [statistical, interpolation, groebner_1, groebner_2, groebner_3]
+        .iter()
+        .map(|v| v.ceil())
+        .fold(f64::MIN, f64::max);
+
+    // Bound of https://eprint.iacr.org/2023/537
+    let r = (tf / 3.0).floor();
+    let over = (rf - 1.0) * tf + rp + r + r * (rf / 2.0) + rp + a;
+    let under = r * (rf / 2.0) + rp + a;
+    let cost_gb4 = (2.0 * log2_binomial(over, under)).ceil();
+
+    rf >= r_f_max && cost_gb4 >= m
+}
+
+/// Cheapest secure round numbers, including the security margin
+fn round_numbers(t: usize, alpha: u64, security: usize) -> (usize, usize) {
+    let mut best: Option<(usize, usize, usize)> = None;
+    for r_p in 1..500 {
+        for r_f in (4..100).step_by(2) {
+            if rounds_secure(t, r_f, r_p, alpha, security) {
+                let r_f = r_f + 2;
+                let r_p = (r_p as f64 * 1.075).ceil() as usize;
+                let cost = t * r_f + r_p;
+                if best.is_none_or(|(c, f, _)| cost < c || (cost == c && r_f < f)) {
+                    best = Some((cost, r_f, r_p));
+                }
+                break;
+            }
+        }
+    }
+    let (_, r_f, r_p) = best.expect("no secure Poseidon round numbers");
+    (r_f, r_p.div_ceil(t) * t)
+}
+
+/// Generate Poseidon parameters for state width `t`, S-box `x^alpha` and
+/// `security` bits over the BN254 scalar field
+pub fn generate_params(t: usize, alpha: u64, security: usize) -> PoseidonParams {
+    assert!(t >= 2, "generate_params: state width must be at least 2");
+    assert!(alpha >= 3 && alpha % 2 == 1, "generate_params: alpha must be an odd integer >= 3");
+
+    let (r_f, r_p) = round_numbers(t, alpha, security);
+    let mut grain = Grain::new(t, r_f, r_p);
+
+    let round_constants: Vec<U256> = (0..(r_f + r_p) * t)
+        .map(|_| grain.field_element())
+        .collect();
+
+    let xy: Vec<U256> = (0..2 * t).map(|_| grain.field_element_reduced()).collect();
+    for i in 0..xy.len() {
+        assert!(!xy[i + 1..].contains(&xy[i]), "generate_params: repeated MDS seed");
+    }
+    let r2 = r_squared();
+    let matrix: Matrix = (0..t)
+        .map(|i| (0..t).map(|j| inv_mod(&add_mod(&xy[i], &xy[t + j]))).collect())
+        .collect();
+
+    let (consts, pre_sparse, sparse) = optimize_partial_rounds(t, r_f, r_p, &round_constants, &matrix, &r2);
+
+    let hex = |values: &[U256]| values.iter().map(to_hex).collect::<Vec<_>>();
+    // mont_mul(m, 2^512) = m * 2^256
+    let hex_montgomery = |values: &[U256]| values.iter().map(|m| to_hex(&mont_mul(m, &r2))).collect::<Vec<_>>();
+    let mds = matrix.concat();
+    let pre_sparse = pre_sparse.concat();
+    let sparse = sparse.concat();
+
+    PoseidonParams {
+        t,
+        alpha,
+        r_f,
+        r_p,
+        round_constants: hex(&round_constants),
+        mds: hex(&mds),
+        mds_montgomery: hex_montgomery(&mds),
+        round_constants_optimized: hex(&consts),
+        pre_sparse_mds: hex(&pre_sparse),
+        pre_sparse_mds_montgomery: hex_montgomery(&pre_sparse),
+        sparse_mds: hex(&sparse),
+        sparse_mds_montgomery: hex_montgomery(&sparse),
+    }
+}
@@ -0,0 +1,28 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Range Checks for Ligetron
+//!
+//! [`assert_in_range`] bit-decomposes a value, costing one boolean
+//! constraint per bit. The VM has no lookup tables, so there is no cheaper
+//! chunked variant.
+
+use crate::bn254fr::Bn254Fr;
+
+/// Assert that `x < 2^bits` by bit decomposition
+pub fn assert_in_range(x: &Bn254Fr, bits: usize) {
+    let _ = x.to_bits(bits);
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Prefix Sums and Cumulative Operations for Ligetron
+//!
+//! Constrained scans over slices of any [`LigetronField`]. With
+//! [`Bn254Fr`](crate::bn254fr::Bn254Fr) a slice is one sequence; with
+//! [`VBn254Fr`](crate::vbn254fr::VBn254Fr) every lane is scanned
+//! independently along the slice, since vectors have no cross-lane
+//! operations.
+//!
+//! - [`prefix_sum`] / [`prefix_product`]: running sums and products
+//! - [`segmented_prefix_sum`]: running sums restarted at private segment
+//!   start flags
+//! - [`segment_sums`]: totals of consecutive segments of public lengths
+
+use crate::field::LigetronField;
+
+/// Running sums: `out[i] = values[0] + ... + values[i]`
+pub fn prefix_sum<F: LigetronField>(values: &[F]) -> Vec<F> {
+    let mut out: Vec<F> = Vec::with_capacity(values.len());
+    for v in values {
+        let next = match out.last() {
+            Some(acc) => {
+                let mut s = F::new();
+                F::add_checked(&mut s, acc, v);
+                s
+            }
+            None => v.clone(),
+        };
+        out.push(next);
+    }
+    out
+}
+
+/// Running products: `out[i] = values[0] · ... · values[i]`
+pub fn prefix_product<F: LigetronField>(values: &[F]) -> Vec<F> {
+    let mut out: Vec<F> = Vec::with_capacity(values.len());
+    for v in values {
+        let next = match out.last() {
+            Some(acc) => {
+                let mut p = F::new();
+                F::mul_checked(&mut p, acc, v);
+                p
+            }
+            None => v.clone(),
+        };
+        out.push(next);
+    }
+    out
+}
+
+/// Running sums that restart wherever `starts[i] == 1`:
+/// `out[i] = values[i]` if `starts[i]`, else `out[i-1] + values[i]`.
+/// The flags are constrained to be boolean.
+pub fn segmented_prefix_sum<F: LigetronField>(values: &[F], starts: &[F]) -> Vec<F> {
+    assert_eq!(values.len(), starts.len(), "segmented_prefix_sum: length mismatch");
+
+    let zero = F::from_u32(0);
+    let mut acc = F::from_u32(0);
+    let mut out = Vec::with_capacity(values.len());
+    for (v, s) in values.iter().zip(starts) {
+        assert_boolean(s);
+        let mut kept = F::new();
+        F::mux(&mut kept, s, &acc, &zero);
+        F::add_checked(&mut acc, &kept, v);
+        out.push(acc.clone());
+    }
+    out
+}
+
+/// Totals of consecutive segments with the given public lengths, which
+/// must add up to `values.len()`
+pub fn segment_sums<F: LigetronField>(values: &[F], lengths: &[usize]) -> Vec<F> {
+    assert_eq!(lengths.iter().sum::<usize>(), values.len(), "segment_sums: lengths do not cover values");
+
+    let mut rest = values;
+    lengths.iter().map(|&len| {
+        let (segment, tail) = rest.split_at(len);
+        rest = tail;
+        let mut acc = F::from_u32(0);
+        for v in segment {
+            acc.add_assign_checked(v);
+        }
+        acc
+    }).collect()
+}
+
+/// Constrain `b·b == b`
+fn assert_boolean<F: LigetronField>(b: &F) {
+    let mut sq = F::new();
+    F::mul_checked(&mut sq, b, b);
+    F::assert_equal(&sq, b);
+}
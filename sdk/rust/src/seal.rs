@@ -0,0 +1,83 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Public-Key Sealed Boxes for Ligetron
+//!
+//! KEM/DEM encryption to a Baby Jubjub public key, entirely in-circuit:
+//!
+//! 1. KEM: an ephemeral key `e` gives `E = e·B` and the [`ecdh`] shared
+//!    secret `s` with the recipient key `PK`.
+//! 2. The DEM key is `Poseidon2(SEAL_DOMAIN, s, E.x, E.y)`, binding the
+//!    ephemeral key into the key schedule.
+//! 3. DEM: the plaintext is encrypted with the Poseidon duplex of [`enc`]
+//!    (sponge stream cipher plus tag) under that key and a zero nonce.
+//!
+//! The nonce can be fixed because each DEM key is used once: the ephemeral
+//! scalar must be fresh and uniformly random for every box, so it is taken
+//! as an argument like the blinding of a commitment. Opening validates the
+//! ephemeral key and asserts the tag.
+
+use crate::babyjubjub::JubjubPoint;
+use crate::bn254fr::Bn254Fr;
+use crate::ecdh;
+use crate::enc::{self, Ciphertext};
+use crate::poseidon2::poseidon2_hash;
+
+/// Domain separation tag for DEM key derivation
+pub const SEAL_DOMAIN: &[u8] = b"ligetron.seal.v1";
+
+/// Plaintext sealed to a public key
+#[derive(Clone)]
+pub struct SealedBox {
+    /// Ephemeral public key `e·B`
+    pub ephemeral: JubjubPoint,
+    /// DEM ciphertext and tag
+    pub ciphertext: Ciphertext,
+}
+
+/// DEM key from the shared secret and the ephemeral public key
+fn dem_key(shared: Bn254Fr, ephemeral: &JubjubPoint) -> Bn254Fr {
+    let mut tag = Bn254Fr::new();
+    tag.set_bytes_big(SEAL_DOMAIN);
+    poseidon2_hash(&[tag, shared, ephemeral.x.clone(), ephemeral.y.clone()])
+}
+
+/// Seal `plaintext` to `recipient` with associated data `ad`.
+/// `ephemeral_scalar` must be fresh randomness, never reused.
+pub fn seal_with_ad(recipient: &JubjubPoint, ephemeral_scalar: &Bn254Fr,
+                    ad: &[Bn254Fr], plaintext: &[Bn254Fr]) -> SealedBox {
+    let ephemeral = ecdh::public_key(ephemeral_scalar);
+    let key = dem_key(ecdh::shared_secret(ephemeral_scalar, recipient), &ephemeral);
+    let ciphertext = enc::encrypt(&key, &Bn254Fr::from_u32(0), ad, plaintext);
+    SealedBox { ephemeral, ciphertext }
+}
+
+/// Open a box sealed with [`seal_with_ad`]; asserts that the tag verifies
+pub fn open_with_ad(private_scalar: &Bn254Fr, sealed: &SealedBox, ad: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    let key = dem_key(ecdh::shared_secret(private_scalar, &sealed.ephemeral), &sealed.ephemeral);
+    enc::decrypt(&key, &Bn254Fr::from_u32(0), ad, &sealed.ciphertext)
+}
+
+/// Seal `plaintext` to `recipient`.
+/// `ephemeral_scalar` must be fresh randomness, never reused.
+pub fn seal(recipient: &JubjubPoint, ephemeral_scalar: &Bn254Fr, plaintext: &[Bn254Fr]) -> SealedBox {
+    seal_with_ad(recipient, ephemeral_scalar, &[], plaintext)
+}
+
+/// Open a box sealed with [`seal`]; asserts that the tag verifies
+pub fn open(private_scalar: &Bn254Fr, sealed: &SealedBox) -> Vec<Bn254Fr> {
+    open_with_ad(private_scalar, sealed, &[])
+}
@@ -0,0 +1,644 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! secp256k1 and ECDSA for Ligetron
+//!
+//! The curve `y^2 = x^3 + 7` used by Bitcoin and Ethereum keys. Both the
+//! base field `p` and the group order `n` exceed the VM's scalar field, so
+//! elements are emulated as [`Uint256`] values in canonical form, like
+//! [`Bn254Fq`](crate::bn254fq::Bn254Fq):
+//!
+//! - [`Fp`] - coordinates, modulo `p`
+//! - [`Scalar`] - scalars and signature values, modulo `n`
+//!
+//! Points are added with the complete projective formulas of Renes,
+//! Costello and Batina (2016) for `a = 0`, so doubling, the identity and
+//! `P + (-P)` need no special cases. Scalar multiplication is a
+//! double-and-add over the constrained scalar bits, and ECDSA uses the
+//! interleaved `u1·G + u2·Q` form (one doubling chain for both scalars).
+//!
+//! ## GLV Double Scalar Multiplication
+//!
+//! secp256k1 has `j = 0`, so `φ(x, y) = (β·x, y)` acts as multiplication
+//! by a cube root of unity `λ` modulo n. [`double_scalar_mul`] splits both
+//! scalars as `k = k1 + k2·λ (mod n)` with `|k1|, |k2| < 2^128` and runs a
+//! single chain of 128 doublings over the four halves, adding one entry of
+//! a 16-point table per step: about 128 doublings and 139 additions instead
+//! of 256 of each. The split is computed off-circuit and checked in
+//! [`Scalar`] arithmetic.
+//!
+//! [`ecdsa_verify`] returns a validity flag instead of asserting, like the
+//! C++ SDK, so invalid signatures can be counted or branched on.
+
+use std::marker::PhantomData;
+
+use crate::bn254fr::Bn254Fr;
+use crate::grumpkin::{GlvBasis, glv_split_limbs};
+use crate::uint256::{self, Uint256, Uint256Wide, add_cc, sub_cc, mul_wide};
+
+/// Base field modulus p (hex, without prefix)
+pub const SECP256K1_P: &str =
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+
+/// Group order n (hex, without prefix)
+pub const SECP256K1_N: &str =
+    "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+/// Generator x-coordinate (hex, without prefix)
+pub const SECP256K1_GX: &str =
+    "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+/// Generator y-coordinate (hex, without prefix)
+pub const SECP256K1_GY: &str =
+    "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+/// Cube root of unity β modulo p, `φ(x, y) = (β·x, y)` (hex, without prefix)
+pub const SECP256K1_GLV_BETA: &str =
+    "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee";
+
+/// Cube root of unity λ modulo n with `φ(P) = λ·P` (hex, without prefix)
+pub const SECP256K1_GLV_LAMBDA: &str =
+    "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72";
+
+/// Bit width of the GLV half scalars
+pub const GLV_SCALAR_BITS: usize = 128;
+
+const GLV_BASIS: GlvBasis = GlvBasis {
+    a1: [0xe86c90e49284eb15, 0x3086d221a7d46bcd, 0],
+    b1_neg: [0x6f547fa90abfe4c3, 0xe4437ed6010e8828, 0],
+    a2: [0x57c1108d9d44cfd8, 0x14ca50f7a8e2f3f6, 0x1],
+    b2: [0xe86c90e49284eb15, 0x3086d221a7d46bcd, 0],
+    g1: [0xe893209a45dbb031, 0x3daa8a1471e8ca7f, 0xe86c90e49284eb15, 0x3086d221a7d46bcd, 0],
+    g2: [0x1571b4ae8ac47f71, 0x221208ac9df506c6, 0x6f547fa90abfe4c4, 0xe4437ed6010e8828, 0],
+};
+
+/// Curve constant b
+const B: u64 = 7;
+
+/// A 256-bit prime modulus above 2^255
+pub trait Modulus256: Clone {
+    /// The modulus in hex, without prefix
+    const HEX: &'static str;
+
+    /// The modulus as 64-bit limbs, least significant first
+    const LIMBS: [u64; 4] = uint256::parse_hex_limbs(Self::HEX);
+}
+
+/// Marker for the base field modulus p
+#[derive(Clone)]
+pub struct BaseModulus;
+
+/// Marker for the group order n
+#[derive(Clone)]
+pub struct OrderModulus;
+
+impl Modulus256 for BaseModulus {
+    const HEX: &'static str = SECP256K1_P;
+}
+
+impl Modulus256 for OrderModulus {
+    const HEX: &'static str = SECP256K1_N;
+}
+
+/// Coordinates, modulo p
+pub type Fp = Field256<BaseModulus>;
+
+/// Scalars, modulo n
+pub type Scalar = Field256<OrderModulus>;
+
+/// `if_one` if cond == 1, `if_zero` if cond == 0 (with constraints).
+/// `uint256::mux` returns its last argument when cond is 1.
+fn select_u256(cond: &Bn254Fr, if_one: &Uint256, if_zero: &Uint256) -> Uint256 {
+    uint256::mux(cond, if_zero, if_one)
+}
+
+/// out = a & b for boolean field elements
+fn and(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    let mut out = a.clone();
+    out.mulmod_checked(b);
+    out
+}
+
+/// out = 1 - a for a boolean field element
+fn not(a: &Bn254Fr) -> Bn254Fr {
+    let mut out = Bn254Fr::from_u32(1);
+    out.submod_checked(a);
+    out
+}
+
+/// An element of the integers modulo `M`, kept canonical
+#[derive(Clone)]
+pub struct Field256<M: Modulus256> {
+    value: Uint256,
+    _modulus: PhantomData<M>,
+}
+
+impl<M: Modulus256> Field256<M> {
+    fn wrap(value: Uint256) -> Self {
+        Field256 { value, _modulus: PhantomData }
+    }
+
+    /// The modulus as a Uint256
+    pub fn modulus() -> Uint256 {
+        Uint256::from_limbs_u64(M::LIMBS)
+    }
+
+    /// Assert that `v` is below the modulus
+    fn assert_canonical(v: &Uint256) {
+        // v - m borrows iff v < m
+        let d = sub_cc(v, &Self::modulus());
+        Bn254Fr::assert_equal(&d.carry, &Bn254Fr::from_u32(1));
+    }
+
+    /// Reduce a 512-bit value with constraints
+    fn reduce_wide(w: &Uint256Wide) -> Uint256 {
+        let m = Self::modulus();
+        let zero = Bn254Fr::from_u32(0);
+
+        // Witness w = k * m + r; k < 2^256 because w < m^2
+        let (k, k_hi, r) = w.divide_qr_normalized(&m);
+        Bn254Fr::assert_equal(&k_hi, &zero);
+
+        let km = mul_wide(&k, &m);
+        let lo = add_cc(&km.lo, &r);
+        let mut carry = Uint256::new();
+        carry.set_words(&[lo.carry.clone(), zero.clone(), zero.clone(), zero.clone()]);
+        let hi = add_cc(&km.hi, &carry);
+        Bn254Fr::assert_equal(&hi.carry, &zero);
+        uint256::assert_equal(&lo.val, &w.lo);
+        uint256::assert_equal(&hi.val, &w.hi);
+
+        Self::assert_canonical(&r);
+        r
+    }
+
+    pub fn zero() -> Self {
+        Self::wrap(Uint256::from_u64(0))
+    }
+
+    pub fn one() -> Self {
+        Self::wrap(Uint256::from_u64(1))
+    }
+
+    pub fn from_u64(v: u64) -> Self {
+        Self::wrap(Uint256::from_u64(v))
+    }
+
+    /// Construct a constant from hex (without prefix).
+    /// The value must already be reduced.
+    pub fn from_hex(s: &str) -> Self {
+        Self::wrap(Uint256::from_str(s, 16))
+    }
+
+    /// Wrap a private Uint256, asserting that it is reduced
+    pub fn from_uint256_checked(v: &Uint256) -> Self {
+        Self::assert_canonical(v);
+        Self::wrap(v.clone())
+    }
+
+    /// Reduce any 256-bit value, e.g. a message hash. One conditional
+    /// subtraction suffices because the modulus is above 2^255.
+    pub fn from_uint256_reduced(v: &Uint256) -> Self {
+        let d = sub_cc(v, &Self::modulus());
+        Self::wrap(select_u256(&d.carry, v, &d.val))
+    }
+
+    /// Element from 32 big-endian bytes, asserting that it is reduced
+    pub fn from_bytes_big_checked(bytes: &[u8; 32]) -> Self {
+        let mut v = Uint256::new();
+        v.set_bytes_big(bytes);
+        Self::from_uint256_checked(&v)
+    }
+
+    /// Underlying canonical integer
+    pub fn value(&self) -> &Uint256 {
+        &self.value
+    }
+
+    /// self + other mod m
+    pub fn add(&self, other: &Self) -> Self {
+        // a + b < 2m may overflow 256 bits. Take the sum minus m when the
+        // addition carried (the subtraction then wraps back into range) or
+        // when the subtraction did not borrow.
+        let s = add_cc(&self.value, &other.value);
+        let t = sub_cc(&s.val, &Self::modulus());
+        let mut take_t = not(&t.carry);
+        take_t.addmod_checked(&s.carry);
+        Self::wrap(select_u256(&take_t, &t.val, &s.val))
+    }
+
+    /// self - other mod m
+    pub fn sub(&self, other: &Self) -> Self {
+        // add m back if the subtraction borrowed
+        let d = sub_cc(&self.value, &other.value);
+        let t = add_cc(&d.val, &Self::modulus());
+        Self::wrap(select_u256(&d.carry, &t.val, &d.val))
+    }
+
+    /// -self mod m
+    pub fn neg(&self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    /// self * other mod m
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::wrap(Self::reduce_wide(&mul_wide(&self.value, &other.value)))
+    }
+
+    /// self^2 mod m
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// self^(-1) mod m; fails for zero
+    pub fn inv(&self) -> Self {
+        let inv = Self::wrap(uint256::invmod(&self.value, &Self::modulus()));
+        Self::assert_canonical(&inv.value);
+        Self::assert_equal(&self.mul(&inv), &Self::one());
+        inv
+    }
+
+    /// `if_one` if cond == 1, `if_zero` if cond == 0 (with constraints)
+    pub fn select(cond: &Bn254Fr, if_one: &Self, if_zero: &Self) -> Self {
+        Self::wrap(select_u256(cond, &if_one.value, &if_zero.value))
+    }
+
+    /// Return 1 if self == 0, 0 otherwise (with constraints)
+    pub fn is_zero_checked(&self) -> Bn254Fr {
+        uint256::eqz(&self.value)
+    }
+
+    /// Return 1 if a == b, 0 otherwise (with constraints)
+    pub fn eq_checked(a: &Self, b: &Self) -> Bn254Fr {
+        uint256::eq(&a.value, &b.value)
+    }
+
+    /// Constrain a == b
+    pub fn assert_equal(a: &Self, b: &Self) {
+        uint256::assert_equal(&a.value, &b.value);
+    }
+}
+
+// ============= Points =============
+
+/// A point in affine coordinates (never the identity)
+#[derive(Clone)]
+pub struct AffinePoint {
+    pub x: Fp,
+    pub y: Fp,
+}
+
+impl AffinePoint {
+    pub fn new(x: Fp, y: Fp) -> Self {
+        AffinePoint { x, y }
+    }
+
+    pub fn generator() -> Self {
+        const GX: [u64; 4] = uint256::parse_hex_limbs(SECP256K1_GX);
+        const GY: [u64; 4] = uint256::parse_hex_limbs(SECP256K1_GY);
+        AffinePoint::new(Fp::wrap(Uint256::from_limbs_u64(GX)), Fp::wrap(Uint256::from_limbs_u64(GY)))
+    }
+
+    /// Point from its 64-byte uncompressed encoding `x || y` (big-endian,
+    /// without the `0x04` prefix), asserting the coordinates are reduced
+    pub fn from_uncompressed(bytes: &[u8; 64]) -> Self {
+        let x: &[u8; 32] = bytes[..32].try_into().unwrap();
+        let y: &[u8; 32] = bytes[32..].try_into().unwrap();
+        AffinePoint::new(Fp::from_bytes_big_checked(x), Fp::from_bytes_big_checked(y))
+    }
+
+    /// Return 1 if y^2 = x^3 + 7, 0 otherwise (with constraints)
+    pub fn on_curve_checked(&self) -> Bn254Fr {
+        let rhs = self.x.square().mul(&self.x).add(&Fp::from_u64(B));
+        Fp::eq_checked(&self.y.square(), &rhs)
+    }
+
+    /// Constrain the point to lie on the curve
+    pub fn assert_on_curve(&self) {
+        let rhs = self.x.square().mul(&self.x).add(&Fp::from_u64(B));
+        Fp::assert_equal(&self.y.square(), &rhs);
+    }
+}
+
+/// A point in projective coordinates `(X : Y : Z)`, the identity being
+/// `(0 : 1 : 0)`
+#[derive(Clone)]
+pub struct ProjectivePoint {
+    pub x: Fp,
+    pub y: Fp,
+    pub z: Fp,
+}
+
+impl ProjectivePoint {
+    pub fn identity() -> Self {
+        ProjectivePoint { x: Fp::zero(), y: Fp::one(), z: Fp::zero() }
+    }
+
+    pub fn from_affine(p: &AffinePoint) -> Self {
+        ProjectivePoint { x: p.x.clone(), y: p.y.clone(), z: Fp::one() }
+    }
+
+    /// Complete addition (RCB16, algorithm 7)
+    pub fn add(&self, other: &Self) -> Self {
+        let b3 = Fp::from_u64(3 * B);
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1.mul(x2);
+        let t1 = y1.mul(y2);
+        let t2 = z1.mul(z2);
+        let t3 = x1.add(y1).mul(&x2.add(y2)).sub(&t0.add(&t1));
+        let t4 = y1.add(z1).mul(&y2.add(z2)).sub(&t1.add(&t2));
+        let y3 = x1.add(z1).mul(&x2.add(z2)).sub(&t0.add(&t2));
+        let t0 = t0.add(&t0).add(&t0);
+        let t2 = b3.mul(&t2);
+        let z3 = t1.add(&t2);
+        let t1 = t1.sub(&t2);
+        let y3 = b3.mul(&y3);
+
+        ProjectivePoint {
+            x: t3.mul(&t1).sub(&t4.mul(&y3)),
+            y: t1.mul(&z3).add(&y3.mul(&t0)),
+            z: z3.mul(&t4).add(&t0.mul(&t3)),
+        }
+    }
+
+    /// Complete doubling (RCB16, algorithm 9)
+    pub fn double(&self) -> Self {
+        let b3 = Fp::from_u64(3 * B);
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+
+        let t0 = y.square();
+        let z3 = t0.add(&t0);
+        let z3 = z3.add(&z3);
+        let z3 = z3.add(&z3);
+        let t1 = y.mul(z);
+        let t2 = b3.mul(&z.square());
+        let x3 = t2.mul(&z3);
+        let y3 = t0.add(&t2);
+        let z3 = t1.mul(&z3);
+        let t2 = t2.add(&t2).add(&t2);
+        let t0 = t0.sub(&t2);
+        let y3 = t0.mul(&y3).add(&x3);
+        let t1 = x.mul(y);
+        let x3 = t0.mul(&t1);
+
+        ProjectivePoint { x: x3.add(&x3), y: y3, z: z3 }
+    }
+
+    /// -self
+    pub fn neg(&self) -> Self {
+        ProjectivePoint { x: self.x.clone(), y: self.y.neg(), z: self.z.clone() }
+    }
+
+    /// The endomorphism `φ(P) = (β·X : Y : Z) = λ·P`
+    pub fn endomorphism(&self) -> Self {
+        let beta = Fp::from_hex(SECP256K1_GLV_BETA);
+        ProjectivePoint { x: beta.mul(&self.x), y: self.y.clone(), z: self.z.clone() }
+    }
+
+    /// `if_one` if cond == 1, `if_zero` if cond == 0 (with constraints)
+    pub fn select(cond: &Bn254Fr, if_one: &Self, if_zero: &Self) -> Self {
+        ProjectivePoint {
+            x: Fp::select(cond, &if_one.x, &if_zero.x),
+            y: Fp::select(cond, &if_one.y, &if_zero.y),
+            z: Fp::select(cond, &if_one.z, &if_zero.z),
+        }
+    }
+
+    /// Return 1 if the point is the identity, 0 otherwise (with constraints)
+    pub fn is_identity_checked(&self) -> Bn254Fr {
+        self.z.is_zero_checked()
+    }
+
+    /// Affine form and an identity flag. For the identity the returned
+    /// coordinates are meaningless and the flag is 1.
+    pub fn to_affine(&self) -> (AffinePoint, Bn254Fr) {
+        let is_identity = self.is_identity_checked();
+        let z_inv = Fp::select(&is_identity, &Fp::one(), &self.z).inv();
+        (AffinePoint::new(self.x.mul(&z_inv), self.y.mul(&z_inv)), is_identity)
+    }
+}
+
+/// k·P with constraints
+pub fn scalar_mul(k: &Scalar, p: &AffinePoint) -> ProjectivePoint {
+    let base = ProjectivePoint::from_affine(p);
+    let mut acc = ProjectivePoint::identity();
+    for bit in k.value().to_bits().iter().rev() {
+        acc = acc.double();
+        acc = ProjectivePoint::select(bit, &acc.add(&base), &acc);
+    }
+    acc
+}
+
+/// Split of a scalar `k = ±|k1| ± |k2|·λ (mod n)`
+#[derive(Clone)]
+pub struct GlvDecomposition {
+    /// Little-endian bits of `|k1|`
+    pub k1_bits: Vec<Bn254Fr>,
+    /// 1 if `k1` is negative
+    pub k1_neg: Bn254Fr,
+    /// Little-endian bits of `|k2|`
+    pub k2_bits: Vec<Bn254Fr>,
+    /// 1 if `k2` is negative
+    pub k2_neg: Bn254Fr,
+}
+
+impl GlvDecomposition {
+    /// Split `k` and constrain the halves to `GLV_SCALAR_BITS` bits and
+    /// `k ≡ k1 + k2·λ (mod n)`
+    pub fn new(k: &Scalar) -> Self {
+        let limbs: [u64; 4] = std::array::from_fn(|i| k.value().limb(i).get_u64());
+        let (k1, k1_neg, k2, k2_neg) = glv_split_limbs(&limbs, &GLV_BASIS);
+        let k1_neg = Bn254Fr::from_u32(k1_neg as u32);
+        let k2_neg = Bn254Fr::from_u32(k2_neg as u32);
+        Bn254Fr::assert_bit(&k1_neg);
+        Bn254Fr::assert_bit(&k2_neg);
+
+        let d = GlvDecomposition {
+            k1_bits: Bn254Fr::from_str(&format!("0x{:x}", k1)).to_bits(GLV_SCALAR_BITS),
+            k1_neg,
+            k2_bits: Bn254Fr::from_str(&format!("0x{:x}", k2)).to_bits(GLV_SCALAR_BITS),
+            k2_neg,
+        };
+
+        let lambda = Scalar::from_hex(SECP256K1_GLV_LAMBDA);
+        let e1 = signed_scalar(&d.k1_bits, &d.k1_neg);
+        let e2 = signed_scalar(&d.k2_bits, &d.k2_neg);
+        Scalar::assert_equal(k, &e1.add(&e2.mul(&lambda)));
+        d
+    }
+}
+
+/// `±v` modulo n for `v` given by `GLV_SCALAR_BITS` bits and a sign bit
+fn signed_scalar(bits: &[Bn254Fr], neg: &Bn254Fr) -> Scalar {
+    let mut all = bits.to_vec();
+    all.resize_with(256, || Bn254Fr::from_u32(0));
+    let v = Scalar::wrap(Uint256::from_bits(&all));
+    Scalar::select(neg, &v.neg(), &v)
+}
+
+/// `Σ k_i·P_i` over four points by constrained little-endian bits of equal
+/// length, adding one entry of the table of all subset sums per doubling
+fn quad_scalar_mul_bits(points: &[ProjectivePoint; 4], bits: [&[Bn254Fr]; 4]) -> ProjectivePoint {
+    // table[m] = sum of points[i] over the bits i set in m
+    let mut table = vec![ProjectivePoint::identity()];
+    for m in 1..16usize {
+        let i = m.trailing_zeros() as usize;
+        let rest = m & (m - 1);
+        table.push(if rest == 0 { points[i].clone() } else { table[rest].add(&points[i]) });
+    }
+
+    let mut acc = ProjectivePoint::identity();
+    for j in (0..bits[0].len()).rev() {
+        acc = acc.double();
+        // Select by bit 0 first, halving the candidates at each level
+        let mut level = table.clone();
+        for b in &bits {
+            level = level.chunks(2).map(|pair| ProjectivePoint::select(&b[j], &pair[1], &pair[0])).collect();
+        }
+        acc = acc.add(&level[0]);
+    }
+    acc
+}
+
+/// a·P + b·Q with the GLV split of both scalars and one shared chain of
+/// `GLV_SCALAR_BITS` doublings
+pub fn double_scalar_mul(a: &Scalar, p: &AffinePoint, b: &Scalar, q: &AffinePoint) -> ProjectivePoint {
+    let da = GlvDecomposition::new(a);
+    let db = GlvDecomposition::new(b);
+    let p = ProjectivePoint::from_affine(p);
+    let q = ProjectivePoint::from_affine(q);
+    let signed = |pt: ProjectivePoint, neg: &Bn254Fr| ProjectivePoint::select(neg, &pt.neg(), &pt);
+
+    let points = [
+        signed(p.clone(), &da.k1_neg),
+        signed(p.endomorphism(), &da.k2_neg),
+        signed(q.clone(), &db.k1_neg),
+        signed(q.endomorphism(), &db.k2_neg),
+    ];
+    quad_scalar_mul_bits(&points, [&da.k1_bits, &da.k2_bits, &db.k1_bits, &db.k2_bits])
+}
+
+// ============= ECDSA =============
+
+/// Check an ECDSA signature `(r, s)` over the 32-byte big-endian
+/// `msg_hash` against `pubkey`, returning 1 if it is valid and 0 otherwise
+/// (with constraints).
+///
+/// The key must be on the curve, `r` and `s` in `[1, n - 1]`, and the x
+/// coordinate of `u1·G + u2·Q` reduced modulo n must equal `r`. The hash is
+/// reduced modulo n; high-s signatures are accepted, as in plain ECDSA.
+pub fn ecdsa_verify(pubkey: &AffinePoint, msg_hash: &Uint256, r: &Uint256, s: &Uint256) -> Bn254Fr {
+    let pubkey_valid = pubkey.on_curve_checked();
+
+    // 1 <= r, s < n: the subtraction of n borrows and the value is nonzero
+    let n = Scalar::modulus();
+    let r_valid = and(&sub_cc(r, &n).carry, &not(&uint256::eqz(r)));
+    let s_valid = and(&sub_cc(s, &n).carry, &not(&uint256::eqz(s)));
+    let rs_valid = and(&r_valid, &s_valid);
+
+    // Replace invalid values by 1 so that the inversion below succeeds
+    let one = Uint256::from_u64(1);
+    let r = Scalar::wrap(select_u256(&rs_valid, r, &one));
+    let s = Scalar::wrap(select_u256(&rs_valid, s, &one));
+
+    let z = Scalar::from_uint256_reduced(msg_hash);
+    let w = s.inv();
+    let u1 = z.mul(&w);
+    let u2 = r.mul(&w);
+
+    let (point, is_identity) = double_scalar_mul(&u1, &AffinePoint::generator(), &u2, pubkey).to_affine();
+    let x = Scalar::from_uint256_reduced(point.x.value());
+    let sig_valid = Scalar::eq_checked(&x, &r);
+
+    and(&and(&pubkey_valid, &rs_valid), &and(&not(&is_identity), &sig_valid))
+}
+
+/// Check a signature given as bytes: the uncompressed key `x || y`, the
+/// message hash and the signature `r || s`, all big-endian
+pub fn ecdsa_verify_bytes(pubkey: &[u8; 64], msg_hash: &[u8; 32], sig: &[u8; 64]) -> Bn254Fr {
+    let mut z = Uint256::new();
+    let mut r = Uint256::new();
+    let mut s = Uint256::new();
+    z.set_bytes_big(msg_hash);
+    r.set_bytes_big(&sig[..32]);
+    s.set_bytes_big(&sig[32..]);
+    ecdsa_verify(&AffinePoint::from_uncompressed(pubkey), &z, &r, &s)
+}
+
+/// Constrain an ECDSA signature to be valid
+pub fn assert_ecdsa_verify(pubkey: &AffinePoint, msg_hash: &Uint256, r: &Uint256, s: &Uint256) {
+    let valid = ecdsa_verify(pubkey, msg_hash, r, s);
+    Bn254Fr::assert_equal(&valid, &Bn254Fr::from_u32(1));
+}
+
+#[cfg(all(test, feature = "native-sim"))]
+mod tests {
+    use super::*;
+
+    /// `Q = 0xc0ffee·G`
+    const QX: &str = "2a5bbcb0eede528e6abe5f2ec50ad7887eb5677af383a460b05ee23bf892dfe5";
+    const QY: &str = "52c93747550eda8404c8b473786c00dfd8fd1ef4bc033f359ccf5b77bd656d21";
+
+    /// ECDSA signature under the key of `Q` over SHA-256("ligetron")
+    const Z: &str = "93c4f88b532599d5bcc19956a19396fdd8871307ae5853187a78b76ff8c0ed14";
+    const R: &str = "942a5fb3aaea0a554dab544b4f61e1190c0db2f7f45ef69943b02fb3ea4c5d27";
+    const S: &str = "a48ca27a4e6259a37d2efb487822a758c881d10003b6f4874529f9994e045db9";
+
+    fn point(x: &str, y: &str) -> AffinePoint {
+        AffinePoint::new(Fp::from_hex(x), Fp::from_hex(y))
+    }
+
+    fn assert_double_scalar_mul(a: &str, b: &str, x: &str, y: &str) {
+        let (r, is_identity) = double_scalar_mul(&Scalar::from_hex(a), &AffinePoint::generator(),
+                                                 &Scalar::from_hex(b), &point(QX, QY)).to_affine();
+        is_identity.assert_equal_const(0);
+        Fp::assert_equal(&r.x, &Fp::from_hex(x));
+        Fp::assert_equal(&r.y, &Fp::from_hex(y));
+    }
+
+    #[test]
+    fn double_scalar_mul_identity() {
+        let zero = Scalar::zero();
+        let (_, is_identity) = double_scalar_mul(&zero, &AffinePoint::generator(), &zero, &point(QX, QY)).to_affine();
+        is_identity.assert_equal_const(1);
+    }
+
+    #[test]
+    fn double_scalar_mul_edge_scalars() {
+        // n - 1 and λ
+        assert_double_scalar_mul(
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140", SECP256K1_GLV_LAMBDA,
+            "4db158ceab251b8ae50f7f5ec02312b02e964fa554a40e3e87ceb290def7c24a",
+            "5b3c0444984b4153d5fbee7ec3501948ccf4f2722e1bd67744d00cc8fbebc0b6");
+    }
+
+    #[test]
+    fn ecdsa_accepts_valid_signature() {
+        let valid = ecdsa_verify(&point(QX, QY), &Uint256::from_str(Z, 16),
+                                 &Uint256::from_str(R, 16), &Uint256::from_str(S, 16));
+        valid.assert_equal_const(1);
+    }
+
+    #[test]
+    fn ecdsa_rejects_other_message() {
+        let mut z = Uint256::from_str(Z, 16);
+        z.set_u64(1);
+        let valid = ecdsa_verify(&point(QX, QY), &z, &Uint256::from_str(R, 16), &Uint256::from_str(S, 16));
+        valid.assert_equal_const(0);
+    }
+}
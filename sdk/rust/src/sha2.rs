@@ -15,6 +15,18 @@
  */
 
 //! SHA-256 cryptographic hash functions for Ligetron
+//!
+//! [`sha2_256`] and [`Sha256Context`] hash bytes in plain wasm.
+//! [`Sha256Gadget`] runs the same compression function over constrained
+//! [`Word32`]s, so a digest can be computed from private field-element bytes
+//! and bound to a public value inside the circuit.
+//!
+//! There is no host-accelerated SHA-256: the VM exports no hash function
+//! whose input and output it binds into the witness, so every digest here
+//! is computed by the guest.
+
+use crate::bn254fr::Bn254Fr;
+use crate::word::{add_many, Word32};
 
 /// SHA-256 constants (K)
 const K: [u32; 64] = [
@@ -212,4 +224,247 @@ pub fn sha2_256(input: &[u8]) -> [u8; 32] {
     let mut output = [0u8; 32];
     ligetron_sha2_256(&mut output, input, input.len() as u32);
     output
-}
\ No newline at end of file
+}
+
+/// Initial SHA-256 hash value
+const H0: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A,
+    0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19
+];
+
+/// Exported SHA-256 context state (midstate)
+///
+/// `state` is the chaining value after `length - pending.len()` bytes,
+/// `pending` the bytes not yet compressed (less than one block). For a
+/// block-aligned prefix `pending` is empty and `(state, length)` is the
+/// classic public midstate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sha256Midstate {
+    pub state: [u32; 8],
+    pub length: u64,
+    pub pending: Vec<u8>,
+}
+
+/// Incremental SHA-256 context
+#[derive(Clone)]
+pub struct Sha256Context {
+    state: [u32; 8],
+    length: u64,
+    buf: [u8; 64],
+    buf_len: usize,
+}
+
+impl Default for Sha256Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256Context {
+    pub fn new() -> Self {
+        Sha256Context { state: H0, length: 0, buf: [0u8; 64], buf_len: 0 }
+    }
+
+    /// Absorb more input
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.length += data.len() as u64;
+
+        if self.buf_len > 0 {
+            let take = (64 - self.buf_len).min(data.len());
+            self.buf[self.buf_len..self.buf_len + take].copy_from_slice(&data[..take]);
+            self.buf_len += take;
+            data = &data[take..];
+            if self.buf_len < 64 {
+                return;
+            }
+            let block = self.buf;
+            sha256_compress(&mut self.state, &block);
+            self.buf_len = 0;
+        }
+
+        while data.len() >= 64 {
+            sha256_compress(&mut self.state, &data[..64]);
+            data = &data[64..];
+        }
+
+        self.buf[..data.len()].copy_from_slice(data);
+        self.buf_len = data.len();
+    }
+
+    /// Pad and return the digest
+    pub fn finalize(mut self) -> [u8; 32] {
+        let bit_len = self.length.wrapping_mul(8);
+
+        let mut pad = [0u8; 72];
+        pad[0] = 0x80;
+        let pad_len = if self.buf_len < 56 { 56 - self.buf_len } else { 120 - self.buf_len };
+        pad[pad_len..pad_len + 8].copy_from_slice(&bit_len.to_be_bytes());
+
+        // update() counts the padding into `length`, which is no longer used
+        self.update(&pad[..pad_len + 8]);
+
+        let mut out = [0u8; 32];
+        for i in 0..8 {
+            store32h(self.state[i], &mut out, 4 * i);
+        }
+        out
+    }
+
+    /// Export the context state so hashing can resume elsewhere
+    pub fn export_state(&self) -> Sha256Midstate {
+        Sha256Midstate {
+            state: self.state,
+            length: self.length,
+            pending: self.buf[..self.buf_len].to_vec(),
+        }
+    }
+
+    /// Resume hashing from an exported state
+    pub fn import_state(midstate: &Sha256Midstate) -> Self {
+        assert!(midstate.pending.len() < 64, "Sha256Context::import_state: invalid pending length");
+        assert_eq!(midstate.length % 64, midstate.pending.len() as u64 % 64,
+                   "Sha256Context::import_state: length does not match pending bytes");
+        let mut buf = [0u8; 64];
+        buf[..midstate.pending.len()].copy_from_slice(&midstate.pending);
+        Sha256Context {
+            state: midstate.state,
+            length: midstate.length,
+            buf,
+            buf_len: midstate.pending.len(),
+        }
+    }
+}
+
+// ============= Constrained SHA-256 =============
+
+/// Constant byte as 8 bits, least significant first
+fn const_byte_bits(b: u8) -> Vec<Bn254Fr> {
+    (0..8).map(|i| Bn254Fr::from_u32(((b >> i) & 1) as u32)).collect()
+}
+
+/// Constrained SHA-256 compression of one block given as 64 bytes of bits
+fn sha256_compress_checked(state: &mut [Word32], block: &[Vec<Bn254Fr>]) {
+    // Big-endian load: the word's low bits come from the last byte
+    let mut w: Vec<Word32> = block.chunks(4)
+        .map(|bytes| Word32::from_bits(bytes.iter().rev().flatten().cloned().collect()))
+        .collect();
+
+    for i in 16..64 {
+        let x = &w[i - 15];
+        let y = &w[i - 2];
+        let g0 = x.rotr(7).xor(&x.rotr(18)).xor(&x.shr(3));
+        let g1 = y.rotr(17).xor(&y.rotr(19)).xor(&y.shr(10));
+        let next = add_many(&[&g1, &w[i - 7], &g0, &w[i - 16]]);
+        w.push(next);
+    }
+
+    let mut s = state.to_vec();
+    for i in 0..64 {
+        let (a, e) = (&s[0], &s[4]);
+        let sigma1 = e.rotr(6).xor(&e.rotr(11)).xor(&e.rotr(25));
+        let ch = s[6].xor(&e.and(&s[5].xor(&s[6])));
+        let sigma0 = a.rotr(2).xor(&a.rotr(13)).xor(&a.rotr(22));
+        let maj = a.and(&s[1]).xor(&s[2].and(&a.xor(&s[1])));
+        let k = Word32::from_u64(K[i] as u64);
+
+        // Each new word is decomposed once from the full sum
+        let t0: [&Word32; 5] = [&s[7], &sigma1, &ch, &k, &w[i]];
+        let new_e = add_many(&[&[&s[3]][..], &t0[..]].concat());
+        let new_a = add_many(&[&t0[..], &[&sigma0, &maj][..]].concat());
+
+        s.rotate_right(1);
+        s[0] = new_a;
+        s[4] = new_e;
+    }
+
+    for (h, x) in state.iter_mut().zip(s.iter()) {
+        *h = h.add(x);
+    }
+}
+
+/// Incremental SHA-256 over constrained bytes.
+///
+/// Each input byte is a `Bn254Fr` range checked to 8 bits. The message
+/// length is public structure; the byte values may be private. The digest
+/// is returned as 32 constrained byte field elements, in the same order as
+/// [`sha2_256`].
+#[derive(Clone)]
+pub struct Sha256Gadget {
+    state: Vec<Word32>,
+    length: u64,
+    /// Pending bytes as bits, least significant first
+    buf: Vec<Vec<Bn254Fr>>,
+}
+
+impl Default for Sha256Gadget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256Gadget {
+    pub fn new() -> Self {
+        Sha256Gadget {
+            state: H0.iter().map(|&h| Word32::from_u64(h as u64)).collect(),
+            length: 0,
+            buf: Vec::with_capacity(64),
+        }
+    }
+
+    /// Absorb bytes given as field elements, range checking each to 8 bits
+    pub fn update(&mut self, bytes: &[Bn254Fr]) {
+        for b in bytes {
+            self.absorb_bits(b.to_bits(8));
+        }
+        self.length += bytes.len() as u64;
+    }
+
+    /// Absorb wasm bytes (private if they come from private inputs)
+    pub fn update_bytes(&mut self, data: &[u8]) {
+        let bytes: Vec<Bn254Fr> = data.iter().map(|&b| Bn254Fr::from_u32(b as u32)).collect();
+        self.update(&bytes);
+    }
+
+    fn absorb_bits(&mut self, bits: Vec<Bn254Fr>) {
+        self.buf.push(bits);
+        if self.buf.len() == 64 {
+            let block = std::mem::take(&mut self.buf);
+            sha256_compress_checked(&mut self.state, &block);
+        }
+    }
+
+    /// Pad and return the digest words, most significant word first
+    pub fn finalize_words(mut self) -> Vec<Word32> {
+        let bit_len = self.length.wrapping_mul(8);
+
+        self.absorb_bits(const_byte_bits(0x80));
+        while self.buf.len() != 56 {
+            self.absorb_bits(const_byte_bits(0));
+        }
+        for b in bit_len.to_be_bytes() {
+            self.absorb_bits(const_byte_bits(b));
+        }
+        self.state
+    }
+
+    /// Pad and return the digest as 32 constrained bytes
+    pub fn finalize(self) -> Vec<Bn254Fr> {
+        self.finalize_words().iter()
+            .flat_map(|w| (0..4).rev().map(move |j| w.extract(8 * j, 8)))
+            .collect()
+    }
+
+    /// Pad and assert that the digest equals the public `expected` bytes
+    pub fn assert_digest(self, expected: &[u8; 32]) {
+        for (d, &e) in self.finalize().iter().zip(expected.iter()) {
+            d.assert_equal_const(e as u64);
+        }
+    }
+
+    /// One-shot constrained SHA-256 of bytes given as field elements
+    pub fn digest(bytes: &[Bn254Fr]) -> Vec<Bn254Fr> {
+        let mut g = Sha256Gadget::new();
+        g.update(bytes);
+        g.finalize()
+    }
+}
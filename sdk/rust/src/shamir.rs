@@ -0,0 +1,128 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Shamir Secret Sharing over the BN254 Scalar Field
+//!
+//! A `t`-of-`n` sharing of `secret` is the polynomial
+//! `f(x) = secret + c_1 x + ... + c_{t-1} x^{t-1}`, committed with Poseidon2
+//! over its coefficients. Share `i` is `f(i)` for the public index `i >= 1`.
+//!
+//! The gadgets prove that shares are evaluations of the committed
+//! polynomial, and that a set of `t` shares reconstructs a given secret by
+//! Lagrange interpolation at zero (the Lagrange weights depend only on the
+//! public indices and are computed as constants).
+
+use crate::bn254fr::{Bn254Fr, submod, divmod, mulmod};
+use crate::poseidon2::poseidon2_hash;
+
+/// A sharing polynomial, lowest coefficient (the secret) first
+#[derive(Clone)]
+pub struct SharingPolynomial {
+    pub coeffs: Vec<Bn254Fr>,
+}
+
+impl SharingPolynomial {
+    /// Polynomial of a `threshold`-of-n sharing of `secret` with the given
+    /// random coefficients (`threshold - 1` of them)
+    pub fn new(secret: Bn254Fr, random: Vec<Bn254Fr>) -> Self {
+        let mut coeffs = Vec::with_capacity(random.len() + 1);
+        coeffs.push(secret);
+        coeffs.extend(random);
+        SharingPolynomial { coeffs }
+    }
+
+    /// Reconstruction threshold
+    pub fn threshold(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    /// The shared secret f(0)
+    pub fn secret(&self) -> &Bn254Fr {
+        &self.coeffs[0]
+    }
+
+    /// Poseidon2 commitment to the coefficients
+    pub fn commit(&self) -> Bn254Fr {
+        poseidon2_hash(&self.coeffs)
+    }
+
+    /// Evaluate f(index) at a public index with constraints (Horner)
+    pub fn share(&self, index: u32) -> Bn254Fr {
+        assert!(index != 0, "SharingPolynomial::share: index 0 is the secret");
+        let x = Bn254Fr::from_u32(index);
+        let mut acc = self.coeffs[self.coeffs.len() - 1].clone();
+        for c in self.coeffs.iter().rev().skip(1) {
+            acc.mulmod_constant_checked(&x);
+            acc.addmod_checked(c);
+        }
+        acc
+    }
+
+    /// Generate the shares for the public indices 1..=n
+    pub fn shares(&self, n: u32) -> Vec<Bn254Fr> {
+        (1..=n).map(|i| self.share(i)).collect()
+    }
+}
+
+/// Assert that `poly` opens `commitment` and that `share` is its
+/// evaluation at the public `index`
+pub fn verify_share(commitment: &Bn254Fr, poly: &SharingPolynomial, index: u32, share: &Bn254Fr) {
+    Bn254Fr::assert_equal(&poly.commit(), commitment);
+    Bn254Fr::assert_equal(&poly.share(index), share);
+}
+
+/// Lagrange weights at x = 0 for distinct public indices
+pub fn lagrange_weights_at_zero(indices: &[u32]) -> Vec<Bn254Fr> {
+    for (j, &xj) in indices.iter().enumerate() {
+        assert!(xj != 0, "lagrange_weights_at_zero: index 0 is reserved");
+        assert!(!indices[..j].contains(&xj), "lagrange_weights_at_zero: duplicate index");
+    }
+
+    indices.iter().map(|&xj| {
+        // w_j = prod_{m != j} x_m / (x_m - x_j)
+        let mut w = Bn254Fr::from_u32(1);
+        for &xm in indices.iter().filter(|&&xm| xm != xj) {
+            let mut den = Bn254Fr::new();
+            submod(&mut den, &Bn254Fr::from_u32(xm), &Bn254Fr::from_u32(xj));
+            let mut term = Bn254Fr::new();
+            divmod(&mut term, &Bn254Fr::from_u32(xm), &den);
+            let mut next = Bn254Fr::new();
+            mulmod(&mut next, &w, &term);
+            w = next;
+        }
+        w
+    }).collect()
+}
+
+/// Reconstruct the secret from shares at distinct public indices with
+/// constraints. The caller provides at least `threshold` shares.
+pub fn reconstruct(indices: &[u32], shares: &[Bn254Fr]) -> Bn254Fr {
+    assert_eq!(indices.len(), shares.len(), "reconstruct: length mismatch");
+    let weights = lagrange_weights_at_zero(indices);
+
+    let mut secret = Bn254Fr::from_u32(0);
+    for (w, y) in weights.iter().zip(shares.iter()) {
+        let mut t = y.clone();
+        t.mulmod_constant_checked(w);
+        secret.addmod_checked(&t);
+    }
+    secret
+}
+
+/// Assert that the shares at the public indices reconstruct `secret`
+pub fn assert_reconstructs(indices: &[u32], shares: &[Bn254Fr], secret: &Bn254Fr) {
+    Bn254Fr::assert_equal(&reconstruct(indices, shares), secret);
+}
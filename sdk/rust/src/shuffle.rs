@@ -0,0 +1,155 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Verifiable Shuffles of ElGamal Ciphertexts for Ligetron
+//!
+//! A shuffle re-randomizes and permutes a list of exponential ElGamal
+//! ciphertexts (see [`voting`](crate::voting)), as done by each server of a
+//! re-encryption mixnet or when dealing a face-down deck of cards:
+//!
+//! `out[i] = in[π(i)] + Enc(0; r[i])`
+//!
+//! The permutation `π` and the randomness `r` are private. `π` is committed
+//! as a permutation matrix `P` with boolean entries and exactly one `1` in
+//! every row and column, and each output selects its input as
+//! `Σ_j P[i][j]·in[j]`, coordinate-wise. This costs `O(n²)` multiplications
+//! plus two scalar multiplications per ciphertext, which suits decks and
+//! mixnet batches of a few hundred entries.
+
+use crate::babyjubjub::JubjubPoint;
+use crate::bn254fr::{Bn254Fr, mulmod_checked};
+use crate::eddsa::EddsaSignature;
+use crate::voting::VoteCiphertext;
+
+/// Constrained permutation matrix
+pub struct PermutationMatrix {
+    /// `rows[i][j] == 1` iff output `i` takes input `j`
+    rows: Vec<Vec<Bn254Fr>>,
+}
+
+impl PermutationMatrix {
+    /// Build the matrix of `perm`, where output `i` takes input `perm[i]`,
+    /// and constrain it to be a permutation matrix.
+    ///
+    /// Panics if `perm` is not a permutation of `0..perm.len()`.
+    pub fn new(perm: &[usize]) -> Self {
+        let n = perm.len();
+        let mut seen = vec![false; n];
+        for &p in perm {
+            assert!(p < n && !seen[p], "PermutationMatrix: not a permutation");
+            seen[p] = true;
+        }
+
+        let rows: Vec<Vec<Bn254Fr>> = perm.iter()
+            .map(|&p| (0..n).map(|j| Bn254Fr::from_u32((j == p) as u32)).collect())
+            .collect();
+        let matrix = PermutationMatrix { rows };
+        matrix.assert_valid();
+        matrix
+    }
+
+    /// Number of rows and columns
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Assert boolean entries and unit row and column sums
+    fn assert_valid(&self) {
+        let n = self.len();
+        let one = Bn254Fr::from_u32(1);
+        let mut col_sums = vec![Bn254Fr::from_u32(0); n];
+        for row in &self.rows {
+            let mut row_sum = Bn254Fr::from_u32(0);
+            for (e, col) in row.iter().zip(col_sums.iter_mut()) {
+                Bn254Fr::assert_bit(e);
+                row_sum.addmod_checked(e);
+                col.addmod_checked(e);
+            }
+            Bn254Fr::assert_equal(&row_sum, &one);
+        }
+        for col in &col_sums {
+            Bn254Fr::assert_equal(col, &one);
+        }
+    }
+
+    /// Select `Σ_j P[i][j]·values[j]` for every row `i`
+    pub fn apply(&self, values: &[Bn254Fr]) -> Vec<Bn254Fr> {
+        assert_eq!(values.len(), self.len(), "PermutationMatrix::apply: length mismatch");
+        self.rows.iter().map(|row| {
+            let mut acc = Bn254Fr::from_u32(0);
+            let mut t = Bn254Fr::new();
+            for (e, v) in row.iter().zip(values) {
+                mulmod_checked(&mut t, e, v);
+                acc.addmod_checked(&t);
+            }
+            acc
+        }).collect()
+    }
+
+    /// Permute points: output `i` is the point selected by row `i`
+    pub fn apply_points(&self, points: &[JubjubPoint]) -> Vec<JubjubPoint> {
+        let xs: Vec<Bn254Fr> = points.iter().map(|p| p.x.clone()).collect();
+        let ys: Vec<Bn254Fr> = points.iter().map(|p| p.y.clone()).collect();
+        self.apply(&xs).into_iter()
+            .zip(self.apply(&ys))
+            .map(|(x, y)| JubjubPoint::new(x, y))
+            .collect()
+    }
+}
+
+/// Re-randomize `ct` under `pk`: `ct + Enc(0; r) = (c1 + r·G, c2 + r·PK)`.
+/// The plaintext is unchanged.
+pub fn rerandomize(ct: &VoteCiphertext, r: &Bn254Fr, pk: &JubjubPoint) -> VoteCiphertext {
+    let g = EddsaSignature::generator();
+    VoteCiphertext {
+        c1: JubjubPoint::twisted_edward_add(&ct.c1, &g.scalar_mul(r)),
+        c2: JubjubPoint::twisted_edward_add(&ct.c2, &pk.scalar_mul(r)),
+    }
+}
+
+/// Shuffle `inputs` under `pk`: output `i` is `inputs[perm[i]]` re-randomized
+/// with `r[i]`. Returns the constrained output ciphertexts.
+pub fn shuffle(inputs: &[VoteCiphertext], perm: &[usize], r: &[Bn254Fr],
+               pk: &JubjubPoint) -> Vec<VoteCiphertext> {
+    assert_eq!(inputs.len(), perm.len(), "shuffle: permutation length mismatch");
+    assert_eq!(inputs.len(), r.len(), "shuffle: randomness length mismatch");
+
+    let matrix = PermutationMatrix::new(perm);
+    let c1: Vec<JubjubPoint> = inputs.iter().map(|ct| ct.c1.clone()).collect();
+    let c2: Vec<JubjubPoint> = inputs.iter().map(|ct| ct.c2.clone()).collect();
+
+    matrix.apply_points(&c1).into_iter()
+        .zip(matrix.apply_points(&c2))
+        .zip(r)
+        .map(|((c1, c2), ri)| rerandomize(&VoteCiphertext { c1, c2 }, ri, pk))
+        .collect()
+}
+
+/// Assert that `outputs` is a shuffle of `inputs` under `pk` with the
+/// private permutation `perm` and randomness `r`
+pub fn assert_shuffle(inputs: &[VoteCiphertext], outputs: &[VoteCiphertext],
+                      perm: &[usize], r: &[Bn254Fr], pk: &JubjubPoint) {
+    assert_eq!(inputs.len(), outputs.len(), "assert_shuffle: length mismatch");
+    for (mut got, want) in shuffle(inputs, perm, r, pk).into_iter().zip(outputs) {
+        let mut want = want.clone();
+        JubjubPoint::assert_equal(&mut got.c1, &mut want.c1);
+        JubjubPoint::assert_equal(&mut got.c2, &mut want.c2);
+    }
+}
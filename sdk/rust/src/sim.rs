@@ -0,0 +1,1222 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Native Host Simulation for Ligetron
+//!
+//! Enabled by the `native-sim` cargo feature. Defines the `env`, `bn254fr`,
+//! `vbn254fr` and `uint256` host imports (and the WASI calls the SDK makes)
+//! as native symbols, so circuit code built for the host target links and
+//! runs under `cargo test` or a debugger without the WebGPU prover:
+//!
+//! ```text
+//! cargo test --features native-sim
+//! ```
+//!
+//! The simulation follows the host modules call for call:
+//!
+//! - field elements are heap-allocated integers and a handle is a pointer
+//!   to one, as in the host; like GMP, integer and bitwise operations do
+//!   not reduce their results
+//! - constraint assertions check the asserted relation on the values and
+//!   panic on failure, where the prover would reject the proof; the host
+//!   imports are declared `C-unwind` so the panic reaches the test harness
+//! - vectors have [`LANES`] lanes and at most [`MAX_VECTORS`] can be live
+//! - the constraint trace digest is computed as by the host, per thread,
+//!   except that pointer arguments are numbered by first use: simulated
+//!   digests are stable across runs but differ from the host's
+//! - circuit arguments are set per thread with [`set_args`]
+//!
+//! Nothing is proven: unconstrained hints, secret inputs and missing
+//! constraints are not detected.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::ffi::CStr;
+use std::hash::BuildHasher;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use num_bigint::{BigInt, Sign};
+
+use crate::backend::FieldHandle;
+use crate::bn254fr::BN254FR_MODULUS;
+
+/// Number of lanes of a simulated vector
+pub const LANES: u64 = 64;
+
+/// Number of vectors that can be allocated at once, as in the host
+pub const MAX_VECTORS: usize = 512;
+
+/// Bit length of the field, the number of bits from `vbn254fr_bit_decompose`
+const FIELD_BITS: u64 = 254;
+
+// ============= Integers =============
+
+fn modulus() -> &'static BigInt {
+    static P: OnceLock<BigInt> = OnceLock::new();
+    P.get_or_init(|| parse_mpz(BN254FR_MODULUS, 0).unwrap())
+}
+
+/// (p + 1) / 2, the first value treated as negative
+fn modulus_middle() -> &'static BigInt {
+    static MIDDLE: OnceLock<BigInt> = OnceLock::new();
+    MIDDLE.get_or_init(|| (modulus() + 1) >> 1)
+}
+
+/// 2^-256 mod p, the Montgomery factor of the vector backend
+fn mont_r_inv() -> &'static BigInt {
+    static R_INV: OnceLock<BigInt> = OnceLock::new();
+    R_INV.get_or_init(|| invmod(&(BigInt::from(1) << 256), modulus()))
+}
+
+fn is_zero(x: &BigInt) -> bool {
+    x.sign() == Sign::NoSign
+}
+
+fn reduce(x: &BigInt) -> BigInt {
+    let r = x % modulus();
+    if r.sign() == Sign::Minus {
+        r + modulus()
+    } else {
+        r
+    }
+}
+
+/// Inverse modulo `m`, or 0 if there is none
+fn invmod(a: &BigInt, m: &BigInt) -> BigInt {
+    let a = a % m;
+    let a = if a.sign() == Sign::Minus { a + m } else { a };
+    a.modinv(m).unwrap_or_default()
+}
+
+/// Floor division, as `mpz_fdiv_qr`
+fn fdiv_qr(a: &BigInt, b: &BigInt) -> (BigInt, BigInt) {
+    assert!(!is_zero(b), "division by zero");
+    let (mut q, mut r) = (a / b, a % b);
+    if !is_zero(&r) && r.sign() != b.sign() {
+        q -= 1;
+        r += b;
+    }
+    (q, r)
+}
+
+/// `x mod 2^bits`, as `mpz_fdiv_r_2exp`
+fn low_bits(x: &BigInt, bits: u32) -> BigInt {
+    let m = BigInt::from(1) << bits;
+    fdiv_qr(x, &m).1
+}
+
+/// Low 64 bits of |x|, as `mpz_get_u64`
+fn low_u64(x: &BigInt) -> u64 {
+    x.magnitude().iter_u64_digits().next().unwrap_or(0)
+}
+
+fn shift_amount(k: &BigInt) -> usize {
+    usize::try_from(k).expect("shift value too large")
+}
+
+fn shlmod(x: &BigInt, k: &BigInt) -> Option<BigInt> {
+    if k.sign() == Sign::Minus {
+        None
+    } else if k < modulus_middle() {
+        Some(reduce(&(x << shift_amount(k))))
+    } else {
+        shrmod(x, &(modulus() - k))
+    }
+}
+
+fn shrmod(x: &BigInt, k: &BigInt) -> Option<BigInt> {
+    if k.sign() == Sign::Minus {
+        None
+    } else if k < modulus_middle() {
+        Some(x >> shift_amount(k))
+    } else {
+        shlmod(x, &(modulus() - k))
+    }
+}
+
+/// Parse like `mpz_set_str`: whitespace is ignored and base 0 detects the
+/// `0x`, `0b` and `0` prefixes
+fn parse_mpz(s: &str, base: u32) -> Option<BigInt> {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    let (neg, digits) = match s.strip_prefix('-') {
+        Some(d) => (true, d),
+        None => (false, s.as_str()),
+    };
+    let (radix, digits) = match base {
+        0 => {
+            if let Some(d) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+                (16, d)
+            } else if let Some(d) = digits.strip_prefix("0b").or_else(|| digits.strip_prefix("0B")) {
+                (2, d)
+            } else if digits.len() > 1 && digits.starts_with('0') {
+                (8, &digits[1..])
+            } else {
+                (10, digits)
+            }
+        }
+        b => (b, digits),
+    };
+    if !(2..=36).contains(&radix) || digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    let v = BigInt::parse_bytes(digits.as_bytes(), radix)?;
+    Some(if neg { -v } else { v })
+}
+
+unsafe fn c_str<'a>(s: *const i8) -> &'a str {
+    CStr::from_ptr(s).to_str().expect("host string is not UTF-8")
+}
+
+unsafe fn parse_c_str(module: &str, s: *const i8, base: u32) -> BigInt {
+    let s = c_str(s);
+    parse_mpz(s, base).unwrap_or_else(|| {
+        panic!("{module}: error parsing string \"{s}\", did you forget the prefix \"0x\"?")
+    })
+}
+
+/// Import bytes as `mpz_import` with `order` 1 (big-endian) or -1
+unsafe fn import_bytes(bytes: *const u8, len: usize, order: i32) -> BigInt {
+    let bytes = std::slice::from_raw_parts(bytes, len);
+    if order == -1 {
+        BigInt::from_bytes_le(Sign::Plus, bytes)
+    } else {
+        BigInt::from_bytes_be(Sign::Plus, bytes)
+    }
+}
+
+// ============= Constraint trace =============
+
+/// Digest of the running trace, with the pointer arguments seen so far
+struct Trace {
+    digest: u64,
+    pointers: HashMap<usize, u64>,
+}
+
+thread_local! {
+    static TRACE: RefCell<Option<Trace>> = const { RefCell::new(None) };
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Whether the host traces a call: only constraint-emitting calls are
+/// recorded, filtered as in each module's `call_host`
+fn emits_constraints(module: &str, name: &str) -> bool {
+    match module {
+        "bn254fr" => name.starts_with("bn254fr_assert_") || name.contains("_checked"),
+        "vbn254fr" => matches!(name, "vbn254fr_copy" | "vbn254fr_mulmod" | "vbn254fr_divmod"
+                                   | "vbn254fr_assert_equal" | "vbn254fr_bit_decompose"),
+        "env" => name.starts_with("assert_") || name.ends_with("_private_const")
+            || name.starts_with("witness_cast"),
+        _ => false,
+    }
+}
+
+/// A host call argument as folded into the trace. Immediates are taken by
+/// value; pointers are numbered by first use since the reset, since native
+/// addresses vary between runs.
+trait TraceOperand {
+    fn trace_word(&self, pointers: &mut HashMap<usize, u64>) -> u64;
+}
+
+impl TraceOperand for u32 {
+    fn trace_word(&self, _: &mut HashMap<usize, u64>) -> u64 {
+        *self as u64
+    }
+}
+
+impl TraceOperand for i32 {
+    fn trace_word(&self, _: &mut HashMap<usize, u64>) -> u64 {
+        *self as u32 as u64
+    }
+}
+
+impl TraceOperand for u64 {
+    fn trace_word(&self, _: &mut HashMap<usize, u64>) -> u64 {
+        *self
+    }
+}
+
+impl<T> TraceOperand for *const T {
+    fn trace_word(&self, pointers: &mut HashMap<usize, u64>) -> u64 {
+        let next = pointers.len() as u64;
+        *pointers.entry(*self as usize).or_insert(next)
+    }
+}
+
+impl<T> TraceOperand for *mut T {
+    fn trace_word(&self, pointers: &mut HashMap<usize, u64>) -> u64 {
+        self.cast_const().trace_word(pointers)
+    }
+}
+
+/// Fold a host call and its arguments into the trace digest, as
+/// `host_call_trace::record`
+fn record(module: &str, name: &str, operands: &[&dyn TraceOperand]) {
+    // Field elements in other thread locals may be freed after the trace
+    let _ = TRACE.try_with(|t| {
+        let mut t = t.borrow_mut();
+        let Some(trace) = t.as_mut() else { return };
+        if !emits_constraints(module, name) {
+            return;
+        }
+        let mut digest = trace.digest;
+        let words: Vec<u64> = operands.iter().map(|op| op.trace_word(&mut trace.pointers)).collect();
+        let bytes = [module, ".", name].into_iter().flat_map(|s| s.bytes())
+            .chain(words.iter().flat_map(|w| w.to_le_bytes()))
+            .chain(*b";");
+        for c in bytes {
+            digest = (digest ^ c as u64).wrapping_mul(FNV_PRIME);
+        }
+        trace.digest = digest;
+    });
+}
+
+/// Define host functions of `$module` that record themselves in the trace
+/// when they emit constraints
+macro_rules! host_module {
+    ($module:literal; $(fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block)*) => {
+        $(
+            #[no_mangle]
+            unsafe extern "C-unwind" fn $name($($arg: $ty),*) $(-> $ret)? {
+                record($module, stringify!($name), &[$(&$arg as &dyn TraceOperand),*]);
+                $body
+            }
+        )*
+    };
+}
+
+// ============= bn254fr =============
+
+unsafe fn fr<'a>(handle: u64) -> &'a mut BigInt {
+    assert!(handle != 0, "use of an unallocated field element");
+    &mut *(handle as *mut BigInt)
+}
+
+unsafe fn get(x: *const FieldHandle) -> BigInt {
+    fr((*x).handle).clone()
+}
+
+unsafe fn set(x: *const FieldHandle, v: BigInt) {
+    *fr((*x).handle) = v;
+}
+
+unsafe fn get_at(arr: *const u64, i: usize) -> BigInt {
+    fr(*arr.add(i)).clone()
+}
+
+unsafe fn set_at(arr: *const u64, i: usize, v: BigInt) {
+    *fr(*arr.add(i)) = v;
+}
+
+fn check_equal(name: &str, a: &BigInt, b: &BigInt) {
+    if reduce(a) != reduce(b) {
+        panic!("{name}: constraint failed: {a} != {b}");
+    }
+}
+
+/// Σ limbs[i]·2^(bits·i), reading negative limbs as `limb - p` if `signed`
+unsafe fn compose(arr: *const u64, count: u32, bits: u32, signed: bool) -> BigInt {
+    (0..count as usize).rev().fold(BigInt::default(), |acc, i| {
+        let limb = get_at(arr, i);
+        let limb = if signed && &limb >= modulus_middle() { limb - modulus() } else { limb };
+        (acc << bits) + limb
+    })
+}
+
+unsafe fn decompose(arr: *const u64, count: u32, x: &BigInt, bits: u32) {
+    let mut cur = x.clone();
+    for i in 0..count as usize {
+        set_at(arr, i, low_bits(&cur, bits));
+        cur >>= bits;
+    }
+}
+
+host_module! { "bn254fr";
+    fn bn254fr_alloc(fr: *mut FieldHandle) {
+        (*fr).handle = Box::into_raw(Box::new(BigInt::default())) as u64;
+    }
+
+    fn bn254fr_free(fr: *mut FieldHandle) {
+        if (*fr).handle != 0 {
+            drop(Box::from_raw((*fr).handle as *mut BigInt));
+        }
+        (*fr).handle = 0;
+    }
+
+    fn bn254fr_set_u32(out: *mut FieldHandle, x: u32) {
+        set(out, x.into());
+    }
+
+    fn bn254fr_set_u64(out: *mut FieldHandle, x: u64) {
+        set(out, x.into());
+    }
+
+    fn bn254fr_set_bytes(out: *mut FieldHandle, bytes: *const u8, len: u32, order: i32) {
+        set(out, import_bytes(bytes, len as usize, order));
+    }
+
+    fn bn254fr_set_str(out: *mut FieldHandle, s: *const i8, base: u32) {
+        set(out, parse_c_str("bn254fr_set_str", s, base));
+    }
+
+    fn bn254fr_get_u64(x: *const FieldHandle) -> u64 {
+        low_u64(&get(x))
+    }
+
+    fn bn254fr_to_bytes(out: *mut u8, x: *const FieldHandle, len: u32, order: i32) {
+        let (_, bytes) = get(x).to_bytes_be();
+        let bytes = if bytes == [0] { Vec::new() } else { bytes };
+        let len = len as usize;
+        assert!(len <= 32 && len >= bytes.len(), "invalid size for bn254fr_to_bytes");
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        out.fill(0);
+        if order == -1 {
+            for (o, b) in out.iter_mut().zip(bytes.iter().rev()) {
+                *o = *b;
+            }
+        } else {
+            out[len - bytes.len()..].copy_from_slice(&bytes);
+        }
+    }
+
+    fn bn254fr_copy(dest: *mut FieldHandle, src: *const FieldHandle) {
+        set(dest, get(src));
+    }
+
+    fn bn254fr_print(a: *const FieldHandle, base: u32) {
+        let v = get(a);
+        match base {
+            10 => println!("@bn254fr_print: addr={:#x}, val={}", (*a).handle, v),
+            16 => println!("@bn254fr_print: addr={:#x}, val={:#x}", (*a).handle, v),
+            _ => panic!("bn254fr_print: invalid base value: {base}"),
+        }
+    }
+
+    fn bn254fr_assert_equal(a: *const FieldHandle, b: *const FieldHandle) {
+        check_equal("bn254fr_assert_equal", &get(a), &get(b));
+    }
+
+    fn bn254fr_assert_equal_u64(a: *const FieldHandle, b: u64) {
+        check_equal("bn254fr_assert_equal_u64", &get(a), &b.into());
+    }
+
+    fn bn254fr_assert_equal_bytes(a: *const FieldHandle, bytes: *const u8, len: u32, order: i32) {
+        let y = import_bytes(bytes, len as usize, order);
+        assert!(&y < modulus(), "bn254fr_assert_equal_bytes: bad bytes equal constraint");
+        check_equal("bn254fr_assert_equal_bytes", &get(a), &y);
+    }
+
+    fn bn254fr_assert_add(out: *const FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        check_equal("bn254fr_assert_add", &get(out), &(get(a) + get(b)));
+    }
+
+    fn bn254fr_assert_mul(out: *const FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        check_equal("bn254fr_assert_mul", &get(out), &(get(a) * get(b)));
+    }
+
+    fn bn254fr_assert_mulc(out: *const FieldHandle, a: *const FieldHandle, k: *const FieldHandle) {
+        check_equal("bn254fr_assert_mulc", &get(out), &(get(a) * get(k)));
+    }
+
+    fn bn254fr_to_bits_checked(outs: *mut FieldHandle, a: *const FieldHandle, count: u32) {
+        let x = get(a);
+        let mut sum = BigInt::default();
+        for i in 0..count as usize {
+            let bit = x.bit(i as u64);
+            set(outs.add(i), u8::from(bit).into());
+            if bit {
+                sum += BigInt::from(1) << i;
+            }
+        }
+        if reduce(&sum) != reduce(&x) {
+            panic!("bn254fr_to_bits_checked: constraint failed: {x} does not fit in {count} bits");
+        }
+    }
+
+    fn bn254fr_from_bits_checked(out: *mut FieldHandle, bits: *const FieldHandle, count: u32) {
+        let sum = (0..count as usize)
+            .rev()
+            .fold(BigInt::default(), |acc, i| (acc << 1) + get(bits.add(i)));
+        let x = get(out) + &sum;
+        set(out, x.clone());
+        check_equal("bn254fr_from_bits_checked", &x, &sum);
+    }
+
+    fn bn254fr_addmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, reduce(&(get(a) + get(b))));
+    }
+
+    fn bn254fr_submod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, reduce(&(get(a) - get(b))));
+    }
+
+    fn bn254fr_mulmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, reduce(&(get(a) * get(b))));
+    }
+
+    fn bn254fr_divmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, reduce(&(get(a) * invmod(&get(b), modulus()))));
+    }
+
+    fn bn254fr_invmod(out: *mut FieldHandle, a: *const FieldHandle) {
+        set(out, invmod(&get(a), modulus()));
+    }
+
+    fn bn254fr_negmod(out: *mut FieldHandle, a: *const FieldHandle) {
+        set(out, reduce(&-get(a)));
+    }
+
+    fn bn254fr_powmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        let (base, exp) = (get(a), get(b));
+        let base = if exp.sign() == Sign::Minus { invmod(&base, modulus()) } else { reduce(&base) };
+        set(out, base.modpow(&(exp.magnitude().clone().into()), modulus()));
+    }
+
+    fn bn254fr_idiv(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, fdiv_qr(&get(a), &get(b)).0);
+    }
+
+    fn bn254fr_irem(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, fdiv_qr(&get(a), &get(b)).1);
+    }
+
+    fn bn254fr_eq(a: *const FieldHandle, b: *const FieldHandle) -> bool {
+        get(a) == get(b)
+    }
+
+    fn bn254fr_lt(a: *const FieldHandle, b: *const FieldHandle) -> bool {
+        get(a) < get(b)
+    }
+
+    fn bn254fr_lte(a: *const FieldHandle, b: *const FieldHandle) -> bool {
+        get(a) <= get(b)
+    }
+
+    fn bn254fr_gt(a: *const FieldHandle, b: *const FieldHandle) -> bool {
+        get(a) > get(b)
+    }
+
+    fn bn254fr_gte(a: *const FieldHandle, b: *const FieldHandle) -> bool {
+        get(a) >= get(b)
+    }
+
+    fn bn254fr_eqz(a: *const FieldHandle) -> bool {
+        is_zero(&get(a))
+    }
+
+    fn bn254fr_land(a: *const FieldHandle, b: *const FieldHandle) -> bool {
+        !is_zero(&get(a)) && !is_zero(&get(b))
+    }
+
+    fn bn254fr_lor(a: *const FieldHandle, b: *const FieldHandle) -> bool {
+        !is_zero(&get(a)) || !is_zero(&get(b))
+    }
+
+    fn bn254fr_band(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, get(a) & get(b));
+    }
+
+    fn bn254fr_bor(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, get(a) | get(b));
+    }
+
+    fn bn254fr_bxor(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        set(out, get(a) ^ get(b));
+    }
+
+    fn bn254fr_bnot(out: *mut FieldHandle, a: *const FieldHandle) {
+        set(out, !get(a));
+    }
+
+    fn bn254fr_to_bits(outs: *mut FieldHandle, a: *const FieldHandle, count: u32) {
+        let x = get(a);
+        for i in 0..count as usize {
+            set(outs.add(i), u8::from(x.bit(i as u64)).into());
+        }
+    }
+
+    fn bn254fr_from_bits(out: *mut FieldHandle, bits: *const FieldHandle, count: u32) {
+        let mut x = get(out);
+        for i in 0..count as usize {
+            x |= get(bits.add(i)) << i;
+        }
+        set(out, x);
+    }
+
+    fn bn254fr_shrmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        if let Some(v) = shrmod(&get(a), &get(b)) {
+            set(out, v);
+        }
+    }
+
+    fn bn254fr_shlmod(out: *mut FieldHandle, a: *const FieldHandle, b: *const FieldHandle) {
+        if let Some(v) = shlmod(&get(a), &get(b)) {
+            set(out, v);
+        }
+    }
+
+    fn bn254fr_bigint_mul_checked_no_carry(
+        out: *mut u64,
+        a: *const u64,
+        b: *const u64,
+        a_count: u32,
+        b_count: u32,
+    ) {
+        let (a_count, b_count) = (a_count as usize, b_count as usize);
+        let mut product = vec![BigInt::default(); a_count + b_count - 1];
+        for i in 0..a_count {
+            for j in 0..b_count {
+                product[i + j] += get_at(a, i) * get_at(b, j);
+            }
+        }
+        // The host accumulates into `out` and checks it against the product
+        for (k, p) in product.iter().enumerate() {
+            let c = get_at(out, k);
+            set_at(out, k, reduce(&(&c + p)));
+            check_equal("bn254fr_bigint_mul_checked_no_carry", &c, &BigInt::default());
+        }
+    }
+
+    fn bn254fr_bigint_idiv(
+        q: *mut u64,
+        r: *mut u64,
+        a: *const u64,
+        b: *const u64,
+        a_count: u32,
+        b_count: u32,
+        bits: u32,
+    ) {
+        let (qv, rv) = fdiv_qr(&compose(a, a_count, bits, false), &compose(b, b_count, bits, false));
+        decompose(q, a_count, &qv, bits);
+        decompose(r, b_count, &rv, bits);
+    }
+
+    fn bn254fr_bigint_invmod(
+        out: *mut u64,
+        a: *const u64,
+        m: *const u64,
+        a_count: u32,
+        m_count: u32,
+        bits: u32,
+    ) {
+        let m_val = compose(m, m_count, bits, true);
+        let inv = invmod(&compose(a, a_count, bits, true), &m_val.magnitude().clone().into());
+        decompose(out, m_count, &inv, bits);
+    }
+
+    fn bn254fr_bigint_convert_to_proper_representation(
+        out: *mut u64,
+        inp: *mut u64,
+        count: u32,
+        bits: u32,
+    ) {
+        let count = count as usize;
+        let splits: Vec<[BigInt; 3]> = (0..count)
+            .map(|i| {
+                let x = get_at(inp, i);
+                [low_bits(&x, bits), low_bits(&(&x >> bits), bits), low_bits(&(&x >> (2 * bits)), bits)]
+            })
+            .collect();
+        let split_carry = |tmp: BigInt| (low_bits(&tmp, bits), low_bits(&(tmp >> bits), bits));
+
+        set_at(out, 0, splits[0][0].clone());
+        if count == 1 {
+            set_at(out, 1, splits[0][1].clone());
+            return;
+        }
+
+        let (limb, mut carry) = split_carry(&splits[0][1] + &splits[1][0]);
+        set_at(out, 1, limb);
+        for i in 2..count {
+            let (limb, c) = split_carry(&splits[i][0] + &splits[i - 1][1] + &splits[i - 2][2] + &carry);
+            set_at(out, i, limb);
+            carry = c;
+        }
+        set_at(out, count, &splits[count - 1][1] + &splits[count - 2][2] + carry);
+    }
+}
+
+// ============= uint256 =============
+
+const UINT256_NLIMBS: u32 = 4;
+
+host_module! { "uint256";
+    fn uint256_set_bytes_little(out: *mut u64, bytes: *const u8, len: u32) {
+        decompose(out, UINT256_NLIMBS, &import_bytes(bytes, len as usize, -1), 64);
+    }
+
+    fn uint256_set_bytes_big(out: *mut u64, bytes: *const u8, len: u32) {
+        decompose(out, UINT256_NLIMBS, &import_bytes(bytes, len as usize, 1), 64);
+    }
+
+    fn uint256_set_str(out: *mut u64, s: *const i8, base: u32) {
+        decompose(out, UINT256_NLIMBS, &parse_c_str("uint256_set_str", s, base), 64);
+    }
+
+    fn uint256_print(a: *const u64) {
+        println!("@uint256_print: val={:x}", compose(a, UINT256_NLIMBS, 64, false));
+    }
+
+    fn uint512_idiv_normalized(
+        q_low: *mut u64,
+        q_high: *mut u64,
+        r: *mut u64,
+        a_low: *const u64,
+        a_high: *const u64,
+        b: *const u64,
+    ) {
+        let a = (compose(a_high, UINT256_NLIMBS, 64, false) << 256) | compose(a_low, UINT256_NLIMBS, 64, false);
+        let (q, rem) = fdiv_qr(&a, &compose(b, UINT256_NLIMBS, 64, false));
+        decompose(q_low, UINT256_NLIMBS, &q, 64);
+        decompose(q_high, 1, &(q >> 256), 64);
+        decompose(r, UINT256_NLIMBS, &rem, 64);
+    }
+
+    fn uint256_invmod(out: *mut u64, a: *const u64, m: *const u64) {
+        let inv = invmod(&compose(a, UINT256_NLIMBS, 64, false), &compose(m, UINT256_NLIMBS, 64, false));
+        decompose(out, UINT256_NLIMBS, &inv, 64);
+    }
+}
+
+// ============= vbn254fr =============
+
+#[derive(Default)]
+struct Vectors {
+    slots: Vec<Vec<BigInt>>,
+    free: Vec<u32>,
+}
+
+impl Vectors {
+    fn lanes(&self, v: *const u32) -> &[BigInt] {
+        &self.slots[unsafe { *v } as usize]
+    }
+
+    fn set(&mut self, v: *const u32, lanes: Vec<BigInt>) {
+        self.slots[unsafe { *v } as usize] = lanes;
+    }
+
+    fn map(&mut self, out: *mut u32, x: *const u32, f: impl Fn(&BigInt) -> BigInt) {
+        let lanes = self.lanes(x).iter().map(f).collect();
+        self.set(out, lanes);
+    }
+
+    fn zip(&mut self, out: *mut u32, x: *const u32, y: *const u32, f: impl Fn(&BigInt, &BigInt) -> BigInt) {
+        let lanes = self.lanes(x).iter().zip(self.lanes(y)).map(|(a, b)| f(a, b)).collect();
+        self.set(out, lanes);
+    }
+}
+
+static VECTORS: Mutex<Vectors> = Mutex::new(Vectors { slots: Vec::new(), free: Vec::new() });
+
+/// Vector storage; a failed assertion in another test does not poison it
+fn vectors() -> MutexGuard<'static, Vectors> {
+    VECTORS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn zero_lanes() -> Vec<BigInt> {
+    vec![BigInt::default(); LANES as usize]
+}
+
+/// Lanes holding `values`, zero-filled to [`LANES`]
+fn fill_lanes(values: impl IntoIterator<Item = BigInt>) -> Vec<BigInt> {
+    let mut lanes: Vec<BigInt> = values.into_iter().map(|v| reduce(&v)).collect();
+    assert!(lanes.len() <= LANES as usize, "vbn254fr: more values than lanes");
+    lanes.resize(LANES as usize, BigInt::default());
+    lanes
+}
+
+/// A `VBn254FrConstant`, eight little-endian 32-bit limbs
+unsafe fn constant(k: *const u32) -> BigInt {
+    BigInt::from_slice(Sign::Plus, std::slice::from_raw_parts(k, 8))
+}
+
+host_module! { "vbn254fr";
+    fn vbn254fr_get_size() -> u64 {
+        LANES
+    }
+
+    fn vbn254fr_alloc(v: *mut u32) {
+        let mut vs = vectors();
+        let slot = match vs.free.pop() {
+            Some(slot) => slot,
+            None => {
+                assert!(vs.slots.len() < MAX_VECTORS, "Bad alloc: 0/{MAX_VECTORS} free buffer available");
+                vs.slots.push(zero_lanes());
+                vs.slots.len() as u32 - 1
+            }
+        };
+        *v = slot;
+    }
+
+    fn vbn254fr_free(v: *mut u32) {
+        let mut vs = vectors();
+        vs.set(v, zero_lanes());
+        vs.free.push(*v);
+        *v = 0;
+    }
+
+    fn vbn254fr_constant_set_str(k: *mut u32, s: *const i8, base: i32) -> i32 {
+        let Some(value) = parse_mpz(c_str(s), base as u32) else {
+            return -1;
+        };
+        let limbs = value.magnitude().to_u32_digits();
+        for i in 0..8 {
+            *k.add(i) = limbs.get(i).copied().unwrap_or(0);
+        }
+        0
+    }
+
+    fn vbn254fr_set_ui(v: *mut u32, nums: *mut u32, len: u64) {
+        let nums = std::slice::from_raw_parts(nums, len as usize);
+        vectors().set(v, fill_lanes(nums.iter().map(|&n| n.into())));
+    }
+
+    fn vbn254fr_set_ui_scalar(v: *mut u32, num: u32) {
+        vectors().set(v, vec![num.into(); LANES as usize]);
+    }
+
+    fn vbn254fr_set_str(v: *mut u32, strings: *const *const i8, len: u64, base: i32) -> i32 {
+        let parsed: Vec<Option<BigInt>> =
+            (0..len as usize).map(|i| parse_mpz(c_str(*strings.add(i)), base as u32)).collect();
+        let failed = parsed.iter().any(Option::is_none);
+        vectors().set(v, fill_lanes(parsed.into_iter().map(Option::unwrap_or_default)));
+        -(failed as i32)
+    }
+
+    fn vbn254fr_set_str_scalar(v: *mut u32, s: *const i8, base: i32) -> i32 {
+        let parsed = parse_mpz(c_str(s), base as u32);
+        let failed = parsed.is_none();
+        vectors().set(v, vec![reduce(&parsed.unwrap_or_default()); LANES as usize]);
+        -(failed as i32)
+    }
+
+    fn vbn254fr_set_bytes(v: *mut u32, bytes: *const u8, num_bytes: u64, count: u64) {
+        let n = num_bytes as usize;
+        let values = (0..count as usize).map(|k| import_bytes(bytes.add(n * k), n, 1));
+        vectors().set(v, fill_lanes(values));
+    }
+
+    fn vbn254fr_set_bytes_scalar(v: *mut u32, bytes: *const u8, num_bytes: u64) {
+        let value = reduce(&import_bytes(bytes, num_bytes as usize, 1));
+        vectors().set(v, vec![value; LANES as usize]);
+    }
+
+    fn vbn254fr_set_bytes_from_offsets(
+        v: *mut u32,
+        bytes: *const u8,
+        offsets: *const u64,
+        count: u64,
+        num_bytes: u64,
+    ) {
+        let values = (0..count as usize).map(|k| import_bytes(bytes.add(*offsets.add(k) as usize), num_bytes as usize, 1));
+        vectors().set(v, fill_lanes(values));
+    }
+
+    fn vbn254fr_addmod(out: *mut u32, x: *const u32, y: *const u32) {
+        vectors().zip(out, x, y, |a, b| reduce(&(a + b)));
+    }
+
+    fn vbn254fr_addmod_constant(out: *mut u32, x: *const u32, k: *const u32) {
+        let k = constant(k);
+        vectors().map(out, x, |a| reduce(&(a + &k)));
+    }
+
+    fn vbn254fr_submod(out: *mut u32, x: *const u32, y: *const u32) {
+        vectors().zip(out, x, y, |a, b| reduce(&(a - b)));
+    }
+
+    fn vbn254fr_submod_constant(out: *mut u32, x: *const u32, k: *const u32) {
+        let k = constant(k);
+        vectors().map(out, x, |a| reduce(&(a - &k)));
+    }
+
+    fn vbn254fr_constant_submod(out: *mut u32, k: *const u32, x: *const u32) {
+        let k = constant(k);
+        vectors().map(out, x, |a| reduce(&(&k - a)));
+    }
+
+    fn vbn254fr_mulmod(out: *mut u32, x: *const u32, y: *const u32) {
+        vectors().zip(out, x, y, |a, b| reduce(&(a * b)));
+    }
+
+    fn vbn254fr_mulmod_constant(out: *mut u32, x: *const u32, k: *const u32) {
+        let k = constant(k);
+        vectors().map(out, x, |a| reduce(&(a * &k)));
+    }
+
+    fn vbn254fr_mont_mul_constant(out: *mut u32, x: *const u32, k: *const u32) {
+        let k = reduce(&(constant(k) * mont_r_inv()));
+        vectors().map(out, x, |a| reduce(&(a * &k)));
+    }
+
+    fn vbn254fr_divmod(out: *mut u32, x: *const u32, y: *const u32) {
+        vectors().zip(out, x, y, |a, b| reduce(&(a * invmod(b, modulus()))));
+    }
+
+    fn vbn254fr_copy(out: *mut u32, input: *const u32) {
+        vectors().map(out, input, BigInt::clone);
+    }
+
+    fn vbn254fr_print(v: *const u32, base: u32) {
+        let lanes: Vec<String> = vectors().lanes(v)[..3]
+            .iter()
+            .map(|x| match base {
+                10 => format!("{x}"),
+                16 => format!("{x:#x}"),
+                _ => panic!("vbn254fr_print: invalid base value: {base}"),
+            })
+            .collect();
+        println!("@print [handle={}] vec: {} ...", *v, lanes.join(" "));
+    }
+
+    fn vbn254fr_get_bytes(out: *mut u8, v: *const u32, count: u64) {
+        assert!(count <= LANES, "vbn254fr_get_bytes: too many lanes");
+        let out = std::slice::from_raw_parts_mut(out, count as usize * 32);
+        for (chunk, x) in out.chunks_mut(32).zip(vectors().lanes(v)) {
+            let (_, bytes) = x.to_bytes_be();
+            chunk.fill(0);
+            chunk[32 - bytes.len()..].copy_from_slice(&bytes);
+        }
+    }
+
+    fn vbn254fr_bit_decompose(arr: *mut u32, x: *const u32) {
+        let mut vs = vectors();
+        let value = vs.lanes(x).to_vec();
+        for i in 0..FIELD_BITS {
+            vs.set(arr.add(i as usize), value.iter().map(|a| u8::from(a.bit(i)).into()).collect());
+        }
+    }
+
+    fn vbn254fr_assert_equal(x: *const u32, y: *const u32) {
+        let mismatch = {
+            let vs = vectors();
+            vs.lanes(x).iter().zip(vs.lanes(y)).enumerate().find(|(_, (a, b))| a != b).map(|(i, (a, b))| (i, a.clone(), b.clone()))
+        };
+        if let Some((lane, a, b)) = mismatch {
+            panic!("vbn254fr_assert_equal: constraint failed in lane {lane}: {a} != {b}");
+        }
+    }
+}
+
+// ============= env =============
+
+#[no_mangle]
+extern "C-unwind" fn assert_zero(value: i32) {
+    record("env", "assert_zero", &[&value]);
+    assert!(value == 0, "assert_zero: constraint failed: expected 0, got {value}");
+}
+
+#[no_mangle]
+extern "C-unwind" fn assert_one(value: i32) {
+    record("env", "assert_one", &[&value]);
+    assert!(value == 1, "assert_one: constraint failed: expected 1, got {value}");
+}
+
+/// Every native value is a constant, so this only records the call
+#[no_mangle]
+extern "C-unwind" fn assert_constant(value: i32) {
+    record("env", "assert_constant", &[&value]);
+}
+
+#[no_mangle]
+unsafe extern "C-unwind" fn print_str(ptr: *const u8, len: i32) {
+    let mut out = std::io::stdout();
+    out.write_all(std::slice::from_raw_parts(ptr, len as usize)).unwrap();
+    out.flush().unwrap();
+}
+
+#[no_mangle]
+unsafe extern "C-unwind" fn dump_memory(ptr: *const u8, len: i32) {
+    println!("@dump: {}", hex::encode_upper(std::slice::from_raw_parts(ptr, len as usize)));
+}
+
+#[no_mangle]
+extern "C-unwind" fn constraint_trace_reset() {
+    TRACE.with(|t| *t.borrow_mut() = Some(Trace { digest: FNV_OFFSET_BASIS, pointers: HashMap::new() }));
+}
+
+#[no_mangle]
+extern "C-unwind" fn constraint_trace_digest() -> u64 {
+    TRACE.with(|t| t.borrow().as_ref().map_or(FNV_OFFSET_BASIS, |trace| trace.digest))
+}
+
+unsafe fn file_path(name: *const u8) -> PathBuf {
+    PathBuf::from(c_str(name as *const i8))
+}
+
+#[no_mangle]
+unsafe extern "C-unwind" fn file_size_get(name_ptr: *const u8) -> i32 {
+    let path = file_path(name_ptr);
+    match std::fs::metadata(&path) {
+        Ok(m) => m.len() as i32,
+        Err(e) => panic!("Cannot stat file {}: {e}", path.display()),
+    }
+}
+
+#[no_mangle]
+unsafe extern "C-unwind" fn file_get(buf_ptr: *mut u8, name_ptr: *const u8) -> i32 {
+    let path = file_path(name_ptr);
+    let data = std::fs::read(&path).unwrap_or_else(|e| panic!("Cannot read file {}: {e}", path.display()));
+    std::ptr::copy_nonoverlapping(data.as_ptr(), buf_ptr, data.len());
+    data.len() as i32
+}
+
+#[no_mangle]
+unsafe extern "C-unwind" fn file_read_chunk(buf_ptr: *mut u8, name_ptr: *const u8, offset: u64, len: u32) -> i32 {
+    let path = file_path(name_ptr);
+    let data = std::fs::read(&path).unwrap_or_else(|e| panic!("Cannot read file {}: {e}", path.display()));
+    let start = (offset as usize).min(data.len());
+    let chunk = &data[start..(start + len as usize).min(data.len())];
+    std::ptr::copy_nonoverlapping(chunk.as_ptr(), buf_ptr, chunk.len());
+    chunk.len() as i32
+}
+
+/// Writes are only permitted when `LIGETRON_OUTPUT_DIR` names a directory
+/// and the relative file name stays inside it, as in the host
+#[no_mangle]
+unsafe extern "C-unwind" fn file_put(name_ptr: *const u8, buf_ptr: *const u8, len: u32) -> i32 {
+    let name = file_path(name_ptr);
+    let escapes = name.is_absolute() || name.components().any(|c| c == Component::ParentDir);
+    let Some(dir) = std::env::var_os("LIGETRON_OUTPUT_DIR") else {
+        return -1;
+    };
+    if escapes {
+        return -1;
+    }
+    match std::fs::write(Path::new(&dir).join(name), std::slice::from_raw_parts(buf_ptr, len as usize)) {
+        Ok(()) => len as i32,
+        Err(_) => -1,
+    }
+}
+
+/// Match `name` against a glob supporting `*` and `?`
+fn glob_match(pat: &[u8], name: &[u8]) -> bool {
+    match pat.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|i| glob_match(rest, &name[i..])),
+        Some((&c, rest)) => match name.split_first() {
+            Some((&n, name)) => (c == b'?' || c == n) && glob_match(rest, name),
+            None => false,
+        },
+    }
+}
+
+#[no_mangle]
+unsafe extern "C-unwind" fn file_list(pattern_ptr: *const u8, buf_ptr: *mut u8, len: u32) -> i32 {
+    let pattern = file_path(pattern_ptr);
+    let dir = pattern.parent().unwrap_or(Path::new("")).to_path_buf();
+    let glob = pattern.file_name().map(|g| g.to_string_lossy().into_owned()).unwrap_or_default();
+
+    let mut names: Vec<String> = std::fs::read_dir(if dir.as_os_str().is_empty() { Path::new(".") } else { &dir })
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_file()))
+        .filter(|e| glob_match(glob.as_bytes(), e.file_name().to_string_lossy().as_bytes()))
+        .map(|e| dir.join(e.file_name()).to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+
+    let out: Vec<u8> = names.iter().flat_map(|n| n.bytes().chain([0])).collect();
+    if out.len() <= len as usize {
+        std::ptr::copy_nonoverlapping(out.as_ptr(), buf_ptr, out.len());
+    }
+    out.len() as i32
+}
+
+// ============= WASI =============
+
+/// A circuit argument, encoded as the prover encodes its JSON `args`
+#[derive(Clone, Debug)]
+pub enum Arg {
+    /// `{"i64": ..}`: 8 little-endian bytes
+    I64(i64),
+    /// `{"str": ..}`: the string and a NUL terminator
+    Str(String),
+    /// `{"hex": ..}`: the raw bytes
+    Bytes(Vec<u8>),
+}
+
+impl Arg {
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            Arg::I64(i) => i.to_le_bytes().to_vec(),
+            Arg::Str(s) => s.bytes().chain([0]).collect(),
+            Arg::Bytes(b) => b.clone(),
+        }
+    }
+}
+
+thread_local! {
+    static ARGS: RefCell<Option<Vec<Vec<u8>>>> = const { RefCell::new(None) };
+}
+
+/// Set the arguments [`get_args`](crate::api::get_args) returns on this
+/// thread, after the program name. Until set, the process arguments are
+/// passed as [`Arg::Str`].
+pub fn set_args(args: &[Arg]) {
+    let encoded = std::iter::once(b"Ligero\0".to_vec()).chain(args.iter().map(Arg::encode)).collect();
+    ARGS.with(|a| *a.borrow_mut() = Some(encoded));
+}
+
+fn encoded_args() -> Vec<Vec<u8>> {
+    ARGS.with(|a| a.borrow().clone()).unwrap_or_else(|| {
+        std::iter::once(b"Ligero\0".to_vec())
+            .chain(std::env::args().skip(1).map(|s| Arg::Str(s).encode()))
+            .collect()
+    })
+}
+
+#[no_mangle]
+unsafe extern "C-unwind" fn args_sizes_get(argc: *mut i32, buf_size: *mut i32) -> i32 {
+    let args = encoded_args();
+    *argc = args.len() as i32;
+    *buf_size = args.iter().map(Vec::len).sum::<usize>() as i32;
+    0
+}
+
+#[no_mangle]
+unsafe extern "C-unwind" fn args_get(argv: *mut *mut u8, buf: *mut u8) -> i32 {
+    let mut cursor = buf;
+    for (i, arg) in encoded_args().iter().enumerate() {
+        *argv.add(i) = cursor;
+        std::ptr::copy_nonoverlapping(arg.as_ptr(), cursor, arg.len());
+        cursor = cursor.add(arg.len());
+    }
+    0
+}
+
+/// Fills `buf` from the standard library's randomly keyed hasher; fine for
+/// blinding in tests, not for real secrets
+#[no_mangle]
+unsafe extern "C-unwind" fn random_get(buf: *mut u8, buf_len: i32) -> i32 {
+    let state = RandomState::new();
+    let buf = std::slice::from_raw_parts_mut(buf, buf_len as usize);
+    for (i, chunk) in buf.chunks_mut(8).enumerate() {
+        let word = state.hash_one(i).to_le_bytes();
+        chunk.copy_from_slice(&word[..chunk.len()]);
+    }
+    0
+}
+
+#[cfg(all(test, feature = "native-sim"))]
+mod tests {
+    use super::*;
+    use crate::api::{constraint_digest_of, get_args};
+    use crate::bn254fr::{Bn254Fr, addmod_checked, divmod_checked, mulmod_checked, submod_checked};
+    use crate::vbn254fr::VBn254Fr;
+
+    #[test]
+    fn field_arithmetic() {
+        let a = Bn254Fr::from_u32(6);
+        let b = Bn254Fr::from_u32(4);
+        let mut out = Bn254Fr::new();
+
+        addmod_checked(&mut out, &a, &b);
+        out.assert_equal_const(10);
+        submod_checked(&mut out, &b, &a);
+        Bn254Fr::assert_equal(&out, &Bn254Fr::from_str(&format!("{}", modulus() - 2)));
+        mulmod_checked(&mut out, &a, &b);
+        out.assert_equal_const(24);
+        let mut q = Bn254Fr::new();
+        divmod_checked(&mut q, &a, &b);
+        mulmod_checked(&mut out, &q, &Bn254Fr::from_u32(2));
+        out.assert_equal_const(3);
+
+        let bits = Bn254Fr::from_u64(0b1011).to_bits(8);
+        let values: Vec<u64> = bits.iter().map(Bn254Fr::get_u64).collect();
+        assert_eq!(values, [1, 1, 0, 1, 0, 0, 0, 0]);
+        Bn254Fr::from_bits_checked(&bits).assert_equal_const(11);
+    }
+
+    #[test]
+    fn vector_lanes() {
+        let x = VBn254Fr::from_ui(&[1, 2, 3]);
+        let mut y = x.clone();
+        y.mulmod_vec(&x);
+        VBn254Fr::assert_equal(&y, &VBn254Fr::from_ui(&[1, 4, 9]));
+    }
+
+    #[test]
+    #[should_panic(expected = "constraint failed")]
+    fn failing_constraint_panics() {
+        Bn254Fr::assert_equal(&Bn254Fr::from_u32(1), &Bn254Fr::from_u32(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "lane 1")]
+    fn failing_vector_constraint_names_lane() {
+        VBn254Fr::assert_equal(&VBn254Fr::from_ui(&[1, 2]), &VBn254Fr::from_ui(&[1, 3]));
+    }
+
+    fn fnv(calls: &[(&str, &[u64])]) -> u64 {
+        calls.iter()
+            .flat_map(|(c, ops)| c.bytes().chain(ops.iter().flat_map(|w| w.to_le_bytes())).chain(*b";"))
+            .fold(FNV_OFFSET_BASIS, |d, c| (d ^ c as u64).wrapping_mul(FNV_PRIME))
+    }
+
+    #[test]
+    fn trace_records_constraint_calls() {
+        let a = Bn254Fr::from_u32(3);
+        let b = Bn254Fr::from_u32(3);
+        let ((), digest) = constraint_digest_of(|| Bn254Fr::assert_equal(&a, &b));
+        assert_eq!(digest, fnv(&[("bn254fr.bn254fr_assert_equal", &[0, 1])]));
+    }
+
+    #[test]
+    fn trace_records_operands() {
+        let a = Bn254Fr::from_u32(3);
+        let b = Bn254Fr::from_u32(3);
+        let (_, same) = constraint_digest_of(|| Bn254Fr::assert_equal(&a, &a));
+        let (_, other) = constraint_digest_of(|| Bn254Fr::assert_equal(&a, &b));
+        assert_ne!(same, other);
+
+        let (_, narrow) = constraint_digest_of(|| a.to_bits(8));
+        let (_, wide) = constraint_digest_of(|| a.to_bits(9));
+        assert_ne!(narrow, wide);
+    }
+
+    #[test]
+    fn trace_ignores_hints_and_prints() {
+        let a = Bn254Fr::from_u32(3);
+        let (_, plain) = constraint_digest_of(|| Bn254Fr::assert_equal(&a, &a));
+        let (_, noisy) = constraint_digest_of(|| {
+            a.print_dec();
+            let _ = a.get_u64();
+            Bn254Fr::assert_equal(&a, &a);
+        });
+        assert_eq!(plain, noisy);
+
+        let (_, other) = constraint_digest_of(|| a.to_bits(8));
+        assert_ne!(plain, other);
+    }
+
+    #[test]
+    fn args_layout() {
+        set_args(&[Arg::I64(-42), Arg::Str("hi".into()), Arg::Bytes(vec![1, 2, 3])]);
+        let args = get_args();
+        assert_eq!(args.len(), 4);
+        assert_eq!(args.get_as_bytes(0), b"Ligero\0");
+        assert_eq!(args.get_as_int(1), -42);
+        assert_eq!(args.get_as_bytes(2), b"hi\0");
+        assert_eq!(args.get_as_bytes(3), [1, 2, 3]);
+    }
+}
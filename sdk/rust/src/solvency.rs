@@ -0,0 +1,76 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Proof of Solvency for Ligetron
+//!
+//! Sums private balances with per-entry range checks, compares the total
+//! against a public value, and binds the entries to a Merkle sum tree
+//! commitment (the root hash users check their inclusion against, see
+//! [`crate::merkle_sum`]).
+
+use crate::bn254fr::{Bn254Fr, submod_checked};
+use crate::merkle_sum::{MerkleSumTree, VALUE_BITS, SUM_BITS};
+
+/// Bit width of a single balance
+pub const BALANCE_BITS: usize = VALUE_BITS;
+
+/// Bit width used for totals (up to 2^32 balances)
+pub const TOTAL_BITS: usize = SUM_BITS;
+
+/// Sum of private balances, range checking every entry to
+/// [`BALANCE_BITS`] bits so the total cannot wrap around the field.
+pub fn sum_balances_checked(balances: &[Bn254Fr]) -> Bn254Fr {
+    assert!((balances.len() as u64) <= 1u64 << (TOTAL_BITS - BALANCE_BITS),
+            "sum_balances_checked: too many balances");
+    let mut total = Bn254Fr::from_u32(0);
+    for b in balances {
+        let _ = b.to_bits(BALANCE_BITS);
+        total.addmod_checked(b);
+    }
+    total
+}
+
+/// Assert total >= bound, for values fitting in [`TOTAL_BITS`] bits
+pub fn assert_total_gte(total: &Bn254Fr, bound: &Bn254Fr) {
+    let _ = bound.to_bits(TOTAL_BITS);
+    let mut diff = Bn254Fr::new();
+    submod_checked(&mut diff, total, bound);
+    let _ = diff.to_bits(TOTAL_BITS);
+}
+
+/// Assert total <= bound, for values fitting in [`TOTAL_BITS`] bits
+pub fn assert_total_lte(total: &Bn254Fr, bound: &Bn254Fr) {
+    let _ = bound.to_bits(TOTAL_BITS);
+    let mut diff = Bn254Fr::new();
+    submod_checked(&mut diff, bound, total);
+    let _ = diff.to_bits(TOTAL_BITS);
+}
+
+/// Prove solvency: the private liabilities commit to the public `root`
+/// and their total does not exceed the public `reserves`.
+/// Returns the total liabilities.
+pub fn assert_solvent(
+    ids: &[Bn254Fr],
+    balances: &[Bn254Fr],
+    root: &Bn254Fr,
+    reserves: &Bn254Fr,
+) -> Bn254Fr {
+    let tree = MerkleSumTree::new(ids, balances);
+    let total = tree.root().sum.clone();
+    Bn254Fr::assert_equal(&tree.root().hash, root);
+    assert_total_lte(&total, reserves);
+    total
+}
@@ -0,0 +1,101 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! SRP Password Verifiers for Ligetron
+//!
+//! Proves knowledge of a password consistent with an SRP-6a verifier
+//! (RFC 2945, RFC 5054) without revealing the password:
+//!
+//! - `x = H(salt || H(identity || ":" || password))`
+//! - `v = g^x mod N`
+//!
+//! `H` is SHA-256 and runs as plain wasm like [`sha2_256`]; the
+//! exponentiation is constrained 2048-bit modular arithmetic from
+//! [`biguint`](crate::biguint) with one squaring and one multiplication per
+//! bit of `x`. The salt, identity and group are public, as they are in the
+//! SRP protocol itself.
+//!
+//! [`sha2_256`]: crate::sha2::sha2_256
+
+use crate::biguint::{self, Uint2048};
+use crate::bn254fr::Bn254Fr;
+use crate::sha2::Sha256Context;
+
+/// 2048-bit safe prime of the RFC 5054 group, generator 2
+pub const RFC5054_2048_N: &str = concat!(
+    "ac6bdb41324a9a9bf166de5e1389582faf72b6651987ee07fc3192943db56050",
+    "a37329cbb4a099ed8193e0757767a13dd52312ab4b03310dcd7f48a9da04fd50",
+    "e8083969edb767b0cf6095179a163ab3661a05fbd5faaae82918a9962f0b93b8",
+    "55f97993ec975eeaa80d740adbf4ff747359d041d5c33ea71d281e446b14773b",
+    "ca97b43a23fb801676bd207a436c6481f1d2b9078717461a5b9d32e688f87748",
+    "544523b524b0d57d5ea77a2775d2ecfa032cfbdbf52fb3786160279004e57ae6",
+    "af874e7303ce53299ccc041c7bc308d82a5698f3a8d0c38271ae35f8e9dbfbb6",
+    "94b5c803d89f7ae435de236d525f54759b65e372fcd68ef20fa7111f9e4aff73",
+);
+
+/// Generator of the RFC 5054 2048-bit group
+pub const RFC5054_2048_G: u64 = 2;
+
+/// SRP group parameters
+#[derive(Clone)]
+pub struct SrpGroup {
+    /// Safe prime modulus
+    pub n: Uint2048,
+    /// Generator
+    pub g: Uint2048,
+}
+
+impl SrpGroup {
+    /// The 2048-bit group from RFC 5054, appendix A
+    pub fn rfc5054_2048() -> Self {
+        Self {
+            n: Uint2048::from_hex(RFC5054_2048_N),
+            g: Uint2048::from_u64(RFC5054_2048_G),
+        }
+    }
+}
+
+/// Private key `x = H(salt || H(identity || ":" || password))`
+pub fn compute_x(salt: &[u8], identity: &[u8], password: &[u8]) -> [u8; 32] {
+    let mut inner = Sha256Context::new();
+    inner.update(identity);
+    inner.update(b":");
+    inner.update(password);
+
+    let mut outer = Sha256Context::new();
+    outer.update(salt);
+    outer.update(&inner.finalize());
+    outer.finalize()
+}
+
+/// Verifier `v = g^x mod N` for the password, with constraints
+pub fn verifier(group: &SrpGroup, salt: &[u8], identity: &[u8], password: &[u8]) -> Uint2048 {
+    let x = compute_x(salt, identity, password);
+
+    // Bits of the big-endian digest, least significant first
+    let exp_bits: Vec<Bn254Fr> = x
+        .iter()
+        .rev()
+        .flat_map(|&b| Bn254Fr::from_u32(b as u32).to_bits(8))
+        .collect();
+    biguint::powmod_bits(&group.g, &exp_bits, &group.n)
+}
+
+/// Assert that `password` matches the SRP verifier `v` stored for
+/// `identity` with `salt`
+pub fn assert_verifier(group: &SrpGroup, v: &Uint2048, salt: &[u8], identity: &[u8], password: &[u8]) {
+    biguint::assert_equal(&verifier(group, salt, identity, password), v);
+}
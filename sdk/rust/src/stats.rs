@@ -0,0 +1,317 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Private Statistics for Ligetron
+//!
+//! Constrained aggregate statistics over private vectors of unsigned
+//! fixed-point values (up to [`VALUE_BITS`] bits, `frac_bits` fractional
+//! bits). The number of samples is public.
+//!
+//! Order statistics (min, max, percentiles) do not sort the data: the result
+//! is witnessed and proven to be a member of the vector with the right number
+//! of elements below and above it, which costs one comparison per element.
+//!
+//! [`sort_checked`] is a Batcher odd-even merge sorting network, used for
+//! [`top_k_checked`]; [`count_above_checked`] needs one comparison per
+//! sample.
+//!
+//! [`histogram_checked`] witnesses each sample's bucket as a one-hot vector
+//! and proves the sample lies between that bucket's bounds, so every sample
+//! is counted exactly once with two comparisons regardless of the number of
+//! buckets.
+
+use crate::bn254fr::{Bn254Fr, addmod_checked, submod_checked, mulmod_checked, mux, idiv, irem};
+use crate::cmp::{lt_checked, assert_lt, assert_lte};
+
+/// Bit width of a sample
+pub const VALUE_BITS: usize = 64;
+
+/// Maximum number of samples (keeps sums of squares inside the field)
+pub const MAX_SAMPLES: u64 = 1 << 32;
+
+fn count_bits(n: usize) -> usize {
+    (usize::BITS - n.leading_zeros()) as usize
+}
+
+/// Range check every sample to VALUE_BITS bits
+fn check_samples(xs: &[Bn254Fr]) {
+    assert!(!xs.is_empty() && xs.len() as u64 <= MAX_SAMPLES, "stats: invalid sample count");
+    for x in xs {
+        let _ = x.to_bits(VALUE_BITS);
+    }
+}
+
+/// Constrained floor division by a public constant `d` < 2^dbits.
+/// `x` must be below 2^(qbits + dbits).
+fn div_constant(x: &Bn254Fr, d: &Bn254Fr, qbits: usize, dbits: usize) -> Bn254Fr {
+    let mut q = Bn254Fr::new();
+    let mut r = Bn254Fr::new();
+    idiv(&mut q, x, d);
+    irem(&mut r, x, d);
+
+    // x = q * d + r, 0 <= r < d
+    let mut qd = q.clone();
+    qd.mulmod_constant_checked(d);
+    Bn254Fr::assert_add(x, &qd, &r);
+    let _ = q.to_bits(qbits);
+    let _ = r.to_bits(dbits);
+    let mut slack = d.clone();
+    slack.submod_checked(&Bn254Fr::from_u32(1));
+    slack.submod_checked(&r);
+    let _ = slack.to_bits(dbits);
+    q
+}
+
+/// Assert that `v` equals one of the elements of `xs`
+fn assert_member(v: &Bn254Fr, xs: &[Bn254Fr]) {
+    let mut prod = Bn254Fr::from_u32(1);
+    for x in xs {
+        let mut d = Bn254Fr::new();
+        submod_checked(&mut d, x, v);
+        prod.mulmod_checked(&d);
+    }
+    Bn254Fr::assert_equal(&prod, &Bn254Fr::from_u32(0));
+}
+
+// ============= Moments =============
+
+/// Sum of the samples with constraints
+pub fn sum_checked(xs: &[Bn254Fr]) -> Bn254Fr {
+    check_samples(xs);
+    let mut sum = Bn254Fr::from_u32(0);
+    for x in xs {
+        sum.addmod_checked(x);
+    }
+    sum
+}
+
+/// Arithmetic mean, rounded down, in the samples' fixed-point scale
+pub fn mean_checked(xs: &[Bn254Fr]) -> Bn254Fr {
+    let n = xs.len();
+    let sum = sum_checked(xs);
+    div_constant(&sum, &Bn254Fr::from_u64(n as u64), VALUE_BITS, count_bits(n))
+}
+
+/// Population variance, rounded down, in the samples' fixed-point scale:
+/// `(n * sum(x^2) - sum(x)^2) / (n^2 * 2^frac_bits)`
+pub fn variance_checked(xs: &[Bn254Fr], frac_bits: usize) -> Bn254Fr {
+    assert!(frac_bits < VALUE_BITS, "variance_checked: invalid fractional bits");
+    let n = xs.len();
+    let sum = sum_checked(xs);
+
+    let mut sum_sq = Bn254Fr::from_u32(0);
+    for x in xs {
+        let mut sq = Bn254Fr::new();
+        mulmod_checked(&mut sq, x, x);
+        sum_sq.addmod_checked(&sq);
+    }
+
+    // num = n * sum(x^2) - sum(x)^2 >= 0 by Cauchy-Schwarz
+    let nb = count_bits(n);
+    let mut num = sum_sq;
+    num.mulmod_constant_checked(&Bn254Fr::from_u64(n as u64));
+    let mut sum2 = Bn254Fr::new();
+    mulmod_checked(&mut sum2, &sum, &sum);
+    num.submod_checked(&sum2);
+
+    let mut den = Bn254Fr::from_u64(n as u64);
+    den.mulmod(&Bn254Fr::from_u64(n as u64));
+    den.mulmod(&Bn254Fr::from_u64(1u64 << frac_bits));
+
+    let dbits = 2 * nb + frac_bits;
+    div_constant(&num, &den, 2 * VALUE_BITS - frac_bits, dbits)
+}
+
+// ============= Order Statistics =============
+
+/// The `k`-th smallest sample (0-based) with constraints.
+///
+/// Proves `v` is a sample, `#{x < v} <= k` and `#{x <= v} >= k + 1`.
+pub fn kth_smallest_checked(xs: &[Bn254Fr], k: usize) -> Bn254Fr {
+    check_samples(xs);
+    assert!(k < xs.len(), "kth_smallest_checked: rank out of range");
+
+    let mut sorted: Vec<u64> = xs.iter().map(|x| x.get_u64()).collect();
+    sorted.sort_unstable();
+    let v = Bn254Fr::from_u64(sorted[k]);
+    assert_member(&v, xs);
+
+    // below = #{x < v}, above = #{x > v}
+    let one = Bn254Fr::from_u32(1);
+    let mut below = Bn254Fr::from_u32(0);
+    let mut above = Bn254Fr::from_u32(0);
+    for x in xs {
+        below.addmod_checked(&lt_checked(x, &v, VALUE_BITS));
+        above.addmod_checked(&lt_checked(&v, x, VALUE_BITS));
+    }
+
+    // k - below >= 0
+    let nb = count_bits(xs.len());
+    let mut t = Bn254Fr::from_u64(k as u64);
+    t.submod_checked(&below);
+    let _ = t.to_bits(nb);
+
+    // n - above >= k + 1
+    let mut t = Bn254Fr::from_u64(xs.len() as u64);
+    t.submod_checked(&above);
+    t.submod_checked(&Bn254Fr::from_u64(k as u64));
+    t.submod_checked(&one);
+    let _ = t.to_bits(nb);
+
+    v
+}
+
+/// Minimum sample with constraints
+pub fn min_checked(xs: &[Bn254Fr]) -> Bn254Fr {
+    kth_smallest_checked(xs, 0)
+}
+
+/// Maximum sample with constraints
+pub fn max_checked(xs: &[Bn254Fr]) -> Bn254Fr {
+    kth_smallest_checked(xs, xs.len() - 1)
+}
+
+/// Median (lower median for even counts) with constraints
+pub fn median_checked(xs: &[Bn254Fr]) -> Bn254Fr {
+    kth_smallest_checked(xs, (xs.len() - 1) / 2)
+}
+
+/// Nearest-rank `p`-th percentile (0 <= p <= 100) with constraints
+pub fn percentile_checked(xs: &[Bn254Fr], p: u32) -> Bn254Fr {
+    assert!(p <= 100, "percentile_checked: p must be in 0..=100");
+    let n = xs.len();
+    let rank = (p as usize * n).div_ceil(100).max(1);
+    kth_smallest_checked(xs, rank - 1)
+}
+
+/// Number of samples strictly above the public `threshold` with constraints
+pub fn count_above_checked(xs: &[Bn254Fr], threshold: u64) -> Bn254Fr {
+    check_samples(xs);
+    let t = Bn254Fr::from_u64(threshold);
+    let mut count = Bn254Fr::from_u32(0);
+    for x in xs {
+        count.addmod_checked(&lt_checked(&t, x, VALUE_BITS));
+    }
+    count
+}
+
+/// Order `xs[i]` and `xs[j]` ascending with one comparison
+fn compare_swap(xs: &mut [Bn254Fr], i: usize, j: usize) {
+    let swap = lt_checked(&xs[j], &xs[i], VALUE_BITS);
+    let mut lo = Bn254Fr::new();
+    mux(&mut lo, &swap, &xs[i], &xs[j]);
+    let mut hi = Bn254Fr::new();
+    addmod_checked(&mut hi, &xs[i], &xs[j]);
+    hi.submod_checked(&lo);
+    xs[i] = lo;
+    xs[j] = hi;
+}
+
+/// The samples in ascending order with constraints, using a Batcher
+/// odd-even merge sorting network (`O(n log² n)` comparisons).
+///
+/// `n` is padded to a power of two with virtual `+∞` entries; comparators
+/// touching them never swap and are skipped.
+pub fn sort_checked(xs: &[Bn254Fr]) -> Vec<Bn254Fr> {
+    check_samples(xs);
+    let n = xs.len();
+    let size = n.next_power_of_two();
+    let mut out = xs.to_vec();
+
+    let mut p = 1;
+    while p < size {
+        let mut k = p;
+        while k >= 1 {
+            let mut j = k % p;
+            while j + k < size {
+                for i in 0..k.min(size - j - k) {
+                    let (a, b) = (i + j, i + j + k);
+                    if a / (2 * p) == b / (2 * p) && b < n {
+                        compare_swap(&mut out, a, b);
+                    }
+                }
+                j += 2 * k;
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+    out
+}
+
+/// The `k` largest samples, largest first, with constraints
+pub fn top_k_checked(xs: &[Bn254Fr], k: usize) -> Vec<Bn254Fr> {
+    assert!(k <= xs.len(), "top_k_checked: k exceeds the sample count");
+    let sorted = sort_checked(xs);
+    sorted.into_iter().rev().take(k).collect()
+}
+
+// ============= Histograms =============
+
+/// Counts of the samples per bucket with constraints.
+///
+/// The strictly increasing public `boundaries` `b_0 < ... < b_{m-1}` define
+/// `m + 1` buckets: `x < b_0`, `b_{j-1} <= x < b_j`, and `x >= b_{m-1}`.
+/// Each sample gets a one-hot bucket indicator, proven by
+/// `lo(bucket) <= x < hi(bucket)`, and the counts are checked to add up to
+/// the number of samples.
+pub fn histogram_checked(xs: &[Bn254Fr], boundaries: &[u64]) -> Vec<Bn254Fr> {
+    check_samples(xs);
+    assert!(boundaries.windows(2).all(|w| w[0] < w[1]), "histogram_checked: boundaries must be strictly increasing");
+
+    // Bucket bounds; the last upper bound is 2^VALUE_BITS
+    let lows: Vec<Bn254Fr> = std::iter::once(0).chain(boundaries.iter().copied())
+        .map(Bn254Fr::from_u64)
+        .collect();
+    let highs: Vec<Bn254Fr> = boundaries.iter().copied()
+        .map(Bn254Fr::from_u64)
+        .chain(std::iter::once(Bn254Fr::from_str("0x10000000000000000")))
+        .collect();
+
+    let one = Bn254Fr::from_u32(1);
+    let mut counts = vec![Bn254Fr::from_u32(0); lows.len()];
+    for x in xs {
+        let bucket = boundaries.partition_point(|&b| b <= x.get_u64());
+
+        let mut total = Bn254Fr::from_u32(0);
+        let mut lo = Bn254Fr::from_u32(0);
+        let mut hi = Bn254Fr::from_u32(0);
+        for (j, count) in counts.iter_mut().enumerate() {
+            let ind = Bn254Fr::from_u32((j == bucket) as u32);
+            Bn254Fr::assert_bit(&ind);
+            total.addmod_checked(&ind);
+            count.addmod_checked(&ind);
+
+            let mut t = ind.clone();
+            t.mulmod_constant_checked(&lows[j]);
+            lo.addmod_checked(&t);
+            let mut t = ind;
+            t.mulmod_constant_checked(&highs[j]);
+            hi.addmod_checked(&t);
+        }
+        Bn254Fr::assert_equal(&total, &one);
+
+        assert_lte(&lo, x, VALUE_BITS + 1);
+        assert_lt(x, &hi, VALUE_BITS + 1);
+    }
+
+    let mut sum = Bn254Fr::from_u32(0);
+    for c in &counts {
+        sum.addmod_checked(c);
+    }
+    Bn254Fr::assert_equal(&sum, &Bn254Fr::from_u64(xs.len() as u64));
+    counts
+}
@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Constrained String Gadgets for Ligetron
+//!
+//! A private string is a slice of `Bn254Fr`, one element per byte. Its
+//! length is public, its contents are not. The gadgets here bind a string to
+//! a Poseidon2 commitment and prove facts about its contents, such as
+//! containing a public substring at a private offset (redacted documents).
+
+use crate::bn254fr::{Bn254Fr, mulmod_checked};
+use crate::poseidon2::Poseidon2Context;
+
+/// Load private bytes as a string of field elements
+pub fn string_from_bytes(s: &[u8]) -> Vec<Bn254Fr> {
+    s.iter().map(|&c| Bn254Fr::from_u32(c as u32)).collect()
+}
+
+/// Blinded Poseidon2 commitment to a string
+pub fn commit(chars: &[Bn254Fr], blinding: &Bn254Fr) -> Bn254Fr {
+    let mut ctx = Poseidon2Context::new();
+    ctx.digest_update(blinding);
+    for c in chars {
+        ctx.digest_update(c);
+    }
+    ctx.digest_final()
+}
+
+/// Assert that `chars` contains the public `needle` starting at the private
+/// position `offset`, without revealing the position.
+///
+/// A one-hot selector over all candidate positions is witnessed and each
+/// needle byte is matched against the selected window, costing
+/// O(len(chars) * len(needle)) multiplications.
+pub fn assert_substring_at_hidden_index(chars: &[Bn254Fr], needle: &[u8], offset: usize) {
+    assert_contains_at_hidden_index(chars, &string_from_bytes(needle), offset);
+}
+
+/// Like [`assert_substring_at_hidden_index`], with a private `needle`
+/// given as field elements (e.g. computed in the circuit)
+pub fn assert_contains_at_hidden_index(chars: &[Bn254Fr], needle: &[Bn254Fr], offset: usize) {
+    assert!(!needle.is_empty() && needle.len() <= chars.len(),
+            "assert_contains_at_hidden_index: invalid needle length");
+    let positions = chars.len() - needle.len() + 1;
+
+    // One-hot selector: bits summing to one
+    let sel: Vec<Bn254Fr> = (0..positions)
+        .map(|i| Bn254Fr::from_u32((i == offset) as u32))
+        .collect();
+    let mut sum = Bn254Fr::from_u32(0);
+    for s in &sel {
+        Bn254Fr::assert_bit(s);
+        sum.addmod_checked(s);
+    }
+    Bn254Fr::assert_equal(&sum, &Bn254Fr::from_u32(1));
+
+    // sum_i sel_i * chars[i + j] == needle[j]
+    for (j, b) in needle.iter().enumerate() {
+        let mut acc = Bn254Fr::from_u32(0);
+        for (i, s) in sel.iter().enumerate() {
+            let mut t = Bn254Fr::new();
+            mulmod_checked(&mut t, s, &chars[i + j]);
+            acc.addmod_checked(&t);
+        }
+        Bn254Fr::assert_equal(&acc, b);
+    }
+}
+
+/// Assert that the string opening `commitment` contains the public `needle`
+/// at a private `offset`.
+pub fn assert_committed_contains(
+    commitment: &Bn254Fr,
+    chars: &[Bn254Fr],
+    blinding: &Bn254Fr,
+    needle: &[u8],
+    offset: usize,
+) {
+    Bn254Fr::assert_equal(&commit(chars, blinding), commitment);
+    assert_substring_at_hidden_index(chars, needle, offset);
+}
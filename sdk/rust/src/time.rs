@@ -0,0 +1,265 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Constrained Calendar Arithmetic for Ligetron
+//!
+//! Converts between Unix timestamps, day numbers and proleptic Gregorian
+//! (year, month, day) dates, with leap years and month lengths enforced in
+//! the circuit. Supported dates range from 1970-01-01 up to the year 65535.
+//!
+//! The date <-> day conversion follows Howard Hinnant's `days_from_civil`
+//! algorithm: the date is witnessed and the day number is recomputed from it
+//! with constrained division by constants.
+
+use crate::bn254fr::{Bn254Fr, submod_checked, eqz_checked, eq_checked, lt_constant_checked};
+use crate::cmp::lt_checked;
+
+/// Seconds per day
+pub const SECONDS_PER_DAY: u32 = 86400;
+
+/// Day number of 1970-01-01 counted from 0000-03-01
+const EPOCH_OFFSET: u32 = 719468;
+
+/// Days in a 400-year era
+const DAYS_PER_ERA: u32 = 146097;
+
+/// Bit width of day numbers (enough for year 65535)
+const DAY_BITS: usize = 25;
+
+/// Bit width of Unix timestamps
+const TIMESTAMP_BITS: usize = 42;
+
+/// Month lengths in a non-leap year
+const MONTH_DAYS: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// A calendar date whose components are constrained field elements
+#[derive(Clone)]
+pub struct Date {
+    pub year: Bn254Fr,
+    pub month: Bn254Fr,
+    pub day: Bn254Fr,
+}
+
+/// Constrained division by a constant: returns (x / k, x % k).
+/// `x` must be below 2^64 and the quotient must fit in `qbits` bits.
+fn divrem_constant(x: &Bn254Fr, k: u32, qbits: usize) -> (Bn254Fr, Bn254Fr) {
+    let v = x.get_u64();
+    let q = Bn254Fr::from_u64(v / k as u64);
+    let r = Bn254Fr::from_u64(v % k as u64);
+
+    // x = q * k + r
+    let mut qk = q.clone();
+    qk.mulmod_constant_checked(&Bn254Fr::from_u32(k));
+    Bn254Fr::assert_add(x, &qk, &r);
+
+    // q < 2^qbits, r < k
+    let _ = q.to_bits(qbits);
+    let rbits = r.to_bits((u32::BITS - k.leading_zeros()) as usize);
+    let lt = lt_constant_checked(&rbits, &format!("{:#x}", k));
+    Bn254Fr::assert_equal(&lt, &Bn254Fr::from_u32(1));
+    (q, r)
+}
+
+/// out = 1 if `year` is a Gregorian leap year, 0 otherwise
+pub fn is_leap_year_checked(year: &Bn254Fr) -> Bn254Fr {
+    let mut z4 = Bn254Fr::new();
+    let mut z100 = Bn254Fr::new();
+    let mut z400 = Bn254Fr::new();
+    eqz_checked(&mut z4, &divrem_constant(year, 4, 16).1);
+    eqz_checked(&mut z100, &divrem_constant(year, 100, 16).1);
+    eqz_checked(&mut z400, &divrem_constant(year, 400, 16).1);
+
+    // Divisible by 400 implies divisible by 100 implies divisible by 4
+    let mut out = Bn254Fr::new();
+    submod_checked(&mut out, &z4, &z100);
+    out.addmod_checked(&z400);
+    out
+}
+
+/// Number of days in `month` of `year` with constraints.
+/// `month` is assumed to be constrained to 1..=12.
+pub fn days_in_month_checked(year: &Bn254Fr, month: &Bn254Fr) -> Bn254Fr {
+    let mut out = Bn254Fr::from_u32(0);
+    for (i, &len) in MONTH_DAYS.iter().enumerate() {
+        let mut sel = Bn254Fr::new();
+        eq_checked(&mut sel, month, &Bn254Fr::from_u32(i as u32 + 1));
+        sel.mulmod_constant_checked(&Bn254Fr::from_u32(len));
+        out.addmod_checked(&sel);
+    }
+
+    // February gains a day in leap years
+    let mut feb = Bn254Fr::new();
+    eq_checked(&mut feb, month, &Bn254Fr::from_u32(2));
+    feb.mulmod_checked(&is_leap_year_checked(year));
+    out.addmod_checked(&feb);
+    out
+}
+
+impl Date {
+    /// Build a date from constrained components, asserting it is a valid
+    /// calendar date.
+    pub fn new_checked(year: &Bn254Fr, month: &Bn254Fr, day: &Bn254Fr) -> Self {
+        let one = Bn254Fr::from_u32(1);
+        let _ = year.to_bits(16);
+
+        // 1 <= month <= 12
+        let mut m0 = Bn254Fr::new();
+        submod_checked(&mut m0, month, &one);
+        let lt = lt_constant_checked(&m0.to_bits(4), "0xc");
+        Bn254Fr::assert_equal(&lt, &one);
+
+        // 1 <= day <= days_in_month
+        let mut d0 = Bn254Fr::new();
+        submod_checked(&mut d0, day, &one);
+        let _ = d0.to_bits(5);
+        let dim = days_in_month_checked(year, month);
+        let lt = lt_checked(&d0, &dim, 5);
+        Bn254Fr::assert_equal(&lt, &one);
+
+        Date { year: year.clone(), month: month.clone(), day: day.clone() }
+    }
+
+    /// Build a constant date (values must be a valid date)
+    pub fn from_ymd(year: u32, month: u32, day: u32) -> Self {
+        Date {
+            year: Bn254Fr::from_u32(year),
+            month: Bn254Fr::from_u32(month),
+            day: Bn254Fr::from_u32(day),
+        }
+    }
+
+    /// Number of days since 1970-01-01 with constraints
+    pub fn to_days_checked(&self) -> Bn254Fr {
+        let one = Bn254Fr::from_u32(1);
+
+        // Shift the year to start in March: y' = y - (m <= 2)
+        let mut m0 = Bn254Fr::new();
+        submod_checked(&mut m0, &self.month, &one);
+        let jan_feb = lt_constant_checked(&m0.to_bits(4), "0x2");
+        let mut y = Bn254Fr::new();
+        submod_checked(&mut y, &self.year, &jan_feb);
+
+        let (era, yoe) = divrem_constant(&y, 400, 8);
+
+        // mp = (m + 9) % 12 = m - 3 + 12 * (m <= 2)
+        let mut mp = jan_feb.clone();
+        mp.mulmod_constant_checked(&Bn254Fr::from_u32(12));
+        mp.addmod_checked(&self.month);
+        mp.submod_checked(&Bn254Fr::from_u32(3));
+
+        // doy = (153 * mp + 2) / 5 + d - 1
+        let mut t = mp;
+        t.mulmod_constant_checked(&Bn254Fr::from_u32(153));
+        t.addmod_checked(&Bn254Fr::from_u32(2));
+        let (mut doy, _) = divrem_constant(&t, 5, 9);
+        doy.addmod_checked(&self.day);
+        doy.submod_checked(&one);
+
+        // doe = yoe * 365 + yoe / 4 - yoe / 100 + doy
+        let (yoe4, _) = divrem_constant(&yoe, 4, 7);
+        let (yoe100, _) = divrem_constant(&yoe, 100, 2);
+        let mut doe = yoe;
+        doe.mulmod_constant_checked(&Bn254Fr::from_u32(365));
+        doe.addmod_checked(&yoe4);
+        doe.submod_checked(&yoe100);
+        doe.addmod_checked(&doy);
+
+        // days = era * 146097 + doe - 719468
+        let mut days = era;
+        days.mulmod_constant_checked(&Bn254Fr::from_u32(DAYS_PER_ERA));
+        days.addmod_checked(&doe);
+        days.submod_checked(&Bn254Fr::from_u32(EPOCH_OFFSET));
+
+        // Rejects dates before the epoch
+        let _ = days.to_bits(DAY_BITS);
+        days
+    }
+
+    /// Unix timestamp of midnight (UTC) of this date with constraints
+    pub fn to_timestamp_checked(&self) -> Bn254Fr {
+        let mut ts = self.to_days_checked();
+        ts.mulmod_constant_checked(&Bn254Fr::from_u32(SECONDS_PER_DAY));
+        ts
+    }
+
+    /// Convert a day number (days since 1970-01-01) into a date with
+    /// constraints. The date is witnessed and checked against the input.
+    pub fn from_days_checked(days: &Bn254Fr) -> Self {
+        let (y, m, d) = civil_from_days(days.get_u64());
+        let date = Date::new_checked(
+            &Bn254Fr::from_u32(y),
+            &Bn254Fr::from_u32(m),
+            &Bn254Fr::from_u32(d));
+        Bn254Fr::assert_equal(&date.to_days_checked(), days);
+        date
+    }
+
+    /// Convert a Unix timestamp into its (UTC) date with constraints
+    pub fn from_timestamp_checked(ts: &Bn254Fr) -> Self {
+        let _ = ts.to_bits(TIMESTAMP_BITS);
+        let (days, _) = divrem_constant(ts, SECONDS_PER_DAY, DAY_BITS);
+        Date::from_days_checked(&days)
+    }
+}
+
+/// Native day number -> (year, month, day)
+fn civil_from_days(days: u64) -> (u32, u32, u32) {
+    let z = days + EPOCH_OFFSET as u64;
+    let era = z / DAYS_PER_ERA as u64;
+    let doe = z - era * DAYS_PER_ERA as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + (m <= 2) as u64;
+    (y as u32, m as u32, d as u32)
+}
+
+// ============= Comparisons =============
+
+/// out = 1 if date `a` is strictly before date `b`, 0 otherwise
+pub fn date_lt_checked(a: &Date, b: &Date) -> Bn254Fr {
+    lt_checked(&a.to_days_checked(), &b.to_days_checked(), DAY_BITS)
+}
+
+/// Whole years elapsed from `birth` to `today` with constraints.
+/// Asserts that `birth` is not after `today`.
+pub fn age_checked(birth: &Date, today: &Date) -> Bn254Fr {
+    let thirty_two = Bn254Fr::from_u32(32);
+
+    // Compare (month, day) as month * 32 + day
+    let mut kb = birth.month.clone();
+    kb.mulmod_constant_checked(&thirty_two);
+    kb.addmod_checked(&birth.day);
+    let mut kt = today.month.clone();
+    kt.mulmod_constant_checked(&thirty_two);
+    kt.addmod_checked(&today.day);
+    let before_birthday = lt_checked(&kt, &kb, 9);
+
+    let mut age = Bn254Fr::new();
+    submod_checked(&mut age, &today.year, &birth.year);
+    age.submod_checked(&before_birthday);
+    let _ = age.to_bits(16);
+    age
+}
+
+/// Assert that at least `years` whole years elapsed from `birth` to `today`
+pub fn assert_age_at_least(birth: &Date, today: &Date, years: u32) {
+    let mut slack = age_checked(birth, today);
+    slack.submod_checked(&Bn254Fr::from_u32(years));
+    let _ = slack.to_bits(16);
+}
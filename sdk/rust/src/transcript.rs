@@ -0,0 +1,114 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! In-Guest Fiat-Shamir Transcripts for Ligetron
+//!
+//! Interactive-style gadgets (grand-product permutation checks, lookups,
+//! random linear combinations) need verifier randomness. The Ligetron
+//! prover derives its own Fiat-Shamir seeds from the program and the public
+//! inputs and only after the whole execution has been committed, so there is
+//! no prover transcript the guest could read challenges from mid-run.
+//!
+//! [`Transcript`] instead derives challenges inside the guest, from a
+//! Poseidon duplex ([`PoseidonDuplex`]) over everything absorbed so far.
+//! Absorbed values and the permutations are part of the constrained
+//! witness, so each challenge is a binding function of the data absorbed
+//! before it. As with any Fiat-Shamir transform the usual discipline
+//! applies:
+//!
+//! - absorb every value a challenge must be independent of (or a
+//!   commitment to it) before squeezing that challenge;
+//! - seed the transcript with the statement ([`Transcript::new`] takes a
+//!   label, public inputs go through [`Transcript::absorb`]).
+//!
+//! Each absorb call is framed by its length, so `[a]` and `[a, 0]` lead to
+//! different challenges.
+
+use crate::bn254fr::{Bn254Fr, addmod_checked};
+use crate::enc::PoseidonDuplex;
+
+/// Domain separation tag for transcripts
+pub const TRANSCRIPT_DOMAIN: &[u8] = b"ligetron.transcript.v1";
+
+fn tag(bytes: &[u8]) -> Bn254Fr {
+    assert!(bytes.len() <= 31, "transcript: label longer than 31 bytes");
+    let mut t = Bn254Fr::new();
+    t.set_bytes_big(bytes);
+    t
+}
+
+/// Duplex-sponge Fiat-Shamir transcript
+pub struct Transcript {
+    duplex: PoseidonDuplex,
+}
+
+impl Transcript {
+    /// Start a transcript for the protocol named `label` (at most 31 bytes)
+    pub fn new(label: &[u8]) -> Self {
+        let duplex = PoseidonDuplex::new(&tag(label), &Bn254Fr::from_u32(0), &tag(TRANSCRIPT_DOMAIN));
+        Self { duplex }
+    }
+
+    /// Absorb field elements as one length-framed message
+    pub fn absorb(&mut self, data: &[Bn254Fr]) {
+        let mut frame = Vec::with_capacity(data.len() + 1);
+        frame.push(Bn254Fr::from_u64(data.len() as u64));
+        frame.extend_from_slice(data);
+        self.duplex.absorb(&frame);
+    }
+
+    /// Squeeze a challenge from the whole field
+    pub fn challenge(&mut self) -> Bn254Fr {
+        self.duplex.squeeze()
+    }
+
+    /// Squeeze `n` challenges
+    pub fn challenges(&mut self, n: usize) -> Vec<Bn254Fr> {
+        (0..n).map(|_| self.challenge()).collect()
+    }
+
+    /// Squeeze a challenge of `bits` bits (at most 253), e.g. to keep
+    /// random linear combinations from wrapping around the modulus
+    pub fn challenge_bits(&mut self, bits: usize) -> Bn254Fr {
+        assert!(bits <= 253, "Transcript::challenge_bits: too many bits");
+        let c = self.challenge();
+        Bn254Fr::from_bits_checked(&c.to_bits_canonical()[..bits])
+    }
+}
+
+/// `prod (x_i + gamma)`
+fn grand_product(xs: &[Bn254Fr], gamma: &Bn254Fr) -> Bn254Fr {
+    let mut acc = Bn254Fr::from_u32(1);
+    for x in xs {
+        let mut term = Bn254Fr::new();
+        addmod_checked(&mut term, x, gamma);
+        acc.mulmod_checked(&term);
+    }
+    acc
+}
+
+/// Assert that `b` is a permutation of `a`.
+///
+/// Both vectors are absorbed, then `prod (a_i + gamma) == prod (b_i + gamma)`
+/// is checked at a squeezed challenge `gamma`. A cheating prover succeeds
+/// with probability at most `n / p`.
+pub fn assert_permutation(transcript: &mut Transcript, a: &[Bn254Fr], b: &[Bn254Fr]) {
+    assert_eq!(a.len(), b.len(), "assert_permutation: length mismatch");
+    transcript.absorb(a);
+    transcript.absorb(b);
+    let gamma = transcript.challenge();
+    Bn254Fr::assert_equal(&grand_product(a, &gamma), &grand_product(b, &gamma));
+}
@@ -17,11 +17,19 @@
 //! 256-bit Unsigned Integer Operations for Ligetron
 //!
 //! This module provides 256-bit unsigned integer arithmetic with automatic
-//! constraint generation for zero-knowledge proofs. A Uint256 is composed
-//! of 4 64-bit limbs stored as BN254 field elements.
+//! constraint generation for zero-knowledge proofs. A Uint256 is a
+//! [`BigUintN`] of 4 64-bit limbs stored as BN254 field elements.
+//!
+//! Comparison, selection, addition and multiplication are the generic
+//! [`crate::biguint`] operations, re-exported here. Parsing, printing,
+//! 512-bit division and inversion use the dedicated `uint256` host module.
 
+use crate::api;
+use crate::biguint::{BigUintN, BigUintNCc, BigUintNWide};
 use crate::bn254fr::Bn254Fr;
 
+pub use crate::biguint::{add_cc, assert_equal, eq, eqz, mul_hi, mul_lo, mul_wide, mux, sub_cc};
+
 /// Raw handle type for a single Bn254Fr element in FFI calls.
 /// This is the u64 handle that the VM uses internally.
 type Bn254FrRawHandle = u64;
@@ -37,19 +45,59 @@ struct Uint256Handle {
 /// Number of 64-bit limbs in a Uint256
 pub const UINT256_NLIMBS: usize = 4;
 
+/// Big-endian bytes to 64-bit limbs, least significant first
+const fn bytes_to_limbs(bytes: [u8; 32]) -> [u64; UINT256_NLIMBS] {
+    let mut limbs = [0u64; UINT256_NLIMBS];
+    let mut i = 0;
+    while i < 32 {
+        limbs[(31 - i) / 8] |= (bytes[i] as u64) << (8 * ((31 - i) % 8));
+        i += 1;
+    }
+    limbs
+}
+
+/// Parse a decimal or 0x-prefixed hex literal into 64-bit limbs, least
+/// significant first. In a constant context a literal that does not fit
+/// in 256 bits is a compile error; this is what [`u256!`](crate::u256) uses.
+pub const fn parse_literal_limbs(s: &str) -> [u64; UINT256_NLIMBS] {
+    bytes_to_limbs(crate::bn254fr::parse_bytes_big(s))
+}
+
+/// Parse a hex constant (with optional 0x prefix) into 64-bit limbs, least
+/// significant first
+pub const fn parse_hex_limbs(s: &str) -> [u64; UINT256_NLIMBS] {
+    bytes_to_limbs(crate::bn254fr::parse_hex_bytes_big(s))
+}
+
+/// Uint256 constant from a decimal or 0x-prefixed hex literal.
+///
+/// The literal is parsed into limbs at compile time and each limb is set
+/// directly, so no string is parsed at runtime:
+///
+/// ```ignore
+/// let p = u256!("0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+/// ```
+#[macro_export]
+macro_rules! u256 {
+    ($lit:expr) => {{
+        const LIMBS: [u64; 4] = $crate::uint256::parse_literal_limbs($lit);
+        $crate::uint256::Uint256::from_limbs_u64(LIMBS)
+    }};
+}
+
+/// A 256-bit unsigned integer
+///
+/// Internally represented as 4 64-bit limbs stored as BN254 field elements.
+/// Limb 0 is the least significant.
+pub type Uint256 = BigUintN<UINT256_NLIMBS>;
+
 /// Result of addition/subtraction with carry/borrow.
 /// C++ equivalent: struct uint256_cc
-pub struct Uint256Cc {
-    pub val: Uint256,
-    pub carry: Bn254Fr,
-}
+pub type Uint256Cc = BigUintNCc<UINT256_NLIMBS>;
 
 /// 512-bit wide result (used for multiplication).
 /// C++ equivalent: struct uint256_wide
-pub struct Uint256Wide {
-    pub lo: Uint256,
-    pub hi: Uint256,
-}
+pub type Uint256Wide = BigUintNWide<UINT256_NLIMBS>;
 
 impl Uint256Wide {
     /// Perform 512-bit division by normalized divisor.
@@ -115,49 +163,7 @@ fn set_uint256_handle(u: &mut Uint256, h: Uint256Handle) {
     }
 }
 
-/// A 256-bit unsigned integer
-///
-/// Internally represented as 4 64-bit limbs stored as BN254 field elements.
-/// Limb 0 is the least significant.
-#[repr(C)]
-pub struct Uint256 {
-    limbs: [Bn254Fr; UINT256_NLIMBS],
-}
-
-impl Drop for Uint256 {
-    fn drop(&mut self) {
-        // Bn254Fr handles its own cleanup via Drop
-    }
-}
-
-impl Clone for Uint256 {
-    fn clone(&self) -> Self {
-        let mut out = Uint256::new();
-        out.copy_checked(&self);
-        out
-    }
-}
-
-impl Default for Uint256 {
-    fn default() -> Self {
-        Uint256 {
-            limbs: [
-                Bn254Fr::new(),
-                Bn254Fr::new(),
-                Bn254Fr::new(),
-                Bn254Fr::new(),
-            ],
-        }
-    }
-}
-
 impl Uint256 {
-    /// Create a new uninitialized Uint256 (all limbs set to zero).
-    /// C++ equivalent: uint256()
-    pub fn new() -> Self {
-        Self::default()
-    }
-
     /// Copy value from another Uint256 with constraints.
     /// C++ equivalent: copy(const uint256&)
     pub fn copy_checked(&mut self, other: &Uint256) {
@@ -167,18 +173,6 @@ impl Uint256 {
         }
     }
 
-    /// Get a reference to a limb
-    #[inline(always)]
-    pub fn limb(&self, i: usize) -> &Bn254Fr {
-        &self.limbs[i]
-    }
-
-    /// Get a mutable reference to a limb
-    #[inline(always)]
-    pub fn limb_mut(&mut self, i: usize) -> &mut Bn254Fr {
-        &mut self.limbs[i]
-    }
-
     /// Set value from little-endian bytes (unchecked - no constraints added).
     /// C++ equivalent: set_bytes_little_unchecked(const unsigned char*, uint32_t)
     pub fn set_bytes_little_unchecked(&mut self, bytes: &[u8]) {
@@ -220,11 +214,13 @@ impl Uint256 {
         }
     }
 
-    /// Create a Uint256 from a u64 value.
-    /// C++ equivalent: uint256(uint64_t)
-    pub fn from_u64(val: u64) -> Self {
+    /// Create a constant Uint256 from 64-bit limbs, least significant
+    /// first, without parsing a string (see [`u256!`](crate::u256))
+    pub fn from_limbs_u64(limbs: [u64; UINT256_NLIMBS]) -> Self {
         let mut out = Self::new();
-        out.set_u64(val);
+        for (l, &v) in out.limbs.iter_mut().zip(limbs.iter()) {
+            l.set_u64(v);
+        }
         out
     }
 
@@ -287,28 +283,6 @@ impl Uint256 {
         self.limbs[0].get_u64()
     }
 
-    /// Set value from little-endian bytes with constraints.
-    /// C++ equivalent: set_bytes_little(const unsigned char*, uint32_t)
-    pub fn set_bytes_little(&mut self, bytes: &[u8]) {
-        // Use unchecked version first
-        self.set_bytes_little_unchecked(bytes);
-        // Add range constraints via bit decomposition
-        for i in 0..UINT256_NLIMBS {
-            let _ = self.limbs[i].to_bits(64);
-        }
-    }
-
-    /// Set value from big-endian bytes with constraints.
-    /// C++ equivalent: set_bytes_big(const unsigned char*, uint32_t)
-    pub fn set_bytes_big(&mut self, bytes: &[u8]) {
-        // Use unchecked version first
-        self.set_bytes_big_unchecked(bytes);
-        // Add range constraints via bit decomposition
-        for i in 0..UINT256_NLIMBS {
-            let _ = self.limbs[i].to_bits(64);
-        }
-    }
-
     /// Set limbs from array of Bn254Fr values with constraints.
     /// C++ equivalent: set_words(const bn254fr_class*)
     pub fn set_words(&mut self, words: &[Bn254Fr; UINT256_NLIMBS]) {
@@ -336,66 +310,51 @@ impl Uint256 {
         words
     }
 
-    /// Convert to little-endian bytes (32 bytes) with constraints.
-    /// C++ equivalent: to_bytes_little(unsigned char*) const
-    pub fn to_bytes_little(&self) -> [u8; 32] {
-        let mut bytes = [0u8; 32];
-        for i in 0..UINT256_NLIMBS {
-            let limb_val = self.limbs[i].get_u64();
-            let limb_bytes = limb_val.to_le_bytes();
-            bytes[i * 8..(i + 1) * 8].copy_from_slice(&limb_bytes);
-        }
-        // Add range constraints via bit decomposition
-        for i in 0..UINT256_NLIMBS {
-            let _ = self.limbs[i].to_bits(64);
-        }
-        bytes
-    }
-
-    /// Convert to big-endian bytes (32 bytes) with constraints.
-    /// C++ equivalent: to_bytes_big(unsigned char*) const
-    pub fn to_bytes_big(&self) -> [u8; 32] {
+    /// Uniformly random value from the host random source, with each limb
+    /// range checked to 64 bits by [`BigUintN::from_limbs`].
+    /// See [`crate::api::random_bytes`] for the properties of the source.
+    pub fn random() -> Self {
         let mut bytes = [0u8; 32];
-        for i in 0..UINT256_NLIMBS {
-            let limb_val = self.limbs[UINT256_NLIMBS - 1 - i].get_u64();
-            let limb_bytes = limb_val.to_be_bytes();
-            bytes[i * 8..(i + 1) * 8].copy_from_slice(&limb_bytes);
-        }
-        // Add range constraints via bit decomposition
-        for i in 0..UINT256_NLIMBS {
-            let _ = self.limbs[i].to_bits(64);
-        }
-        bytes
-    }
-
-    /// Decompose uint256 into 256 bits with constraints.
-    /// C++ equivalent: to_bits(bn254fr_class*) const
-    pub fn to_bits(&self) -> Vec<Bn254Fr> {
-        let mut all_bits = Vec::with_capacity(256);
-        for i in 0..UINT256_NLIMBS {
-            let limb_bits = self.limbs[i].to_bits(64);
-            all_bits.extend(limb_bits);
-        }
-        all_bits
-    }
-
-    /// Compose uint256 from 256 bits with constraints.
-    /// C++ equivalent: from_bits(const bn254fr_class*)
-    pub fn from_bits(bits: &[Bn254Fr]) -> Self {
-        assert!(bits.len() == 256, "from_bits requires exactly 256 bits");
-        let mut result = Uint256::new();
-        for i in 0..UINT256_NLIMBS {
-            let start = i * 64;
-            let end = start + 64;
-            result.limbs[i] = Bn254Fr::from_bits_checked(&bits[start..end]);
-        }
-        result
+        api::random_bytes(&mut bytes);
+        Uint256::from_limbs(std::array::from_fn(|i| {
+            Bn254Fr::from_u64(u64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap()))
+        }))
+    }
+
+    /// Uniformly random value in `[0, modulus)` by rejection sampling,
+    /// constrained to be below `modulus`. Panics if `modulus` is zero.
+    pub fn random_below(modulus: &Uint256) -> Self {
+        let m: Vec<u64> = (0..UINT256_NLIMBS).map(|i| modulus.limbs[i].get_u64()).collect();
+        let top = m.iter().rposition(|&l| l != 0)
+            .expect("Uint256::random_below: zero modulus");
+        let top_mask = u64::MAX >> m[top].leading_zeros();
+
+        let limbs = loop {
+            let mut bytes = [0u8; 32];
+            api::random_bytes(&mut bytes);
+            let mut limbs = [0u64; UINT256_NLIMBS];
+            for (i, limb) in limbs.iter_mut().enumerate().take(top + 1) {
+                *limb = u64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap());
+            }
+            limbs[top] &= top_mask;
+            // Accept if below the modulus, comparing from the top limb
+            if limbs.iter().rev().lt(m.iter().rev()) {
+                break limbs;
+            }
+        };
+
+        let out = Uint256::from_limbs(limbs.map(Bn254Fr::from_u64));
+
+        // out - modulus must borrow
+        let cc = sub_cc(&out, modulus);
+        Bn254Fr::assert_equal(&cc.carry, &Bn254Fr::from_u32(1));
+        out
     }
 }
 
 // ============= Host Function Declarations =============
 #[link(wasm_import_module = "uint256")]
-extern "C" {
+extern "C-unwind" {
     #[link_name = "uint256_set_bytes_little"]
     fn _uint256_set_bytes_little(out: *mut Uint256Handle, bytes: *const u8, len: u32);
 
@@ -422,59 +381,6 @@ extern "C" {
     fn _uint256_invmod(out: *mut Uint256Handle, a: *const Uint256Handle, m: *const Uint256Handle);
 }
 
-// ============= Comparison Operations =============
-
-/// Compare two Uint256 values for equality.
-/// Returns 1 if equal, 0 otherwise. Adds constraints.
-/// C++ equivalent: eq(bn254fr_class&, const uint256&, const uint256&)
-pub fn eq(x: &Uint256, y: &Uint256) -> Bn254Fr {
-    // Compare all limbs and AND the results
-    let mut eq0 = Bn254Fr::new();
-    let mut eq1 = Bn254Fr::new();
-    let mut eq2 = Bn254Fr::new();
-    let mut eq3 = Bn254Fr::new();
-
-    crate::bn254fr::eq_checked(&mut eq0, x.limb(0), y.limb(0));
-    crate::bn254fr::eq_checked(&mut eq1, x.limb(1), y.limb(1));
-    crate::bn254fr::eq_checked(&mut eq2, x.limb(2), y.limb(2));
-    crate::bn254fr::eq_checked(&mut eq3, x.limb(3), y.limb(3));
-
-    // AND all equality results: result = eq0 * eq1 * eq2 * eq3
-    // mulmod_checked(&mut self, a) computes self = self * a
-    eq0.mulmod_checked(&eq1);
-    eq0.mulmod_checked(&eq2);
-    eq0.mulmod_checked(&eq3);
-
-    eq0
-}
-
-/// Check if Uint256 value is zero.
-/// Returns 1 if zero, 0 otherwise. Adds constraints.
-/// C++ equivalent: eqz(bn254fr_class&, const uint256&)
-pub fn eqz(x: &Uint256) -> Bn254Fr {
-    let zero = Uint256::new();
-    eq(x, &zero)
-}
-
-/// Conditional select: returns a if cond == 1, b if cond == 0.
-/// cond must be either 0 or 1. Adds constraints.
-/// C++ equivalent: mux(uint256&, const bn254fr_class&, const uint256&, const uint256&)
-pub fn mux(cond: &Bn254Fr, a: &Uint256, b: &Uint256) -> Uint256 {
-    let mut result = Uint256::new();
-    for i in 0..UINT256_NLIMBS {
-        crate::bn254fr::mux(result.limb_mut(i), cond, a.limb(i), b.limb(i));
-    }
-    result
-}
-
-/// Add equality constraints for two Uint256 values.
-/// C++ equivalent: assert_equal(const uint256&, const uint256&)
-pub fn assert_equal(x: &Uint256, y: &Uint256) {
-    for i in 0..UINT256_NLIMBS {
-        Bn254Fr::assert_equal(x.limb(i), y.limb(i));
-    }
-}
-
 // ============= Arithmetic Operations =============
 
 /// Number of bits per limb
@@ -482,7 +388,7 @@ const LIMB_BITS: usize = 64;
 
 /// Add two 64-bit limbs with carry-in, returning (sum, carry-out).
 /// Decomposes into 65 bits to extract carry.
-fn add_limb_with_carry(a: &Bn254Fr, b: &Bn254Fr, carry_in: &Bn254Fr) -> (Bn254Fr, Bn254Fr) {
+pub(crate) fn add_limb_with_carry(a: &Bn254Fr, b: &Bn254Fr, carry_in: &Bn254Fr) -> (Bn254Fr, Bn254Fr) {
     // sum = a + b + carry_in
     let mut sum = Bn254Fr::new();
     crate::bn254fr::addmod_checked(&mut sum, a, b);
@@ -502,7 +408,7 @@ fn add_limb_with_carry(a: &Bn254Fr, b: &Bn254Fr, carry_in: &Bn254Fr) -> (Bn254Fr
 
 /// Subtract two 64-bit limbs with borrow-in, returning (diff, borrow-out).
 /// Uses the formula: diff = 2^64 + a - b - borrow_in, then extracts borrow.
-fn sub_limb_with_borrow(a: &Bn254Fr, b: &Bn254Fr, borrow_in: &Bn254Fr) -> (Bn254Fr, Bn254Fr) {
+pub(crate) fn sub_limb_with_borrow(a: &Bn254Fr, b: &Bn254Fr, borrow_in: &Bn254Fr) -> (Bn254Fr, Bn254Fr) {
     // Compute 2^64 + a - b - borrow_in
     let two_pow_64 = Bn254Fr::from_str("18446744073709551616"); // 2^64
 
@@ -525,94 +431,36 @@ fn sub_limb_with_borrow(a: &Bn254Fr, b: &Bn254Fr, borrow_in: &Bn254Fr) -> (Bn254
     (result, borrow_out)
 }
 
-/// Perform uint256 addition with carry, adds constraints.
-/// C++ equivalent: add_cc(const uint256&, const uint256&) -> uint256_cc
-pub fn add_cc(a: &Uint256, b: &Uint256) -> Uint256Cc {
-    let mut result = Uint256::new();
-    let mut carry = Bn254Fr::from_u32(0);
-
-    for i in 0..UINT256_NLIMBS {
-        let (limb_result, limb_carry) = add_limb_with_carry(a.limb(i), b.limb(i), &carry);
-        result.limbs[i] = limb_result;
-        carry = limb_carry;
-    }
-
-    Uint256Cc { val: result, carry }
-}
-
-/// Perform uint256 subtraction with borrow, adds constraints.
-/// C++ equivalent: sub_cc(const uint256&, const uint256&) -> uint256_cc
-pub fn sub_cc(a: &Uint256, b: &Uint256) -> Uint256Cc {
-    let mut result = Uint256::new();
-    let mut borrow = Bn254Fr::from_u32(0);
-
-    for i in 0..UINT256_NLIMBS {
-        let (limb_result, limb_borrow) = sub_limb_with_borrow(a.limb(i), b.limb(i), &borrow);
-        result.limbs[i] = limb_result;
-        borrow = limb_borrow;
-    }
-
-    // Return borrow as "carry" (underflow indicator)
-    Uint256Cc {
-        val: result,
-        carry: borrow,
-    }
-}
-
-/// Perform uint256 multiplication returning 512-bit result, adds constraints.
-/// C++ equivalent: mul_wide(const uint256&, const uint256&) -> uint256_wide
-pub fn mul_wide(a: &Uint256, b: &Uint256) -> Uint256Wide {
-    // Use bigint multiplication from bn254fr
-    // Convert limbs to slices
-    let a_limbs: [Bn254Fr; UINT256_NLIMBS] = [
-        a.limbs[0].clone(),
-        a.limbs[1].clone(),
-        a.limbs[2].clone(),
-        a.limbs[3].clone(),
-    ];
-    let b_limbs: [Bn254Fr; UINT256_NLIMBS] = [
-        b.limbs[0].clone(),
-        b.limbs[1].clone(),
-        b.limbs[2].clone(),
-        b.limbs[3].clone(),
-    ];
-
-    // Output has 2*NLIMBS - 1 = 7 limbs before carry propagation
-    let mut mul_no_carry: Vec<Bn254Fr> = (0..7).map(|_| Bn254Fr::new()).collect();
-
-    crate::bn254fr::bigint_mul_checked_no_carry(&mut mul_no_carry, &a_limbs, &b_limbs);
-
-    // Convert to proper representation with carry (8 limbs)
-    let mut out_limbs: Vec<Bn254Fr> = (0..8).map(|_| Bn254Fr::new()).collect();
-
-    crate::bn254fr::bigint_convert_to_proper_representation(
-        &mut out_limbs,
-        &mut mul_no_carry,
-        LIMB_BITS as u32,
-    );
-
-    // Build result
-    let mut lo = Uint256::new();
-    let mut hi = Uint256::new();
-
-    for i in 0..UINT256_NLIMBS {
-        lo.limbs[i] = out_limbs[i].clone();
-        hi.limbs[i] = out_limbs[UINT256_NLIMBS + i].clone();
-    }
-
-    Uint256Wide { lo, hi }
-}
-
-/// Perform uint256 multiplication returning low 256 bits, adds constraints.
-/// C++ equivalent: mul_lo(const uint256&, const uint256&) -> uint256
-pub fn mul_lo(a: &Uint256, b: &Uint256) -> Uint256 {
-    mul_wide(a, b).lo
-}
-
-/// Perform uint256 multiplication returning high 256 bits, adds constraints.
-/// C++ equivalent: mul_hi(const uint256&, const uint256&) -> uint256
-pub fn mul_hi(a: &Uint256, b: &Uint256) -> Uint256 {
-    mul_wide(a, b).hi
+/// Division with remainder of 256-bit values: returns `(q, r)` with
+/// `q·b + r = a` and `r < b`, adds constraints.
+///
+/// The quotient and remainder come from the host's 512-bit division with a
+/// zero high half. That division needs no normalized divisor here: the
+/// quotient of a 256-bit dividend always fits in 256 bits, which is
+/// asserted along with the full `q·b + r = a` relation.
+///
+/// Panics if `b` is zero, before the host is asked to divide by it; in the
+/// proof, `r < b` rules out a zero divisor.
+pub fn div_qr(a: &Uint256, b: &Uint256) -> (Uint256, Uint256) {
+    assert!(b.limbs.iter().any(|l| l.get_u64() != 0), "uint256::div_qr: division by zero");
+
+    let zero = Uint256::from_u64(0);
+    let wide = Uint256Wide { lo: a.clone(), hi: zero.clone() };
+    let (q, q_high, r) = wide.divide_qr_normalized(b);
+    Bn254Fr::assert_equal(&q_high, &Bn254Fr::from_u32(0));
+
+    // q·b + r = a without overflow past 256 bits
+    let prod = mul_wide(&q, b);
+    assert_equal(&prod.hi, &zero);
+    let sum = add_cc(&prod.lo, &r);
+    Bn254Fr::assert_equal(&sum.carry, &Bn254Fr::from_u32(0));
+    assert_equal(&sum.val, a);
+
+    // r < b: r - b borrows
+    let diff = sub_cc(&r, b);
+    Bn254Fr::assert_equal(&diff.carry, &Bn254Fr::from_u32(1));
+
+    (q, r)
 }
 
 /// Perform modular inverse: out = a^(-1) mod m, adds constraints.
@@ -110,10 +110,27 @@ impl VBn254Fr {
         }
     }
 
-    /// Set vector from byte buffer
+    /// Set lane `k` from the `k`-th of `count` equal-size big-endian chunks
+    /// of `bytes`
     pub fn set_bytes(&mut self, bytes: &[u8], count: u64) {
+        assert!(count > 0 && count <= Self::get_size(), "set_bytes: invalid lane count");
+        assert!((bytes.len() as u64).is_multiple_of(count), "set_bytes: bytes must split into count chunks");
         unsafe {
-            _vbn254fr_set_bytes(self, bytes.as_ptr(), bytes.len() as u64, count);
+            _vbn254fr_set_bytes(self, bytes.as_ptr(), bytes.len() as u64 / count, count);
+        }
+    }
+
+    /// Gather lane `k` from the `num_bytes` big-endian bytes of `buffer`
+    /// starting at `offsets[k]`, e.g. one field of every k-th record,
+    /// without packing them into a staging buffer first
+    pub fn set_from_offsets(&mut self, buffer: &[u8], offsets: &[u64], num_bytes: usize) {
+        assert!(offsets.len() as u64 <= Self::get_size(), "set_from_offsets: too many offsets");
+        for &o in offsets {
+            assert!(o as usize + num_bytes <= buffer.len(), "set_from_offsets: offset out of range");
+        }
+        unsafe {
+            _vbn254fr_set_bytes_from_offsets(self, buffer.as_ptr(), offsets.as_ptr(),
+                                             offsets.len() as u64, num_bytes as u64);
         }
     }
 
@@ -188,6 +205,17 @@ impl VBn254Fr {
         }
     }
 
+    /// First `count` lanes as 32-byte big-endian values, for debugging.
+    /// The values are read without constraints.
+    pub fn get_bytes_unchecked(&self, count: usize) -> Vec<[u8; 32]> {
+        assert!(count as u64 <= Self::get_size(), "get_bytes_unchecked: too many lanes");
+        let mut out = vec![[0u8; 32]; count];
+        unsafe {
+            _vbn254fr_get_bytes(out.as_mut_ptr() as *mut u8, self, count as u64);
+        }
+        out
+    }
+
     /// Decompose vector elements into bits
     pub fn bit_decompose(&self) -> Vec<VBn254Fr> {
         let mut bits: Vec<VBn254Fr> = vec![VBn254Fr::new(); 254];
@@ -407,6 +435,56 @@ pub fn oblivious_if_vec(out: &mut VBn254Fr, cond: bool, t: &VBn254Fr, f: &VBn254
     mux_vec(out, &cond_fr, f, t);
 }
 
+// ============= Lane Chunking =============
+
+/// Active packing width: the number of lanes in a [`VBn254Fr`]
+pub fn lane_count() -> usize {
+    VBn254Fr::get_size() as usize
+}
+
+/// One step of [`chunks_of_lanes`]
+pub enum LaneChunk<'a, T> {
+    /// Exactly [`lane_count`] items, to be processed as one vector
+    Vector(&'a [T]),
+    /// A single item of the tail, to be processed as a scalar
+    Scalar(&'a T),
+}
+
+/// Iterator returned by [`chunks_of_lanes`]
+pub struct LaneChunks<'a, T> {
+    full: std::slice::ChunksExact<'a, T>,
+    tail: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for LaneChunks<'a, T> {
+    type Item = LaneChunk<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.full.next() {
+            Some(chunk) => Some(LaneChunk::Vector(chunk)),
+            None => self.tail.next().map(LaneChunk::Scalar),
+        }
+    }
+}
+
+/// Split `data` into full vectors of [`lane_count`] items followed by the
+/// remaining items one at a time, so batch code handles the tail in the
+/// same loop:
+///
+/// ```ignore
+/// for chunk in chunks_of_lanes(&values) {
+///     match chunk {
+///         LaneChunk::Vector(v) => { /* VBn254Fr::from_ui(v) ... */ }
+///         LaneChunk::Scalar(x) => { /* Bn254Fr::from_u32(*x) ... */ }
+///     }
+/// }
+/// ```
+pub fn chunks_of_lanes<T>(data: &[T]) -> LaneChunks<'_, T> {
+    let full = data.chunks_exact(lane_count());
+    let tail = full.remainder().iter();
+    LaneChunks { full, tail }
+}
+
 impl Drop for VBn254Fr {
     fn drop(&mut self) {
         unsafe {
@@ -418,10 +496,10 @@ impl Drop for VBn254Fr {
 impl VBn254FrConstant {
     /// Create a new constant from string representation
     pub fn from_str_with_base(s: &str, base: i32) -> Self {
-        let constant = VBn254FrConstant { data: [0; 8] };
+        let mut constant = VBn254FrConstant { data: [0; 8] };
         let c_str = CString::new(s).expect("Error parsing numeric string");
         unsafe {
-            _vbn254fr_constant_set_str(&constant, c_str.as_ptr(), base);
+            _vbn254fr_constant_set_str(&mut constant, c_str.as_ptr(), base);
         }
         constant
     }
@@ -433,7 +511,7 @@ impl VBn254FrConstant {
 
 // Import declarations for all vectorized BN254FR functions
 #[link(wasm_import_module = "vbn254fr")]
-extern "C" {
+extern "C-unwind" {
     // Memory management
     #[link_name = "vbn254fr_get_size"]
     fn _vbn254fr_get_size() -> u64;
@@ -446,7 +524,7 @@ extern "C" {
 
     // Initialization
     #[link_name = "vbn254fr_constant_set_str"]
-    fn _vbn254fr_constant_set_str(k: *const VBn254FrConstant, s: *const i8, base: i32) -> i32;
+    fn _vbn254fr_constant_set_str(k: *mut VBn254FrConstant, s: *const i8, base: i32) -> i32;
 
     #[link_name = "vbn254fr_set_ui"]
     fn _vbn254fr_set_ui(v: *mut VBn254Fr, nums: *mut u32, len: u64);
@@ -466,6 +544,10 @@ extern "C" {
     #[link_name = "vbn254fr_set_bytes_scalar"]
     fn _vbn254fr_set_bytes_scalar(v: *mut VBn254Fr, bytes: *const u8, num_bytes: u64);
 
+    #[link_name = "vbn254fr_set_bytes_from_offsets"]
+    fn _vbn254fr_set_bytes_from_offsets(v: *mut VBn254Fr, bytes: *const u8, offsets: *const u64,
+                                        count: u64, num_bytes: u64);
+
     // Vector arithmetic
     #[link_name = "vbn254fr_addmod"]
     fn _vbn254fr_addmod(out: *mut VBn254Fr, x: *const VBn254Fr, y: *const VBn254Fr);
@@ -501,6 +583,9 @@ extern "C" {
     #[link_name = "vbn254fr_print"]
     fn _vbn254fr_print(v: *const VBn254Fr, base: u32);
 
+    #[link_name = "vbn254fr_get_bytes"]
+    fn _vbn254fr_get_bytes(out: *mut u8, v: *const VBn254Fr, count: u64);
+
     #[link_name = "vbn254fr_bit_decompose"]
     fn _vbn254fr_bit_decompose(arr: *mut VBn254Fr, x: *const VBn254Fr);
 
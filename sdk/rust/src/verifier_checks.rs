@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Ligetron Verifier Row Checks
+//!
+//! Constrained versions of the final row checks of the Ligetron verifier
+//! (`webgpu_verifier.cpp`). They are building blocks only: this module does
+//! not verify a proof, and the host exposes no proof verifier to guest
+//! programs. Recursive verification of Ligetron proofs is not supported.
+//!
+//! - [`assert_low_degree`]: the decoded code-test row vanishes past the
+//!   message length `k` (encoding correctness).
+//! - [`assert_linear_sum`]: the decoded linear-test row sums to the
+//!   negated running linear sum.
+//! - [`assert_quadratic`]: the decoded quadratic-test row is zero.
+//! - [`assert_sample_equality`]: the encoded test rows agree with the
+//!   verifier's replayed column samples at the sampled positions.
+//!
+//! Checking a proof also requires recomputing the SHA-256 Merkle root of
+//! the opened columns, deriving the stage seeds and sample indexes from the
+//! transcript, replaying the inner program over the sampled columns and
+//! decoding the encoded test rows (inverse NTT). None of these is provided
+//! here; the row checks say nothing about a proof unless the caller
+//! establishes them by other means.
+
+use crate::bn254fr::Bn254Fr;
+
+/// Assert that the decoded code-test row has no coefficients past `k`
+pub fn assert_low_degree(decoded_code: &[Bn254Fr], k: usize) {
+    let zero = Bn254Fr::from_u32(0);
+    for c in decoded_code.iter().skip(k) {
+        Bn254Fr::assert_equal(c, &zero);
+    }
+}
+
+/// Assert that `sum_i decoded_linear[i] + linear_sum == 0`
+pub fn assert_linear_sum(decoded_linear: &[Bn254Fr], linear_sum: &Bn254Fr) {
+    let mut acc = linear_sum.clone();
+    for v in decoded_linear {
+        acc.addmod_checked(v);
+    }
+    Bn254Fr::assert_equal(&acc, &Bn254Fr::from_u32(0));
+}
+
+/// Assert that every entry of the decoded quadratic-test row is zero
+pub fn assert_quadratic(decoded_quad: &[Bn254Fr]) {
+    let zero = Bn254Fr::from_u32(0);
+    for q in decoded_quad {
+        Bn254Fr::assert_equal(q, &zero);
+    }
+}
+
+/// Assert that `encoded[sample_index[i]] == samples[i]` for every sample
+pub fn assert_sample_equality(encoded: &[Bn254Fr], sample_index: &[usize], samples: &[Bn254Fr]) {
+    assert_eq!(sample_index.len(), samples.len(), "assert_sample_equality: sample count mismatch");
+    for (&j, s) in sample_index.iter().zip(samples.iter()) {
+        assert!(j < encoded.len(), "assert_sample_equality: sample index out of range");
+        Bn254Fr::assert_equal(&encoded[j], s);
+    }
+}
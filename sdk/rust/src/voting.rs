@@ -0,0 +1,153 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Ballot Validity and Tally Gadgets for Ligetron
+//!
+//! A ballot is a vector of per-option weights. Voters prove their ballot is
+//! well formed (a single choice, or bounded weights within a budget), derive
+//! a per-election nullifier against double voting, and publish the ballot as
+//! exponential ElGamal ciphertexts over Baby Jubjub:
+//!
+//! `Enc(v; r) = (r·G, v·G + r·PK)`
+//!
+//! Ciphertexts add homomorphically, so the tally of an option is the
+//! point-wise sum of its ciphertexts; the authority then proves that the
+//! aggregate decrypts to the announced count.
+
+use crate::babyjubjub::JubjubPoint;
+use crate::bn254fr::{Bn254Fr, submod_checked, negmod_checked};
+use crate::eddsa::EddsaSignature;
+use crate::poseidon2::poseidon2_hash;
+
+/// Bit width of a single option weight
+pub const WEIGHT_BITS: usize = 32;
+
+// ============= Ballot Validity =============
+
+/// Assert that the ballot selects exactly one option (one-hot)
+pub fn assert_one_hot(ballot: &[Bn254Fr]) {
+    let mut sum = Bn254Fr::from_u32(0);
+    for v in ballot {
+        Bn254Fr::assert_bit(v);
+        sum.addmod_checked(v);
+    }
+    Bn254Fr::assert_equal(&sum, &Bn254Fr::from_u32(1));
+}
+
+/// Assert that every weight is at most `max_weight` and that the weights sum
+/// to at most `budget`.
+pub fn assert_bounded_weights(ballot: &[Bn254Fr], max_weight: u32, budget: u32) {
+    let mut sum = Bn254Fr::from_u32(0);
+    for v in ballot {
+        let _ = v.to_bits(WEIGHT_BITS);
+        let mut slack = Bn254Fr::new();
+        submod_checked(&mut slack, &Bn254Fr::from_u32(max_weight), v);
+        let _ = slack.to_bits(WEIGHT_BITS);
+        sum.addmod_checked(v);
+    }
+    let mut slack = Bn254Fr::new();
+    submod_checked(&mut slack, &Bn254Fr::from_u32(budget), &sum);
+    let _ = slack.to_bits(WEIGHT_BITS);
+}
+
+/// Commitment registered for a voter holding `secret`
+pub fn voter_commitment(secret: &Bn254Fr) -> Bn254Fr {
+    poseidon2_hash(std::slice::from_ref(secret))
+}
+
+/// Nullifier of `secret` in `election`: Poseidon2(secret, election).
+/// Publishing it lets the tallier reject a second ballot from the same voter
+/// without learning who voted.
+pub fn nullifier(secret: &Bn254Fr, election: &Bn254Fr) -> Bn254Fr {
+    poseidon2_hash(&[secret.clone(), election.clone()])
+}
+
+// ============= Encrypted Tally =============
+
+/// Exponential ElGamal ciphertext over Baby Jubjub
+#[derive(Clone)]
+pub struct VoteCiphertext {
+    pub c1: JubjubPoint,
+    pub c2: JubjubPoint,
+}
+
+impl VoteCiphertext {
+    /// Encryption of zero with no randomness (the tally identity)
+    pub fn zero() -> Self {
+        VoteCiphertext { c1: JubjubPoint::identity(), c2: JubjubPoint::identity() }
+    }
+
+    /// Homomorphic addition
+    pub fn add(&self, other: &VoteCiphertext) -> VoteCiphertext {
+        VoteCiphertext {
+            c1: JubjubPoint::twisted_edward_add(&self.c1, &other.c1),
+            c2: JubjubPoint::twisted_edward_add(&self.c2, &other.c2),
+        }
+    }
+}
+
+/// Encrypt a single-bit vote `v` under `pk` with randomness `r`.
+/// `v` must already be constrained to {0, 1} (e.g. by [`assert_one_hot`]).
+pub fn encrypt_vote(v: &Bn254Fr, r: &Bn254Fr, pk: &JubjubPoint) -> VoteCiphertext {
+    let g = EddsaSignature::generator();
+    let vg = JubjubPoint::mux(v, &JubjubPoint::identity(), &g);
+    let rpk = pk.scalar_mul(r);
+    VoteCiphertext {
+        c1: g.scalar_mul(r),
+        c2: JubjubPoint::twisted_edward_add(&vg, &rpk),
+    }
+}
+
+/// Encrypt every option of a one-hot ballot; `r` holds one randomness per
+/// option.
+pub fn encrypt_ballot(ballot: &[Bn254Fr], r: &[Bn254Fr], pk: &JubjubPoint) -> Vec<VoteCiphertext> {
+    assert_eq!(ballot.len(), r.len(), "encrypt_ballot: randomness length mismatch");
+    ballot.iter().zip(r.iter())
+        .map(|(v, ri)| encrypt_vote(v, ri, pk))
+        .collect()
+}
+
+/// Aggregate ballots option-wise into per-option tally ciphertexts
+pub fn tally(ballots: &[Vec<VoteCiphertext>]) -> Vec<VoteCiphertext> {
+    assert!(!ballots.is_empty(), "tally: no ballots");
+    let options = ballots[0].len();
+    let mut acc = vec![VoteCiphertext::zero(); options];
+    for ballot in ballots {
+        assert_eq!(ballot.len(), options, "tally: ballots differ in option count");
+        for (a, c) in acc.iter_mut().zip(ballot.iter()) {
+            *a = a.add(c);
+        }
+    }
+    acc
+}
+
+/// Assert that `ct` decrypts to the public `count` under the secret key `sk`
+/// of the election key `pk`: `sk·G == pk` and `c2 - sk·c1 == count·G`.
+pub fn assert_tally(ct: &VoteCiphertext, pk: &JubjubPoint, sk: &Bn254Fr, count: &Bn254Fr) {
+    let g = EddsaSignature::generator();
+    let mut derived = g.scalar_mul(sk);
+    let mut pk = pk.clone();
+    JubjubPoint::assert_equal(&mut derived, &mut pk);
+
+    let mut shared = ct.c1.scalar_mul(sk);
+    let mut neg_x = Bn254Fr::new();
+    negmod_checked(&mut neg_x, &shared.x);
+    shared.x = neg_x;
+
+    let mut m = JubjubPoint::twisted_edward_add(&ct.c2, &shared);
+    let mut expected = g.scalar_mul(count);
+    JubjubPoint::assert_equal(&mut m, &mut expected);
+}
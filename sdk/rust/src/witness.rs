@@ -0,0 +1,94 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Witness Serialization and Replay for Ligetron
+//!
+//! Exports the values of a chosen set of field elements to a byte blob and
+//! re-imports them in a later run. This supports two-phase flows where an
+//! expensive unconstrained computation (searches, hints, host oracles) is
+//! run once and replayed while the constraint logic around it is iterated
+//! on.
+//!
+//! Loaded values are fresh, unconstrained witnesses, exactly like the hints
+//! they replace: the circuit must still check them.
+//!
+//! Blob layout: the magic `LGWT`, a little-endian `u32` count, then each
+//! value as 32 big-endian bytes.
+
+use crate::api::{get_file_size, list_files, read_file, write_file};
+use crate::bn254fr::{Bn254Fr, BN254FR_MODULUS};
+
+const MAGIC: &[u8; 4] = b"LGWT";
+const HEADER_LEN: usize = 8;
+const VALUE_LEN: usize = 32;
+
+/// Serialize the values of `values`
+pub fn save(values: &[Bn254Fr]) -> Vec<u8> {
+    let mut blob = Vec::with_capacity(HEADER_LEN + VALUE_LEN * values.len());
+    blob.extend_from_slice(MAGIC);
+    blob.extend_from_slice(&(values.len() as u32).to_le_bytes());
+    for v in values {
+        blob.extend_from_slice(&v.get_bytes_big());
+    }
+    blob
+}
+
+/// Deserialize values written by [`save`].
+/// Panics if the blob is malformed or holds a non-canonical value.
+pub fn load(blob: &[u8]) -> Vec<Bn254Fr> {
+    assert!(blob.len() >= HEADER_LEN && &blob[..4] == MAGIC, "witness::load: bad header");
+    let count = u32::from_le_bytes([blob[4], blob[5], blob[6], blob[7]]) as usize;
+    assert_eq!(blob.len(), HEADER_LEN + VALUE_LEN * count, "witness::load: wrong length");
+
+    let modulus = BN254FR_MODULUS.trim_start_matches("0x");
+    blob[HEADER_LEN..]
+        .chunks(VALUE_LEN)
+        .map(|bytes| {
+            let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            assert!(hex.as_str() < modulus, "witness::load: value not below the modulus");
+            let mut v = Bn254Fr::new();
+            v.set_bytes_big(bytes);
+            v
+        })
+        .collect()
+}
+
+/// Write the values of `values` to a host file.
+///
+/// Returns false if the host refused the write (see
+/// [`write_file`](crate::api::write_file)).
+pub fn save_to_file(filename: &str, values: &[Bn254Fr]) -> bool {
+    write_file(filename, &save(values)) >= 0
+}
+
+/// Read values saved by [`save_to_file`], or `None` if the file is missing
+pub fn load_from_file(filename: &str) -> Option<Vec<Bn254Fr>> {
+    let name = list_files(filename).into_iter().find(|n| n == filename)?;
+    let mut blob = vec![0u8; get_file_size(&name) as u32 as usize];
+    read_file(&name, &mut blob);
+    Some(load(&blob))
+}
+
+/// Replay the values saved in `filename`, or compute them with `f` and
+/// save them for the next run
+pub fn replay_or_compute(filename: &str, f: impl FnOnce() -> Vec<Bn254Fr>) -> Vec<Bn254Fr> {
+    if let Some(values) = load_from_file(filename) {
+        return values;
+    }
+    let values = f();
+    save_to_file(filename, &values);
+    values
+}
@@ -0,0 +1,237 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Fixed-width Word Gadgets for Ligetron
+//!
+//! A [`Word`] keeps a fixed-width machine word as its constrained bits
+//! (least significant bit first). Rotations, shifts and byte swaps are pure
+//! rewiring of those bits, while the bitwise operators cost one quadratic
+//! constraint per bit. Hash gadgets (SHA-2, Keccak, ...) share these helpers
+//! instead of re-deriving the same bit constraints.
+
+use crate::bn254fr::{Bn254Fr, addmod_checked, submod_checked, mulmod_checked};
+
+/// A constrained word of `BITS` bits, stored least significant bit first
+#[derive(Clone)]
+pub struct Word<const BITS: usize> {
+    bits: Vec<Bn254Fr>,
+}
+
+/// 32-bit word (SHA-256)
+pub type Word32 = Word<32>;
+
+/// 64-bit word (SHA-512, Keccak)
+pub type Word64 = Word<64>;
+
+// ============= Single-bit Gadgets =============
+// All inputs are assumed to be constrained to {0, 1}; outputs are then
+// boolean as well.
+
+/// out = a ^ b = a + b - 2ab
+pub fn bit_xor(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    let two = Bn254Fr::from_u32(2);
+    let mut ab = Bn254Fr::new();
+    let mut out = Bn254Fr::new();
+    mulmod_checked(&mut ab, a, b);
+    ab.mulmod_constant_checked(&two);
+    addmod_checked(&mut out, a, b);
+    out.submod_checked(&ab);
+    out
+}
+
+/// out = a & b = ab
+pub fn bit_and(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    let mut out = Bn254Fr::new();
+    mulmod_checked(&mut out, a, b);
+    out
+}
+
+/// out = a | b = a + b - ab
+pub fn bit_or(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    let mut ab = Bn254Fr::new();
+    let mut out = Bn254Fr::new();
+    mulmod_checked(&mut ab, a, b);
+    addmod_checked(&mut out, a, b);
+    out.submod_checked(&ab);
+    out
+}
+
+/// out = !a = 1 - a
+pub fn bit_not(a: &Bn254Fr) -> Bn254Fr {
+    let one = Bn254Fr::from_u32(1);
+    let mut out = Bn254Fr::new();
+    submod_checked(&mut out, &one, a);
+    out
+}
+
+/// out = !a & b = b - ab
+pub fn bit_andn(a: &Bn254Fr, b: &Bn254Fr) -> Bn254Fr {
+    let mut ab = Bn254Fr::new();
+    let mut out = Bn254Fr::new();
+    mulmod_checked(&mut ab, a, b);
+    submod_checked(&mut out, b, &ab);
+    out
+}
+
+impl<const BITS: usize> Word<BITS> {
+    /// Decompose a field element into a word, range checking it to `BITS` bits
+    pub fn from_fr(x: &Bn254Fr) -> Self {
+        Word { bits: x.to_bits(BITS) }
+    }
+
+    /// Create a constant word. Bits above `BITS` are ignored.
+    pub fn from_u64(value: u64) -> Self {
+        let bits = (0..BITS)
+            .map(|i| Bn254Fr::from_u32(if i < 64 { ((value >> i) & 1) as u32 } else { 0 }))
+            .collect();
+        Word { bits }
+    }
+
+    /// Wrap already boolean-constrained bits (least significant first)
+    pub fn from_bits(bits: Vec<Bn254Fr>) -> Self {
+        assert_eq!(bits.len(), BITS, "Word::from_bits requires exactly BITS bits");
+        Word { bits }
+    }
+
+    /// Get the bits of the word (least significant first)
+    #[inline(always)]
+    pub fn bits(&self) -> &[Bn254Fr] {
+        &self.bits
+    }
+
+    /// Get bit `i` of the word
+    #[inline(always)]
+    pub fn bit(&self, i: usize) -> &Bn254Fr {
+        &self.bits[i]
+    }
+
+    /// Compose the word back into a single field element with constraints
+    pub fn to_fr(&self) -> Bn254Fr {
+        Bn254Fr::from_bits_checked(&self.bits)
+    }
+
+    /// Get the low 64 bits of the word value (no constraints, for debugging
+    /// and host-side bookkeeping)
+    pub fn get_u64(&self) -> u64 {
+        self.bits.iter().take(64).enumerate()
+            .fold(0u64, |acc, (i, b)| acc | (b.get_u64() << i))
+    }
+
+    // ============= Bit Rewiring =============
+
+    /// Rotate right by `n` bits
+    pub fn rotr(&self, n: usize) -> Self {
+        let bits = (0..BITS).map(|i| self.bits[(i + n) % BITS].clone()).collect();
+        Word { bits }
+    }
+
+    /// Rotate left by `n` bits
+    pub fn rotl(&self, n: usize) -> Self {
+        self.rotr(BITS - (n % BITS))
+    }
+
+    /// Logical shift right by `n` bits
+    pub fn shr(&self, n: usize) -> Self {
+        let bits = (0..BITS)
+            .map(|i| if i + n < BITS { self.bits[i + n].clone() } else { Bn254Fr::from_u32(0) })
+            .collect();
+        Word { bits }
+    }
+
+    /// Logical shift left by `n` bits
+    pub fn shl(&self, n: usize) -> Self {
+        let bits = (0..BITS)
+            .map(|i| if i >= n { self.bits[i - n].clone() } else { Bn254Fr::from_u32(0) })
+            .collect();
+        Word { bits }
+    }
+
+    /// Extract `len` bits starting at bit `lo` as a constrained field element
+    pub fn extract(&self, lo: usize, len: usize) -> Bn254Fr {
+        assert!(lo + len <= BITS, "Word::extract out of range");
+        Bn254Fr::from_bits_checked(&self.bits[lo..lo + len])
+    }
+
+    /// Reverse the byte order of the word
+    pub fn swap_bytes(&self) -> Self {
+        assert!(BITS.is_multiple_of(8), "Word::swap_bytes requires a whole number of bytes");
+        let nbytes = BITS / 8;
+        let bits = (0..BITS)
+            .map(|i| self.bits[(nbytes - 1 - i / 8) * 8 + i % 8].clone())
+            .collect();
+        Word { bits }
+    }
+
+    // ============= Bitwise Operations =============
+
+    /// out = self ^ other
+    pub fn xor(&self, other: &Self) -> Self {
+        self.zip_with(other, bit_xor)
+    }
+
+    /// out = self & other
+    pub fn and(&self, other: &Self) -> Self {
+        self.zip_with(other, bit_and)
+    }
+
+    /// out = self | other
+    pub fn or(&self, other: &Self) -> Self {
+        self.zip_with(other, bit_or)
+    }
+
+    /// out = !self & other
+    pub fn andn(&self, other: &Self) -> Self {
+        self.zip_with(other, bit_andn)
+    }
+
+    /// out = !self
+    pub fn not(&self) -> Self {
+        Word { bits: self.bits.iter().map(bit_not).collect() }
+    }
+
+    fn zip_with(&self, other: &Self, f: fn(&Bn254Fr, &Bn254Fr) -> Bn254Fr) -> Self {
+        let bits = self.bits.iter().zip(other.bits.iter())
+            .map(|(a, b)| f(a, b))
+            .collect();
+        Word { bits }
+    }
+
+    // ============= Modular Arithmetic =============
+
+    /// out = self + other mod 2^BITS
+    pub fn add(&self, other: &Self) -> Self {
+        add_many(&[self, other])
+    }
+}
+
+/// Sum any number of words modulo 2^BITS with constraints.
+///
+/// The words are composed into field elements, summed, and the sum is
+/// decomposed once with enough extra bits to hold the carries.
+pub fn add_many<const BITS: usize>(words: &[&Word<BITS>]) -> Word<BITS> {
+    assert!(!words.is_empty(), "add_many requires at least one word");
+    let carry_bits = (usize::BITS - (words.len() - 1).leading_zeros()) as usize;
+    assert!(BITS + carry_bits <= 253, "add_many: sum does not fit in the field");
+
+    let mut sum = words[0].to_fr();
+    for w in &words[1..] {
+        sum.addmod_checked(&w.to_fr());
+    }
+
+    let mut bits = sum.to_bits(BITS + carry_bits);
+    bits.truncate(BITS);
+    Word { bits }
+}
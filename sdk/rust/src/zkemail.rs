@@ -0,0 +1,539 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! DKIM Verification for zk-email Proofs
+//!
+//! Proves that an email carries a valid `DKIM-Signature` (RFC 6376,
+//! `rsa-sha256`) from a domain with a given RSA key, and that its body
+//! contains a claimed string, without revealing the email. [`prepare`]
+//! parses the raw message on the prover side and rebuilds the exact byte
+//! strings covered by the signature; [`verify`] then constrains:
+//!
+//! - the SHA-256 of the canonicalized signed headers ([`Sha256Gadget`]) is
+//!   RSA-signed under the public modulus, with the PKCS#1 v1.5 encoding
+//!   checked byte for byte ([`assert_rsa_sha256`])
+//! - the signed `DKIM-Signature` header holds `bh=` followed by the base64
+//!   SHA-256 of the canonicalized body
+//! - the body contains the public claim at a private offset
+//!
+//! and returns a nullifier derived from the verified signature, so the
+//! same email cannot be used twice.
+//!
+//! The `DKIM-Signature` header is always the last signed header. Its
+//! offset in the signed headers is public structure; the circuit checks
+//! that it starts a header named `dkim-signature` and contains no CR, so
+//! that the `bh=` tag cannot be taken from another header. Only relaxed
+//! header canonicalization, under which header values are unfolded, is
+//! supported by [`verify`], as are 2048-bit keys with `e = 65537`.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::biguint::{self, Uint2048};
+use crate::bn254fr::{Bn254Fr, invmod_checked, mulmod_checked, submod_checked};
+use crate::encoding::to_base64_checked;
+use crate::poseidon2::poseidon2_hash;
+use crate::sha2::{Sha256Gadget, sha2_256};
+use crate::string::{self, string_from_bytes};
+
+/// Size of an RSA-2048 modulus and signature in bytes
+pub const RSA_BYTES: usize = 256;
+
+/// Domain separation tag for email nullifiers
+pub const EMAIL_NULLIFIER_DOMAIN: &[u8] = b"ligetron.zkemail.nullifier.v1";
+
+/// DER `DigestInfo` prefix of a SHA-256 digest (RFC 8017, 9.2)
+const SHA256_DIGEST_INFO: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01,
+    0x65, 0x03, 0x04, 0x02, 0x01, 0x05, 0x00, 0x04, 0x20,
+];
+
+/// DKIM canonicalization algorithm
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Canonicalization {
+    Simple,
+    Relaxed,
+}
+
+/// Parsed `DKIM-Signature` header
+#[derive(Clone, Debug)]
+pub struct DkimSignature {
+    /// Signing algorithm (`a=`), e.g. `rsa-sha256`
+    pub algorithm: String,
+    /// Signing domain (`d=`)
+    pub domain: String,
+    /// Key selector (`s=`)
+    pub selector: String,
+    /// Header and body canonicalization (`c=`)
+    pub header_canon: Canonicalization,
+    pub body_canon: Canonicalization,
+    /// Signed header names in signing order (`h=`)
+    pub headers: Vec<String>,
+    /// Body hash (`bh=`, decoded)
+    pub body_hash: Vec<u8>,
+    /// Signature (`b=`, decoded)
+    pub signature: Vec<u8>,
+    /// Optional body length limit (`l=`)
+    pub body_length: Option<usize>,
+}
+
+/// A message laid out for DKIM verification
+#[derive(Clone, Debug)]
+pub struct DkimMessage {
+    pub dkim: DkimSignature,
+    /// Canonicalized signed headers, ending with the DKIM-Signature header
+    /// with an empty `b=` value (no trailing CRLF)
+    pub signed_headers: Vec<u8>,
+    /// Offset of the DKIM-Signature header in `signed_headers`
+    pub signature_header_offset: usize,
+    /// Canonicalized body, truncated to `l=` if present
+    pub body: Vec<u8>,
+}
+
+impl DkimMessage {
+    /// Check the `bh=` tag against the canonicalized body (no constraints)
+    pub fn body_hash_matches(&self) -> bool {
+        sha2_256(&self.body)[..] == self.dkim.body_hash[..]
+    }
+
+    /// SHA-256 of the signed headers, the value RSA-signed by the domain
+    /// (no constraints)
+    pub fn header_hash(&self) -> [u8; 32] {
+        sha2_256(&self.signed_headers)
+    }
+}
+
+/// Nullifier of a verified DKIM signature. RSA signatures below the
+/// modulus are unique, so each email has exactly one nullifier.
+pub fn email_nullifier(signature: &Uint2048) -> Bn254Fr {
+    let mut tag = Bn254Fr::new();
+    tag.set_bytes_big(EMAIL_NULLIFIER_DOMAIN);
+    let mut inputs = vec![tag];
+    inputs.extend(signature.limbs().iter().cloned());
+    poseidon2_hash(&inputs)
+}
+
+// ============= Constrained Verification =============
+
+/// 64-bit limb from 8 big-endian bytes given as field elements
+fn compose_limb(bytes: &[Bn254Fr]) -> Bn254Fr {
+    let mut acc = Bn254Fr::from_u32(0);
+    for b in bytes {
+        acc.mulmod_constant_checked(&Bn254Fr::from_u32(256));
+        acc.addmod_checked(b);
+    }
+    acc
+}
+
+/// Assert that `signature` is an RSASSA-PKCS1-v1_5 signature with SHA-256
+/// and `e = 65537` of the message with the constrained SHA-256 `digest`
+/// (32 bytes), under the public `modulus`
+pub fn assert_rsa_sha256(signature: &Uint2048, modulus: &Uint2048, digest: &[Bn254Fr]) {
+    assert_eq!(digest.len(), 32, "assert_rsa_sha256: digest must be 32 bytes");
+
+    // s < n, so that the signature (and its nullifier) is unique
+    Bn254Fr::assert_equal(&biguint::lt(signature, modulus), &Bn254Fr::from_u32(1));
+
+    // m = s^65537 = s^(2^16)·s mod n
+    let mut m = signature.clone();
+    for _ in 0..16 {
+        m = biguint::mulmod(&m, &m, modulus);
+    }
+    let m = biguint::mulmod(&m, signature, modulus);
+
+    // EM = 0x00 0x01 || 0xff.. || 0x00 || DigestInfo || H
+    let mut em = [0u8; RSA_BYTES];
+    em[1] = 0x01;
+    em[2..RSA_BYTES - 52].fill(0xff);
+    em[RSA_BYTES - 51..RSA_BYTES - 32].copy_from_slice(&SHA256_DIGEST_INFO);
+
+    // The low 4 limbs hold H, the others are constant
+    for (i, limb) in m.limbs().iter().enumerate() {
+        if i < 4 {
+            let end = 32 - 8 * i;
+            Bn254Fr::assert_equal(limb, &compose_limb(&digest[end - 8..end]));
+        } else {
+            let end = RSA_BYTES - 8 * i;
+            let chunk: [u8; 8] = em[end - 8..end].try_into().unwrap();
+            limb.assert_equal_const(u64::from_be_bytes(chunk));
+        }
+    }
+}
+
+/// Assert that the DKIM-Signature header, the last of the signed
+/// `headers`, contains `bh=` followed by the base64 of `body_hash`
+fn assert_body_hash(msg: &DkimMessage, headers: &[Bn254Fr], body_hash: &[Bn254Fr]) {
+    let offset = msg.signature_header_offset;
+    let name = b"dkim-signature:";
+    assert!(offset >= 2 && headers.len() - offset > name.len(),
+            "zkemail: invalid DKIM-Signature header offset");
+
+    // The header starts a line and runs to the end without a CR
+    headers[offset - 2].assert_equal_const(b'\r' as u64);
+    headers[offset - 1].assert_equal_const(b'\n' as u64);
+    for (c, &e) in headers[offset..].iter().zip(name.iter()) {
+        c.assert_equal_const(e as u64);
+    }
+    let cr = Bn254Fr::from_u32(b'\r' as u32);
+    for c in &headers[offset..] {
+        let mut d = Bn254Fr::new();
+        let mut inv = Bn254Fr::new();
+        submod_checked(&mut d, c, &cr);
+        invmod_checked(&mut inv, &d);
+    }
+
+    // The tag follows a separator, which rules out matches inside the
+    // quoted-printable `z=` values
+    let header = &msg.signed_headers[offset..];
+    let pos = header.windows(4)
+        .position(|w| (w[0] == b';' || w[0] == b' ') && &w[1..] == b"bh=")
+        .unwrap_or(0);
+    let sep = Bn254Fr::from_u32(header[pos] as u32);
+    let mut d0 = Bn254Fr::new();
+    let mut d1 = Bn254Fr::new();
+    let mut prod = Bn254Fr::new();
+    submod_checked(&mut d0, &sep, &Bn254Fr::from_u32(b';' as u32));
+    submod_checked(&mut d1, &sep, &Bn254Fr::from_u32(b' ' as u32));
+    mulmod_checked(&mut prod, &d0, &d1);
+    prod.assert_equal_const(0);
+
+    let mut needle = vec![sep];
+    needle.extend(string_from_bytes(b"bh="));
+    needle.extend(to_base64_checked(body_hash));
+    string::assert_contains_at_hidden_index(&headers[offset..], &needle, pos);
+}
+
+/// Verify the DKIM signature of a prepared message under the public RSA
+/// `modulus` of the signing domain (with constraints), assert that the
+/// body contains the public `body_claim`, and return the email nullifier.
+///
+/// The message contents stay private; their lengths and the offset of the
+/// DKIM-Signature header are public structure. The proof fails if the
+/// signature does not verify or the claim is not in the body.
+pub fn verify(msg: &DkimMessage, modulus: &Uint2048, body_claim: &[u8]) -> Bn254Fr {
+    assert_eq!(msg.dkim.algorithm, "rsa-sha256", "zkemail::verify: unsupported algorithm");
+    assert_eq!(msg.dkim.header_canon, Canonicalization::Relaxed,
+               "zkemail::verify: only relaxed header canonicalization is supported");
+    assert_eq!(msg.dkim.signature.len(), RSA_BYTES, "zkemail::verify: only 2048-bit keys are supported");
+
+    let headers = string_from_bytes(&msg.signed_headers);
+    let body = string_from_bytes(&msg.body);
+
+    let mut signature = Uint2048::new();
+    signature.set_bytes_big(&msg.dkim.signature);
+    assert_rsa_sha256(&signature, modulus, &Sha256Gadget::digest(&headers));
+
+    assert_body_hash(msg, &headers, &Sha256Gadget::digest(&body));
+
+    let claim_offset = msg.body.windows(body_claim.len())
+        .position(|w| w == body_claim)
+        .unwrap_or(0);
+    string::assert_substring_at_hidden_index(&body, body_claim, claim_offset);
+
+    email_nullifier(&signature)
+}
+
+// ============= Parsing =============
+
+/// Raw (name, value) header fields in message order
+type HeaderFields = Vec<(String, String)>;
+
+fn is_wsp(c: char) -> bool {
+    c == ' ' || c == '\t'
+}
+
+/// Split a raw message into unfolded-but-uncanonicalized (name, value)
+/// headers, keeping folding whitespace, and the body.
+fn split_message(email: &[u8]) -> Option<(HeaderFields, &[u8])> {
+    let sep = email.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = String::from_utf8_lossy(&email[..sep]);
+    let body = &email[sep + 4..];
+
+    let mut headers: HeaderFields = Vec::new();
+    for line in head.split("\r\n") {
+        if line.starts_with(is_wsp) {
+            // Continuation of the previous (folded) header
+            let last = headers.last_mut()?;
+            last.1.push_str("\r\n");
+            last.1.push_str(line);
+        } else {
+            let (name, value) = line.split_once(':')?;
+            headers.push((name.to_string(), value.to_string()));
+        }
+    }
+    Some((headers, body))
+}
+
+/// Parse the tag list of a `DKIM-Signature` header value
+pub fn parse_dkim_signature(value: &str) -> Option<DkimSignature> {
+    let mut sig = DkimSignature {
+        algorithm: String::new(),
+        domain: String::new(),
+        selector: String::new(),
+        header_canon: Canonicalization::Simple,
+        body_canon: Canonicalization::Simple,
+        headers: Vec::new(),
+        body_hash: Vec::new(),
+        signature: Vec::new(),
+        body_length: None,
+    };
+
+    let canon = |s: &str| match s {
+        "simple" => Some(Canonicalization::Simple),
+        "relaxed" => Some(Canonicalization::Relaxed),
+        _ => None,
+    };
+    let b64 = |s: &str| {
+        let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        STANDARD.decode(compact).ok()
+    };
+
+    for tag in value.split(';') {
+        let Some((k, v)) = tag.split_once('=') else { continue };
+        let v = v.trim();
+        match k.trim() {
+            "a" => sig.algorithm = v.to_string(),
+            "d" => sig.domain = v.to_string(),
+            "s" => sig.selector = v.to_string(),
+            "c" => {
+                let (h, b) = v.split_once('/').unwrap_or((v, "simple"));
+                sig.header_canon = canon(h)?;
+                sig.body_canon = canon(b)?;
+            }
+            "h" => {
+                sig.headers = v.split(':')
+                    .map(|h| h.trim().to_ascii_lowercase())
+                    .collect();
+            }
+            "bh" => sig.body_hash = b64(v)?,
+            "b" => sig.signature = b64(v)?,
+            "l" => sig.body_length = Some(v.parse().ok()?),
+            _ => {}
+        }
+    }
+
+    if sig.headers.is_empty() || sig.body_hash.is_empty() || sig.signature.is_empty() {
+        return None;
+    }
+    Some(sig)
+}
+
+// ============= Canonicalization =============
+
+/// Canonicalize one header field, including its trailing CRLF
+pub fn canonicalize_header(name: &str, value: &str, canon: Canonicalization) -> String {
+    match canon {
+        Canonicalization::Simple => format!("{}:{}\r\n", name, value),
+        Canonicalization::Relaxed => {
+            // Unfold, collapse whitespace runs, trim
+            let unfolded = value.replace("\r\n", "");
+            let mut out = String::with_capacity(unfolded.len());
+            let mut in_wsp = false;
+            for c in unfolded.chars() {
+                if is_wsp(c) {
+                    in_wsp = true;
+                } else {
+                    if in_wsp && !out.is_empty() {
+                        out.push(' ');
+                    }
+                    in_wsp = false;
+                    out.push(c);
+                }
+            }
+            format!("{}:{}\r\n", name.trim().to_ascii_lowercase(), out)
+        }
+    }
+}
+
+/// Canonicalize a message body
+pub fn canonicalize_body(body: &[u8], canon: Canonicalization) -> Vec<u8> {
+    let text = String::from_utf8_lossy(body);
+    let mut lines: Vec<String> = text.split("\r\n").map(|l| match canon {
+        Canonicalization::Simple => l.to_string(),
+        Canonicalization::Relaxed => {
+            let mut out = String::with_capacity(l.len());
+            let mut in_wsp = false;
+            for c in l.chars() {
+                if is_wsp(c) {
+                    in_wsp = true;
+                } else {
+                    if in_wsp {
+                        out.push(' ');
+                    }
+                    in_wsp = false;
+                    out.push(c);
+                }
+            }
+            out
+        }
+    }).collect();
+
+    // Drop trailing empty lines
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+
+    if lines.is_empty() {
+        return match canon {
+            Canonicalization::Simple => b"\r\n".to_vec(),
+            Canonicalization::Relaxed => Vec::new(),
+        };
+    }
+
+    let mut out = Vec::with_capacity(body.len() + 2);
+    for l in lines {
+        out.extend_from_slice(l.as_bytes());
+        out.extend_from_slice(b"\r\n");
+    }
+    out
+}
+
+/// Strip the value of the `b=` tag, keeping everything else verbatim
+fn strip_signature_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for (i, tag) in value.split(';').enumerate() {
+        if i > 0 {
+            out.push(';');
+        }
+        match tag.split_once('=') {
+            Some((k, _)) if k.trim() == "b" => {
+                out.push_str(k);
+                out.push('=');
+            }
+            _ => out.push_str(tag),
+        }
+    }
+    out
+}
+
+/// Parse a raw message and lay out the data covered by its (first)
+/// DKIM signature.
+pub fn prepare(email: &[u8]) -> Option<DkimMessage> {
+    let (headers, body) = split_message(email)?;
+
+    let (dkim_name, dkim_value) = headers.iter()
+        .find(|(n, _)| n.trim().eq_ignore_ascii_case("dkim-signature"))?;
+    let dkim = parse_dkim_signature(dkim_value)?;
+
+    // Signed headers are picked bottom-up, each instance used once
+    let mut used = vec![false; headers.len()];
+    let mut signed_headers = String::new();
+    for name in &dkim.headers {
+        let found = (0..headers.len()).rev()
+            .find(|&i| !used[i] && headers[i].0.trim().eq_ignore_ascii_case(name));
+        if let Some(i) = found {
+            used[i] = true;
+            signed_headers.push_str(
+                &canonicalize_header(&headers[i].0, &headers[i].1, dkim.header_canon));
+        }
+    }
+
+    let signature_header_offset = signed_headers.len();
+    let mut sig_header = canonicalize_header(
+        dkim_name, &strip_signature_value(dkim_value), dkim.header_canon);
+    sig_header.truncate(sig_header.len() - 2);
+    signed_headers.push_str(&sig_header);
+
+    let mut body = canonicalize_body(body, dkim.body_canon);
+    if let Some(l) = dkim.body_length {
+        body.truncate(l);
+    }
+
+    Some(DkimMessage {
+        dkim,
+        signed_headers: signed_headers.into_bytes(),
+        signature_header_offset,
+        body,
+    })
+}
+
+#[cfg(all(test, feature = "native-sim"))]
+mod tests {
+    use super::*;
+
+    /// Signed with a throwaway 2048-bit key generated for this test
+    const EMAIL: &str = concat!(
+        "DKIM-Signature: v=1; a=rsa-sha256; c=relaxed/relaxed; d=example.com; s=t",
+        "est; h=from:to:subject; bh=mEWNTAmWnb1msc8r37kGeda662wxfQYFqU060uZjemE=;",
+        " b=LtAN6O9gumLCUS+Y43UxWI1Iu1rZM8eCqXOKQLLuH9AR7tYZenyiBMqpNMCNjV7WEKBiM",
+        "GwyISC09Qrs3z16b5nRbbzCM/gGKX7jGMnqzwjO2jzm2glebl7VfuzCOBwd0fkZ0qPfmdRrU",
+        "eTVGWJ2aDBzE9E7NhlP6iRdzKlRJEo0prrB1YUg3FKYZg+Dx+kHz2vigJFAdtQQPInLhDefn",
+        "kZY7gSDeYgmNOCYCpz85RlXDGXiijv6gOl21kjWi7lhvuel1jQBWk635LpXWY1vmrGW7Id74",
+        "S9pcWBzspCO+E9hYUFb3wfBgxSzquWSsNgNrmr2032PHD+R9F5sT7XjPg==\r\n",
+        "From: alice@example.com\r\n",
+        "To: bob@example.org\r\n",
+        "Subject: Order shipped\r\n",
+        "\r\n",
+        "Hello Bob,\r\n",
+        "Your order #1234 has shipped.\r\n",
+    );
+
+    const MODULUS: &str = concat!(
+        "bea6fea6012d5257745a91feb4e5bf15dae51b3a1393befec5ef0454e3f466c8",
+        "d5139a8cde3a69728cbc478fcc73261d318ca179a43d3bf53d9d07f01c738909",
+        "bf56fa24ea4311889a296651dcd92e33d1e21889f3eefbb5dfcd66bb0b7092b9",
+        "ab1d30fdfe3beb3e38f4a95383b62eefde4eb5c5f6835b5ed65e9a4eb7fe5b43",
+        "32b2db4b44afc8897a09046ae0abbdd4ba1b7c5813fde6d3e7826afff2966ccb",
+        "95e9d8a4a8e356a004840590324f39c771631243b32528f6fd30fda3ab0fdc44",
+        "a9d38f85595c54691c26185e757849f893bd86d3e057096ab3b1a0ab83586aac",
+        "9c81f933d3dd0bf171932545b6a4d9f3b405077eb187bb7ff949e23d21139ee1",
+    );
+
+    fn modulus() -> Uint2048 {
+        Uint2048::from_hex(MODULUS)
+    }
+
+    #[test]
+    fn prepare_rebuilds_signed_data() {
+        let msg = prepare(EMAIL.as_bytes()).unwrap();
+        assert!(msg.body_hash_matches());
+        assert_eq!(msg.dkim.domain, "example.com");
+        assert_eq!(msg.dkim.headers, ["from", "to", "subject"]);
+        assert!(msg.signed_headers[msg.signature_header_offset..].starts_with(b"dkim-signature:v=1;"));
+    }
+
+    #[test]
+    fn verify_binds_claim_and_nullifier() {
+        let msg = prepare(EMAIL.as_bytes()).unwrap();
+        let nullifier = verify(&msg, &modulus(), b"order #1234");
+
+        let mut signature = Uint2048::new();
+        signature.set_bytes_big(&msg.dkim.signature);
+        Bn254Fr::assert_equal(&nullifier, &email_nullifier(&signature));
+    }
+
+    #[test]
+    #[should_panic(expected = "constraint failed")]
+    fn verify_rejects_missing_claim() {
+        let msg = prepare(EMAIL.as_bytes()).unwrap();
+        verify(&msg, &modulus(), b"order #9999");
+    }
+
+    #[test]
+    #[should_panic(expected = "constraint failed")]
+    fn verify_rejects_modified_body() {
+        let email = EMAIL.replace("#1234", "#1235");
+        let msg = prepare(email.as_bytes()).unwrap();
+        verify(&msg, &modulus(), b"order #1235");
+    }
+
+    #[test]
+    #[should_panic(expected = "constraint failed")]
+    fn verify_rejects_modified_header() {
+        let email = EMAIL.replace("Subject: Order shipped", "Subject: Order refunded");
+        let msg = prepare(email.as_bytes()).unwrap();
+        verify(&msg, &modulus(), b"order #1234");
+    }
+}
@@ -0,0 +1,376 @@
+/*
+ * Copyright (C) 2023-2026 Ligero, Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! A Tiny Constrained Register Machine for Ligetron
+//!
+//! Proves the execution of a public program for a public number of steps
+//! over private initial memory, without hand-writing a circuit. Enabled by
+//! the `zkvm` cargo feature.
+//!
+//! ## Machine
+//!
+//! - [`NUM_REGS`] registers of 32-bit words, all starting at 0
+//! - a word-addressed memory of public size, initialized privately
+//! - a program of [`Instr`]; arithmetic wraps modulo 2^32
+//! - [`Instr::Halt`] keeps the machine in place, so a run can be padded to
+//!   the step budget
+//!
+//! ## Constraints
+//!
+//! Each step keeps the program counter as a one-hot vector over the
+//! program. Opcode flags and register selectors are linear combinations of
+//! it, operands are selected with one product per register, and every
+//! candidate result is computed and range checked. Memory is checked by
+//! linear scan: the accessed address is witnessed one-hot and constrained
+//! to equal the operand on loads and stores, so each load returns the last
+//! stored word. A step costs roughly `program + 3·regs + 3·memory`
+//! multiplications plus about 200 bits of range checks.
+
+use crate::bn254fr::{Bn254Fr, addmod_checked, submod_checked, mulmod_checked, eq_checked};
+use crate::cmp::lt_checked;
+
+/// Number of registers
+pub const NUM_REGS: usize = 8;
+
+/// Bit width of a word
+pub const WORD_BITS: usize = 32;
+
+/// One instruction. Register operands are indices below [`NUM_REGS`];
+/// jump targets are program indices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instr {
+    /// `rd = imm`
+    Li { rd: usize, imm: u32 },
+    /// `rd = rs1 + rs2`
+    Add { rd: usize, rs1: usize, rs2: usize },
+    /// `rd = rs1 - rs2`
+    Sub { rd: usize, rs1: usize, rs2: usize },
+    /// `rd = rs1 · rs2` (low word)
+    Mul { rd: usize, rs1: usize, rs2: usize },
+    /// `rd = rs1 < rs2` (unsigned)
+    Lt { rd: usize, rs1: usize, rs2: usize },
+    /// `rd = rs1 == rs2`
+    Eq { rd: usize, rs1: usize, rs2: usize },
+    /// `rd = mem[rs1]`
+    Load { rd: usize, rs1: usize },
+    /// `mem[rs1] = rs2`
+    Store { rs1: usize, rs2: usize },
+    /// `pc = target`
+    Jmp { target: usize },
+    /// `pc = target` if `rs1 == rs2`
+    Beq { rs1: usize, rs2: usize, target: usize },
+    /// Stop; the program counter no longer moves
+    Halt,
+}
+
+const NUM_OPS: usize = 11;
+const OP_LI: usize = 0;
+const OP_ADD: usize = 1;
+const OP_SUB: usize = 2;
+const OP_MUL: usize = 3;
+const OP_LT: usize = 4;
+const OP_EQ: usize = 5;
+const OP_LOAD: usize = 6;
+const OP_STORE: usize = 7;
+const OP_JMP: usize = 8;
+const OP_BEQ: usize = 9;
+const OP_HALT: usize = 10;
+
+/// Decoded fields of an instruction; unused fields are 0
+#[derive(Clone, Copy)]
+struct Decoded {
+    op: usize,
+    rd: usize,
+    rs1: usize,
+    rs2: usize,
+    imm: u64,
+    writes: bool,
+}
+
+impl Instr {
+    fn decode(&self) -> Decoded {
+        let d = |op, rd, rs1, rs2, imm: u64, writes| Decoded { op, rd, rs1, rs2, imm, writes };
+        match *self {
+            Instr::Li { rd, imm } => d(OP_LI, rd, 0, 0, imm as u64, true),
+            Instr::Add { rd, rs1, rs2 } => d(OP_ADD, rd, rs1, rs2, 0, true),
+            Instr::Sub { rd, rs1, rs2 } => d(OP_SUB, rd, rs1, rs2, 0, true),
+            Instr::Mul { rd, rs1, rs2 } => d(OP_MUL, rd, rs1, rs2, 0, true),
+            Instr::Lt { rd, rs1, rs2 } => d(OP_LT, rd, rs1, rs2, 0, true),
+            Instr::Eq { rd, rs1, rs2 } => d(OP_EQ, rd, rs1, rs2, 0, true),
+            Instr::Load { rd, rs1 } => d(OP_LOAD, rd, rs1, 0, 0, true),
+            Instr::Store { rs1, rs2 } => d(OP_STORE, 0, rs1, rs2, 0, false),
+            Instr::Jmp { target } => d(OP_JMP, 0, 0, 0, target as u64, false),
+            Instr::Beq { rs1, rs2, target } => d(OP_BEQ, 0, rs1, rs2, target as u64, false),
+            Instr::Halt => d(OP_HALT, 0, 0, 0, 0, false),
+        }
+    }
+}
+
+/// Machine state after a run
+pub struct VmState {
+    /// One-hot program counter
+    pub pc: Vec<Bn254Fr>,
+    pub regs: Vec<Bn254Fr>,
+    pub memory: Vec<Bn254Fr>,
+    /// 1 if the program counter points at [`Instr::Halt`]
+    pub halted: Bn254Fr,
+}
+
+/// Native mirror of the machine, used to compute witnesses
+struct Native {
+    pc: usize,
+    regs: [u32; NUM_REGS],
+    memory: Vec<u32>,
+}
+
+/// Constrained interpreter for a public program
+pub struct Vm {
+    program: Vec<Decoded>,
+}
+
+fn one_hot(len: usize, index: usize) -> Vec<Bn254Fr> {
+    (0..len).map(|i| Bn254Fr::from_u32((i == index) as u32)).collect()
+}
+
+/// Constrain `bits` to be one-hot and to encode `value`
+fn assert_one_hot(bits: &[Bn254Fr], value: &Bn254Fr) {
+    let mut sum = Bn254Fr::from_u32(0);
+    let mut index = Bn254Fr::from_u32(0);
+    for (i, b) in bits.iter().enumerate() {
+        Bn254Fr::assert_bit(b);
+        sum.addmod_checked(b);
+        let mut t = b.clone();
+        t.mulmod_constant_checked(&Bn254Fr::from_u64(i as u64));
+        index.addmod_checked(&t);
+    }
+    Bn254Fr::assert_equal(&sum, &Bn254Fr::from_u32(1));
+    Bn254Fr::assert_equal(&index, value);
+}
+
+/// Sum of `bits[i]` over the indices selected by `pred`
+fn select_sum(bits: &[Bn254Fr], mut pred: impl FnMut(usize) -> bool) -> Bn254Fr {
+    let mut out = Bn254Fr::from_u32(0);
+    for (i, b) in bits.iter().enumerate() {
+        if pred(i) {
+            out.addmod_checked(b);
+        }
+    }
+    out
+}
+
+/// Inner product of a one-hot selector with values
+fn select(sel: &[Bn254Fr], values: &[Bn254Fr]) -> Bn254Fr {
+    let mut out = Bn254Fr::from_u32(0);
+    let mut t = Bn254Fr::new();
+    for (s, v) in sel.iter().zip(values) {
+        mulmod_checked(&mut t, s, v);
+        out.addmod_checked(&t);
+    }
+    out
+}
+
+/// `x mod 2^32` for `x < 2^(32 + extra)`, with constraints
+fn wrap_word(x: &Bn254Fr, extra: usize) -> Bn254Fr {
+    let v = x.get_u64();
+    let hi = Bn254Fr::from_u64(v >> WORD_BITS);
+    let lo = Bn254Fr::from_u64(v & 0xffff_ffff);
+    let _ = hi.to_bits(extra);
+    let _ = lo.to_bits(WORD_BITS);
+
+    let mut t = hi;
+    t.mulmod_constant_checked(&Bn254Fr::from_u64(1 << WORD_BITS));
+    t.addmod_checked(&lo);
+    Bn254Fr::assert_equal(&t, x);
+    lo
+}
+
+impl Vm {
+    pub fn new(program: &[Instr]) -> Self {
+        assert!(!program.is_empty(), "Vm: empty program");
+        let program: Vec<Decoded> = program.iter().map(Instr::decode).collect();
+        for d in &program {
+            assert!(d.rd < NUM_REGS && d.rs1 < NUM_REGS && d.rs2 < NUM_REGS, "Vm: register out of range");
+        }
+        Vm { program }
+    }
+
+    /// Run for exactly `steps` steps from private initial `memory` (words
+    /// are range checked) and return the final state with constraints.
+    pub fn run(&self, memory: &[Bn254Fr], steps: usize) -> VmState {
+        assert!(!memory.is_empty(), "Vm::run: empty memory");
+        for m in memory {
+            let _ = m.to_bits(WORD_BITS);
+        }
+
+        let mut native = Native {
+            pc: 0,
+            regs: [0; NUM_REGS],
+            memory: memory.iter().map(|m| m.get_u64() as u32).collect(),
+        };
+        let mut state = VmState {
+            pc: one_hot(self.program.len(), 0),
+            regs: (0..NUM_REGS).map(|_| Bn254Fr::from_u32(0)).collect(),
+            memory: memory.to_vec(),
+            halted: Bn254Fr::from_u32(0),
+        };
+        for _ in 0..steps {
+            state = self.step(&state, &mut native);
+        }
+        state.halted = select_sum(&state.pc, |i| self.program[i].op == OP_HALT);
+        state
+    }
+
+    /// Run and assert that the program halted within `steps` steps
+    pub fn run_to_halt(&self, memory: &[Bn254Fr], steps: usize) -> VmState {
+        let state = self.run(memory, steps);
+        Bn254Fr::assert_equal(&state.halted, &Bn254Fr::from_u32(1));
+        state
+    }
+
+    fn step(&self, s: &VmState, native: &mut Native) -> VmState {
+        let prog = &self.program;
+        let cur = prog[native.pc];
+
+        // Decode from the one-hot program counter
+        let flags: Vec<Bn254Fr> = (0..NUM_OPS)
+            .map(|op| select_sum(&s.pc, |i| prog[i].op == op))
+            .collect();
+        let sel_rs1: Vec<Bn254Fr> = (0..NUM_REGS)
+            .map(|r| select_sum(&s.pc, |i| prog[i].rs1 == r))
+            .collect();
+        let sel_rs2: Vec<Bn254Fr> = (0..NUM_REGS)
+            .map(|r| select_sum(&s.pc, |i| prog[i].rs2 == r))
+            .collect();
+        let sel_rd: Vec<Bn254Fr> = (0..NUM_REGS)
+            .map(|r| select_sum(&s.pc, |i| prog[i].writes && prog[i].rd == r))
+            .collect();
+        let mut imm = Bn254Fr::from_u32(0);
+        let mut pc_val = Bn254Fr::from_u32(0);
+        for (i, b) in s.pc.iter().enumerate() {
+            let mut t = b.clone();
+            t.mulmod_constant_checked(&Bn254Fr::from_u64(prog[i].imm));
+            imm.addmod_checked(&t);
+            let mut t = b.clone();
+            t.mulmod_constant_checked(&Bn254Fr::from_u64(i as u64));
+            pc_val.addmod_checked(&t);
+        }
+
+        let a = select(&sel_rs1, &s.regs);
+        let b = select(&sel_rs2, &s.regs);
+
+        // Candidate results
+        let mut t = Bn254Fr::new();
+        addmod_checked(&mut t, &a, &b);
+        let add = wrap_word(&t, 1);
+        submod_checked(&mut t, &a, &b);
+        t.addmod_checked(&Bn254Fr::from_u64(1 << WORD_BITS));
+        let sub = wrap_word(&t, 1);
+        mulmod_checked(&mut t, &a, &b);
+        let mul = wrap_word(&t, WORD_BITS);
+        let lt = lt_checked(&a, &b, WORD_BITS);
+        let mut eq = Bn254Fr::new();
+        eq_checked(&mut eq, &a, &b);
+
+        // Memory access at address `a` on loads and stores
+        let is_mem = cur.op == OP_LOAD || cur.op == OP_STORE;
+        let addr = if is_mem { native.regs[cur.rs1] as usize } else { 0 };
+        assert!(addr < native.memory.len(), "Vm: memory access out of range");
+        let sel_addr = one_hot(s.memory.len(), addr);
+        // Other instructions select address 0
+        let mut addr_val = Bn254Fr::new();
+        addmod_checked(&mut addr_val, &flags[OP_LOAD], &flags[OP_STORE]);
+        addr_val.mulmod_checked(&a);
+        assert_one_hot(&sel_addr, &addr_val);
+        let load = select(&sel_addr, &s.memory);
+
+        let mut memory = Vec::with_capacity(s.memory.len());
+        let mut w = Bn254Fr::new();
+        let mut d = Bn254Fr::new();
+        for (sel, m) in sel_addr.iter().zip(&s.memory) {
+            mulmod_checked(&mut w, sel, &flags[OP_STORE]);
+            submod_checked(&mut d, &b, m);
+            d.mulmod_checked(&w);
+            let mut next = Bn254Fr::new();
+            addmod_checked(&mut next, m, &d);
+            memory.push(next);
+        }
+
+        // Write back
+        let candidates = [(OP_LI, &imm), (OP_ADD, &add), (OP_SUB, &sub), (OP_MUL, &mul),
+                          (OP_LT, &lt), (OP_EQ, &eq), (OP_LOAD, &load)];
+        let mut result = Bn254Fr::from_u32(0);
+        for (op, v) in candidates {
+            mulmod_checked(&mut t, &flags[op], v);
+            result.addmod_checked(&t);
+        }
+        let mut regs = Vec::with_capacity(NUM_REGS);
+        for (sel, r) in sel_rd.iter().zip(&s.regs) {
+            submod_checked(&mut d, &result, r);
+            d.mulmod_checked(sel);
+            let mut next = Bn254Fr::new();
+            addmod_checked(&mut next, r, &d);
+            regs.push(next);
+        }
+
+        // Next program counter
+        let mut seq = pc_val.clone();
+        seq.addmod_checked(&Bn254Fr::from_u32(1));
+        let mut taken = Bn254Fr::new();
+        mulmod_checked(&mut taken, &flags[OP_BEQ], &eq);
+        let mut jump = Bn254Fr::new();
+        addmod_checked(&mut jump, &flags[OP_JMP], &taken);
+
+        // next = seq + jump·(imm - seq) + halt·(pc - seq)
+        let mut next_pc = seq.clone();
+        submod_checked(&mut d, &imm, &seq);
+        d.mulmod_checked(&jump);
+        next_pc.addmod_checked(&d);
+        submod_checked(&mut d, &pc_val, &seq);
+        d.mulmod_checked(&flags[OP_HALT]);
+        next_pc.addmod_checked(&d);
+
+        // Advance the native machine
+        let (ra, rb) = (native.regs[cur.rs1], native.regs[cur.rs2]);
+        let value = match cur.op {
+            OP_LI => cur.imm as u32,
+            OP_ADD => ra.wrapping_add(rb),
+            OP_SUB => ra.wrapping_sub(rb),
+            OP_MUL => ra.wrapping_mul(rb),
+            OP_LT => (ra < rb) as u32,
+            OP_EQ => (ra == rb) as u32,
+            OP_LOAD => native.memory[addr],
+            _ => 0,
+        };
+        if cur.op == OP_STORE {
+            native.memory[addr] = rb;
+        }
+        if cur.writes {
+            native.regs[cur.rd] = value;
+        }
+        native.pc = match cur.op {
+            OP_JMP => cur.imm as usize,
+            OP_BEQ if ra == rb => cur.imm as usize,
+            OP_HALT => native.pc,
+            _ => native.pc + 1,
+        };
+        assert!(native.pc < prog.len(), "Vm: program counter out of range");
+
+        let pc = one_hot(prog.len(), native.pc);
+        assert_one_hot(&pc, &next_pc);
+
+        VmState { pc, regs, memory, halted: Bn254Fr::from_u32(0) }
+    }
+}